@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::owl::{AnnotationAssertion, Axiom, Declaration, IRIBuilder, ResourceId, IRI};
+use crate::rdf::Triple;
 
 #[cfg(feature = "wasm")]
 #[wasm_bindgen::prelude::wasm_bindgen]
@@ -10,6 +11,14 @@ pub struct Ontology {
     pub(crate) iri: IRI,
     pub(crate) imports: HashMap<String, IRI>,
     pub(crate) owl: crate::owl::Ontology,
+    #[serde(default)]
+    pub(crate) unmapped: Vec<Triple>,
+    #[serde(default)]
+    pub(crate) base: Option<IRI>,
+    #[serde(default)]
+    pub(crate) prefixes: Vec<(String, IRI)>,
+    #[serde(default)]
+    pub(crate) comments: Vec<(IRI, String)>,
 }
 
 #[cfg(not(feature = "wasm"))]
@@ -18,6 +27,14 @@ pub struct Ontology {
     pub(crate) iri: IRI,
     pub(crate) imports: HashMap<String, IRI>,
     pub(crate) owl: crate::owl::Ontology,
+    #[serde(default)]
+    pub(crate) unmapped: Vec<Triple>,
+    #[serde(default)]
+    pub(crate) base: Option<IRI>,
+    #[serde(default)]
+    pub(crate) prefixes: Vec<(String, IRI)>,
+    #[serde(default)]
+    pub(crate) comments: Vec<(IRI, String)>,
 }
 
 impl Ontology {
@@ -27,6 +44,10 @@ impl Ontology {
             iri,
             imports: Default::default(),
             owl: crate::owl::Ontology::new(vec![], vec![]),
+            unmapped: Default::default(),
+            base: None,
+            prefixes: Default::default(),
+            comments: Default::default(),
         }
     }
 
@@ -45,6 +66,46 @@ impl Ontology {
         self.imports.insert(name.into(), iri)
     }
 
+    /// Get the base IRI (`@base`) of the document this ontology was parsed from.
+    pub fn base(&self) -> Option<&IRI> {
+        self.base.as_ref()
+    }
+
+    /// Set the base IRI which is stated when the ontology is serialized to turtle.
+    pub fn set_base(&mut self, base: Option<IRI>) {
+        self.base = base
+    }
+
+    /// Get the prefixes of the document this ontology was parsed from, in the order they were
+    /// declared. The turtle serialization declares them in this order, before the imports.
+    pub fn prefixes(&self) -> &Vec<(String, IRI)> {
+        &self.prefixes
+    }
+
+    /// push the given iri with name as prefix.
+    /// If a prefix with this name already existed it keeps its position and the old iri is returned.
+    pub fn push_prefix(&mut self, name: &str, iri: IRI) -> Option<IRI> {
+        match self.prefixes.iter_mut().find(|(n, _)| n == name) {
+            Some((_, existing)) => Some(std::mem::replace(existing, iri)),
+            None => {
+                self.prefixes.push((name.into(), iri));
+                None
+            }
+        }
+    }
+
+    /// Get the comments of the document this ontology was parsed from together with the subject
+    /// they were written in front of (see [`crate::parser::ParserOptionsBuilder::comments`]).
+    pub fn comments(&self) -> &Vec<(IRI, String)> {
+        &self.comments
+    }
+
+    /// Push a comment which is written in front of the statements about the given subject when
+    /// the ontology is serialized to turtle. Lines of the comment don't start with `#`.
+    pub fn push_comment(&mut self, subject: IRI, comment: &str) {
+        self.comments.push((subject, comment.into()))
+    }
+
     /// Get a IRIBuilder to create new iris based on imports for this ontology.
     pub fn iri_builder(&self) -> IRIBuilder {
         IRIBuilder::construct(self.iri.clone(), &self.imports)
@@ -60,6 +121,11 @@ impl Ontology {
         &self.owl.axioms
     }
 
+    /// Get all triples of the parsed document which are not part of any OWL construct.
+    pub fn unmapped_triples(&self) -> &Vec<Triple> {
+        &self.unmapped
+    }
+
     /// Finds all annotations assertions for a given `ResourceId`.
     pub fn annotation_assertions_for_resource_id(
         &self,
@@ -131,6 +197,11 @@ impl Ontology {
     pub fn push_declaration(&mut self, declaration: Declaration) {
         self.owl.declarations.push(declaration)
     }
+
+    /// Push the given triple which is not part of any OWL construct to this ontology
+    pub fn push_unmapped_triple(&mut self, triple: Triple) {
+        self.unmapped.push(triple)
+    }
 }
 
 impl From<(IRI, crate::owl::Ontology)> for Ontology {
@@ -139,6 +210,10 @@ impl From<(IRI, crate::owl::Ontology)> for Ontology {
             iri,
             imports: Default::default(),
             owl,
+            unmapped: Default::default(),
+            base: None,
+            prefixes: Default::default(),
+            comments: Default::default(),
         }
     }
 }
@@ -1,12 +1,17 @@
 #![allow(non_snake_case)]
 
-use crate::owl::{AnnotationAssertion, Axiom, Literal, ResourceId as OwlResourceId};
+use crate::owl::{
+    AnnotationAssertion, Axiom as OwlAxiom, ClassConstructor as OwlClassConstructor, Literal,
+    ResourceId as OwlResourceId,
+};
 
 use super::Ontology;
 use js_sys::{Array, Number, JSON};
 use wasm_bindgen::{prelude::*, JsCast};
 use web_sys::console::error_1;
 use crate::computation::GetComputations;
+use crate::manchester::Manchester;
+use crate::jsonld::ToJsonLd;
 
 #[wasm_bindgen]
 impl Ontology {
@@ -22,6 +27,9 @@ impl Ontology {
         for a in other.owl.axioms {
             self.owl.axioms.push(a);
         }
+        for t in other.unmapped {
+            self.unmapped.push(t);
+        }
         for (key, import) in other.imports {
             if !self.imports.contains_key(&key) {
                 self.imports.insert(key, import);
@@ -31,36 +39,32 @@ impl Ontology {
 
     /// Create an ontology based on a turtle formatted string.
     pub fn parseTurtle(ttl: String, options: ParserOptions) -> Option<Ontology> {
-        match js_sys::JSON::stringify(&options) {
-            Ok(json) => match json.as_string() {
-                Some(json) => match serde_json::from_str(&json) {
-                    Ok(options) => match Ontology::parse(&ttl, options) {
-                        Ok(o) => Some(o),
-                        Err(e) => {
-                            error_1(&format!("Failed to parse ontology: {:?}", e).into());
-                            None
-                        }
-                    },
-                    Err(e) => {
-                        error_1(&format!("Invalid parser options: {}", e).into());
-                        None
-                    }
-                },
-                None => {
-                    error_1(
-                        &format!("Invalid parser options: Could not stringify provided object")
-                            .into(),
-                    );
-                    None
-                }
-            },
+        match Ontology::parse(&ttl, parser_options(&options)?) {
+            Ok(o) => Some(o),
             Err(e) => {
-                error_1(&format!("Invalid parser options: {:?}", e).into());
+                error_1(&format!("Failed to parse ontology: {}", e).into());
                 None
             }
         }
     }
 
+    /// Create an ontology based on a JSON-LD document.
+    pub fn parseJsonLd(jsonld: String, options: ParserOptions) -> Option<Ontology> {
+        match Ontology::parse_jsonld(&jsonld, parser_options(&options)?) {
+            Ok(o) => Some(o),
+            Err(e) => {
+                error_1(&format!("Failed to parse ontology: {}", e).into());
+                None
+            }
+        }
+    }
+
+    /// Serializes this ontology to a JSON-LD document.
+    #[wasm_bindgen(js_name = "toJsonLd")]
+    pub fn wasm_to_jsonld(&self) -> String {
+        self.jsonld()
+    }
+
     /// Create an ontology based on a json serialized owlish::Ontology.
     pub fn parse_json(json: String) -> Option<Ontology> {
         serde_json::from_str(&json).ok()
@@ -115,6 +119,73 @@ impl Ontology {
         array.unchecked_into()
     }
 
+    /// Get all triples of the parsed document which are not part of any OWL construct.
+    #[wasm_bindgen(js_name = "unmappedTriples")]
+    pub fn wasm_unmapped_triples(&self) -> RdfTripleArray {
+        let array = Array::new();
+        for t in self.unmapped_triples() {
+            if let Ok(s) = serde_json::to_string(&t) {
+                if let Ok(value) = JSON::parse(&s) {
+                    array.push(&value);
+                }
+            }
+        }
+        array.unchecked_into()
+    }
+
+    /// Renders a class expression in Manchester Syntax, e.g. `Person and (hasChild some Doctor)`.
+    #[wasm_bindgen(js_name = "renderManchester")]
+    pub fn wasm_render_manchester(
+        &self,
+        cls: &ClassConstructor,
+        useLabels: bool,
+    ) -> Option<String> {
+        let json = JSON::stringify(cls).ok()?.as_string()?;
+        match serde_json::from_str::<OwlClassConstructor>(&json) {
+            Ok(cls) => Some(
+                Manchester::new(self)
+                    .use_labels(useLabels)
+                    .render_class(&cls),
+            ),
+            Err(e) => {
+                error_1(&format!("Invalid class constructor: {}", e).into());
+                None
+            }
+        }
+    }
+
+    /// Renders an axiom in Manchester Syntax, e.g. `Man SubClassOf Person`.
+    #[wasm_bindgen(js_name = "renderAxiomManchester")]
+    pub fn wasm_render_axiom_manchester(&self, axiom: &Axiom, useLabels: bool) -> Option<String> {
+        let json = JSON::stringify(axiom).ok()?.as_string()?;
+        match serde_json::from_str::<OwlAxiom>(&json) {
+            Ok(axiom) => Some(
+                Manchester::new(self)
+                    .use_labels(useLabels)
+                    .render_axiom(&axiom),
+            ),
+            Err(e) => {
+                error_1(&format!("Invalid axiom: {}", e).into());
+                None
+            }
+        }
+    }
+
+    /// Parses a class expression in Manchester Syntax against the declarations of this ontology.
+    #[wasm_bindgen(js_name = "parseManchester")]
+    pub fn wasm_parse_manchester(&self, manchester: &str) -> Option<ClassConstructor> {
+        match Manchester::new(self).parse_class(manchester) {
+            Ok(cls) => {
+                let s = serde_json::to_string(&cls).ok()?;
+                JSON::parse(&s).ok().map(|v| v.into())
+            }
+            Err(e) => {
+                error_1(&format!("Failed to parse class expression: {}", e).into());
+                None
+            }
+        }
+    }
+
     #[wasm_bindgen(js_name = "annotationsForResourceId")]
     pub fn wasm_annotations_for_resource_id(&self, resource_id: &ResourceId) -> AnnotationAssertionArray {
         let owl_resource_id: OwlResourceId = serde_json::from_str(&JSON::stringify(resource_id).unwrap().as_string().unwrap()).unwrap();
@@ -142,6 +213,12 @@ extern "C" {
 
     #[wasm_bindgen(typescript_type = "Array<AnnotationAssertion>")]
     pub type AnnotationAssertionArray;
+    #[wasm_bindgen(typescript_type = "Array<RdfTriple>")]
+    pub type RdfTripleArray;
+    #[wasm_bindgen(typescript_type = "Axiom")]
+    pub type Axiom;
+    #[wasm_bindgen(typescript_type = "ClassConstructor")]
+    pub type ClassConstructor;
 }
 
 #[wasm_bindgen(typescript_custom_section)]
@@ -162,6 +239,7 @@ interface Axiom {
     EquivalentDataProperties?: EquivalentDataProperties
     InverseObjectProperties?: InverseObjectProperties
     DisjointObjectProperties?: DisjointObjectProperties
+    DisjointDataProperties?: DisjointDataProperties
     ObjectPropertyDomain?: ObjectPropertyDomain
     ObjectPropertyRange?: ObjectPropertyRange
     DataPropertyDomain?: DataPropertyDomain
@@ -177,6 +255,7 @@ interface Axiom {
     SubClassOf?: SubClassOf
     EquivalentClasses?: EquivalentClasses
     DisjointClasses?: DisjointClasses
+    DisjointUnion?: DisjointUnion
     DatatypeDefinition?: DatatypeDefinition
     ClassAssertion?: ClassAssertion
     SameIndividual?: SameIndividual
@@ -200,6 +279,7 @@ export interface AxiomMatcher<R> {
     EquivalentDataProperties?: (a: EquivalentDataProperties) => R
     InverseObjectProperties?: (a: InverseObjectProperties) => R
     DisjointObjectProperties?: (a: DisjointObjectProperties) => R
+    DisjointDataProperties?: (a: DisjointDataProperties) => R
     ObjectPropertyDomain?: (a: ObjectPropertyDomain) => R
     ObjectPropertyRange?: (a: ObjectPropertyRange) => R
     DataPropertyDomain?: (a: DataPropertyDomain) => R
@@ -215,6 +295,7 @@ export interface AxiomMatcher<R> {
     SubClassOf?: (a: SubClassOf) => R
     EquivalentClasses?: (a: EquivalentClasses) => R
     DisjointClasses?: (a: DisjointClasses) => R
+    DisjointUnion?: (a: DisjointUnion) => R
     DatatypeDefinition?: (a: DatatypeDefinition) => R
     ClassAssertion?: (a: ClassAssertion) => R
     SameIndividual?: (a: SameIndividual) => R
@@ -430,6 +511,30 @@ extern "C" {
 
 }
 
+fn parser_options(options: &ParserOptions) -> Option<crate::parser::ParserOptions> {
+    match js_sys::JSON::stringify(options) {
+        Ok(json) => match json.as_string() {
+            Some(json) => match serde_json::from_str(&json) {
+                Ok(options) => Some(options),
+                Err(e) => {
+                    error_1(&format!("Invalid parser options: {}", e).into());
+                    None
+                }
+            },
+            None => {
+                error_1(
+                    &format!("Invalid parser options: Could not stringify provided object").into(),
+                );
+                None
+            }
+        },
+        Err(e) => {
+            error_1(&format!("Invalid parser options: {:?}", e).into());
+            None
+        }
+    }
+}
+
 #[wasm_bindgen]
 pub fn Iri(iri: &str) -> Option<IRI> {
     match crate::owl::IRI::new(iri) {
@@ -570,6 +675,9 @@ impl well_known {
     pub fn owl_DatatypeProperty() -> IRI {
         iri_to_js_iri(&crate::owl::well_known::owl_DatatypeProperty()).unwrap()
     }
+    pub fn owl_hasValue() -> IRI {
+        iri_to_js_iri(&crate::owl::well_known::owl_hasValue()).unwrap()
+    }
     pub fn owl_hasSelf() -> IRI {
         iri_to_js_iri(&crate::owl::well_known::owl_hasSelf()).unwrap()
     }
@@ -588,6 +696,9 @@ impl well_known {
     pub fn owl_maxQualifiedCardinality() -> IRI {
         iri_to_js_iri(&crate::owl::well_known::owl_maxQualifiedCardinality()).unwrap()
     }
+    pub fn owl_minCardinality() -> IRI {
+        iri_to_js_iri(&crate::owl::well_known::owl_minCardinality()).unwrap()
+    }
     pub fn owl_minQualifiedCardinality() -> IRI {
         iri_to_js_iri(&crate::owl::well_known::owl_minQualifiedCardinality()).unwrap()
     }
@@ -619,6 +730,9 @@ impl well_known {
     pub fn rdf_type() -> IRI {
         iri_to_js_iri(crate::owl::well_known::rdf_type().as_iri()).unwrap()
     }
+    pub fn rdf_langRange() -> IRI {
+        iri_to_js_iri(crate::owl::well_known::rdf_langRange().as_iri()).unwrap()
+    }
     pub fn rdfs_comment() -> IRI {
         iri_to_js_iri(crate::owl::well_known::rdfs_comment().as_iri()).unwrap()
     }
@@ -656,6 +770,24 @@ impl well_known {
     pub fn xsd_minInclusive() -> IRI {
         iri_to_js_iri(crate::owl::well_known::xsd_minInclusive().as_iri()).unwrap()
     }
+    pub fn xsd_length() -> IRI {
+        iri_to_js_iri(crate::owl::well_known::xsd_length().as_iri()).unwrap()
+    }
+    pub fn xsd_minLength() -> IRI {
+        iri_to_js_iri(crate::owl::well_known::xsd_minLength().as_iri()).unwrap()
+    }
+    pub fn xsd_maxLength() -> IRI {
+        iri_to_js_iri(crate::owl::well_known::xsd_maxLength().as_iri()).unwrap()
+    }
+    pub fn xsd_pattern() -> IRI {
+        iri_to_js_iri(crate::owl::well_known::xsd_pattern().as_iri()).unwrap()
+    }
+    pub fn xsd_totalDigits() -> IRI {
+        iri_to_js_iri(crate::owl::well_known::xsd_totalDigits().as_iri()).unwrap()
+    }
+    pub fn xsd_fractionDigits() -> IRI {
+        iri_to_js_iri(crate::owl::well_known::xsd_fractionDigits().as_iri()).unwrap()
+    }
     pub fn xsd_nonNegativeInteger() -> IRI {
         iri_to_js_iri(crate::owl::well_known::xsd_nonNegativeInteger().as_iri()).unwrap()
     }
@@ -734,7 +866,24 @@ pub fn NonNegativeNumericValue(value: Number) -> Option<Value> {
 #[wasm_bindgen(typescript_custom_section)]
 const PARSER_OPTIONS_TS_API: &'static str = r#"
 interface ParserOptions {
-    known: Array<Declaration>
+    known: Array<Declaration>,
+    lenient?: boolean,
+    comments?: boolean
+}
+"#;
+
+#[wasm_bindgen(typescript_custom_section)]
+const RDF_TRIPLE_TS_API: &'static str = r#"
+interface RdfTriple {
+    subject: ResourceId,
+    predicate: IRI,
+    object: { IRI: IRI } | { BlankNode: { _type: "BlankNode", inner: unknown } } | { Literal: RdfLiteral }
+}
+
+interface RdfLiteral {
+    lexical_form: string,
+    datatype: IRI | null,
+    lang: string | null
 }
 "#;
 
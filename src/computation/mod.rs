@@ -36,7 +36,9 @@ impl GetComputations for Ontology {
                 annotations: _,
             }) = axiom
             {
-                let individual = individual.as_iri();
+                let Some(individual) = individual.as_iri() else {
+                    continue;
+                };
                 if let ClassConstructor::IRI(iri) = cls {
                     let iri = iri.as_iri();
                     if iri == &well_known::fno_Function() {
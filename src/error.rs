@@ -1,8 +1,84 @@
 use std::fmt::Display;
 
-#[derive(Debug)]
+use serde::{Deserialize, Serialize};
+
+/// The category of an [`Error`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The input is not syntactically valid.
+    Syntax,
+    /// The input is valid but uses a construct that is not (yet) supported.
+    UnsupportedConstruct,
+    /// An IRI could not be parsed.
+    InvalidIri,
+    /// A literal could not be parsed or converted.
+    InvalidLiteral,
+    /// An RDF list (`rdf:first`/`rdf:rest`) is referenced but never defined or not terminated.
+    DanglingList,
+    /// The same IRI is used in roles that exclude each other (e.g. as data and object property).
+    TypePunningConflict,
+    /// A blank node refers (directly or indirectly) to itself.
+    CyclicBlankNode,
+    /// The ontology does not declare an IRI.
+    MissingOntologyIri,
+    /// A name does not refer to any (suitable) entity of the ontology.
+    UnknownEntity,
+    /// Any other error.
+    Other,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ErrorKind::Syntax => "syntax error",
+            ErrorKind::UnsupportedConstruct => "unsupported construct",
+            ErrorKind::InvalidIri => "invalid IRI",
+            ErrorKind::InvalidLiteral => "invalid literal",
+            ErrorKind::DanglingList => "dangling list",
+            ErrorKind::TypePunningConflict => "type punning conflict",
+            ErrorKind::CyclicBlankNode => "cyclic blank node",
+            ErrorKind::MissingOntologyIri => "missing ontology IRI",
+            ErrorKind::UnknownEntity => "unknown entity",
+            ErrorKind::Other => "error",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A 1-based line and column in the parsed source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    /// Computes the position of the byte `offset` in `source`.
+    pub fn from_offset(source: &str, offset: usize) -> Self {
+        let mut offset = offset.min(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+        Self { line, column }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Error {
+    kind: ErrorKind,
     message: String,
+    triple: Option<String>,
+    position: Option<Position>,
 }
 
 impl std::error::Error for Error {
@@ -13,20 +89,62 @@ impl std::error::Error for Error {
 
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message)
+        write!(f, "{}: {}", self.kind, self.message)?;
+        if let Some(position) = &self.position {
+            write!(f, " (at {})", position)?;
+        }
+        if let Some(triple) = &self.triple {
+            write!(f, " in triple {}", triple)?;
+        }
+        Ok(())
     }
 }
 
 impl Error {
     pub fn new(message: String) -> Self {
-        Self { message }
+        Self::with_kind(ErrorKind::Other, message)
+    }
+
+    pub fn with_kind(kind: ErrorKind, message: String) -> Self {
+        Self {
+            kind,
+            message,
+            triple: None,
+            position: None,
+        }
+    }
+
+    /// Attaches the offending triple, unless one is already attached.
+    pub fn at_triple(mut self, triple: String) -> Self {
+        self.triple.get_or_insert(triple);
+        self
+    }
+
+    /// Attaches the source position, unless one is already attached.
+    pub fn at_position(mut self, position: Position) -> Self {
+        self.position.get_or_insert(position);
+        self
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn triple(&self) -> Option<&str> {
+        self.triple.as_deref()
+    }
+
+    pub fn position(&self) -> Option<Position> {
+        self.position
     }
 }
 
 impl From<iref::Error> for Error {
     fn from(e: iref::Error) -> Self {
-        Error {
-            message: e.to_string(),
-        }
+        Error::with_kind(ErrorKind::InvalidIri, e.to_string())
     }
 }
@@ -229,8 +229,7 @@ pub fn family() -> ApiOntology {
                 vec![],
             )),
             Axiom::DisjointObjectProperties(DisjointObjectProperties::new(
-                iri.new("hasSon"),
-                iri.new("hasDaughter"),
+                vec![iri.new("hasSon"), iri.new("hasDaughter")],
                 vec![],
             )),
             Axiom::ObjectPropertyDomain(ObjectPropertyDomain::new(
@@ -263,8 +262,7 @@ pub fn family() -> ApiOntology {
                 vec![],
             )),
             Axiom::DisjointObjectProperties(DisjointObjectProperties::new(
-                iri.new("hasParent"),
-                iri.new("hasSpouse"),
+                vec![iri.new("hasParent"), iri.new("hasSpouse")],
                 vec![],
             )),
             Axiom::ReflexiveObjectProperty(ReflexiveObjectProperty::new(
@@ -315,14 +313,8 @@ pub fn family() -> ApiOntology {
                     DatatypeRestriction::new(
                         wk::xsd_integer(),
                         vec![
-                            Restriction::Numeric {
-                                datatype_iri: wk::xsd_minExclusive(),
-                                value: Literal::from(12u8),
-                            },
-                            Restriction::Numeric {
-                                datatype_iri: wk::xsd_maxInclusive(),
-                                value: Literal::from(19u8),
-                            },
+                            Restriction::MinExclusive(Literal::from(12u8)),
+                            Restriction::MaxInclusive(Literal::from(19u8)),
                         ],
                         vec![],
                     ),
@@ -357,7 +349,7 @@ pub fn family() -> ApiOntology {
                         ObjectComplementOf::new(
                             ObjectSomeValuesFrom::new(
                                 ObjectInverseOf(iri.new("hasParent")).into(),
-                                wk::owl_Thing(),
+                                wk::owl_Thing().into(),
                                 vec![],
                             )
                             .into(),
@@ -439,7 +431,7 @@ pub fn family() -> ApiOntology {
                 iri.class("Parent"),
                 ObjectSomeValuesFrom::new(
                     iri.new::<ObjectPropertyIRI>("hasChild").into(),
-                    iri.class("Person"),
+                    iri.class("Person").into(),
                     vec![],
                 )
                 .into(),
@@ -451,13 +443,13 @@ pub fn family() -> ApiOntology {
                     vec![
                         ObjectAllValuesFrom::new(
                             iri.new::<ObjectPropertyIRI>("hasChild").into(),
-                            iri.class("HappyPerson"),
+                            iri.class("HappyPerson").into(),
                             vec![],
                         )
                         .into(),
                         ObjectSomeValuesFrom::new(
                             iri.new::<ObjectPropertyIRI>("hasChild").into(),
-                            iri.class("HappyPerson"),
+                            iri.class("HappyPerson").into(),
                             vec![],
                         )
                         .into(),
@@ -495,7 +487,7 @@ pub fn family() -> ApiOntology {
                 iri.class("Orphan"),
                 ObjectAllValuesFrom::new(
                     ObjectInverseOf(iri.new("hasChild")).into(),
-                    iri.class("Dead"),
+                    iri.class("Dead").into(),
                     vec![],
                 )
                 .into(),
@@ -510,7 +502,7 @@ pub fn family() -> ApiOntology {
                 iri.class("Parent"),
                 ObjectSomeValuesFrom::new(
                     iri.new::<ObjectPropertyIRI>("hasChild").into(),
-                    iri.class("Person"),
+                    iri.class("Person").into(),
                     vec![],
                 )
                 .into(),
@@ -529,7 +521,8 @@ pub fn family() -> ApiOntology {
                 vec![],
             )),
             Axiom::HasKey(HasKey::new(
-                iri.class("Person"),
+                iri.class("Person").into(),
+                vec![],
                 vec![iri.new("hasSSN")],
                 vec![],
             )),
@@ -539,14 +532,8 @@ pub fn family() -> ApiOntology {
                 DatatypeRestriction::new(
                     wk::xsd_integer(),
                     vec![
-                        Restriction::Numeric {
-                            datatype_iri: wk::xsd_minInclusive(),
-                            value: Literal::from(0u8),
-                        },
-                        Restriction::Numeric {
-                            datatype_iri: wk::xsd_maxInclusive(),
-                            value: Literal::from(150u8),
-                        },
+                        Restriction::MinInclusive(Literal::from(0u8)),
+                        Restriction::MaxInclusive(Literal::from(150u8)),
                     ],
                     vec![],
                 )
@@ -558,14 +545,8 @@ pub fn family() -> ApiOntology {
                 DatatypeRestriction::new(
                     wk::xsd_integer(),
                     vec![
-                        Restriction::Numeric {
-                            datatype_iri: wk::xsd_minExclusive(),
-                            value: Literal::from(0u8),
-                        },
-                        Restriction::Numeric {
-                            datatype_iri: wk::xsd_maxInclusive(),
-                            value: Literal::from(18u8),
-                        },
+                        Restriction::MinExclusive(Literal::from(0u8)),
+                        Restriction::MaxInclusive(Literal::from(18u8)),
                     ],
                     vec![],
                 )
@@ -611,20 +592,24 @@ pub fn family() -> ApiOntology {
                 vec![],
             )),
             Axiom::ClassAssertion(ClassAssertion::new(
-                ObjectMaxCardinality::new(4, iri.new("hasChild"), iri.class("Parent").into())
+                ObjectMaxCardinality::new(4, iri.new("hasChild"), Some(iri.class("Parent").into()))
                     .into(),
                 iri.new("John"),
                 vec![],
             )),
             Axiom::ClassAssertion(ClassAssertion::new(
-                ObjectMinCardinality::new(2, iri.new("hasChild"), iri.class("Parent").into())
+                ObjectMinCardinality::new(2, iri.new("hasChild"), Some(iri.class("Parent").into()))
                     .into(),
                 iri.new("john"),
                 vec![],
             )),
             Axiom::ClassAssertion(ClassAssertion::new(
-                ObjectExactCardinality::new(3, iri.new("hasChild"), iri.class("Parent").into())
-                    .into(),
+                ObjectExactCardinality::new(
+                    3,
+                    iri.new("hasChild"),
+                    Some(iri.class("Parent").into()),
+                )
+                .into(),
                 iri.new("john"),
                 vec![],
             )),
@@ -677,23 +662,19 @@ pub fn family() -> ApiOntology {
                 vec![],
             )),
             Axiom::SameIndividual(SameIndividual::new(
-                iri.new("James"),
-                iri.new("Jim"),
+                vec![iri.new("James"), iri.new("Jim")],
                 vec![],
             )),
             Axiom::SameIndividual(SameIndividual::new(
-                iri.new("John"),
-                other_ont.new("JohnBrown"),
+                vec![iri.new("John"), other_ont.new("JohnBrown")],
                 vec![],
             )),
             Axiom::SameIndividual(SameIndividual::new(
-                iri.new("Mary"),
-                other_ont.new("MaryBrown"),
+                vec![iri.new("Mary"), other_ont.new("MaryBrown")],
                 vec![],
             )),
             Axiom::DifferentIndividuals(DifferentIndividuals::new(
-                iri.new("John"),
-                iri.new("Bill"),
+                vec![iri.new("John"), iri.new("Bill")],
                 vec![],
             )),
         ],
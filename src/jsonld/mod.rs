@@ -0,0 +1,31 @@
+//! Reading and writing ontologies in [JSON-LD](https://www.w3.org/TR/json-ld11/).
+//!
+//! Documents are expanded with their (local) contexts and turned into triples directly, the
+//! JSON-LD processing algorithms are not implemented in full: remote contexts, `@reverse` and
+//! named graphs other than the default graph are not supported.
+//!
+//! Written documents consist of an `@context`, which declares the well-known prefixes and the
+//! prefixes of the ontology's imports, and a `@graph` with a node object per subject. Blank
+//! nodes which are used only once are embedded into the node referencing them, RDF lists are
+//! written as `@list`.
+//!
+//! ```rust
+//! use owlish::{api::Ontology, jsonld::ToJsonLd};
+//!
+//! let ontology = Ontology::parse(r#"
+//!     @prefix owl: <http://www.w3.org/2002/07/owl#> .
+//!     <http://example.com#> a owl:Ontology .
+//!     <http://example.com#Person> a owl:Class .
+//! "#, Default::default()).unwrap();
+//!
+//! let json = ontology.jsonld();
+//! assert!(json.contains(r#""@id": "http://example.com#Person""#));
+//!
+//! let parsed = Ontology::parse_jsonld(&json, Default::default()).unwrap();
+//! assert_eq!(parsed.declarations(), ontology.declarations());
+//! ```
+
+pub(crate) mod parser;
+pub(crate) mod serializer;
+
+pub use serializer::ToJsonLd;
@@ -0,0 +1,469 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use harriet::triple_production::{
+    RdfBlankNode, RdfIri, RdfLiteral, RdfObject, RdfPredicate, RdfSubject, RdfTriple,
+};
+use serde_json::{Map, Value};
+use snowflake::ProcessUniqueId;
+
+use crate::{
+    api::Ontology,
+    error::{Error, ErrorKind, Position},
+    owl::well_known,
+    parser::{source::SourceMap, Diagnostic, ParserOptions},
+    rdf_xml::parser::resolve,
+};
+
+impl Ontology {
+    /// Parses an ontology from a JSON-LD document.
+    pub fn parse_jsonld(json: &str, options: ParserOptions) -> Result<Self, Error> {
+        Self::parse_jsonld_with_diagnostics(json, options).map(|(ontology, _)| ontology)
+    }
+
+    /// Parses an ontology from a JSON-LD document and reports everything that could not be
+    /// understood (see [`Ontology::parse_with_diagnostics`]).
+    pub fn parse_jsonld_with_diagnostics(
+        json: &str,
+        options: ParserOptions,
+    ) -> Result<(Self, Vec<Diagnostic>), Error> {
+        let document: Value = serde_json::from_str(json).map_err(|e| {
+            Error::with_kind(ErrorKind::Syntax, format!("Invalid JSON: {}", e)).at_position(
+                Position {
+                    line: e.line(),
+                    column: e.column(),
+                },
+            )
+        })?;
+
+        let mut reader = Reader {
+            triples: Vec::new(),
+            blank_nodes: HashMap::new(),
+        };
+        let context = match &document {
+            Value::Object(o) => match o.get("@context") {
+                Some(context) => Context::default().extend(context)?,
+                None => Context::default(),
+            },
+            _ => Context::default(),
+        };
+        match &document {
+            Value::Array(nodes) => {
+                for node in nodes {
+                    reader.top_level_node(node, &context)?;
+                }
+            }
+            // an object with a graph only wraps the nodes of the document
+            Value::Object(o) if o.keys().all(|k| k == "@context" || k == "@graph") => {
+                for node in o.get("@graph").map(as_array).unwrap_or_default() {
+                    reader.top_level_node(node, &context)?;
+                }
+            }
+            Value::Object(o) => {
+                reader.node(o, &context)?;
+            }
+            _ => return Err(syntax_error("Expected a JSON-LD object or array".into())),
+        }
+
+        let prefixes = context
+            .terms
+            .iter()
+            .filter_map(|(name, term)| Some((name.clone(), context.expand_term(term, 0)?)))
+            .collect();
+        let source_map = SourceMap::for_triples(vec![None; reader.triples.len()]);
+        Self::from_triples(reader.triples, prefixes, &source_map, options)
+    }
+}
+
+/// The active context of a node: term definitions and the defaults which apply to them.
+#[derive(Debug, Clone, Default)]
+struct Context {
+    terms: HashMap<String, Term>,
+    vocab: Option<String>,
+    base: Option<String>,
+    language: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Term {
+    /// The (possibly compact) IRI the term stands for.
+    id: String,
+    /// `@id`, `@vocab` or the datatype of values.
+    coerce: Option<String>,
+    list: bool,
+}
+
+impl Context {
+    /// Applies a local context.
+    fn extend(&self, local: &Value) -> Result<Self, Error> {
+        let mut context = self.clone();
+        match local {
+            Value::Null => context = Context::default(),
+            Value::Array(contexts) => {
+                for local in contexts {
+                    context = context.extend(local)?;
+                }
+            }
+            Value::String(url) => {
+                return Err(Error::with_kind(
+                    ErrorKind::UnsupportedConstruct,
+                    format!("Remote context '{}' is not supported", url),
+                ))
+            }
+            Value::Object(definitions) => {
+                for (key, definition) in definitions {
+                    match (key.as_str(), definition) {
+                        ("@vocab", Value::String(vocab)) => {
+                            context.vocab = Some(vocab.clone());
+                        }
+                        ("@base", Value::String(base)) => context.base = Some(base.clone()),
+                        ("@language", Value::String(language)) => {
+                            context.language = Some(language.clone());
+                        }
+                        ("@vocab" | "@base" | "@language", Value::Null) => match key.as_str() {
+                            "@vocab" => context.vocab = None,
+                            "@base" => context.base = None,
+                            _ => context.language = None,
+                        },
+                        (key, _) if key.starts_with('@') => {}
+                        (key, Value::Null) => {
+                            context.terms.remove(key);
+                        }
+                        (key, Value::String(id)) => {
+                            context.terms.insert(
+                                key.to_string(),
+                                Term {
+                                    id: id.clone(),
+                                    coerce: None,
+                                    list: false,
+                                },
+                            );
+                        }
+                        (key, Value::Object(definition)) => {
+                            let id = match definition.get("@id") {
+                                Some(Value::String(id)) => id.clone(),
+                                _ => key.to_string(),
+                            };
+                            let coerce = match definition.get("@type") {
+                                Some(Value::String(t)) => Some(t.clone()),
+                                _ => None,
+                            };
+                            let list = definition.get("@container")
+                                == Some(&Value::String("@list".into()));
+                            context
+                                .terms
+                                .insert(key.to_string(), Term { id, coerce, list });
+                        }
+                        (key, _) => {
+                            return Err(syntax_error(format!(
+                                "Invalid definition of the term '{}'",
+                                key
+                            )))
+                        }
+                    }
+                }
+            }
+            _ => return Err(syntax_error("Invalid context".into())),
+        }
+        Ok(context)
+    }
+
+    fn expand_term(&self, term: &Term, depth: usize) -> Option<String> {
+        // terms may be defined using other terms, but not cyclically
+        if depth > 8 {
+            return None;
+        }
+        match term.id.split_once(':') {
+            Some((prefix, suffix)) if !suffix.starts_with("//") => match self.terms.get(prefix) {
+                Some(p) if p.id != term.id => {
+                    Some(format!("{}{}", self.expand_term(p, depth + 1)?, suffix))
+                }
+                _ => Some(term.id.clone()),
+            },
+            Some(_) => Some(term.id.clone()),
+            None => match self.terms.get(&term.id) {
+                Some(t) if t.id != term.id => self.expand_term(t, depth + 1),
+                _ => self
+                    .vocab
+                    .as_ref()
+                    .map(|vocab| format!("{}{}", vocab, term.id)),
+            },
+        }
+    }
+
+    /// Expands a term, compact IRI or IRI. Keys and types are relative to the vocabulary,
+    /// identifiers to the base IRI of the document.
+    fn expand_iri(&self, value: &str, relative_to_vocab: bool) -> Option<String> {
+        if value.starts_with('@') {
+            return None;
+        }
+        if relative_to_vocab {
+            if let Some(term) = self.terms.get(value) {
+                return self.expand_term(term, 0);
+            }
+        }
+        if let Some((prefix, suffix)) = value.split_once(':') {
+            if prefix == "_" || suffix.starts_with("//") {
+                return Some(value.to_string());
+            }
+            return match self.terms.get(prefix) {
+                Some(term) => Some(format!("{}{}", self.expand_term(term, 0)?, suffix)),
+                None => Some(value.to_string()),
+            };
+        }
+        if relative_to_vocab {
+            self.vocab
+                .as_ref()
+                .map(|vocab| format!("{}{}", vocab, value))
+        } else {
+            self.base.as_ref().map(|base| resolve(base, value))
+        }
+    }
+}
+
+struct Reader {
+    triples: Vec<RdfTriple<'static>>,
+    blank_nodes: HashMap<String, RdfBlankNode>,
+}
+
+impl Reader {
+    fn blank_node(&mut self, label: Option<&str>) -> RdfBlankNode {
+        let new = || RdfBlankNode {
+            internal_id: ProcessUniqueId::new(),
+        };
+        match label {
+            Some(label) => self
+                .blank_nodes
+                .entry(label.to_string())
+                .or_insert_with(new)
+                .clone(),
+            None => new(),
+        }
+    }
+
+    fn push(&mut self, subject: &RdfSubject<'static>, predicate: &str, object: RdfObject<'static>) {
+        self.triples.push(RdfTriple {
+            subject: subject.clone(),
+            predicate: RdfPredicate::IRI(iri(predicate.to_string())),
+            object,
+        });
+    }
+
+    fn top_level_node(&mut self, node: &Value, context: &Context) -> Result<(), Error> {
+        match node {
+            Value::Object(o) => self.node(o, context).map(|_| ()),
+            _ => Err(syntax_error("Expected a node object".into())),
+        }
+    }
+
+    /// A resource which is identified by an `@id`.
+    fn resource(&mut self, id: &str, context: &Context) -> Result<RdfSubject<'static>, Error> {
+        if let Some(label) = id.strip_prefix("_:") {
+            return Ok(RdfSubject::BlankNode(self.blank_node(Some(label))));
+        }
+        match context.expand_iri(id, false) {
+            Some(expanded) if expanded.contains(':') => Ok(RdfSubject::IRI(iri(expanded))),
+            _ => Err(Error::with_kind(
+                ErrorKind::InvalidIri,
+                format!("'{}' is not an absolute IRI", id),
+            )),
+        }
+    }
+
+    /// Reads the statements of a node object and returns the node.
+    fn node(
+        &mut self,
+        node: &Map<String, Value>,
+        context: &Context,
+    ) -> Result<RdfSubject<'static>, Error> {
+        let context = match node.get("@context") {
+            Some(local) => context.extend(local)?,
+            None => context.clone(),
+        };
+        let subject = match node.get("@id") {
+            Some(Value::String(id)) => self.resource(id, &context)?,
+            None => RdfSubject::BlankNode(self.blank_node(None)),
+            Some(_) => return Err(syntax_error("The value of '@id' has to be a string".into())),
+        };
+
+        for t in node.get("@type").map(as_array).unwrap_or_default() {
+            let Value::String(t) = t else {
+                return Err(syntax_error(
+                    "The value of '@type' has to be a string".into(),
+                ));
+            };
+            let class = match t.strip_prefix("_:") {
+                Some(label) => RdfObject::BlankNode(self.blank_node(Some(label))),
+                None => match context.expand_iri(t, true) {
+                    Some(class) => RdfObject::IRI(iri(class)),
+                    None => continue,
+                },
+            };
+            self.push(&subject, well_known::rdf_type_str, class);
+        }
+
+        for (key, value) in node {
+            match key.as_str() {
+                "@context" | "@id" | "@type" | "@index" => continue,
+                "@reverse" | "@graph" | "@included" | "@nest" => {
+                    return Err(Error::with_kind(
+                        ErrorKind::UnsupportedConstruct,
+                        format!("'{}' is not supported", key),
+                    ))
+                }
+                _ => {}
+            }
+            // keys which don't expand to an IRI are dropped like in JSON-LD expansion
+            let Some(predicate) = context.expand_iri(key, true) else {
+                continue;
+            };
+            let term = context.terms.get(key);
+            let objects = match (term, value) {
+                (Some(term), Value::Array(items)) if term.list => {
+                    vec![self.list(items.iter().collect(), Some(term), &context)?]
+                }
+                _ => self.objects(value, term, &context)?,
+            };
+            for object in objects {
+                self.push(&subject, &predicate, object);
+            }
+        }
+        Ok(subject)
+    }
+
+    fn objects(
+        &mut self,
+        value: &Value,
+        term: Option<&Term>,
+        context: &Context,
+    ) -> Result<Vec<RdfObject<'static>>, Error> {
+        let coerce = term.and_then(|t| t.coerce.as_deref());
+        let object = match value {
+            Value::Null => return Ok(vec![]),
+            Value::Array(items) => {
+                let mut objects = Vec::new();
+                for item in items {
+                    objects.extend(self.objects(item, term, context)?);
+                }
+                return Ok(objects);
+            }
+            Value::String(s) => match coerce {
+                Some("@id") => object_of(self.resource(s, context)?),
+                Some("@vocab") => match context.expand_iri(s, true) {
+                    Some(expanded) => RdfObject::IRI(iri(expanded)),
+                    None => object_of(self.resource(s, context)?),
+                },
+                Some(datatype) => literal(s.clone(), context.expand_iri(datatype, true), None),
+                None => literal(s.clone(), None, context.language.clone()),
+            },
+            Value::Number(n) => {
+                let datatype = match coerce {
+                    Some(datatype) => context.expand_iri(datatype, true),
+                    None if n.is_f64() => Some(well_known::xsd_double_str.to_string()),
+                    None => Some(well_known::xsd_integer_str.to_string()),
+                };
+                literal(n.to_string(), datatype, None)
+            }
+            Value::Bool(b) => literal(
+                b.to_string(),
+                Some(well_known::xsd_boolean_str.to_string()),
+                None,
+            ),
+            Value::Object(o) => {
+                if let Some(value) = o.get("@value") {
+                    let lexical_form = match value {
+                        Value::String(s) => s.clone(),
+                        Value::Number(n) => n.to_string(),
+                        Value::Bool(b) => b.to_string(),
+                        Value::Null => return Ok(vec![]),
+                        _ => {
+                            return Err(syntax_error(
+                                "The value of '@value' has to be a scalar".into(),
+                            ))
+                        }
+                    };
+                    let datatype = match o.get("@type") {
+                        Some(Value::String(t)) => context.expand_iri(t, true),
+                        _ => match value {
+                            Value::Number(n) if n.is_f64() => {
+                                Some(well_known::xsd_double_str.to_string())
+                            }
+                            Value::Number(_) => Some(well_known::xsd_integer_str.to_string()),
+                            Value::Bool(_) => Some(well_known::xsd_boolean_str.to_string()),
+                            _ => None,
+                        },
+                    };
+                    let language = match o.get("@language") {
+                        Some(Value::String(language)) => Some(language.clone()),
+                        _ if datatype.is_none() && value.is_string() => context.language.clone(),
+                        _ => None,
+                    };
+                    literal(lexical_form, datatype, language)
+                } else if let Some(items) = o.get("@list") {
+                    self.list(as_array(items), term, context)?
+                } else if let Some(items) = o.get("@set") {
+                    return self.objects(items, term, context);
+                } else {
+                    object_of(self.node(o, context)?)
+                }
+            }
+        };
+        Ok(vec![object])
+    }
+
+    /// Reads the items of a list into `rdf:first`/`rdf:rest` cells and returns the first cell.
+    fn list(
+        &mut self,
+        items: Vec<&Value>,
+        term: Option<&Term>,
+        context: &Context,
+    ) -> Result<RdfObject<'static>, Error> {
+        let mut objects = Vec::new();
+        for item in items {
+            objects.extend(self.objects(item, term, context)?);
+        }
+        let mut list = RdfObject::IRI(iri(well_known::rdf_nil_str.to_string()));
+        for object in objects.into_iter().rev() {
+            let cell = RdfSubject::BlankNode(self.blank_node(None));
+            self.push(&cell, well_known::rdf_first_str, object);
+            self.push(&cell, well_known::rdf_rest_str, list);
+            list = object_of(cell);
+        }
+        Ok(list)
+    }
+}
+
+fn as_array(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(values) => values.iter().collect(),
+        value => vec![value],
+    }
+}
+
+fn syntax_error(message: String) -> Error {
+    Error::with_kind(ErrorKind::Syntax, message)
+}
+
+fn iri(iri: String) -> RdfIri<'static> {
+    RdfIri {
+        iri: Cow::Owned(iri),
+    }
+}
+
+fn object_of(node: RdfSubject<'static>) -> RdfObject<'static> {
+    match node {
+        RdfSubject::IRI(iri) => RdfObject::IRI(iri),
+        RdfSubject::BlankNode(bn) => RdfObject::BlankNode(bn),
+    }
+}
+
+fn literal(
+    lexical_form: String,
+    datatype: Option<String>,
+    language: Option<String>,
+) -> RdfObject<'static> {
+    RdfObject::Literal(RdfLiteral {
+        lexical_form: Cow::Owned(lexical_form),
+        datatype_iri: datatype.map(iri),
+        language_tag: language.map(Cow::Owned),
+    })
+}
@@ -0,0 +1,266 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use serde_json::{json, Map, Value};
+use snowflake::ProcessUniqueId;
+
+use crate::{
+    api::Ontology,
+    owl::{well_known, ResourceId},
+    rdf::{Graph, Term, ToTriples},
+    rdf_xml::serializer::is_ncname,
+};
+
+pub trait ToJsonLd {
+    fn jsonld(&self) -> String;
+}
+
+impl ToJsonLd for Ontology {
+    fn jsonld(&self) -> String {
+        let triples = self.to_triples();
+
+        let mut context: BTreeMap<String, String> = [
+            ("owl", well_known::owl_base_str),
+            ("rdf", well_known::rdf_base_str),
+            ("rdfs", well_known::rdfs_base_str),
+            ("xsd", well_known::xsd_base_str),
+        ]
+        .into_iter()
+        .map(|(prefix, iri)| (prefix.to_string(), iri.to_string()))
+        .collect();
+        for (prefix, iri) in self.imports() {
+            if is_ncname(prefix) {
+                context
+                    .entry(prefix.clone())
+                    .or_insert_with(|| iri.as_str().to_string());
+            }
+        }
+
+        let document = Writer::new(&triples, context).document();
+        serde_json::to_string_pretty(&document).expect("JSON values can be serialized")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Node {
+    Iri(String),
+    Blank(ProcessUniqueId),
+}
+
+#[derive(Debug, Clone)]
+enum Object {
+    Node(Node),
+    Literal {
+        lexical_form: String,
+        datatype: Option<String>,
+        lang: Option<String>,
+    },
+}
+
+struct Writer {
+    context: BTreeMap<String, String>,
+    /// subjects in the order of their first statement
+    subjects: Vec<Node>,
+    statements: HashMap<Node, Vec<(String, Object)>>,
+    /// how often a blank node is used as object
+    references: HashMap<ProcessUniqueId, usize>,
+    labels: HashMap<ProcessUniqueId, String>,
+    written: HashSet<Node>,
+}
+
+impl Writer {
+    fn new(triples: &Graph, context: BTreeMap<String, String>) -> Self {
+        let mut writer = Writer {
+            context,
+            subjects: Vec::new(),
+            statements: HashMap::new(),
+            references: HashMap::new(),
+            labels: HashMap::new(),
+            written: HashSet::new(),
+        };
+        for triple in triples {
+            let subject = match &triple.subject {
+                ResourceId::IRI(iri) => Node::Iri(iri.as_str().to_string()),
+                ResourceId::BlankNode(bn) => Node::Blank(bn.internal_id()),
+            };
+            let predicate = triple.predicate.as_str().to_string();
+            let object = match &triple.object {
+                Term::IRI(iri) => Object::Node(Node::Iri(iri.as_str().to_string())),
+                Term::BlankNode(bn) => {
+                    *writer.references.entry(bn.internal_id()).or_default() += 1;
+                    Object::Node(Node::Blank(bn.internal_id()))
+                }
+                Term::Literal(l) => Object::Literal {
+                    lexical_form: l.lexical_form.clone(),
+                    datatype: l.datatype.as_ref().map(|dt| dt.as_str().to_string()),
+                    lang: l.lang.clone(),
+                },
+            };
+            if !writer.statements.contains_key(&subject) {
+                writer.subjects.push(subject.clone());
+            }
+            writer
+                .statements
+                .entry(subject)
+                .or_default()
+                .push((predicate, object));
+        }
+        writer
+    }
+
+    /// Abbreviates an IRI with the longest matching prefix of the context.
+    fn compact(&self, iri: &str) -> String {
+        self.context
+            .iter()
+            .filter(|(_, ns)| {
+                iri.len() > ns.len()
+                    && iri.starts_with(ns.as_str())
+                    && !iri[ns.len()..].starts_with("//")
+            })
+            .max_by_key(|(_, ns)| ns.len())
+            .map(|(prefix, ns)| format!("{}:{}", prefix, &iri[ns.len()..]))
+            .unwrap_or_else(|| iri.to_string())
+    }
+
+    fn label(&mut self, bn: ProcessUniqueId) -> String {
+        let next = self.labels.len();
+        self.labels
+            .entry(bn)
+            .or_insert_with(|| format!("_:b{}", next))
+            .clone()
+    }
+
+    fn document(mut self) -> Value {
+        let mut graph = Vec::new();
+        let subjects = self.subjects.clone();
+        for subject in &subjects {
+            let nested = match subject {
+                Node::Blank(bn) => self.references.get(bn) == Some(&1),
+                Node::Iri(_) => false,
+            };
+            if !nested {
+                graph.push(self.node(subject, false));
+            }
+        }
+        // blank nodes that only reference each other
+        for subject in &subjects {
+            if !self.written.contains(subject) {
+                graph.push(self.node(subject, false));
+            }
+        }
+        json!({
+            "@context": self.context,
+            "@graph": graph,
+        })
+    }
+
+    /// Writes a node object with its statements.
+    fn node(&mut self, node: &Node, nested: bool) -> Value {
+        self.written.insert(node.clone());
+        let statements = self.statements.get(node).cloned().unwrap_or_default();
+        let mut object = Map::new();
+        match node {
+            Node::Iri(iri) => {
+                object.insert("@id".into(), self.compact(iri).into());
+            }
+            Node::Blank(_) if nested => {}
+            Node::Blank(bn) => {
+                object.insert("@id".into(), self.label(*bn).into());
+            }
+        }
+
+        let mut properties: Vec<(String, Vec<Value>)> = Vec::new();
+        for (predicate, value) in statements {
+            let (key, value) = match value {
+                Object::Node(Node::Iri(class)) if predicate == well_known::rdf_type_str => {
+                    ("@type".to_string(), self.compact(&class).into())
+                }
+                value => (self.compact(&predicate), self.object(&value)),
+            };
+            match properties.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, values)) => values.push(value),
+                None => properties.push((key, vec![value])),
+            }
+        }
+        for (key, mut values) in properties {
+            let value = if values.len() == 1 {
+                values.remove(0)
+            } else {
+                Value::Array(values)
+            };
+            object.insert(key, value);
+        }
+        Value::Object(object)
+    }
+
+    fn object(&mut self, object: &Object) -> Value {
+        match object {
+            Object::Node(Node::Iri(iri)) => json!({ "@id": self.compact(iri) }),
+            Object::Node(node @ Node::Blank(bn)) => {
+                if !self.nestable(node) {
+                    json!({ "@id": self.label(*bn) })
+                } else if let Some(items) = self.list(node) {
+                    // the cells of the list are written as part of the list
+                    let mut list = Vec::new();
+                    for (cell, item) in items {
+                        self.written.insert(cell);
+                        list.push(self.object(&item));
+                    }
+                    json!({ "@list": list })
+                } else {
+                    self.node(node, true)
+                }
+            }
+            Object::Literal {
+                lexical_form,
+                datatype,
+                lang,
+            } => match (lang, datatype) {
+                (Some(lang), _) => json!({ "@value": lexical_form, "@language": lang }),
+                (None, Some(datatype)) if datatype != well_known::xsd_string_str => {
+                    json!({ "@value": lexical_form, "@type": self.compact(datatype) })
+                }
+                (None, _) => json!({ "@value": lexical_form }),
+            },
+        }
+    }
+
+    fn nestable(&self, node: &Node) -> bool {
+        match node {
+            Node::Blank(bn) => self.references.get(bn) == Some(&1) && !self.written.contains(node),
+            Node::Iri(_) => false,
+        }
+    }
+
+    /// The cells and items of a list which can be written with `@list`: every cell is used once
+    /// and consists of an `rdf:first` and an `rdf:rest` only.
+    fn list(&self, node: &Node) -> Option<Vec<(Node, Object)>> {
+        let nil = Node::Iri(well_known::rdf_nil_str.to_string());
+        let mut cells = Vec::new();
+        let mut cell = node.clone();
+        while cell != nil {
+            if !self.nestable(&cell) || cells.iter().any(|(c, _)| *c == cell) {
+                return None;
+            }
+            let statements = self.statements.get(&cell)?;
+            let object = |predicate: &str| {
+                statements
+                    .iter()
+                    .filter(|(p, _)| p == predicate)
+                    .map(|(_, o)| o)
+                    .collect::<Vec<_>>()
+            };
+            let (firsts, rests) = (
+                object(well_known::rdf_first_str),
+                object(well_known::rdf_rest_str),
+            );
+            match (firsts.as_slice(), rests.as_slice()) {
+                ([item], [Object::Node(next)]) if statements.len() == 2 => {
+                    cells.push((cell, (*item).clone()));
+                    cell = next.clone();
+                }
+                _ => return None,
+            }
+        }
+        Some(cells)
+    }
+}
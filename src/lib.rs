@@ -14,9 +14,17 @@ extern crate log;
 pub mod api;
 pub mod error;
 pub mod owl;
+pub mod rdf;
 
 pub mod examples;
 pub mod parser;
 pub mod serializer;
 
+pub mod jsonld;
+pub mod manchester;
+pub mod ntriples;
+pub mod ofn;
+pub mod owl_xml;
+pub mod rdf_xml;
+
 pub mod computation;
\ No newline at end of file
@@ -0,0 +1,141 @@
+//! Rendering and parsing of class expressions and axioms in the
+//! [OWL 2 Manchester Syntax](https://www.w3.org/TR/owl2-manchester-syntax/).
+//!
+//! Names are resolved in the context of an ontology: entities are rendered by their `rdfs:label`
+//! (if enabled and available), by their fragment if they belong to the ontology, by a prefixed
+//! name if they belong to one of its imports and by their full IRI otherwise. The parser accepts
+//! all of these forms.
+//!
+//! ```rust
+//! use owlish::{api::Ontology, manchester::Manchester};
+//!
+//! let ontology = Ontology::parse_ofn(r#"
+//! Ontology(<http://example.com#>
+//!     Declaration(Class(<http://example.com#Person>))
+//!     Declaration(Class(<http://example.com#Doctor>))
+//!     Declaration(ObjectProperty(<http://example.com#hasChild>))
+//! )
+//! "#, Default::default()).unwrap();
+//!
+//! let manchester = Manchester::new(&ontology);
+//! let cls = manchester.parse_class("Person and (hasChild some Doctor)").unwrap();
+//! assert_eq!(manchester.render_class(&cls), "Person and (hasChild some Doctor)");
+//! ```
+
+mod parser;
+mod renderer;
+
+use std::collections::HashMap;
+
+use crate::{
+    api::Ontology,
+    owl::{well_known, Axiom, Declaration, LiteralOrIRI, ResourceId, IRI},
+};
+
+/// Prefixes which are known without being imported.
+const WELL_KNOWN_PREFIXES: [(&str, &str); 4] = [
+    ("owl", well_known::owl_base_str),
+    ("rdf", well_known::rdf_base_str),
+    ("rdfs", well_known::rdfs_base_str),
+    ("xsd", well_known::xsd_base_str),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EntityKind {
+    Class,
+    ObjectProperty,
+    DataProperty,
+    AnnotationProperty,
+    NamedIndividual,
+    Datatype,
+}
+
+/// Renders and parses Manchester Syntax for the entities of an ontology.
+pub struct Manchester<'a> {
+    ontology: &'a Ontology,
+    use_labels: bool,
+    kinds: HashMap<IRI, EntityKind>,
+    labels: HashMap<IRI, String>,
+    labelled: HashMap<String, IRI>,
+}
+
+impl<'a> Manchester<'a> {
+    pub fn new(ontology: &'a Ontology) -> Self {
+        let mut kinds = HashMap::new();
+        let mut labels = HashMap::new();
+        let label_iri = well_known::rdfs_label();
+
+        for d in ontology.declarations() {
+            let (iri, kind) = match d {
+                Declaration::Class { iri, .. } => (iri.as_iri(), EntityKind::Class),
+                Declaration::NamedIndividual { iri, .. } => {
+                    (iri.as_iri(), EntityKind::NamedIndividual)
+                }
+                Declaration::ObjectProperty { iri, .. } => {
+                    (iri.as_iri(), EntityKind::ObjectProperty)
+                }
+                Declaration::DataProperty { iri, .. } => (iri.as_iri(), EntityKind::DataProperty),
+                Declaration::AnnotationProperty { iri, .. } => {
+                    (iri.as_iri(), EntityKind::AnnotationProperty)
+                }
+                Declaration::Datatype { iri, .. } => (iri.as_iri(), EntityKind::Datatype),
+            };
+            kinds.entry(iri.clone()).or_insert(kind);
+            for a in d.annotations() {
+                if a.iri == label_iri {
+                    if let LiteralOrIRI::Literal(l) = &a.value {
+                        labels.entry(iri.clone()).or_insert_with(|| label(l));
+                    }
+                }
+            }
+        }
+        for a in ontology.axioms() {
+            if let Axiom::AnnotationAssertion(a) = a {
+                if let (ResourceId::IRI(iri), LiteralOrIRI::Literal(l)) = (&a.subject, &a.value) {
+                    if a.iri == label_iri {
+                        labels.entry(iri.clone()).or_insert_with(|| label(l));
+                    }
+                }
+            }
+        }
+
+        let mut labelled = HashMap::new();
+        for (iri, label) in &labels {
+            labelled.entry(label.clone()).or_insert_with(|| iri.clone());
+        }
+
+        Self {
+            ontology,
+            use_labels: true,
+            kinds,
+            labels,
+            labelled,
+        }
+    }
+
+    /// Whether entities are rendered by their `rdfs:label`. Enabled by default.
+    pub fn use_labels(mut self, use_labels: bool) -> Self {
+        self.use_labels = use_labels;
+        self
+    }
+
+    /// The namespaces which entity names can be abbreviated with: the ontology itself (as empty
+    /// prefix), its imports and the well known prefixes.
+    fn namespaces(&self) -> impl Iterator<Item = (&str, &str)> {
+        std::iter::once(("", self.ontology.iri().as_str()))
+            .chain(
+                self.ontology
+                    .imports()
+                    .iter()
+                    .map(|(prefix, iri)| (prefix.as_str(), iri.as_str())),
+            )
+            .chain(WELL_KNOWN_PREFIXES)
+    }
+}
+
+fn label(literal: &crate::owl::Literal) -> String {
+    match literal {
+        crate::owl::Literal::LangString { string, .. } => string.clone(),
+        l => l.to_string(),
+    }
+}
@@ -0,0 +1,627 @@
+use std::borrow::Cow;
+
+use crate::{
+    error::{Error, ErrorKind, Position},
+    owl::*,
+    parser::matcher::Value,
+};
+
+use super::{
+    renderer::{is_keyword, FACETS},
+    EntityKind, Manchester,
+};
+
+impl<'a> Manchester<'a> {
+    /// Parses a Manchester Syntax class expression, e.g. `Person and (hasChild some Doctor)`.
+    ///
+    /// Names are resolved against the declarations of the ontology. Whether a restriction is on
+    /// an object or a data property is decided by the declaration of the property.
+    pub fn parse_class(&self, manchester: &str) -> Result<ClassConstructor, Error> {
+        let mut parser = ManchesterParser {
+            m: self,
+            source: manchester,
+            tokens: tokenize(manchester)?,
+            pos: 0,
+        };
+        let cls = parser.description()?;
+        if parser.peek().is_some() {
+            return Err(parser.unexpected("the end of the class expression"));
+        }
+        Ok(cls)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token<'a> {
+    Open,
+    Close,
+    BraceOpen,
+    BraceClose,
+    BracketOpen,
+    BracketClose,
+    Comma,
+    DoubleCaret,
+    Facet(&'a str),
+    LangTag(&'a str),
+    FullIri(&'a str),
+    Label(&'a str),
+    String(String),
+    Number(&'a str),
+    Word(&'a str),
+}
+
+impl<'a> std::fmt::Display for Token<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Open => write!(f, "'('"),
+            Token::Close => write!(f, "')'"),
+            Token::BraceOpen => write!(f, "'{{'"),
+            Token::BraceClose => write!(f, "'}}'"),
+            Token::BracketOpen => write!(f, "'['"),
+            Token::BracketClose => write!(f, "']'"),
+            Token::Comma => write!(f, "','"),
+            Token::DoubleCaret => write!(f, "'^^'"),
+            Token::Facet(facet) => write!(f, "'{}'", facet),
+            Token::LangTag(tag) => write!(f, "'@{}'", tag),
+            Token::FullIri(iri) => write!(f, "'<{}>'", iri),
+            Token::Label(label) => write!(f, "''{}''", label),
+            Token::String(s) => write!(f, "'\"{}\"'", s),
+            Token::Number(n) => write!(f, "'{}'", n),
+            Token::Word(w) => write!(f, "'{}'", w),
+        }
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<(Token<'_>, usize)>, Error> {
+    let error = |offset: usize, message: &str| {
+        Error::with_kind(ErrorKind::Syntax, message.into())
+            .at_position(Position::from_offset(source, offset))
+    };
+    let is_delimiter = |c: char| {
+        c.is_whitespace()
+            || matches!(
+                c,
+                '(' | ')' | '{' | '}' | '[' | ']' | ',' | '\'' | '"' | '<' | '>' | '^' | '@'
+            )
+    };
+
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let rest = &source[start..];
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '{' => Token::BraceOpen,
+            '}' => Token::BraceClose,
+            '[' => Token::BracketOpen,
+            ']' => Token::BracketClose,
+            ',' => Token::Comma,
+            '^' => match chars.next() {
+                Some((_, '^')) => Token::DoubleCaret,
+                _ => return Err(error(start, "Expected '^^'")),
+            },
+            '>' | '<' if rest[1..].starts_with('=') => {
+                chars.next();
+                Token::Facet(&rest[..2])
+            }
+            '<' => {
+                let end = rest.find(|c: char| c == '>' || c.is_whitespace());
+                match end {
+                    Some(end) if rest[end..].starts_with('>') && end > 1 => {
+                        while chars
+                            .peek()
+                            .map(|(i, _)| *i <= start + end)
+                            .unwrap_or(false)
+                        {
+                            chars.next();
+                        }
+                        Token::FullIri(&rest[1..end])
+                    }
+                    _ => Token::Facet("<"),
+                }
+            }
+            '>' => Token::Facet(">"),
+            '@' => {
+                let mut end = start + 1;
+                while let Some((i, c)) = chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || *c == '-') {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                Token::LangTag(&source[start + 1..end])
+            }
+            '\'' => {
+                let Some(end) = rest[1..].find('\'') else {
+                    return Err(error(start, "Unterminated quoted name"));
+                };
+                let end = start + 1 + end;
+                while chars.peek().map(|(i, _)| *i <= end).unwrap_or(false) {
+                    chars.next();
+                }
+                Token::Label(&source[start + 1..end])
+            }
+            '"' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((i, '\\')) => match chars.next() {
+                            Some((_, '"')) => string.push('"'),
+                            Some((_, '\\')) => string.push('\\'),
+                            _ => return Err(error(i, "Invalid escape sequence")),
+                        },
+                        Some((_, c)) => string.push(c),
+                        None => return Err(error(start, "Unterminated string literal")),
+                    }
+                }
+                Token::String(string)
+            }
+            _ => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, c)) = chars.peek() {
+                    if is_delimiter(*c) {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                let word = &source[start..end];
+                let unsigned = word.strip_prefix(['-', '+']).unwrap_or(word);
+                if unsigned.starts_with(|c: char| c.is_ascii_digit()) {
+                    Token::Number(word)
+                } else {
+                    Token::Word(word)
+                }
+            }
+        };
+        tokens.push((token, start));
+    }
+    Ok(tokens)
+}
+
+/// The property of a restriction, its kind decides which class constructor is created.
+enum Property {
+    Object(ObjectPropertyConstructor),
+    Data(DataPropertyIRI),
+}
+
+struct ManchesterParser<'a, 'm> {
+    m: &'m Manchester<'m>,
+    source: &'a str,
+    tokens: Vec<(Token<'a>, usize)>,
+    pos: usize,
+}
+
+impl<'a, 'm> ManchesterParser<'a, 'm> {
+    fn error_at(&self, pos: usize, kind: ErrorKind, message: String) -> Error {
+        let offset = match self.tokens.get(pos) {
+            Some((_, offset)) => *offset,
+            None => self.source.len(),
+        };
+        Error::with_kind(kind, message).at_position(Position::from_offset(self.source, offset))
+    }
+
+    fn unexpected(&self, expected: &str) -> Error {
+        let message = match self.peek() {
+            Some(token) => format!("Expected {} but found {}", expected, token),
+            None => format!("Expected {} but the expression ended", expected),
+        };
+        self.error_at(self.pos, ErrorKind::Syntax, message)
+    }
+
+    fn peek(&self) -> Option<&Token<'a>> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn peek_word(&self) -> Option<&'a str> {
+        match self.peek() {
+            Some(Token::Word(w)) => Some(w),
+            _ => None,
+        }
+    }
+
+    fn expect(&mut self, expected: Token, name: &str) -> Result<(), Error> {
+        if self.peek() == Some(&expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected(name))
+        }
+    }
+
+    fn description(&mut self) -> Result<ClassConstructor, Error> {
+        let mut classes = vec![self.conjunction()?];
+        while self.peek_word() == Some("or") {
+            self.pos += 1;
+            classes.push(self.conjunction()?);
+        }
+        Ok(match classes.len() {
+            1 => classes.remove(0),
+            _ => ObjectUnionOf::new(classes, vec![]).into(),
+        })
+    }
+
+    fn conjunction(&mut self) -> Result<ClassConstructor, Error> {
+        let mut classes = vec![self.primary()?];
+        while matches!(self.peek_word(), Some("and") | Some("that")) {
+            self.pos += 1;
+            classes.push(self.primary()?);
+        }
+        Ok(match classes.len() {
+            1 => classes.remove(0),
+            _ => ObjectIntersectionOf::new(classes, vec![]).into(),
+        })
+    }
+
+    fn primary(&mut self) -> Result<ClassConstructor, Error> {
+        match self.peek() {
+            Some(Token::Word("not")) => {
+                self.pos += 1;
+                Ok(ObjectComplementOf::new(Box::new(self.primary()?), vec![]).into())
+            }
+            Some(Token::Word("inverse")) => {
+                let property = self.inverse_property()?;
+                self.restriction(Property::Object(property))
+            }
+            Some(Token::Open) => {
+                self.pos += 1;
+                let cls = self.description()?;
+                self.expect(Token::Close, "')'")?;
+                Ok(cls)
+            }
+            Some(Token::BraceOpen) => {
+                self.pos += 1;
+                let mut individuals = vec![self.entity(EntityKind::NamedIndividual)?.into()];
+                while self.peek() == Some(&Token::Comma) {
+                    self.pos += 1;
+                    individuals.push(self.entity(EntityKind::NamedIndividual)?.into());
+                }
+                self.expect(Token::BraceClose, "'}'")?;
+                Ok(ObjectOneOf::new(individuals, vec![]).into())
+            }
+            _ => {
+                let is_restriction = matches!(
+                    self.tokens.get(self.pos + 1),
+                    Some((
+                        Token::Word("some" | "only" | "value" | "Self" | "min" | "max" | "exactly"),
+                        _
+                    ))
+                );
+                if is_restriction {
+                    let property = self.property()?;
+                    self.restriction(property)
+                } else {
+                    Ok(ClassConstructor::IRI(
+                        self.entity(EntityKind::Class)?.into(),
+                    ))
+                }
+            }
+        }
+    }
+
+    fn inverse_property(&mut self) -> Result<ObjectPropertyConstructor, Error> {
+        self.pos += 1;
+        let parenthesized = self.peek() == Some(&Token::Open);
+        if parenthesized {
+            self.pos += 1;
+        }
+        let iri = self.entity(EntityKind::ObjectProperty)?;
+        if parenthesized {
+            self.expect(Token::Close, "')'")?;
+        }
+        Ok(ObjectInverseOf(iri.into()).into())
+    }
+
+    fn property(&mut self) -> Result<Property, Error> {
+        let start = self.pos;
+        let iri = self.resolve()?;
+        match self.m.kinds.get(&iri) {
+            Some(EntityKind::ObjectProperty) => {
+                Ok(Property::Object(ObjectPropertyConstructor::IRI(iri.into())))
+            }
+            Some(EntityKind::DataProperty) => Ok(Property::Data(iri.into())),
+            _ => Err(self.error_at(
+                start,
+                ErrorKind::UnknownEntity,
+                format!("'{}' is not a declared object or data property", iri),
+            )),
+        }
+    }
+
+    fn restriction(&mut self, property: Property) -> Result<ClassConstructor, Error> {
+        let start = self.pos;
+        let Some(keyword) = self.peek_word() else {
+            return Err(self.unexpected("a restriction"));
+        };
+        self.pos += 1;
+        match property {
+            Property::Object(property) => {
+                let cardinality_property = |p: &Self| match &property {
+                    ObjectPropertyConstructor::IRI(iri) => Ok(iri.clone()),
+                    _ => Err(p.error_at(
+                        start,
+                        ErrorKind::UnsupportedConstruct,
+                        "Cardinality restrictions on inverse properties are not supported".into(),
+                    )),
+                };
+                Ok(match keyword {
+                    "some" => {
+                        ObjectSomeValuesFrom::new(property, Box::new(self.primary()?), vec![])
+                            .into()
+                    }
+                    "only" => {
+                        ObjectAllValuesFrom::new(property, Box::new(self.primary()?), vec![]).into()
+                    }
+                    "value" => {
+                        let individual = self.entity(EntityKind::NamedIndividual)?;
+                        ObjectHasValue::new(property, individual.into(), vec![]).into()
+                    }
+                    "Self" => ObjectHasSelf::new(property, vec![]).into(),
+                    "min" | "max" | "exactly" => {
+                        let property = cardinality_property(self)?;
+                        let value = self.cardinality()?;
+                        let cls = match self.has_filler() {
+                            true => Some(Box::new(self.primary()?)),
+                            false => None,
+                        };
+                        match keyword {
+                            "min" => ObjectMinCardinality::new(value, property, cls).into(),
+                            "max" => ObjectMaxCardinality::new(value, property, cls).into(),
+                            _ => ObjectExactCardinality::new(value, property, cls).into(),
+                        }
+                    }
+                    _ => {
+                        self.pos = start;
+                        return Err(self.unexpected("a restriction"));
+                    }
+                })
+            }
+            Property::Data(property) => Ok(match keyword {
+                "some" => {
+                    let restriction = self.data_range()?;
+                    DataSomeValuesFrom::new(property, restriction, vec![]).into()
+                }
+                "only" => {
+                    let range_start = self.pos;
+                    let restriction = self.data_range()?;
+                    if !restriction.restrictions.is_empty() {
+                        return Err(self.error_at(
+                            range_start,
+                            ErrorKind::UnsupportedConstruct,
+                            "Facets in universal data restrictions are not supported".into(),
+                        ));
+                    }
+                    DataAllValuesFrom::new(property, restriction.datatype_iri, vec![]).into()
+                }
+                "value" => DataHasValue::new(property, self.literal()?, vec![]).into(),
+                "min" | "max" | "exactly" => {
+                    let value = self.cardinality()?;
+                    let datatype = match self.has_filler() {
+                        true => Some(self.entity(EntityKind::Datatype)?.into()),
+                        false => None,
+                    };
+                    match keyword {
+                        "min" => DataMinCardinality::new(value, property, datatype).into(),
+                        "max" => DataMaxCardinality::new(value, property, datatype).into(),
+                        _ => DataExactCardinality::new(value, property, datatype).into(),
+                    }
+                }
+                "Self" => {
+                    self.pos = start;
+                    return Err(self.error_at(
+                        start,
+                        ErrorKind::Syntax,
+                        "Self restrictions are only allowed on object properties".into(),
+                    ));
+                }
+                _ => {
+                    self.pos = start;
+                    return Err(self.unexpected("a restriction"));
+                }
+            }),
+        }
+    }
+
+    /// Whether the qualifying class or datatype of a cardinality restriction follows.
+    fn has_filler(&self) -> bool {
+        !matches!(
+            self.peek(),
+            None | Some(Token::Close)
+                | Some(Token::BraceClose)
+                | Some(Token::Comma)
+                | Some(Token::Word("and" | "or" | "that"))
+        )
+    }
+
+    fn cardinality(&mut self) -> Result<u64, Error> {
+        match self.peek() {
+            Some(Token::Number(n)) => match n.parse() {
+                Ok(n) => {
+                    self.pos += 1;
+                    Ok(n)
+                }
+                Err(_) => Err(self.unexpected("a non-negative integer")),
+            },
+            _ => Err(self.unexpected("a non-negative integer")),
+        }
+    }
+
+    fn data_range(&mut self) -> Result<DatatypeRestriction, Error> {
+        if self.peek() == Some(&Token::BraceOpen) {
+            return Err(self.error_at(
+                self.pos,
+                ErrorKind::UnsupportedConstruct,
+                "Enumerations of literals in data restrictions are not supported".into(),
+            ));
+        }
+        let datatype = self.entity(EntityKind::Datatype)?;
+        let mut restrictions = vec![];
+        if self.peek() == Some(&Token::BracketOpen) {
+            self.pos += 1;
+            loop {
+                let facet = match self.peek() {
+                    Some(Token::Facet(f)) | Some(Token::Word(f)) => FACETS
+                        .iter()
+                        .find(|(symbol, _)| symbol == f)
+                        .map(|(_, iri)| IRI::new(iri).unwrap()),
+                    _ => None,
+                };
+                let Some(facet) = facet else {
+                    return Err(self.unexpected("a facet"));
+                };
+                self.pos += 1;
+                let value = self.literal()?;
+                // all Manchester facets are facets of OWL 2
+                restrictions.extend(Restriction::new(&facet, value));
+                match self.peek() {
+                    Some(Token::Comma) => self.pos += 1,
+                    _ => break,
+                }
+            }
+            self.expect(Token::BracketClose, "']'")?;
+        }
+        Ok(DatatypeRestriction::new(
+            datatype.into(),
+            restrictions,
+            vec![],
+        ))
+    }
+
+    fn literal(&mut self) -> Result<Literal, Error> {
+        let start = self.pos;
+        let (lexical_form, datatype_iri, language_tag): (String, Option<String>, _) =
+            match self.peek().cloned() {
+                Some(Token::String(s)) => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(Token::LangTag(tag)) => {
+                            let tag = *tag;
+                            self.pos += 1;
+                            (s, None, Some(Cow::Borrowed(tag)))
+                        }
+                        Some(Token::DoubleCaret) => {
+                            self.pos += 1;
+                            let datatype = self.entity(EntityKind::Datatype)?;
+                            (s, Some(datatype.to_string()), None)
+                        }
+                        _ => (s, None, None),
+                    }
+                }
+                Some(Token::Number(n)) => {
+                    self.pos += 1;
+                    if let Some(float) = n.strip_suffix(['f', 'F']) {
+                        (float.into(), Some(well_known::xsd_float_str.into()), None)
+                    } else if n.contains(['.', 'e', 'E']) {
+                        (n.into(), Some(well_known::xsd_decimal_str.into()), None)
+                    } else {
+                        (n.into(), Some(well_known::xsd_integer_str.into()), None)
+                    }
+                }
+                Some(Token::Word(b @ ("true" | "false"))) => {
+                    self.pos += 1;
+                    (b.into(), Some(well_known::xsd_boolean_str.into()), None)
+                }
+                _ => return Err(self.unexpected("a literal")),
+            };
+        Value::Literal {
+            lexical_form: Cow::Owned(lexical_form.clone()),
+            datatype_iri: datatype_iri.map(Cow::Owned),
+            language_tag,
+        }
+        .try_into()
+        .map_err(|_| {
+            self.error_at(
+                start,
+                ErrorKind::InvalidLiteral,
+                format!("Invalid literal \"{}\"", lexical_form),
+            )
+        })
+    }
+
+    /// Resolves the next name to an entity of the expected kind.
+    fn entity(&mut self, kind: EntityKind) -> Result<IRI, Error> {
+        let start = self.pos;
+        // prefixed names and full IRIs are explicit enough to be used without a declaration
+        let explicit = match self.peek() {
+            Some(Token::FullIri(_)) => true,
+            Some(Token::Word(w)) => w.contains(':'),
+            _ => false,
+        };
+        let iri = self.resolve()?;
+        let declared = self.m.kinds.get(&iri).copied();
+        let matches = match declared {
+            Some(declared) => declared == kind || kind == EntityKind::NamedIndividual,
+            None => explicit || kind == EntityKind::NamedIndividual,
+        };
+        if matches {
+            return Ok(iri);
+        }
+        let expected = match kind {
+            EntityKind::Class => "class",
+            EntityKind::ObjectProperty => "object property",
+            EntityKind::DataProperty => "data property",
+            EntityKind::AnnotationProperty => "annotation property",
+            EntityKind::NamedIndividual => "individual",
+            EntityKind::Datatype => "datatype",
+        };
+        Err(self.error_at(
+            start,
+            ErrorKind::UnknownEntity,
+            format!("'{}' is not a declared {}", iri, expected),
+        ))
+    }
+
+    /// Resolves the next name to an IRI: full IRIs, prefixed names, quoted labels, and unquoted
+    /// labels or names of the ontology.
+    fn resolve(&mut self) -> Result<IRI, Error> {
+        let start = self.pos;
+        let unknown = |p: &Self, name: &str| {
+            p.error_at(
+                start,
+                ErrorKind::UnknownEntity,
+                format!("Unknown name '{}'", name),
+            )
+        };
+        let iri = match self.peek() {
+            Some(Token::FullIri(iri)) => iri.to_string(),
+            Some(Token::Label(label)) => match self.m.labelled.get(*label) {
+                Some(iri) => {
+                    let iri = iri.clone();
+                    self.pos += 1;
+                    return Ok(iri);
+                }
+                None => return Err(unknown(self, label)),
+            },
+            Some(Token::Word(word)) if !is_keyword(word) => {
+                if let Some(iri) = self.m.labelled.get(*word) {
+                    let iri = iri.clone();
+                    self.pos += 1;
+                    return Ok(iri);
+                }
+                let (prefix, local) = word.split_once(':').unwrap_or(("", word));
+                let Some((_, namespace)) = self.m.namespaces().find(|(p, _)| *p == prefix) else {
+                    return Err(unknown(self, word));
+                };
+                if namespace.ends_with(['#', '/']) {
+                    format!("{}{}", namespace, local)
+                } else {
+                    format!("{}#{}", namespace, local)
+                }
+            }
+            _ => return Err(self.unexpected("a name")),
+        };
+        let iri = IRI::new(&iri).map_err(|e| {
+            self.error_at(
+                start,
+                ErrorKind::InvalidIri,
+                format!("Invalid IRI '{}': {}", iri, e),
+            )
+        })?;
+        self.pos += 1;
+        Ok(iri)
+    }
+}
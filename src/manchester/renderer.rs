@@ -0,0 +1,523 @@
+use crate::owl::{
+    well_known, Axiom, ClassConstructor, DatatypeDefinitionConstructor, DatatypeRestriction,
+    Literal, LiteralOrIRI, ObjectPropertyConstructor, ResourceId, IRI,
+};
+
+use super::Manchester;
+
+/// Manchester names of the facets of datatype restrictions.
+pub(super) const FACETS: [(&str, &str); 9] = [
+    (">=", well_known::xsd_minInclusive_str),
+    (">", well_known::xsd_minExclusive_str),
+    ("<=", well_known::xsd_maxInclusive_str),
+    ("<", well_known::xsd_maxExclusive_str),
+    ("length", well_known::xsd_length_str),
+    ("minLength", well_known::xsd_minLength_str),
+    ("maxLength", well_known::xsd_maxLength_str),
+    ("pattern", well_known::xsd_pattern_str),
+    ("langRange", well_known::rdf_langRange_str),
+];
+
+impl<'a> Manchester<'a> {
+    /// Renders a class expression, e.g. `Person and (hasChild some Doctor)`.
+    ///
+    /// Class constructors which are axioms on their own (e.g. `SubClassOf`) are rendered as
+    /// their first class.
+    pub fn render_class(&self, cls: &ClassConstructor) -> String {
+        let cardinality =
+            |keyword: &str, value: u64, property: String, filler: Option<String>| match filler {
+                Some(filler) => format!("{} {} {} {}", property, keyword, value, filler),
+                None => format!("{} {} {}", property, keyword, value),
+            };
+        match cls {
+            ClassConstructor::IRI(iri) => self.name(iri.as_iri()),
+            ClassConstructor::SubClassOf(a) => self.render_class(&a.cls),
+            ClassConstructor::EquivalentClasses(a) => self.name(a.class_iri.as_iri()),
+            ClassConstructor::DisjointClasses(a) => match a.classes.first() {
+                Some(first) => self.render_class(first),
+                None => self.name(well_known::owl_Thing().as_iri()),
+            },
+            ClassConstructor::ObjectIntersectionOf(c) => self.operands(&c.classes, " and "),
+            ClassConstructor::ObjectUnionOf(c) => self.operands(&c.classes, " or "),
+            ClassConstructor::ObjectComplementOf(c) => format!("not {}", self.operand(&c.cls)),
+            ClassConstructor::ObjectOneOf(c) => format!(
+                "{{{}}}",
+                c.individuals
+                    .iter()
+                    .map(|i| self.name(i.as_iri()))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            ClassConstructor::ObjectSomeValuesFrom(c) => format!(
+                "{} some {}",
+                self.property(&c.object_property),
+                self.operand(&c.cls)
+            ),
+            ClassConstructor::ObjectAllValuesFrom(c) => format!(
+                "{} only {}",
+                self.property(&c.object_property),
+                self.operand(&c.cls)
+            ),
+            ClassConstructor::ObjectHasValue(c) => format!(
+                "{} value {}",
+                self.property(&c.object_property),
+                self.value(&c.value_or_iri)
+            ),
+            ClassConstructor::ObjectHasSelf(c) => {
+                format!("{} Self", self.property(&c.object_property))
+            }
+            ClassConstructor::ObjectMinCardinality(c) => cardinality(
+                "min",
+                c.value,
+                self.name(c.object_property_iri.as_iri()),
+                c.cls.as_ref().map(|c| self.operand(c)),
+            ),
+            ClassConstructor::ObjectMaxCardinality(c) => cardinality(
+                "max",
+                c.value,
+                self.name(c.object_property_iri.as_iri()),
+                c.cls.as_ref().map(|c| self.operand(c)),
+            ),
+            ClassConstructor::ObjectExactCardinality(c) => cardinality(
+                "exactly",
+                c.value,
+                self.name(c.object_property_iri.as_iri()),
+                c.cls.as_ref().map(|c| self.operand(c)),
+            ),
+            ClassConstructor::DataSomeValuesFrom(c) => format!(
+                "{} some {}",
+                self.name(c.data_property_iri.as_iri()),
+                self.restriction(&c.restriction)
+            ),
+            ClassConstructor::DataAllValuesFrom(c) => format!(
+                "{} only {}",
+                self.name(c.data_property_iri.as_iri()),
+                self.name(c.datatype_iri.as_iri())
+            ),
+            ClassConstructor::DataHasValue(c) => format!(
+                "{} value {}",
+                self.name(c.data_property_iri.as_iri()),
+                self.literal(&c.value)
+            ),
+            ClassConstructor::DataMinCardinality(c) => cardinality(
+                "min",
+                c.value,
+                self.name(c.data_property_iri.as_iri()),
+                c.datatype_iri.as_ref().map(|d| self.name(d.as_iri())),
+            ),
+            ClassConstructor::DataMaxCardinality(c) => cardinality(
+                "max",
+                c.value,
+                self.name(c.data_property_iri.as_iri()),
+                c.datatype_iri.as_ref().map(|d| self.name(d.as_iri())),
+            ),
+            ClassConstructor::DataExactCardinality(c) => cardinality(
+                "exactly",
+                c.value,
+                self.name(c.data_property_iri.as_iri()),
+                c.datatype_iri.as_ref().map(|d| self.name(d.as_iri())),
+            ),
+        }
+    }
+
+    /// Renders an axiom in the style of a Manchester frame entry, e.g. `Man SubClassOf Person`.
+    /// Annotations of the axiom are not rendered.
+    pub fn render_axiom(&self, axiom: &Axiom) -> String {
+        let names = |iris: Vec<&IRI>| {
+            iris.iter()
+                .map(|iri| self.name(iri))
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        let resources = |resources: &[ResourceId]| {
+            resources
+                .iter()
+                .map(|r| self.resource(r))
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        let classes = |classes: &[ClassConstructor]| {
+            classes
+                .iter()
+                .map(|c| self.render_class(c))
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        match axiom {
+            Axiom::AnnotationAssertion(a) => format!(
+                "{} {} {}",
+                self.resource(&a.subject),
+                self.name(a.iri.as_iri()),
+                self.value(&a.value)
+            ),
+            Axiom::AnnotationPropertyDomain(a) => format!(
+                "{} Domain {}",
+                self.name(a.iri.as_iri()),
+                self.name(a.class_iri.as_iri())
+            ),
+            Axiom::AnnotationPropertyRange(a) => format!(
+                "{} Range {}",
+                self.name(a.iri.as_iri()),
+                self.name(a.datatype_iri.as_iri())
+            ),
+            Axiom::SubAnnotationPropertyOf(a) => format!(
+                "{} SubPropertyOf {}",
+                self.name(a.subject_iri.as_iri()),
+                self.name(a.parent_iri.as_iri())
+            ),
+            Axiom::SubClassOf(a) => format!(
+                "{} SubClassOf {}",
+                self.render_class(&a.cls),
+                self.render_class(&a.parent_class)
+            ),
+            Axiom::EquivalentClasses(a) => format!(
+                "{} EquivalentTo {}",
+                self.name(a.class_iri.as_iri()),
+                self.render_class(&a.cls)
+            ),
+            Axiom::DisjointClasses(a) => format!("DisjointClasses: {}", classes(&a.classes)),
+            Axiom::DisjointUnion(a) => format!(
+                "{} DisjointUnionOf {}",
+                self.name(a.class_iri.as_iri()),
+                classes(&a.classes)
+            ),
+            Axiom::SubObjectPropertyOf(a) => format!(
+                "{} SubPropertyOf {}",
+                self.property(&a.object_property),
+                self.name(a.parent_object_property_iri.as_iri())
+            ),
+            Axiom::EquivalentObjectProperties(a) => format!(
+                "{} EquivalentTo {}",
+                self.name(a.object_property_iri_1.as_iri()),
+                self.name(a.object_property_iri_2.as_iri())
+            ),
+            Axiom::DisjointObjectProperties(a) => format!(
+                "DisjointProperties: {}",
+                names(a.object_property_iris.iter().map(|p| p.as_iri()).collect())
+            ),
+            Axiom::InverseObjectProperties(a) => format!(
+                "{} InverseOf {}",
+                self.name(a.object_property_iri_1.as_iri()),
+                self.name(a.object_property_iri_2.as_iri())
+            ),
+            Axiom::ObjectPropertyDomain(a) => format!(
+                "{} Domain {}",
+                self.name(a.iri.as_iri()),
+                self.render_class(&a.cls)
+            ),
+            Axiom::ObjectPropertyRange(a) => format!(
+                "{} Range {}",
+                self.name(a.iri.as_iri()),
+                self.render_class(&a.cls)
+            ),
+            Axiom::FunctionalObjectProperty(a) => {
+                format!("Functional: {}", self.name(a.object_property_iri.as_iri()))
+            }
+            Axiom::InverseFunctionalObjectProperty(a) => format!(
+                "InverseFunctional: {}",
+                self.name(a.object_property_iri.as_iri())
+            ),
+            Axiom::ReflexiveObjectProperty(a) => {
+                format!("Reflexive: {}", self.name(a.object_property_iri.as_iri()))
+            }
+            Axiom::IrreflexiveObjectProperty(a) => {
+                format!("Irreflexive: {}", self.name(a.object_property_iri.as_iri()))
+            }
+            Axiom::SymmetricObjectProperty(a) => {
+                format!("Symmetric: {}", self.name(a.object_property_iri.as_iri()))
+            }
+            Axiom::AsymmetricObjectProperty(a) => {
+                format!("Asymmetric: {}", self.name(a.object_property_iri.as_iri()))
+            }
+            Axiom::TransitiveObjectProperty(a) => {
+                format!("Transitive: {}", self.name(a.object_property_iri.as_iri()))
+            }
+            Axiom::SubDataPropertyOf(a) => format!(
+                "{} SubPropertyOf {}",
+                self.name(a.subject_iri.as_iri()),
+                self.name(a.parent_iri.as_iri())
+            ),
+            Axiom::EquivalentDataProperties(a) => format!(
+                "{} EquivalentTo {}",
+                self.name(a.data_property_iri_1.as_iri()),
+                self.name(a.data_property_iri_2.as_iri())
+            ),
+            Axiom::DisjointDataProperties(a) => format!(
+                "DisjointProperties: {}",
+                names(a.data_property_iris.iter().map(|p| p.as_iri()).collect())
+            ),
+            Axiom::DataPropertyDomain(a) => format!(
+                "{} Domain {}",
+                self.name(a.iri.as_iri()),
+                self.render_class(&a.cls)
+            ),
+            Axiom::DataPropertyRange(a) => format!(
+                "{} Range {}",
+                self.name(a.iri.as_iri()),
+                self.name(a.datatype_iri.as_iri())
+            ),
+            Axiom::FunctionalDataProperty(a) => {
+                format!("Functional: {}", self.name(a.data_property_iri.as_iri()))
+            }
+            Axiom::DatatypeDefinition(a) => format!(
+                "{} EquivalentTo {}",
+                self.name(a.data_property_iri.as_iri()),
+                self.data_range(&a.datatype)
+            ),
+            Axiom::HasKey(a) => format!(
+                "{} HasKey {}",
+                self.render_class(&a.cls),
+                names(
+                    a.object_properties
+                        .iter()
+                        .map(|p| p.as_iri())
+                        .chain(a.data_properties.iter().map(|p| p.as_iri()))
+                        .collect()
+                )
+            ),
+            Axiom::SameIndividual(a) => match a.individuals.as_slice() {
+                [i1, i2] => format!("{} SameAs {}", self.resource(i1), self.resource(i2)),
+                individuals => format!("SameIndividual: {}", resources(individuals)),
+            },
+            Axiom::DifferentIndividuals(a) => {
+                format!("DifferentIndividuals: {}", resources(&a.individuals))
+            }
+            Axiom::ClassAssertion(a) => format!(
+                "{} Type {}",
+                self.resource(&a.individual),
+                self.render_class(&a.cls)
+            ),
+            Axiom::ObjectPropertyAssertion(a) => format!(
+                "{} {} {}",
+                self.resource(&a.subject),
+                self.name(a.iri.as_iri()),
+                match &a.object {
+                    crate::owl::IRIList::IRI(iri) => self.name(iri),
+                    crate::owl::IRIList::BlankNode(bn) => self.resource(&bn.clone().into()),
+                    crate::owl::IRIList::List(iris) =>
+                        format!("({})", names(iris.iter().collect())),
+                }
+            ),
+            Axiom::NegativeObjectPropertyAssertion(a) => format!(
+                "{} not {} {}",
+                self.resource(&a.subject),
+                self.name(a.iri.as_iri()),
+                self.resource(&a.object)
+            ),
+            Axiom::DataPropertyAssertion(a) => format!(
+                "{} {} {}",
+                self.resource(&a.subject),
+                self.name(a.iri.as_iri()),
+                self.literal(&a.value)
+            ),
+            Axiom::NegativeDataPropertyAssertion(a) => format!(
+                "{} not {} {}",
+                self.resource(&a.subject),
+                self.name(a.data_property_iri.as_iri()),
+                self.literal(&a.value)
+            ),
+        }
+    }
+
+    /// The Manchester name of an entity.
+    pub fn name(&self, iri: &IRI) -> String {
+        if self.use_labels {
+            if let Some(label) = self.labels.get(iri) {
+                return if is_simple_name(label) && !is_keyword(label) {
+                    label.clone()
+                } else {
+                    format!("'{}'", label)
+                };
+            }
+        }
+        let s = iri.as_str();
+        for (prefix, namespace) in self.namespaces() {
+            let Some(local) = local_name(s, namespace) else {
+                continue;
+            };
+            if !is_simple_name(local) {
+                continue;
+            }
+            if !prefix.is_empty() {
+                return format!("{}:{}", prefix, local);
+            }
+            // Unprefixed names must not be mistaken for labels or keywords.
+            if !is_keyword(local) && !self.labelled.contains_key(local) {
+                return local.into();
+            }
+        }
+        format!("<{}>", s)
+    }
+
+    fn operands(&self, classes: &[ClassConstructor], separator: &str) -> String {
+        classes
+            .iter()
+            .map(|c| self.operand(c))
+            .collect::<Vec<String>>()
+            .join(separator)
+    }
+
+    /// Renders an operand of another class expression, in parentheses unless it is atomic.
+    fn operand(&self, cls: &ClassConstructor) -> String {
+        match cls {
+            ClassConstructor::IRI(_)
+            | ClassConstructor::ObjectOneOf(_)
+            | ClassConstructor::ObjectComplementOf(_) => self.render_class(cls),
+            _ => format!("({})", self.render_class(cls)),
+        }
+    }
+
+    fn property(&self, property: &ObjectPropertyConstructor) -> String {
+        match property {
+            ObjectPropertyConstructor::IRI(iri) => self.name(iri.as_iri()),
+            ObjectPropertyConstructor::ObjectInverseOf(inverse) => {
+                format!("inverse {}", self.name(inverse.0.as_iri()))
+            }
+            ObjectPropertyConstructor::ObjectPropertyChain(chain) => chain
+                .0
+                .iter()
+                .map(|p| self.name(p.as_iri()))
+                .collect::<Vec<String>>()
+                .join(" o "),
+        }
+    }
+
+    fn resource(&self, resource: &ResourceId) -> String {
+        match resource {
+            ResourceId::IRI(iri) => self.name(iri),
+            ResourceId::BlankNode(bn) => format!("_:{}", bn),
+        }
+    }
+
+    fn value(&self, value: &LiteralOrIRI) -> String {
+        match value {
+            LiteralOrIRI::IRI(iri) => self.name(iri),
+            LiteralOrIRI::Literal(l) => self.literal(l),
+        }
+    }
+
+    fn literal(&self, literal: &Literal) -> String {
+        let typed = |lexical: &str, datatype: &str| match IRI::new(datatype) {
+            Ok(iri) => format!("{}^^{}", quoted(lexical), self.name(&iri)),
+            Err(_) => quoted(lexical),
+        };
+        match literal {
+            Literal::Raw { data, type_iri } => {
+                typed(&String::from_utf8_lossy(data), type_iri.as_iri().as_str())
+            }
+            Literal::String(s) => quoted(s),
+            Literal::DateTime(d) => typed(d, well_known::xsd_dateTime_str),
+            Literal::LangString { string, lang } => format!("{}@{}", quoted(string), lang),
+            Literal::Number { number, type_iri } => match type_iri {
+                None => number.to_string(),
+                Some(t)
+                    if t.as_iri().as_str() == well_known::xsd_integer_str && !number.is_f64() =>
+                {
+                    number.to_string()
+                }
+                Some(t) => typed(&number.to_string(), t.as_iri().as_str()),
+            },
+            Literal::Duration(d) => typed(&d.to_string(), well_known::xsd_duration_str),
+            Literal::YearMonthDuration(d) => {
+                typed(&d.to_string(), well_known::xsd_yearMonthDuration_str)
+            }
+            Literal::DayTimeDuration(d) => {
+                typed(&d.to_string(), well_known::xsd_dayTimeDuration_str)
+            }
+            Literal::Bool(b) => b.to_string(),
+        }
+    }
+
+    fn restriction(&self, restriction: &DatatypeRestriction) -> String {
+        let datatype = self.name(restriction.datatype_iri.as_iri());
+        if restriction.restrictions.is_empty() {
+            return datatype;
+        }
+        let facets: Vec<String> = restriction
+            .restrictions
+            .iter()
+            .map(|r| {
+                let facet = r.facet();
+                let facet = FACETS
+                    .iter()
+                    .find(|(_, iri)| facet.as_str() == *iri)
+                    .map(|(symbol, _)| symbol.to_string())
+                    .unwrap_or_else(|| self.name(&facet));
+                format!("{} {}", facet, self.literal(r.value()))
+            })
+            .collect();
+        format!("{}[{}]", datatype, facets.join(", "))
+    }
+
+    fn data_range(&self, range: &DatatypeDefinitionConstructor) -> String {
+        match range {
+            DatatypeDefinitionConstructor::DatatypeRestriction(r) => self.restriction(r),
+            DatatypeDefinitionConstructor::DataComplementOf(c) => {
+                format!("not {}", self.name(c.data_property_iri.as_iri()))
+            }
+            DatatypeDefinitionConstructor::DataIntersectionOf(c) => format!(
+                "{} and {}",
+                self.name(c.data_property_iri.as_iri()),
+                self.data_range(&c.datatype)
+            ),
+            DatatypeDefinitionConstructor::DataUnionOf(c) => format!(
+                "{} or {}",
+                self.name(c.data_property_iri.as_iri()),
+                self.data_range(&c.datatype)
+            ),
+            DatatypeDefinitionConstructor::DataOneOf(c) => format!(
+                "{{{}}}",
+                c.literals
+                    .iter()
+                    .map(|l| self.literal(l))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+/// The part of `iri` after `namespace`. Namespaces without a trailing `#` or `/` are separated
+/// from the name by a fragment (see [`crate::owl::IRIBuilder`]).
+pub(super) fn local_name<'i>(iri: &'i str, namespace: &str) -> Option<&'i str> {
+    let local = iri.strip_prefix(namespace)?;
+    if namespace.ends_with('#') || namespace.ends_with('/') {
+        Some(local)
+    } else {
+        local.strip_prefix('#')
+    }
+}
+
+pub(super) fn is_simple_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+pub(super) fn is_keyword(name: &str) -> bool {
+    matches!(
+        name,
+        "and"
+            | "or"
+            | "not"
+            | "that"
+            | "some"
+            | "only"
+            | "value"
+            | "Self"
+            | "min"
+            | "max"
+            | "exactly"
+            | "inverse"
+            | "true"
+            | "false"
+            | "length"
+            | "minLength"
+            | "maxLength"
+            | "pattern"
+    )
+}
+
+fn quoted(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
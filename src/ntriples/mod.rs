@@ -0,0 +1,32 @@
+//! Reading and writing ontologies in [N-Triples](https://www.w3.org/TR/n-triples/) and
+//! [N-Quads](https://www.w3.org/TR/n-quads/).
+//!
+//! Documents are read line by line without a syntax tree, but all triples of a document are
+//! collected before they are mapped to axioms, as the mapping of a triple can depend on triples
+//! further down, e.g. the declarations of its terms. Every statement is written on a line of its
+//! own with absolute IRIs. Blank nodes are labelled in the order of their first use, so
+//! serializing the same ontology twice yields the same document and two versions of an ontology
+//! can be compared line by line.
+//!
+//! ```rust
+//! use owlish::{api::Ontology, ntriples::ToNTriples};
+//!
+//! let ontology = Ontology::parse(r#"
+//!     @prefix owl: <http://www.w3.org/2002/07/owl#> .
+//!     <http://example.com#> a owl:Ontology .
+//!     <http://example.com#Person> a owl:Class .
+//! "#, Default::default()).unwrap();
+//!
+//! let nt = ontology.ntriples();
+//! assert!(nt.contains(
+//!     "<http://example.com#Person> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Class> .\n"
+//! ));
+//!
+//! let parsed = Ontology::parse_ntriples(&nt, Default::default()).unwrap();
+//! assert_eq!(parsed.declarations(), ontology.declarations());
+//! ```
+
+pub(crate) mod parser;
+pub(crate) mod serializer;
+
+pub use serializer::ToNTriples;
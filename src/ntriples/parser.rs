@@ -0,0 +1,330 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use harriet::triple_production::{
+    RdfBlankNode, RdfIri, RdfLiteral, RdfObject, RdfPredicate, RdfSubject, RdfTriple,
+};
+use snowflake::ProcessUniqueId;
+
+use crate::{
+    api::Ontology,
+    error::{Error, ErrorKind, Position},
+    parser::{source::SourceMap, Diagnostic, ParserOptions},
+};
+
+impl Ontology {
+    /// Parses an ontology from an N-Triples document.
+    pub fn parse_ntriples(nt: &str, options: ParserOptions) -> Result<Self, Error> {
+        Self::parse_ntriples_with_diagnostics(nt, options).map(|(ontology, _)| ontology)
+    }
+
+    /// Parses an ontology from an N-Triples document and reports everything that could not be
+    /// understood (see [`Ontology::parse_with_diagnostics`]).
+    pub fn parse_ntriples_with_diagnostics(
+        nt: &str,
+        options: ParserOptions,
+    ) -> Result<(Self, Vec<Diagnostic>), Error> {
+        parse_lines(nt, false, options)
+    }
+
+    /// Parses an ontology from an N-Quads document.
+    ///
+    /// The statements of all graphs are part of the ontology, graph labels are ignored.
+    pub fn parse_nquads(nq: &str, options: ParserOptions) -> Result<Self, Error> {
+        Self::parse_nquads_with_diagnostics(nq, options).map(|(ontology, _)| ontology)
+    }
+
+    /// Parses an ontology from an N-Quads document and reports everything that could not be
+    /// understood (see [`Ontology::parse_with_diagnostics`]).
+    pub fn parse_nquads_with_diagnostics(
+        nq: &str,
+        options: ParserOptions,
+    ) -> Result<(Self, Vec<Diagnostic>), Error> {
+        parse_lines(nq, true, options)
+    }
+}
+
+/// Reads the statements of the document line by line and maps them to an ontology once all of
+/// them are known.
+fn parse_lines(
+    source: &str,
+    quads: bool,
+    options: ParserOptions,
+) -> Result<(Ontology, Vec<Diagnostic>), Error> {
+    let mut triples = Vec::new();
+    let mut positions = Vec::new();
+    let mut blank_nodes = HashMap::new();
+    for (i, line) in source.lines().enumerate() {
+        let mut parser = LineParser {
+            line,
+            line_number: i + 1,
+            pos: 0,
+            blank_nodes: &mut blank_nodes,
+        };
+        if let Some(triple) = parser.statement(quads)? {
+            triples.push(triple);
+            positions.push(Some(Position {
+                line: i + 1,
+                column: parser.statement_column(),
+            }));
+        }
+    }
+    let source_map = SourceMap::for_triples(positions);
+    Ontology::from_triples(triples, HashMap::new(), &source_map, options)
+}
+
+struct LineParser<'l, 'b> {
+    line: &'l str,
+    line_number: usize,
+    /// byte offset in the line
+    pos: usize,
+    blank_nodes: &'b mut HashMap<String, RdfBlankNode>,
+}
+
+impl<'l, 'b> LineParser<'l, 'b> {
+    fn rest(&self) -> &'l str {
+        &self.line[self.pos..]
+    }
+
+    fn position(&self) -> Position {
+        Position {
+            line: self.line_number,
+            column: self.line[..self.pos].chars().count() + 1,
+        }
+    }
+
+    fn statement_column(&self) -> usize {
+        let indentation = self.line.len() - self.line.trim_start().len();
+        self.line[..indentation].chars().count() + 1
+    }
+
+    fn error(&self, kind: ErrorKind, message: String) -> Error {
+        Error::with_kind(kind, message).at_position(self.position())
+    }
+
+    fn expected(&self, what: &str) -> Error {
+        let found: String = self.rest().chars().take(20).collect();
+        if found.is_empty() {
+            self.error(
+                ErrorKind::Syntax,
+                format!("Expected {} but the line ended", what),
+            )
+        } else {
+            self.error(
+                ErrorKind::Syntax,
+                format!("Expected {} but found '{}'", what, found),
+            )
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t']).len();
+    }
+
+    /// Reads the statement of the line (if it is not empty or a comment).
+    fn statement(&mut self, quads: bool) -> Result<Option<RdfTriple<'static>>, Error> {
+        self.skip_whitespace();
+        if self.rest().is_empty() || self.rest().starts_with('#') {
+            return Ok(None);
+        }
+        let subject = match self.rest().chars().next() {
+            Some('<') => RdfSubject::IRI(self.iri()?),
+            Some('_') => RdfSubject::BlankNode(self.blank_node()?),
+            _ => return Err(self.expected("an IRI or blank node as subject")),
+        };
+        self.skip_whitespace();
+        if !self.rest().starts_with('<') {
+            return Err(self.expected("an IRI as predicate"));
+        }
+        let predicate = RdfPredicate::IRI(self.iri()?);
+        self.skip_whitespace();
+        let object = match self.rest().chars().next() {
+            Some('<') => RdfObject::IRI(self.iri()?),
+            Some('_') => RdfObject::BlankNode(self.blank_node()?),
+            Some('"') => RdfObject::Literal(self.literal()?),
+            _ => return Err(self.expected("an IRI, blank node or literal as object")),
+        };
+        self.skip_whitespace();
+        if quads {
+            match self.rest().chars().next() {
+                Some('<') => {
+                    self.iri()?;
+                }
+                Some('_') => {
+                    self.blank_node()?;
+                }
+                _ => {}
+            }
+            self.skip_whitespace();
+        }
+        if !self.rest().starts_with('.') {
+            return Err(self.expected("'.'"));
+        }
+        self.pos += 1;
+        self.skip_whitespace();
+        if !self.rest().is_empty() && !self.rest().starts_with('#') {
+            return Err(self.expected("the end of the line"));
+        }
+        Ok(Some(RdfTriple {
+            subject,
+            predicate,
+            object,
+        }))
+    }
+
+    fn iri(&mut self) -> Result<RdfIri<'static>, Error> {
+        let start = self.position();
+        self.pos += 1;
+        let mut iri = String::new();
+        loop {
+            let Some(c) = self.rest().chars().next() else {
+                return Err(
+                    Error::with_kind(ErrorKind::Syntax, "Unterminated IRI".into())
+                        .at_position(start),
+                );
+            };
+            match c {
+                '>' => {
+                    self.pos += 1;
+                    break;
+                }
+                '\\' => iri.push(self.escape(false)?),
+                c if c <= ' ' || "<\"{}|^`".contains(c) => {
+                    return Err(self.error(
+                        ErrorKind::InvalidIri,
+                        format!("Character '{}' is not allowed in IRIs", c.escape_default()),
+                    ))
+                }
+                c => {
+                    iri.push(c);
+                    self.pos += c.len_utf8();
+                }
+            }
+        }
+        if !iri.contains(':') {
+            return Err(Error::with_kind(
+                ErrorKind::InvalidIri,
+                format!("<{}> is not an absolute IRI", iri),
+            )
+            .at_position(start));
+        }
+        Ok(RdfIri {
+            iri: Cow::Owned(iri),
+        })
+    }
+
+    fn blank_node(&mut self) -> Result<RdfBlankNode, Error> {
+        if !self.rest().starts_with("_:") {
+            return Err(self.expected("a blank node"));
+        }
+        self.pos += 2;
+        let label: String = self
+            .rest()
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || "_-.".contains(*c))
+            .collect();
+        // a label may not end with a '.', which terminates the statement instead
+        let label = label.trim_end_matches('.');
+        if label.is_empty() {
+            return Err(self.expected("a blank node label"));
+        }
+        self.pos += label.len();
+        Ok(self
+            .blank_nodes
+            .entry(label.to_string())
+            .or_insert_with(|| RdfBlankNode {
+                internal_id: ProcessUniqueId::new(),
+            })
+            .clone())
+    }
+
+    fn literal(&mut self) -> Result<RdfLiteral<'static>, Error> {
+        let start = self.position();
+        self.pos += 1;
+        let mut lexical_form = String::new();
+        loop {
+            let Some(c) = self.rest().chars().next() else {
+                return Err(
+                    Error::with_kind(ErrorKind::Syntax, "Unterminated literal".into())
+                        .at_position(start),
+                );
+            };
+            match c {
+                '"' => {
+                    self.pos += 1;
+                    break;
+                }
+                '\\' => lexical_form.push(self.escape(true)?),
+                c => {
+                    lexical_form.push(c);
+                    self.pos += c.len_utf8();
+                }
+            }
+        }
+
+        let (mut datatype_iri, mut language_tag) = (None, None);
+        if self.rest().starts_with("^^") {
+            self.pos += 2;
+            if !self.rest().starts_with('<') {
+                return Err(self.expected("an IRI as datatype"));
+            }
+            datatype_iri = Some(self.iri()?);
+        } else if self.rest().starts_with('@') {
+            self.pos += 1;
+            let tag: String = self
+                .rest()
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+                .collect();
+            if tag.is_empty() {
+                return Err(self.expected("a language tag"));
+            }
+            self.pos += tag.len();
+            language_tag = Some(Cow::Owned(tag));
+        }
+        Ok(RdfLiteral {
+            lexical_form: Cow::Owned(lexical_form),
+            datatype_iri,
+            language_tag,
+        })
+    }
+
+    /// Reads an escape sequence. Literals allow escaped characters in addition to unicode
+    /// escapes.
+    fn escape(&mut self, in_literal: bool) -> Result<char, Error> {
+        let position = self.position();
+        let invalid = |sequence: &str| {
+            Error::with_kind(
+                ErrorKind::Syntax,
+                format!("Invalid escape sequence '{}'", sequence),
+            )
+            .at_position(position)
+        };
+        let mut chars = self.rest()[1..].chars();
+        let Some(c) = chars.next() else {
+            return Err(invalid("\\"));
+        };
+        let escaped = match c {
+            'u' | 'U' => {
+                let length = if c == 'u' { 4 } else { 8 };
+                let hex: String = chars.take(length).collect();
+                let code = (hex.len() == length)
+                    .then(|| u32::from_str_radix(&hex, 16).ok())
+                    .flatten()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| invalid(&format!("\\{}{}", c, hex)))?;
+                self.pos += 2 + length;
+                return Ok(code);
+            }
+            't' if in_literal => '\t',
+            'b' if in_literal => '\u{8}',
+            'n' if in_literal => '\n',
+            'r' if in_literal => '\r',
+            'f' if in_literal => '\u{c}',
+            '"' | '\'' | '\\' if in_literal => c,
+            c => return Err(invalid(&format!("\\{}", c))),
+        };
+        self.pos += 2;
+        Ok(escaped)
+    }
+}
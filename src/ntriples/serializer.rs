@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use crate::{
+    api::Ontology,
+    owl::{well_known, BlankNode, ResourceId},
+    rdf::{Term, ToTriples, Triple},
+};
+
+pub trait ToNTriples {
+    fn ntriples(&self) -> String;
+
+    /// Like [`ToNTriples::ntriples`] but every statement is part of the graph named by the
+    /// ontology IRI.
+    fn nquads(&self) -> String;
+}
+
+impl ToNTriples for Ontology {
+    fn ntriples(&self) -> String {
+        write(self, None)
+    }
+
+    fn nquads(&self) -> String {
+        write(self, Some(self.iri().as_str()))
+    }
+}
+
+fn write(ontology: &Ontology, graph: Option<&str>) -> String {
+    let mut labels = BlankNodeLabels::default();
+    let mut out = String::new();
+    for Triple {
+        subject,
+        predicate,
+        object,
+    } in ontology.to_triples()
+    {
+        match subject {
+            ResourceId::IRI(iri) => out.push_str(&iri_ref(iri.as_str())),
+            ResourceId::BlankNode(bn) => out.push_str(&labels.get(bn)),
+        }
+        out.push(' ');
+        out.push_str(&iri_ref(predicate.as_str()));
+        out.push(' ');
+        match object {
+            Term::IRI(iri) => out.push_str(&iri_ref(iri.as_str())),
+            Term::BlankNode(bn) => out.push_str(&labels.get(bn)),
+            Term::Literal(literal) => {
+                out.push_str(&quoted(&literal.lexical_form));
+                if let Some(lang) = literal.lang {
+                    out.push('@');
+                    out.push_str(&lang);
+                } else {
+                    // simple literals are written with their datatype, too
+                    let datatype = literal
+                        .datatype
+                        .unwrap_or_else(|| well_known::xsd_string().as_iri().clone());
+                    out.push_str("^^");
+                    out.push_str(&iri_ref(datatype.as_str()));
+                }
+            }
+        }
+        if let Some(graph) = graph {
+            out.push(' ');
+            out.push_str(&iri_ref(graph));
+        }
+        out.push_str(" .\n");
+    }
+    out
+}
+
+/// Labels blank nodes in the order they are first used.
+#[derive(Default)]
+struct BlankNodeLabels(HashMap<BlankNode, String>);
+
+impl BlankNodeLabels {
+    fn get(&mut self, bn: BlankNode) -> String {
+        let next = self.0.len();
+        self.0
+            .entry(bn)
+            .or_insert_with(|| format!("_:b{}", next))
+            .clone()
+    }
+}
+
+fn iri_ref(iri: &str) -> String {
+    let mut out = String::with_capacity(iri.len() + 2);
+    out.push('<');
+    for c in iri.chars() {
+        if c <= ' ' || "<>\"{}|^`\\".contains(c) {
+            out.push_str(&format!("\\u{:04X}", c as u32));
+        } else {
+            out.push(c);
+        }
+    }
+    out.push('>');
+    out
+}
+
+fn quoted(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,31 @@
+//! Reading and writing ontologies in the
+//! [OWL 2 Functional-Style Syntax](https://www.w3.org/TR/owl2-syntax/).
+//!
+//! ```rust
+//! use owlish::{api::Ontology, ofn::ToOfn};
+//!
+//! let ontology = Ontology::parse_ofn(r#"
+//! Prefix(:=<http://example.com/>)
+//! Ontology(<http://example.com/>
+//!     Declaration(Class(:Person))
+//! )
+//! "#, Default::default()).unwrap();
+//! assert!(ontology.ofn().contains("Declaration(Class(<http://example.com/Person>))"));
+//! ```
+
+pub(crate) mod parser;
+pub(crate) mod serializer;
+
+pub use serializer::ToOfn;
+
+use crate::owl::well_known;
+
+/// Prefixes which are known in every document without being declared.
+pub(crate) fn well_known_prefixes() -> [(&'static str, &'static str); 4] {
+    [
+        ("owl", well_known::owl_base_str),
+        ("rdf", well_known::rdf_base_str),
+        ("rdfs", well_known::rdfs_base_str),
+        ("xsd", well_known::xsd_base_str),
+    ]
+}
@@ -0,0 +1,1155 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use crate::{
+    api::Ontology,
+    error::{Error, ErrorKind, Position},
+    owl::*,
+    parser::{matcher::Value, Diagnostic, ParserOptions},
+};
+
+use super::well_known_prefixes;
+
+impl Ontology {
+    /// Parses an ontology in OWL 2 Functional-Style Syntax.
+    ///
+    /// Declared prefixes which belong to an `Import(...)` become imports of the ontology,
+    /// annotations of the ontology itself become annotation assertions on the ontology IRI.
+    ///
+    /// With [`crate::parser::ParserOptionsBuilder::lenient`] axioms which can't be read are
+    /// skipped. The kind of every entity is stated in the document, so known declarations are not
+    /// needed.
+    pub fn parse_ofn(ofn: &str, options: ParserOptions) -> Result<Self, Error> {
+        Self::parse_ofn_with_diagnostics(ofn, options).map(|(ontology, _)| ontology)
+    }
+
+    /// Parses an ontology in OWL 2 Functional-Style Syntax and reports the axioms which were
+    /// skipped in lenient mode (see [`Ontology::parse_with_diagnostics`]).
+    pub fn parse_ofn_with_diagnostics(
+        ofn: &str,
+        options: ParserOptions,
+    ) -> Result<(Self, Vec<Diagnostic>), Error> {
+        OfnParser::new(ofn)?.lenient(options.lenient).ontology()
+    }
+}
+
+/// A token of a functional-style document, other syntaxes which share its structure (e.g.
+/// OWL/XML) are translated into the same tokens.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Token<'a> {
+    Open,
+    Close,
+    Equals,
+    DoubleCaret,
+    LangTag(&'a str),
+    FullIri(Cow<'a, str>),
+    PrefixedName(&'a str, &'a str),
+    BlankNode(&'a str),
+    String(String),
+    Number(&'a str),
+    Keyword(&'a str),
+}
+
+impl<'a> std::fmt::Display for Token<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Open => write!(f, "'('"),
+            Token::Close => write!(f, "')'"),
+            Token::Equals => write!(f, "'='"),
+            Token::DoubleCaret => write!(f, "'^^'"),
+            Token::LangTag(tag) => write!(f, "'@{}'", tag),
+            Token::FullIri(iri) => write!(f, "'<{}>'", iri),
+            Token::PrefixedName(prefix, local) => write!(f, "'{}:{}'", prefix, local),
+            Token::BlankNode(label) => write!(f, "'_:{}'", label),
+            Token::String(s) => write!(f, "'\"{}\"'", s),
+            Token::Number(n) => write!(f, "'{}'", n),
+            Token::Keyword(k) => write!(f, "'{}'", k),
+        }
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<(Token<'_>, usize)>, Error> {
+    let error = |offset: usize, message: String| {
+        Error::with_kind(ErrorKind::Syntax, message)
+            .at_position(Position::from_offset(source, offset))
+    };
+    let is_delimiter =
+        |c: char| c.is_whitespace() || matches!(c, '(' | ')' | '=' | '"' | '<' | '^' | '@' | '#');
+
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '#' => {
+                for (_, c) in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+                continue;
+            }
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '=' => Token::Equals,
+            '^' => match chars.next() {
+                Some((_, '^')) => Token::DoubleCaret,
+                _ => return Err(error(start, "Expected '^^'".into())),
+            },
+            '@' => {
+                let mut end = start + 1;
+                while let Some((i, c)) = chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || *c == '-') {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                Token::LangTag(&source[start + 1..end])
+            }
+            '<' => {
+                let Some(end) = source[start..].find('>') else {
+                    return Err(error(start, "Unterminated IRI".into()));
+                };
+                let end = start + end;
+                while chars.peek().map(|(i, _)| *i <= end).unwrap_or(false) {
+                    chars.next();
+                }
+                Token::FullIri(Cow::Borrowed(&source[start + 1..end]))
+            }
+            '"' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((i, '\\')) => match chars.next() {
+                            Some((_, '"')) => string.push('"'),
+                            Some((_, '\\')) => string.push('\\'),
+                            Some((_, 'n')) => string.push('\n'),
+                            Some((_, 'r')) => string.push('\r'),
+                            Some((_, 't')) => string.push('\t'),
+                            _ => return Err(error(i, "Invalid escape sequence".into())),
+                        },
+                        Some((_, c)) => string.push(c),
+                        None => return Err(error(start, "Unterminated string literal".into())),
+                    }
+                }
+                Token::String(string)
+            }
+            _ => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, c)) = chars.peek() {
+                    if is_delimiter(*c) {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                let word = &source[start..end];
+                if let Some(label) = word.strip_prefix("_:") {
+                    Token::BlankNode(label)
+                } else if let Some((prefix, local)) = word.split_once(':') {
+                    Token::PrefixedName(prefix, local)
+                } else if word.chars().all(|c| c.is_ascii_digit()) {
+                    Token::Number(word)
+                } else {
+                    Token::Keyword(word)
+                }
+            }
+        };
+        tokens.push((token, start));
+    }
+    Ok(tokens)
+}
+
+pub(crate) struct OfnParser<'a> {
+    source: &'a str,
+    tokens: Vec<(Token<'a>, usize)>,
+    pos: usize,
+    prefixes: HashMap<String, String>,
+    blank_nodes: HashMap<String, BlankNode>,
+    /// Skip axioms which can't be read instead of failing.
+    lenient: bool,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> OfnParser<'a> {
+    fn new(source: &'a str) -> Result<Self, Error> {
+        Ok(Self::from_tokens(source, tokenize(source)?))
+    }
+
+    /// Creates a parser for tokens which were read from `source` at the given byte offsets.
+    pub(crate) fn from_tokens(source: &'a str, tokens: Vec<(Token<'a>, usize)>) -> Self {
+        Self {
+            source,
+            tokens,
+            pos: 0,
+            prefixes: well_known_prefixes()
+                .into_iter()
+                .map(|(prefix, iri)| (prefix.into(), iri.into()))
+                .collect(),
+            blank_nodes: Default::default(),
+            lenient: false,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub(crate) fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Anonymous individuals with the same label denote the same node within a document.
+    fn blank_node(&mut self, label: String) -> BlankNode {
+        self.blank_nodes
+            .entry(label)
+            .or_insert_with(BlankNode::new)
+            .clone()
+    }
+
+    fn error(&self, kind: ErrorKind, message: String) -> Error {
+        let offset = match self.tokens.get(self.pos) {
+            Some((_, offset)) => *offset,
+            None => self.source.len(),
+        };
+        Error::with_kind(kind, message).at_position(Position::from_offset(self.source, offset))
+    }
+
+    fn unexpected(&self, expected: &str) -> Error {
+        match self.peek() {
+            Some(token) => self.error(
+                ErrorKind::Syntax,
+                format!("Expected {} but found {}", expected, token),
+            ),
+            None => self.error(
+                ErrorKind::Syntax,
+                format!("Expected {} but the document ended", expected),
+            ),
+        }
+    }
+
+    fn unsupported(&self, construct: &str) -> Error {
+        self.error(
+            ErrorKind::UnsupportedConstruct,
+            format!("{} is not supported", construct),
+        )
+    }
+
+    fn peek(&self) -> Option<&Token<'a>> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn peek_keyword(&self) -> Option<&'a str> {
+        match self.peek() {
+            Some(Token::Keyword(k)) => Some(k),
+            _ => None,
+        }
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.tokens.get(self.pos).map(|(token, _)| token.clone());
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: Token, name: &str) -> Result<(), Error> {
+        if self.peek() == Some(&expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected(name))
+        }
+    }
+
+    fn open(&mut self) -> Result<(), Error> {
+        self.expect(Token::Open, "'('")
+    }
+
+    fn close(&mut self) -> Result<(), Error> {
+        self.expect(Token::Close, "')'")
+    }
+
+    fn keyword(&mut self, keyword: &str) -> Result<(), Error> {
+        self.expect(Token::Keyword(keyword), keyword)
+    }
+
+    fn is_iri_next(&self) -> bool {
+        matches!(
+            self.peek(),
+            Some(Token::FullIri(_)) | Some(Token::PrefixedName(_, _))
+        )
+    }
+
+    fn iri(&mut self) -> Result<IRI, Error> {
+        let iri = match self.peek() {
+            Some(Token::FullIri(iri)) => iri.to_string(),
+            Some(Token::PrefixedName(prefix, local)) => match self.prefixes.get(*prefix) {
+                Some(namespace) => format!("{}{}", namespace, local),
+                None => {
+                    return Err(self.error(
+                        ErrorKind::Syntax,
+                        format!("Prefix '{}:' is not declared", prefix),
+                    ))
+                }
+            },
+            Some(Token::BlankNode(_)) => return Err(self.unsupported("Anonymous individual")),
+            _ => return Err(self.unexpected("an IRI")),
+        };
+        let iri = IRI::new(&iri).map_err(|e| {
+            self.error(
+                ErrorKind::InvalidIri,
+                format!("Invalid IRI '{}': {}", iri, e),
+            )
+        })?;
+        self.pos += 1;
+        Ok(iri)
+    }
+
+    fn number(&mut self) -> Result<u64, Error> {
+        match self.peek() {
+            Some(Token::Number(n)) => {
+                let n = n.parse().map_err(|_| {
+                    self.error(ErrorKind::Syntax, format!("Invalid cardinality '{}'", n))
+                })?;
+                self.pos += 1;
+                Ok(n)
+            }
+            _ => Err(self.unexpected("a non-negative integer")),
+        }
+    }
+
+    fn literal(&mut self) -> Result<Literal, Error> {
+        let Some(Token::String(lexical_form)) = self.peek().cloned() else {
+            return Err(self.unexpected("a literal"));
+        };
+        let start = self.pos;
+        self.pos += 1;
+        let (datatype_iri, language_tag) = match self.peek() {
+            Some(Token::DoubleCaret) => {
+                self.pos += 1;
+                (Some(Cow::Owned(self.iri()?.to_string())), None)
+            }
+            Some(Token::LangTag(tag)) => {
+                let tag = Cow::Borrowed(*tag);
+                self.pos += 1;
+                (None, Some(tag))
+            }
+            _ => (None, None),
+        };
+        Value::Literal {
+            lexical_form: Cow::Owned(lexical_form.clone()),
+            datatype_iri,
+            language_tag,
+        }
+        .try_into()
+        .map_err(|_| {
+            self.pos = start;
+            self.error(
+                ErrorKind::InvalidLiteral,
+                format!("Invalid literal \"{}\"", lexical_form),
+            )
+        })
+    }
+
+    /// Skips the construct at the current position together with its arguments.
+    fn skip(&mut self) -> Result<(), Error> {
+        self.pos += 1;
+        self.open()?;
+        let mut depth = 1;
+        while depth > 0 {
+            match self.next() {
+                Some(Token::Open) => depth += 1,
+                Some(Token::Close) => depth -= 1,
+                Some(_) => {}
+                None => {
+                    self.pos -= 1;
+                    return Err(self.unexpected("')'"));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a parenthesized list of elements, e.g. the key properties of `HasKey`.
+    fn list<T>(&mut self, element: fn(&mut Self) -> Result<T, Error>) -> Result<Vec<T>, Error> {
+        self.open()?;
+        let mut elements = Vec::new();
+        while self.peek() != Some(&Token::Close) {
+            elements.push(element(self)?);
+        }
+        self.close()?;
+        Ok(elements)
+    }
+
+    /// Parses elements until the closing parenthesis of the current construct.
+    fn until_close<T>(
+        &mut self,
+        element: fn(&mut Self) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        let mut elements = Vec::new();
+        while self.peek() != Some(&Token::Close) {
+            if self.peek().is_none() {
+                return Err(self.unexpected("')'"));
+            }
+            elements.push(element(self)?);
+        }
+        Ok(elements)
+    }
+
+    pub(crate) fn ontology(mut self) -> Result<(Ontology, Vec<Diagnostic>), Error> {
+        while self.peek_keyword() == Some("Prefix") {
+            self.pos += 1;
+            self.open()?;
+            let prefix = match self.next() {
+                Some(Token::PrefixedName(prefix, "")) => prefix,
+                _ => {
+                    self.pos -= 1;
+                    return Err(self.unexpected("a prefix name"));
+                }
+            };
+            self.expect(Token::Equals, "'='")?;
+            let Some(Token::FullIri(iri)) = self.peek().cloned() else {
+                return Err(self.unexpected("a full IRI"));
+            };
+            self.pos += 1;
+            self.close()?;
+            self.prefixes.insert(prefix.into(), iri.into());
+        }
+
+        self.keyword("Ontology")?;
+        self.open()?;
+        if !self.is_iri_next() {
+            return Err(self.error(
+                ErrorKind::MissingOntologyIri,
+                "The ontology does not declare an IRI".into(),
+            ));
+        }
+        let iri = self.iri()?;
+        // version IRI
+        if self.is_iri_next() {
+            self.iri()?;
+        }
+        let mut ontology = Ontology::new(iri.clone());
+
+        while self.peek_keyword() == Some("Import") {
+            self.pos += 1;
+            self.open()?;
+            let import = self.iri()?;
+            self.close()?;
+            let name = self
+                .prefixes
+                .iter()
+                .filter(|(_, namespace)| {
+                    namespace.trim_end_matches(['#', '/'])
+                        == import.as_str().trim_end_matches(['#', '/'])
+                })
+                .map(|(prefix, _)| prefix.clone())
+                .min()
+                .unwrap_or_else(|| import.to_string());
+            ontology.push_import(&name, import);
+        }
+
+        for annotation in self.annotations()? {
+            ontology.push_axiom(annotation.to_assertion(ResourceId::IRI(iri.clone())).into());
+        }
+
+        while self.peek() != Some(&Token::Close) {
+            if self.peek().is_none() {
+                return Err(self.unexpected("')'"));
+            }
+            let start = self.pos;
+            if let Err(e) = self.axiom(&mut ontology) {
+                if !self.lenient {
+                    return Err(e);
+                }
+                self.diagnostics.push(Diagnostic::skipped(e));
+                self.pos = start;
+                self.skip()?;
+            }
+        }
+        self.close()?;
+        if self.peek().is_some() {
+            return Err(self.unexpected("the end of the document"));
+        }
+        Ok((ontology, self.diagnostics))
+    }
+
+    fn annotations(&mut self) -> Result<Vec<Annotation>, Error> {
+        let mut annotations = Vec::new();
+        while self.peek_keyword() == Some("Annotation") {
+            annotations.push(self.annotation()?);
+        }
+        Ok(annotations)
+    }
+
+    fn annotation(&mut self) -> Result<Annotation, Error> {
+        self.keyword("Annotation")?;
+        self.open()?;
+        let annotations = self.annotations()?.into_iter().map(Box::new).collect();
+        let iri = self.iri()?;
+        let value = self.annotation_value()?;
+        self.close()?;
+        Ok(Annotation::new(iri.into(), value, annotations))
+    }
+
+    fn annotation_value(&mut self) -> Result<LiteralOrIRI, Error> {
+        match self.peek() {
+            Some(Token::String(_)) => Ok(self.literal()?.into()),
+            _ => Ok(self.iri()?.into()),
+        }
+    }
+
+    fn named_individual(&mut self) -> Result<IndividualIRI, Error> {
+        Ok(self.iri()?.into())
+    }
+
+    /// A named or an anonymous individual.
+    fn individual(&mut self) -> Result<ResourceId, Error> {
+        match self.peek() {
+            Some(Token::BlankNode(label)) => {
+                let label = label.to_string();
+                self.pos += 1;
+                Ok(ResourceId::BlankNode(self.blank_node(label)))
+            }
+            _ => Ok(ResourceId::IRI(self.iri()?)),
+        }
+    }
+
+    fn object_property(&mut self) -> Result<ObjectPropertyIRI, Error> {
+        if self.peek_keyword() == Some("ObjectInverseOf") {
+            return Err(self.unsupported("ObjectInverseOf in this position"));
+        }
+        Ok(self.iri()?.into())
+    }
+
+    fn object_property_expression(&mut self) -> Result<ObjectPropertyConstructor, Error> {
+        if self.peek_keyword() == Some("ObjectInverseOf") {
+            self.pos += 1;
+            self.open()?;
+            let iri = self.iri()?;
+            self.close()?;
+            return Ok(ObjectInverseOf(iri.into()).into());
+        }
+        Ok(ObjectPropertyConstructor::IRI(self.iri()?.into()))
+    }
+
+    fn data_property(&mut self) -> Result<DataPropertyIRI, Error> {
+        Ok(self.iri()?.into())
+    }
+
+    fn datatype(&mut self) -> Result<DatatypeIRI, Error> {
+        if !self.is_iri_next() {
+            return Err(self.unsupported("A complex data range in this position"));
+        }
+        Ok(self.iri()?.into())
+    }
+
+    fn datatype_restriction(&mut self) -> Result<DatatypeRestriction, Error> {
+        if self.is_iri_next() {
+            return Ok(DatatypeRestriction::new(self.iri()?.into(), vec![], vec![]));
+        }
+        if self.peek_keyword() != Some("DatatypeRestriction") {
+            return Err(self.unsupported("A data range other than a datatype restriction"));
+        }
+        self.pos += 1;
+        self.open()?;
+        let datatype = self.iri()?;
+        let restrictions = self.until_close(|p| {
+            let start = p.pos;
+            let facet = p.iri()?;
+            let value = p.literal()?;
+            Restriction::new(&facet, value).ok_or_else(|| {
+                p.pos = start;
+                p.unexpected("a constraining facet")
+            })
+        })?;
+        self.close()?;
+        Ok(DatatypeRestriction::new(
+            datatype.into(),
+            restrictions,
+            vec![],
+        ))
+    }
+
+    fn data_range(&mut self) -> Result<DatatypeDefinitionConstructor, Error> {
+        let keyword = match self.peek_keyword() {
+            Some("DatatypeRestriction") => None,
+            None if self.is_iri_next() => None,
+            Some(keyword) => Some(keyword),
+            None => return Err(self.unexpected("a data range")),
+        };
+        let Some(keyword) = keyword else {
+            return Ok(DatatypeDefinitionConstructor::DatatypeRestriction(
+                self.datatype_restriction()?,
+            ));
+        };
+        self.pos += 1;
+        self.open()?;
+        let range = match keyword {
+            "DataComplementOf" => DatatypeDefinitionConstructor::DataComplementOf(
+                DataComplementOf::new(self.datatype()?.as_iri().clone().into(), vec![]),
+            ),
+            "DataOneOf" => DatatypeDefinitionConstructor::DataOneOf(DataOneOf::new(
+                self.until_close(Self::literal)?,
+                vec![],
+            )),
+            "DataIntersectionOf" | "DataUnionOf" => {
+                let start = self.pos;
+                let mut members = self.until_close(|p| {
+                    if p.is_iri_next() {
+                        Ok(Err(p.iri()?))
+                    } else {
+                        Ok(Ok(p.data_range()?))
+                    }
+                })?;
+                if members.len() < 2 {
+                    self.pos = start;
+                    return Err(self.unexpected("at least two data ranges"));
+                }
+                // The members are nested from the right, each but the last has to be a datatype.
+                let last = match members.pop() {
+                    Some(Err(iri)) => DatatypeDefinitionConstructor::DatatypeRestriction(
+                        DatatypeRestriction::new(iri.into(), vec![], vec![]),
+                    ),
+                    Some(Ok(range)) => range,
+                    None => unreachable!(),
+                };
+                let mut range = last;
+                for member in members.into_iter().rev() {
+                    let Err(iri) = member else {
+                        self.pos = start;
+                        return Err(self.unsupported(&format!(
+                            "{} with a complex data range before the last member",
+                            keyword
+                        )));
+                    };
+                    range =
+                        if keyword == "DataIntersectionOf" {
+                            DatatypeDefinitionConstructor::DataIntersectionOf(
+                                DataIntersectionOf::new(iri.into(), Box::new(range), vec![]),
+                            )
+                        } else {
+                            DatatypeDefinitionConstructor::DataUnionOf(DataUnionOf::new(
+                                iri.into(),
+                                Box::new(range),
+                                vec![],
+                            ))
+                        };
+                }
+                range
+            }
+            _ => {
+                self.pos -= 2;
+                return Err(self.unexpected("a data range"));
+            }
+        };
+        self.close()?;
+        Ok(range)
+    }
+
+    fn class_expression(&mut self) -> Result<ClassConstructor, Error> {
+        let Some(keyword) = self.peek_keyword() else {
+            return Ok(ClassConstructor::IRI(self.iri()?.into()));
+        };
+        self.pos += 1;
+        self.open()?;
+        let cls = match keyword {
+            "ObjectIntersectionOf" => ClassConstructor::ObjectIntersectionOf(
+                ObjectIntersectionOf::new(self.until_close(Self::class_expression)?, vec![]),
+            ),
+            "ObjectUnionOf" => ClassConstructor::ObjectUnionOf(ObjectUnionOf::new(
+                self.until_close(Self::class_expression)?,
+                vec![],
+            )),
+            "ObjectComplementOf" => ClassConstructor::ObjectComplementOf(ObjectComplementOf::new(
+                Box::new(self.class_expression()?),
+                vec![],
+            )),
+            "ObjectOneOf" => ClassConstructor::ObjectOneOf(ObjectOneOf::new(
+                self.until_close(Self::named_individual)?,
+                vec![],
+            )),
+            "ObjectSomeValuesFrom" => {
+                let property = self.object_property_expression()?;
+                let cls = self.class_expression()?;
+                ClassConstructor::ObjectSomeValuesFrom(ObjectSomeValuesFrom::new(
+                    property,
+                    Box::new(cls),
+                    vec![],
+                ))
+            }
+            "ObjectAllValuesFrom" => {
+                let property = self.object_property_expression()?;
+                let cls = self.class_expression()?;
+                ClassConstructor::ObjectAllValuesFrom(ObjectAllValuesFrom::new(
+                    property,
+                    Box::new(cls),
+                    vec![],
+                ))
+            }
+            "ObjectHasValue" => {
+                let property = self.object_property_expression()?;
+                let individual = self.iri()?;
+                ClassConstructor::ObjectHasValue(ObjectHasValue::new(
+                    property,
+                    individual.into(),
+                    vec![],
+                ))
+            }
+            "ObjectHasSelf" => ClassConstructor::ObjectHasSelf(ObjectHasSelf::new(
+                self.object_property_expression()?,
+                vec![],
+            )),
+            "ObjectMinCardinality" | "ObjectMaxCardinality" | "ObjectExactCardinality" => {
+                let value = self.number()?;
+                let property = self.object_property()?;
+                let cls = match self.peek() {
+                    Some(Token::Close) => None,
+                    _ => Some(Box::new(self.class_expression()?)),
+                };
+                match keyword {
+                    "ObjectMinCardinality" => ClassConstructor::ObjectMinCardinality(
+                        ObjectMinCardinality::new(value, property, cls),
+                    ),
+                    "ObjectMaxCardinality" => ClassConstructor::ObjectMaxCardinality(
+                        ObjectMaxCardinality::new(value, property, cls),
+                    ),
+                    _ => ClassConstructor::ObjectExactCardinality(ObjectExactCardinality::new(
+                        value, property, cls,
+                    )),
+                }
+            }
+            "DataSomeValuesFrom" => {
+                let property = self.data_property()?;
+                if self.is_iri_next()
+                    && self.tokens.get(self.pos + 1).map(|(t, _)| t) != Some(&Token::Close)
+                {
+                    return Err(
+                        self.unsupported("DataSomeValuesFrom with multiple data properties")
+                    );
+                }
+                let restriction = self.datatype_restriction()?;
+                ClassConstructor::DataSomeValuesFrom(DataSomeValuesFrom::new(
+                    property,
+                    restriction,
+                    vec![],
+                ))
+            }
+            "DataAllValuesFrom" => {
+                let property = self.data_property()?;
+                let datatype = self.datatype()?;
+                ClassConstructor::DataAllValuesFrom(DataAllValuesFrom::new(
+                    property,
+                    datatype,
+                    vec![],
+                ))
+            }
+            "DataHasValue" => {
+                let property = self.data_property()?;
+                let value = self.literal()?;
+                ClassConstructor::DataHasValue(DataHasValue::new(property, value, vec![]))
+            }
+            "DataMinCardinality" | "DataMaxCardinality" | "DataExactCardinality" => {
+                let value = self.number()?;
+                let property = self.data_property()?;
+                let datatype = match self.peek() {
+                    Some(Token::Close) => None,
+                    _ => Some(self.datatype()?),
+                };
+                match keyword {
+                    "DataMinCardinality" => ClassConstructor::DataMinCardinality(
+                        DataMinCardinality::new(value, property, datatype),
+                    ),
+                    "DataMaxCardinality" => ClassConstructor::DataMaxCardinality(
+                        DataMaxCardinality::new(value, property, datatype),
+                    ),
+                    _ => ClassConstructor::DataExactCardinality(DataExactCardinality::new(
+                        value, property, datatype,
+                    )),
+                }
+            }
+            _ => {
+                self.pos -= 2;
+                return Err(self.unexpected("a class expression"));
+            }
+        };
+        self.close()?;
+        Ok(cls)
+    }
+
+    fn declaration(&mut self, annotations: Vec<Annotation>) -> Result<Declaration, Error> {
+        let Some(keyword) = self.peek_keyword() else {
+            return Err(self.unexpected("an entity"));
+        };
+        self.pos += 1;
+        self.open()?;
+        let iri = self.iri()?;
+        self.close()?;
+        Ok(match keyword {
+            "Class" => Declaration::Class {
+                iri: iri.into(),
+                annotations,
+            },
+            "Datatype" => Declaration::Datatype {
+                iri: iri.into(),
+                annotations,
+            },
+            "ObjectProperty" => Declaration::ObjectProperty {
+                iri: iri.into(),
+                annotations,
+            },
+            "DataProperty" => Declaration::DataProperty {
+                iri: iri.into(),
+                annotations,
+            },
+            "AnnotationProperty" => Declaration::AnnotationProperty {
+                iri: iri.into(),
+                annotations,
+            },
+            "NamedIndividual" => Declaration::NamedIndividual {
+                iri: iri.into(),
+                annotations,
+            },
+            _ => {
+                self.pos -= 4;
+                return Err(self.unexpected("an entity"));
+            }
+        })
+    }
+
+    fn axiom(&mut self, o: &mut Ontology) -> Result<(), Error> {
+        let Some(keyword) = self.peek_keyword() else {
+            return Err(self.unexpected("an axiom"));
+        };
+        let start = self.pos;
+        self.pos += 1;
+        self.open()?;
+        let annotations = self.annotations()?;
+        match keyword {
+            "Declaration" => {
+                let declaration = self.declaration(annotations)?;
+                o.push_declaration(declaration);
+            }
+
+            // class expression axioms
+            "SubClassOf" => {
+                let cls = self.class_expression()?;
+                let parent = self.class_expression()?;
+                o.push_axiom(Axiom::SubClassOf(SubClassOf::new(
+                    Box::new(cls),
+                    Box::new(parent),
+                    annotations,
+                )));
+            }
+            "EquivalentClasses" => {
+                let mut classes = self.until_close(Self::class_expression)?;
+                // The axiom is stated from the perspective of a named class.
+                let Some(named) = classes.iter().position(|c| c.iri().is_some()) else {
+                    self.pos = start;
+                    return Err(self.unsupported("EquivalentClasses without a named class"));
+                };
+                let ClassConstructor::IRI(class_iri) = classes.remove(named) else {
+                    unreachable!()
+                };
+                for cls in classes {
+                    o.push_axiom(Axiom::EquivalentClasses(EquivalentClasses::new(
+                        class_iri.clone(),
+                        Box::new(cls),
+                        annotations.clone(),
+                    )));
+                }
+            }
+            "DisjointClasses" => {
+                let classes = self.until_close(Self::class_expression)?;
+                o.push_axiom(Axiom::DisjointClasses(DisjointClasses::new(
+                    classes,
+                    annotations,
+                )));
+            }
+            "DisjointUnion" => {
+                let class_iri = self.iri()?;
+                let classes = self.until_close(Self::class_expression)?;
+                o.push_axiom(Axiom::DisjointUnion(DisjointUnion::new(
+                    class_iri.into(),
+                    classes,
+                    annotations,
+                )));
+            }
+
+            // object property axioms
+            "SubObjectPropertyOf" => {
+                let property = if self.peek_keyword() == Some("ObjectPropertyChain") {
+                    self.pos += 1;
+                    self.open()?;
+                    let chain = self.until_close(Self::object_property)?;
+                    self.close()?;
+                    ObjectPropertyChain(chain).into()
+                } else {
+                    self.object_property_expression()?
+                };
+                let parent = self.object_property()?;
+                o.push_axiom(Axiom::SubObjectPropertyOf(SubObjectPropertyOf::new(
+                    property,
+                    parent,
+                    annotations,
+                )));
+            }
+            "EquivalentObjectProperties" => {
+                let first = self.object_property()?;
+                for other in self.until_close(Self::object_property)? {
+                    o.push_axiom(Axiom::EquivalentObjectProperties(
+                        EquivalentObjectProperties::new(first.clone(), other, annotations.clone()),
+                    ));
+                }
+            }
+            "DisjointObjectProperties" => {
+                let properties = self.until_close(Self::object_property)?;
+                o.push_axiom(Axiom::DisjointObjectProperties(
+                    DisjointObjectProperties::new(properties, annotations),
+                ));
+            }
+            "InverseObjectProperties" => {
+                let first = self.object_property()?;
+                let second = self.object_property()?;
+                o.push_axiom(Axiom::InverseObjectProperties(
+                    InverseObjectProperties::new(first, second, annotations),
+                ));
+            }
+            "ObjectPropertyDomain" => {
+                let property = self.object_property()?;
+                let cls = self.class_expression()?;
+                o.push_axiom(Axiom::ObjectPropertyDomain(ObjectPropertyDomain::new(
+                    property,
+                    cls,
+                    annotations,
+                )));
+            }
+            "ObjectPropertyRange" => {
+                let property = self.object_property()?;
+                let cls = self.class_expression()?;
+                o.push_axiom(Axiom::ObjectPropertyRange(ObjectPropertyRange::new(
+                    property,
+                    cls,
+                    annotations,
+                )));
+            }
+            "FunctionalObjectProperty" => {
+                let property = self.object_property()?;
+                o.push_axiom(Axiom::FunctionalObjectProperty(
+                    FunctionalObjectProperty::new(property, annotations),
+                ));
+            }
+            "InverseFunctionalObjectProperty" => {
+                let property = self.object_property()?;
+                o.push_axiom(Axiom::InverseFunctionalObjectProperty(
+                    InverseFunctionalObjectProperty::new(property, annotations),
+                ));
+            }
+            "ReflexiveObjectProperty" => {
+                let property = self.object_property()?;
+                o.push_axiom(Axiom::ReflexiveObjectProperty(
+                    ReflexiveObjectProperty::new(property, annotations),
+                ));
+            }
+            "IrreflexiveObjectProperty" => {
+                let property = self.object_property()?;
+                o.push_axiom(Axiom::IrreflexiveObjectProperty(
+                    IrreflexiveObjectProperty::new(property, annotations),
+                ));
+            }
+            "SymmetricObjectProperty" => {
+                let property = self.object_property()?;
+                o.push_axiom(Axiom::SymmetricObjectProperty(
+                    SymmetricObjectProperty::new(property, annotations),
+                ));
+            }
+            "AsymmetricObjectProperty" => {
+                let property = self.object_property()?;
+                o.push_axiom(Axiom::AsymmetricObjectProperty(
+                    AsymmetricObjectProperty::new(property, annotations),
+                ));
+            }
+            "TransitiveObjectProperty" => {
+                let property = self.object_property()?;
+                o.push_axiom(Axiom::TransitiveObjectProperty(
+                    TransitiveObjectProperty::new(property, annotations),
+                ));
+            }
+
+            // data property axioms
+            "SubDataPropertyOf" => {
+                let property = self.data_property()?;
+                let parent = self.data_property()?;
+                o.push_axiom(Axiom::SubDataPropertyOf(SubDataPropertyOf::new(
+                    property,
+                    parent,
+                    annotations,
+                )));
+            }
+            "EquivalentDataProperties" => {
+                let first = self.data_property()?;
+                for other in self.until_close(Self::data_property)? {
+                    o.push_axiom(Axiom::EquivalentDataProperties(
+                        EquivalentDataProperties::new(first.clone(), other, annotations.clone()),
+                    ));
+                }
+            }
+            "DisjointDataProperties" => {
+                let properties = self.until_close(Self::data_property)?;
+                o.push_axiom(Axiom::DisjointDataProperties(DisjointDataProperties::new(
+                    properties,
+                    annotations,
+                )));
+            }
+            "DataPropertyDomain" => {
+                let property = self.data_property()?;
+                let cls = self.class_expression()?;
+                o.push_axiom(Axiom::DataPropertyDomain(DataPropertyDomain::new(
+                    property,
+                    cls,
+                    annotations,
+                )));
+            }
+            "DataPropertyRange" => {
+                let property = self.data_property()?;
+                let datatype = self.datatype()?;
+                o.push_axiom(Axiom::DataPropertyRange(DataPropertyRange::new(
+                    property,
+                    datatype,
+                    annotations,
+                )));
+            }
+            "FunctionalDataProperty" => {
+                let property = self.data_property()?;
+                o.push_axiom(Axiom::FunctionalDataProperty(FunctionalDataProperty::new(
+                    property,
+                    annotations,
+                )));
+            }
+
+            "DatatypeDefinition" => {
+                let datatype = self.iri()?;
+                let range = self.data_range()?;
+                o.push_axiom(Axiom::DatatypeDefinition(DatatypeDefinition::new(
+                    datatype.into(),
+                    range,
+                    annotations,
+                )));
+            }
+            "HasKey" => {
+                let cls = self.class_expression()?;
+                let object_properties = self.list(Self::object_property)?;
+                let data_properties = self.list(Self::data_property)?;
+                o.push_axiom(Axiom::HasKey(HasKey::new(
+                    cls,
+                    object_properties,
+                    data_properties,
+                    annotations,
+                )));
+            }
+
+            // assertions
+            "SameIndividual" => {
+                let individuals = self.until_close(Self::individual)?;
+                o.push_axiom(Axiom::SameIndividual(SameIndividual::new(
+                    individuals,
+                    annotations,
+                )));
+            }
+            "DifferentIndividuals" => {
+                let individuals = self.until_close(Self::individual)?;
+                o.push_axiom(Axiom::DifferentIndividuals(DifferentIndividuals::new(
+                    individuals,
+                    annotations,
+                )));
+            }
+            "ClassAssertion" => {
+                let cls = self.class_expression()?;
+                let individual = self.individual()?;
+                o.push_axiom(Axiom::ClassAssertion(ClassAssertion::new(
+                    cls,
+                    individual,
+                    annotations,
+                )));
+            }
+            "ObjectPropertyAssertion" => {
+                let property = self.object_property()?;
+                let subject = self.individual()?;
+                let object = self.individual()?;
+                o.push_axiom(Axiom::ObjectPropertyAssertion(
+                    ObjectPropertyAssertion::new(property, subject, object, annotations, vec![]),
+                ));
+            }
+            "NegativeObjectPropertyAssertion" => {
+                let property = self.object_property()?;
+                let subject = self.individual()?;
+                let object = self.individual()?;
+                o.push_axiom(Axiom::NegativeObjectPropertyAssertion(
+                    NegativeObjectPropertyAssertion::new(property, subject, object, annotations),
+                ));
+            }
+            "DataPropertyAssertion" => {
+                let property = self.data_property()?;
+                let subject = self.individual()?;
+                let value = self.literal()?;
+                o.push_axiom(Axiom::DataPropertyAssertion(DataPropertyAssertion::new(
+                    property,
+                    subject,
+                    value,
+                    annotations,
+                    vec![],
+                )));
+            }
+            "NegativeDataPropertyAssertion" => {
+                let property = self.data_property()?;
+                let subject = self.individual()?;
+                let value = self.literal()?;
+                o.push_axiom(Axiom::NegativeDataPropertyAssertion(
+                    NegativeDataPropertyAssertion::new(property, subject, value, annotations),
+                ));
+            }
+
+            // annotation axioms
+            "AnnotationAssertion" => {
+                let property = self.iri()?;
+                let subject = self.individual()?;
+                let value = self.annotation_value()?;
+                o.push_axiom(Axiom::AnnotationAssertion(AnnotationAssertion::new(
+                    property.into(),
+                    subject,
+                    value,
+                    annotations,
+                    vec![],
+                )));
+            }
+            "SubAnnotationPropertyOf" => {
+                let property = self.iri()?;
+                let parent = self.iri()?;
+                o.push_axiom(Axiom::SubAnnotationPropertyOf(
+                    SubAnnotationPropertyOf::new(property.into(), parent.into(), annotations),
+                ));
+            }
+            "AnnotationPropertyDomain" => {
+                let property = self.iri()?;
+                let cls = self.iri()?;
+                o.push_axiom(Axiom::AnnotationPropertyDomain(
+                    AnnotationPropertyDomain::new(property.into(), cls.into(), annotations),
+                ));
+            }
+            "AnnotationPropertyRange" => {
+                let property = self.iri()?;
+                let datatype = self.iri()?;
+                o.push_axiom(Axiom::AnnotationPropertyRange(
+                    AnnotationPropertyRange::new(property.into(), datatype.into(), annotations),
+                ));
+            }
+            _ => {
+                self.pos = start;
+                return Err(self.error(
+                    ErrorKind::UnsupportedConstruct,
+                    format!("Unknown axiom '{}'", keyword),
+                ));
+            }
+        }
+        self.close()
+    }
+}
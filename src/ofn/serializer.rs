@@ -0,0 +1,603 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    api::Ontology,
+    owl::{
+        well_known, Annotation, AnnotationPropertyIRI, Axiom, BlankNode, ClassConstructor,
+        ClassIRI, DataPropertyIRI, DatatypeDefinitionConstructor, DatatypeIRI, DatatypeRestriction,
+        Declaration, IndividualIRI, Literal, LiteralOrIRI, ObjectPropertyConstructor,
+        ObjectPropertyIRI, ResourceId, IRI,
+    },
+    rdf,
+};
+
+use super::well_known_prefixes;
+
+pub trait ToOfn {
+    fn ofn(&self) -> String;
+}
+
+/// The prefixes which are used to abbreviate IRIs, by name.
+pub(crate) type Prefixes = BTreeMap<String, String>;
+
+impl ToOfn for Ontology {
+    fn ofn(&self) -> String {
+        let mut prefixes: Prefixes = well_known_prefixes()
+            .into_iter()
+            .map(|(prefix, iri)| (prefix.into(), iri.into()))
+            .collect();
+        for (name, iri) in &self.imports {
+            prefixes.insert(name.clone(), iri.to_string());
+        }
+
+        let mut lines: Vec<String> = prefixes
+            .iter()
+            .map(|(prefix, iri)| format!("Prefix({}:=<{}>)", prefix, iri))
+            .collect();
+        lines.push(String::new());
+        lines.push(format!("Ontology(<{}>", self.iri.as_str()));
+
+        let mut imports: Vec<&IRI> = self.imports.values().collect();
+        imports.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        for iri in imports {
+            lines.push(format!("Import(<{}>)", iri.as_str()));
+        }
+
+        for d in self.declarations() {
+            let (entity, iri, annotations) = match d {
+                Declaration::Class { iri, annotations } => ("Class", iri.as_iri(), annotations),
+                Declaration::NamedIndividual { iri, annotations } => {
+                    ("NamedIndividual", iri.as_iri(), annotations)
+                }
+                Declaration::ObjectProperty { iri, annotations } => {
+                    ("ObjectProperty", iri.as_iri(), annotations)
+                }
+                Declaration::DataProperty { iri, annotations } => {
+                    ("DataProperty", iri.as_iri(), annotations)
+                }
+                Declaration::AnnotationProperty { iri, annotations } => {
+                    ("AnnotationProperty", iri.as_iri(), annotations)
+                }
+                Declaration::Datatype { iri, annotations } => {
+                    ("Datatype", iri.as_iri(), annotations)
+                }
+            };
+            lines.push(format!(
+                "Declaration({}{}({}))",
+                annotations_ofn(annotations, &prefixes),
+                entity,
+                iri.ofn(&prefixes)
+            ));
+        }
+
+        for a in self.axioms() {
+            lines.extend(axiom_ofn(a, &prefixes));
+        }
+
+        if !self.unmapped.is_empty() {
+            lines.push("# Triples which are not part of any OWL construct are omitted.".into());
+        }
+        lines.push(")".into());
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+fn annotations_ofn(annotations: &[Annotation], prefixes: &Prefixes) -> String {
+    annotations
+        .iter()
+        .map(|a| annotation_ofn(a, prefixes) + " ")
+        .collect()
+}
+
+fn annotation_ofn(annotation: &Annotation, prefixes: &Prefixes) -> String {
+    let nested: Vec<Annotation> = annotation.annotations.iter().map(|a| *a.clone()).collect();
+    format!(
+        "Annotation({}{} {})",
+        annotations_ofn(&nested, prefixes),
+        annotation.iri.ofn(prefixes),
+        annotation.value.ofn(prefixes)
+    )
+}
+
+/// Serializes an axiom, followed by the axioms which are nested in its class expressions.
+fn axiom_ofn(axiom: &Axiom, prefixes: &Prefixes) -> Vec<String> {
+    let p = prefixes;
+    let mut context = Vec::new();
+    let (name, args) = match axiom {
+        Axiom::AnnotationAssertion(a) => (
+            "AnnotationAssertion",
+            vec![a.iri.ofn(p), a.subject.ofn(p), a.value.ofn(p)],
+        ),
+        Axiom::AnnotationPropertyDomain(a) => (
+            "AnnotationPropertyDomain",
+            vec![a.iri.ofn(p), a.class_iri.ofn(p)],
+        ),
+        Axiom::AnnotationPropertyRange(a) => (
+            "AnnotationPropertyRange",
+            vec![a.iri.ofn(p), a.datatype_iri.ofn(p)],
+        ),
+        Axiom::SubAnnotationPropertyOf(a) => (
+            "SubAnnotationPropertyOf",
+            vec![a.subject_iri.ofn(p), a.parent_iri.ofn(p)],
+        ),
+
+        Axiom::SubClassOf(a) => (
+            "SubClassOf",
+            vec![
+                class_ofn(&a.cls, p, &mut context),
+                class_ofn(&a.parent_class, p, &mut context),
+            ],
+        ),
+        Axiom::EquivalentClasses(a) => (
+            "EquivalentClasses",
+            vec![a.class_iri.ofn(p), class_ofn(&a.cls, p, &mut context)],
+        ),
+        Axiom::DisjointClasses(a) => (
+            "DisjointClasses",
+            a.classes
+                .iter()
+                .map(|c| class_ofn(c, p, &mut context))
+                .collect(),
+        ),
+        Axiom::DisjointUnion(a) => (
+            "DisjointUnion",
+            std::iter::once(a.class_iri.ofn(p))
+                .chain(a.classes.iter().map(|c| class_ofn(c, p, &mut context)))
+                .collect(),
+        ),
+
+        Axiom::SubObjectPropertyOf(a) => (
+            "SubObjectPropertyOf",
+            vec![
+                property_ofn(&a.object_property, p),
+                a.parent_object_property_iri.ofn(p),
+            ],
+        ),
+        Axiom::EquivalentObjectProperties(a) => (
+            "EquivalentObjectProperties",
+            vec![
+                a.object_property_iri_1.ofn(p),
+                a.object_property_iri_2.ofn(p),
+            ],
+        ),
+        Axiom::DisjointObjectProperties(a) => (
+            "DisjointObjectProperties",
+            a.object_property_iris
+                .iter()
+                .map(|iri| iri.ofn(p))
+                .collect(),
+        ),
+        Axiom::InverseObjectProperties(a) => (
+            "InverseObjectProperties",
+            vec![
+                a.object_property_iri_1.ofn(p),
+                a.object_property_iri_2.ofn(p),
+            ],
+        ),
+        Axiom::ObjectPropertyDomain(a) => (
+            "ObjectPropertyDomain",
+            vec![a.iri.ofn(p), class_ofn(&a.cls, p, &mut context)],
+        ),
+        Axiom::ObjectPropertyRange(a) => (
+            "ObjectPropertyRange",
+            vec![a.iri.ofn(p), class_ofn(&a.cls, p, &mut context)],
+        ),
+        Axiom::FunctionalObjectProperty(a) => (
+            "FunctionalObjectProperty",
+            vec![a.object_property_iri.ofn(p)],
+        ),
+        Axiom::InverseFunctionalObjectProperty(a) => (
+            "InverseFunctionalObjectProperty",
+            vec![a.object_property_iri.ofn(p)],
+        ),
+        Axiom::ReflexiveObjectProperty(a) => (
+            "ReflexiveObjectProperty",
+            vec![a.object_property_iri.ofn(p)],
+        ),
+        Axiom::IrreflexiveObjectProperty(a) => (
+            "IrreflexiveObjectProperty",
+            vec![a.object_property_iri.ofn(p)],
+        ),
+        Axiom::SymmetricObjectProperty(a) => (
+            "SymmetricObjectProperty",
+            vec![a.object_property_iri.ofn(p)],
+        ),
+        Axiom::AsymmetricObjectProperty(a) => (
+            "AsymmetricObjectProperty",
+            vec![a.object_property_iri.ofn(p)],
+        ),
+        Axiom::TransitiveObjectProperty(a) => (
+            "TransitiveObjectProperty",
+            vec![a.object_property_iri.ofn(p)],
+        ),
+
+        Axiom::SubDataPropertyOf(a) => (
+            "SubDataPropertyOf",
+            vec![a.subject_iri.ofn(p), a.parent_iri.ofn(p)],
+        ),
+        Axiom::EquivalentDataProperties(a) => (
+            "EquivalentDataProperties",
+            vec![a.data_property_iri_1.ofn(p), a.data_property_iri_2.ofn(p)],
+        ),
+        Axiom::DisjointDataProperties(a) => (
+            "DisjointDataProperties",
+            a.data_property_iris.iter().map(|iri| iri.ofn(p)).collect(),
+        ),
+        Axiom::DataPropertyDomain(a) => (
+            "DataPropertyDomain",
+            vec![a.iri.ofn(p), class_ofn(&a.cls, p, &mut context)],
+        ),
+        Axiom::DataPropertyRange(a) => (
+            "DataPropertyRange",
+            vec![a.iri.ofn(p), a.datatype_iri.ofn(p)],
+        ),
+        Axiom::FunctionalDataProperty(a) => {
+            ("FunctionalDataProperty", vec![a.data_property_iri.ofn(p)])
+        }
+
+        Axiom::DatatypeDefinition(a) => (
+            "DatatypeDefinition",
+            vec![a.data_property_iri.ofn(p), data_range_ofn(&a.datatype, p)],
+        ),
+        Axiom::HasKey(a) => (
+            "HasKey",
+            vec![
+                class_ofn(&a.cls, p, &mut context),
+                format!(
+                    "({})",
+                    a.object_properties
+                        .iter()
+                        .map(|iri| iri.ofn(p))
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                ),
+                format!(
+                    "({})",
+                    a.data_properties
+                        .iter()
+                        .map(|iri| iri.ofn(p))
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                ),
+            ],
+        ),
+
+        Axiom::SameIndividual(a) => (
+            "SameIndividual",
+            a.individuals.iter().map(|i| i.ofn(p)).collect(),
+        ),
+        Axiom::DifferentIndividuals(a) => (
+            "DifferentIndividuals",
+            a.individuals.iter().map(|i| i.ofn(p)).collect(),
+        ),
+        Axiom::ClassAssertion(a) => (
+            "ClassAssertion",
+            vec![class_ofn(&a.cls, p, &mut context), a.individual.ofn(p)],
+        ),
+        Axiom::ObjectPropertyAssertion(a) => match &a.object {
+            crate::owl::IRIList::IRI(object) => (
+                "ObjectPropertyAssertion",
+                vec![a.iri.ofn(p), a.subject.ofn(p), object.ofn(p)],
+            ),
+            crate::owl::IRIList::BlankNode(object) => (
+                "ObjectPropertyAssertion",
+                vec![a.iri.ofn(p), a.subject.ofn(p), object.ofn(p)],
+            ),
+            crate::owl::IRIList::List(_) => {
+                return vec![format!(
+                "# ObjectPropertyAssertion({} {} ( ... )) with a list object is not expressible",
+                a.iri.ofn(p),
+                a.subject.ofn(p)
+            )]
+            }
+        },
+        Axiom::NegativeObjectPropertyAssertion(a) => (
+            "NegativeObjectPropertyAssertion",
+            vec![a.iri.ofn(p), a.subject.ofn(p), a.object.ofn(p)],
+        ),
+        Axiom::DataPropertyAssertion(a) => (
+            "DataPropertyAssertion",
+            vec![a.iri.ofn(p), a.subject.ofn(p), a.value.ofn(p)],
+        ),
+        Axiom::NegativeDataPropertyAssertion(a) => (
+            "NegativeDataPropertyAssertion",
+            vec![a.data_property_iri.ofn(p), a.subject.ofn(p), a.value.ofn(p)],
+        ),
+    };
+    let mut lines = vec![format!(
+        "{}({}{})",
+        name,
+        annotations_ofn(axiom.annotations(), p),
+        args.join(" ")
+    )];
+    lines.extend(context);
+    lines
+}
+
+fn property_ofn(property: &ObjectPropertyConstructor, prefixes: &Prefixes) -> String {
+    match property {
+        ObjectPropertyConstructor::IRI(iri) => iri.ofn(prefixes),
+        ObjectPropertyConstructor::ObjectInverseOf(inverse) => {
+            format!("ObjectInverseOf({})", inverse.0.ofn(prefixes))
+        }
+        ObjectPropertyConstructor::ObjectPropertyChain(chain) => format!(
+            "ObjectPropertyChain({})",
+            chain
+                .0
+                .iter()
+                .map(|iri| iri.ofn(prefixes))
+                .collect::<Vec<String>>()
+                .join(" ")
+        ),
+    }
+}
+
+/// Serializes a class expression. Class constructors which are axioms on their own are
+/// represented by their first class, the axiom itself is added to `context`.
+fn class_ofn(cls: &ClassConstructor, prefixes: &Prefixes, context: &mut Vec<String>) -> String {
+    let p = prefixes;
+    let classes = |classes: &[ClassConstructor], context: &mut Vec<String>| {
+        classes
+            .iter()
+            .map(|c| class_ofn(c, p, context))
+            .collect::<Vec<String>>()
+            .join(" ")
+    };
+    let cardinality = |value: u64, property: String, filler: Option<String>| match filler {
+        Some(filler) => format!("{} {} {}", value, property, filler),
+        None => format!("{} {}", value, property),
+    };
+    match cls {
+        ClassConstructor::IRI(iri) => iri.ofn(p),
+        ClassConstructor::SubClassOf(a) => {
+            context.extend(axiom_ofn(&Axiom::SubClassOf(a.clone()), p));
+            class_ofn(&a.cls, p, &mut Vec::new())
+        }
+        ClassConstructor::EquivalentClasses(a) => {
+            context.extend(axiom_ofn(&Axiom::EquivalentClasses(a.clone()), p));
+            a.class_iri.ofn(p)
+        }
+        ClassConstructor::DisjointClasses(a) => {
+            context.extend(axiom_ofn(&Axiom::DisjointClasses(a.clone()), p));
+            match a.classes.first() {
+                Some(first) => class_ofn(first, p, &mut Vec::new()),
+                None => well_known::owl_Thing().ofn(p),
+            }
+        }
+        ClassConstructor::ObjectIntersectionOf(c) => {
+            format!("ObjectIntersectionOf({})", classes(&c.classes, context))
+        }
+        ClassConstructor::ObjectUnionOf(c) => {
+            format!("ObjectUnionOf({})", classes(&c.classes, context))
+        }
+        ClassConstructor::ObjectComplementOf(c) => {
+            format!("ObjectComplementOf({})", class_ofn(&c.cls, p, context))
+        }
+        ClassConstructor::ObjectOneOf(c) => format!(
+            "ObjectOneOf({})",
+            c.individuals
+                .iter()
+                .map(|iri| iri.ofn(p))
+                .collect::<Vec<String>>()
+                .join(" ")
+        ),
+        ClassConstructor::ObjectSomeValuesFrom(c) => format!(
+            "ObjectSomeValuesFrom({} {})",
+            property_ofn(&c.object_property, p),
+            class_ofn(&c.cls, p, context)
+        ),
+        ClassConstructor::ObjectAllValuesFrom(c) => format!(
+            "ObjectAllValuesFrom({} {})",
+            property_ofn(&c.object_property, p),
+            class_ofn(&c.cls, p, context)
+        ),
+        ClassConstructor::ObjectHasValue(c) => format!(
+            "ObjectHasValue({} {})",
+            property_ofn(&c.object_property, p),
+            c.value_or_iri.ofn(p)
+        ),
+        ClassConstructor::ObjectHasSelf(c) => {
+            format!("ObjectHasSelf({})", property_ofn(&c.object_property, p))
+        }
+        ClassConstructor::ObjectMinCardinality(c) => format!(
+            "ObjectMinCardinality({})",
+            cardinality(
+                c.value,
+                c.object_property_iri.ofn(p),
+                c.cls.as_ref().map(|c| class_ofn(c, p, context))
+            )
+        ),
+        ClassConstructor::ObjectMaxCardinality(c) => format!(
+            "ObjectMaxCardinality({})",
+            cardinality(
+                c.value,
+                c.object_property_iri.ofn(p),
+                c.cls.as_ref().map(|c| class_ofn(c, p, context))
+            )
+        ),
+        ClassConstructor::ObjectExactCardinality(c) => format!(
+            "ObjectExactCardinality({})",
+            cardinality(
+                c.value,
+                c.object_property_iri.ofn(p),
+                c.cls.as_ref().map(|c| class_ofn(c, p, context))
+            )
+        ),
+        ClassConstructor::DataSomeValuesFrom(c) => format!(
+            "DataSomeValuesFrom({} {})",
+            c.data_property_iri.ofn(p),
+            restriction_ofn(&c.restriction, p)
+        ),
+        ClassConstructor::DataAllValuesFrom(c) => format!(
+            "DataAllValuesFrom({} {})",
+            c.data_property_iri.ofn(p),
+            c.datatype_iri.ofn(p)
+        ),
+        ClassConstructor::DataHasValue(c) => format!(
+            "DataHasValue({} {})",
+            c.data_property_iri.ofn(p),
+            c.value.ofn(p)
+        ),
+        ClassConstructor::DataMinCardinality(c) => format!(
+            "DataMinCardinality({})",
+            cardinality(
+                c.value,
+                c.data_property_iri.ofn(p),
+                c.datatype_iri.as_ref().map(|iri| iri.ofn(p))
+            )
+        ),
+        ClassConstructor::DataMaxCardinality(c) => format!(
+            "DataMaxCardinality({})",
+            cardinality(
+                c.value,
+                c.data_property_iri.ofn(p),
+                c.datatype_iri.as_ref().map(|iri| iri.ofn(p))
+            )
+        ),
+        ClassConstructor::DataExactCardinality(c) => format!(
+            "DataExactCardinality({})",
+            cardinality(
+                c.value,
+                c.data_property_iri.ofn(p),
+                c.datatype_iri.as_ref().map(|iri| iri.ofn(p))
+            )
+        ),
+    }
+}
+
+fn restriction_ofn(restriction: &DatatypeRestriction, prefixes: &Prefixes) -> String {
+    if restriction.restrictions.is_empty() {
+        return restriction.datatype_iri.ofn(prefixes);
+    }
+    let facets: Vec<String> = restriction
+        .restrictions
+        .iter()
+        .map(|r| format!("{} {}", r.facet().ofn(prefixes), r.value().ofn(prefixes)))
+        .collect();
+    format!(
+        "DatatypeRestriction({} {})",
+        restriction.datatype_iri.ofn(prefixes),
+        facets.join(" ")
+    )
+}
+
+fn data_range_ofn(range: &DatatypeDefinitionConstructor, prefixes: &Prefixes) -> String {
+    match range {
+        DatatypeDefinitionConstructor::DatatypeRestriction(r) => restriction_ofn(r, prefixes),
+        DatatypeDefinitionConstructor::DataComplementOf(c) => {
+            format!("DataComplementOf({})", c.data_property_iri.ofn(prefixes))
+        }
+        DatatypeDefinitionConstructor::DataIntersectionOf(c) => format!(
+            "DataIntersectionOf({} {})",
+            c.data_property_iri.ofn(prefixes),
+            data_range_ofn(&c.datatype, prefixes)
+        ),
+        DatatypeDefinitionConstructor::DataUnionOf(c) => format!(
+            "DataUnionOf({} {})",
+            c.data_property_iri.ofn(prefixes),
+            data_range_ofn(&c.datatype, prefixes)
+        ),
+        DatatypeDefinitionConstructor::DataOneOf(c) => format!(
+            "DataOneOf({})",
+            c.literals
+                .iter()
+                .map(|l| l.ofn(prefixes))
+                .collect::<Vec<String>>()
+                .join(" ")
+        ),
+    }
+}
+
+trait TermToOfn {
+    fn ofn(&self, prefixes: &Prefixes) -> String;
+}
+
+impl TermToOfn for IRI {
+    fn ofn(&self, prefixes: &Prefixes) -> String {
+        abbreviate(self.as_str(), prefixes).unwrap_or_else(|| format!("<{}>", self.as_str()))
+    }
+}
+
+/// Writes an IRI as prefixed name, using the longest matching namespace.
+pub(crate) fn abbreviate(iri: &str, prefixes: &Prefixes) -> Option<String> {
+    prefixes
+        .iter()
+        .filter_map(|(prefix, namespace)| {
+            iri.strip_prefix(namespace.as_str())
+                .filter(|local| is_simple_local_name(local))
+                .map(|local| (namespace.len(), format!("{}:{}", prefix, local)))
+        })
+        .max_by_key(|(len, _)| *len)
+        .map(|(_, name)| name)
+}
+
+/// Whether the local part of an IRI can be written as prefixed name without escaping.
+fn is_simple_local_name(local: &str) -> bool {
+    let mut chars = local.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        && !local.ends_with('.')
+}
+
+macro_rules! iri_to_ofn {
+    ($($t:ty),*) => {
+        $(impl TermToOfn for $t {
+            fn ofn(&self, prefixes: &Prefixes) -> String {
+                self.as_iri().ofn(prefixes)
+            }
+        })*
+    };
+}
+
+iri_to_ofn!(
+    AnnotationPropertyIRI,
+    ClassIRI,
+    DatatypeIRI,
+    IndividualIRI,
+    DataPropertyIRI,
+    ObjectPropertyIRI
+);
+
+impl TermToOfn for BlankNode {
+    fn ofn(&self, _prefixes: &Prefixes) -> String {
+        format!("_:{}", self)
+    }
+}
+
+impl TermToOfn for ResourceId {
+    fn ofn(&self, prefixes: &Prefixes) -> String {
+        match self {
+            ResourceId::IRI(iri) => iri.ofn(prefixes),
+            ResourceId::BlankNode(bn) => bn.ofn(prefixes),
+        }
+    }
+}
+
+impl TermToOfn for LiteralOrIRI {
+    fn ofn(&self, prefixes: &Prefixes) -> String {
+        match self {
+            LiteralOrIRI::IRI(iri) => iri.ofn(prefixes),
+            LiteralOrIRI::Literal(l) => l.ofn(prefixes),
+        }
+    }
+}
+
+impl TermToOfn for Literal {
+    fn ofn(&self, prefixes: &Prefixes) -> String {
+        let literal = rdf::Literal::from(self);
+        let lexical = quoted(&literal.lexical_form);
+        match (literal.lang, literal.datatype) {
+            (Some(lang), _) => format!("{}@{}", lexical, lang),
+            (None, Some(datatype)) => format!("{}^^{}", lexical, datatype.ofn(prefixes)),
+            (None, None) => lexical,
+        }
+    }
+}
+
+fn quoted(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
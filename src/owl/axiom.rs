@@ -16,6 +16,7 @@ pub enum Axiom {
     EquivalentDataProperties(EquivalentDataProperties),
     InverseObjectProperties(InverseObjectProperties),
     DisjointObjectProperties(DisjointObjectProperties),
+    DisjointDataProperties(DisjointDataProperties),
     ObjectPropertyDomain(ObjectPropertyDomain),
     ObjectPropertyRange(ObjectPropertyRange),
     DataPropertyDomain(DataPropertyDomain),
@@ -32,6 +33,7 @@ pub enum Axiom {
     SubClassOf(SubClassOf),
     EquivalentClasses(EquivalentClasses),
     DisjointClasses(DisjointClasses),
+    DisjointUnion(DisjointUnion),
     // Datatypes
     DatatypeDefinition(DatatypeDefinition),
     // Individuals
@@ -49,6 +51,47 @@ pub enum Axiom {
 }
 
 impl Axiom {
+    pub fn annotations(&self) -> &Vec<Annotation> {
+        match self {
+            Axiom::AnnotationAssertion(a) => &a.annotations,
+            Axiom::AnnotationPropertyDomain(a) => &a.annotations,
+            Axiom::AnnotationPropertyRange(a) => &a.annotations,
+            Axiom::SubObjectPropertyOf(a) => &a.annotations,
+            Axiom::SubAnnotationPropertyOf(a) => &a.annotations,
+            Axiom::SubDataPropertyOf(a) => &a.annotations,
+            Axiom::EquivalentObjectProperties(a) => &a.annotations,
+            Axiom::EquivalentDataProperties(a) => &a.annotations,
+            Axiom::InverseObjectProperties(a) => &a.annotations,
+            Axiom::DisjointObjectProperties(a) => &a.annotations,
+            Axiom::DisjointDataProperties(a) => &a.annotations,
+            Axiom::ObjectPropertyDomain(a) => &a.annotations,
+            Axiom::ObjectPropertyRange(a) => &a.annotations,
+            Axiom::DataPropertyDomain(a) => &a.annotations,
+            Axiom::DataPropertyRange(a) => &a.annotations,
+            Axiom::SymmetricObjectProperty(a) => &a.annotations,
+            Axiom::AsymmetricObjectProperty(a) => &a.annotations,
+            Axiom::ReflexiveObjectProperty(a) => &a.annotations,
+            Axiom::IrreflexiveObjectProperty(a) => &a.annotations,
+            Axiom::FunctionalObjectProperty(a) => &a.annotations,
+            Axiom::InverseFunctionalObjectProperty(a) => &a.annotations,
+            Axiom::TransitiveObjectProperty(a) => &a.annotations,
+            Axiom::FunctionalDataProperty(a) => &a.annotations,
+            Axiom::SubClassOf(a) => &a.annotations,
+            Axiom::EquivalentClasses(a) => &a.annotations,
+            Axiom::DisjointClasses(a) => &a.annotations,
+            Axiom::DisjointUnion(a) => &a.annotations,
+            Axiom::DatatypeDefinition(a) => &a.annotations,
+            Axiom::ClassAssertion(a) => &a.annotations,
+            Axiom::SameIndividual(a) => &a.annotations,
+            Axiom::DifferentIndividuals(a) => &a.annotations,
+            Axiom::ObjectPropertyAssertion(a) => &a.annotations,
+            Axiom::NegativeObjectPropertyAssertion(a) => &a.annotations,
+            Axiom::DataPropertyAssertion(a) => &a.annotations,
+            Axiom::NegativeDataPropertyAssertion(a) => &a.annotations,
+            Axiom::HasKey(a) => &a.annotations,
+        }
+    }
+
     pub fn annotations_mut(&mut self) -> &mut Vec<Annotation> {
         match self {
             Axiom::AnnotationAssertion(a) => &mut a.annotations,
@@ -61,6 +104,7 @@ impl Axiom {
             Axiom::EquivalentDataProperties(a) => &mut a.annotations,
             Axiom::InverseObjectProperties(a) => &mut a.annotations,
             Axiom::DisjointObjectProperties(a) => &mut a.annotations,
+            Axiom::DisjointDataProperties(a) => &mut a.annotations,
             Axiom::ObjectPropertyDomain(a) => &mut a.annotations,
             Axiom::ObjectPropertyRange(a) => &mut a.annotations,
             Axiom::DataPropertyDomain(a) => &mut a.annotations,
@@ -76,6 +120,7 @@ impl Axiom {
             Axiom::SubClassOf(a) => &mut a.annotations,
             Axiom::EquivalentClasses(a) => &mut a.annotations,
             Axiom::DisjointClasses(a) => &mut a.annotations,
+            Axiom::DisjointUnion(a) => &mut a.annotations,
             Axiom::DatatypeDefinition(a) => &mut a.annotations,
             Axiom::ClassAssertion(a) => &mut a.annotations,
             Axiom::SameIndividual(a) => &mut a.annotations,
@@ -111,7 +156,12 @@ impl Axiom {
             Axiom::EquivalentObjectProperties(a) => Some(a.object_property_iri_1.as_iri()),
             Axiom::EquivalentDataProperties(a) => Some(a.data_property_iri_1.as_iri()),
             Axiom::InverseObjectProperties(a) => Some(a.object_property_iri_1.as_iri()),
-            Axiom::DisjointObjectProperties(a) => Some(a.object_property_iri_1.as_iri()),
+            Axiom::DisjointObjectProperties(a) => {
+                a.object_property_iris.first().map(|iri| iri.as_iri())
+            }
+            Axiom::DisjointDataProperties(a) => {
+                a.data_property_iris.first().map(|iri| iri.as_iri())
+            }
             Axiom::ObjectPropertyDomain(a) => Some(a.iri.as_iri()),
             Axiom::ObjectPropertyRange(a) => Some(a.iri.as_iri()),
             Axiom::DataPropertyDomain(a) => Some(a.iri.as_iri()),
@@ -130,15 +180,19 @@ impl Axiom {
             },
             Axiom::EquivalentClasses(a) => Some(a.class_iri.as_iri()),
             Axiom::DisjointClasses(_) => None,
+            Axiom::DisjointUnion(a) => Some(a.class_iri.as_iri()),
             Axiom::DatatypeDefinition(a) => Some(a.data_property_iri.as_iri()),
-            Axiom::ClassAssertion(a) => Some(a.individual.as_iri()),
-            Axiom::SameIndividual(a) => Some(a.individual1.as_iri()),
-            Axiom::DifferentIndividuals(a) => Some(a.individual1.as_iri()),
-            Axiom::ObjectPropertyAssertion(a) => Some(a.subject.as_iri()),
-            Axiom::NegativeObjectPropertyAssertion(a) => Some(a.subject.as_iri()),
-            Axiom::DataPropertyAssertion(a) => Some(a.subject.as_iri()),
-            Axiom::NegativeDataPropertyAssertion(a) => Some(a.subject.as_iri()),
-            Axiom::HasKey(a) => Some(a.iri.as_iri()),
+            Axiom::ClassAssertion(a) => a.individual.as_iri(),
+            Axiom::SameIndividual(a) => a.individuals.first().and_then(|i| i.as_iri()),
+            Axiom::DifferentIndividuals(a) => a.individuals.first().and_then(|i| i.as_iri()),
+            Axiom::ObjectPropertyAssertion(a) => a.subject.as_iri(),
+            Axiom::NegativeObjectPropertyAssertion(a) => a.subject.as_iri(),
+            Axiom::DataPropertyAssertion(a) => a.subject.as_iri(),
+            Axiom::NegativeDataPropertyAssertion(a) => a.subject.as_iri(),
+            Axiom::HasKey(a) => match &a.cls {
+                ClassConstructor::IRI(iri) => Some(iri.as_iri()),
+                _ => None,
+            },
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::owl::{Annotation, ClassConstructor};
+use crate::owl::{Annotation, Axiom, ClassConstructor};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct DisjointClasses {
@@ -26,6 +26,12 @@ impl From<DisjointClasses> for Box<ClassConstructor> {
     }
 }
 
+impl From<DisjointClasses> for Axiom {
+    fn from(c: DisjointClasses) -> Self {
+        Axiom::DisjointClasses(c)
+    }
+}
+
 impl ClassConstructor {
     pub fn disjoint_classes(&self) -> Option<&DisjointClasses> {
         match self {
@@ -0,0 +1,48 @@
+use crate::owl::{Annotation, Axiom, ClassConstructor, ClassIRI};
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct DisjointUnion {
+    #[serde(rename = "classIRI")]
+    pub class_iri: ClassIRI,
+    #[serde(rename = "classes")]
+    pub classes: Vec<ClassConstructor>,
+    #[serde(rename = "annotations")]
+    pub annotations: Vec<Annotation>,
+}
+
+impl DisjointUnion {
+    pub fn new(
+        class_iri: ClassIRI,
+        classes: Vec<ClassConstructor>,
+        annotations: Vec<Annotation>,
+    ) -> Self {
+        Self {
+            class_iri,
+            classes,
+            annotations,
+        }
+    }
+}
+
+impl From<DisjointUnion> for Axiom {
+    fn from(du: DisjointUnion) -> Self {
+        Axiom::DisjointUnion(du)
+    }
+}
+
+#[cfg(feature = "wasm")]
+mod wasm {
+    use wasm_bindgen::prelude::wasm_bindgen;
+
+    #[wasm_bindgen(typescript_custom_section)]
+    const WASM_API: &'static str = r#"
+/**
+ * The class is the union of the given, pairwise disjoint classes.
+ */
+export type DisjointUnion = {
+    classIRI: IRI,
+    classes: Array<ClassConstructor>,
+    annotations: Array<Annotation>,
+};
+"#;
+}
@@ -8,6 +8,9 @@ pub use equivalent_classes::*;
 mod disjoint_classes;
 pub use disjoint_classes::*;
 
+mod disjoint_union;
+pub use disjoint_union::*;
+
 mod object_intersection_of;
 pub use object_intersection_of::*;
 
@@ -1,23 +1,23 @@
-use crate::owl::{Annotation, ClassConstructor, ClassIRI, ObjectPropertyConstructor};
+use crate::owl::{Annotation, ClassConstructor, ObjectPropertyConstructor};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct ObjectAllValuesFrom {
     #[serde(rename = "objectProperty")]
     pub object_property: ObjectPropertyConstructor,
-    #[serde(rename = "class_iri")]
-    pub class_iri: ClassIRI,
+    #[serde(rename = "cls")]
+    pub cls: Box<ClassConstructor>,
     pub annotations: Vec<Annotation>,
 }
 
 impl ObjectAllValuesFrom {
     pub fn new(
         object_property: ObjectPropertyConstructor,
-        cls: ClassIRI,
+        cls: Box<ClassConstructor>,
         annotations: Vec<Annotation>,
     ) -> Self {
         Self {
             object_property,
-            class_iri: cls,
+            cls,
             annotations,
         }
     }
@@ -52,7 +52,7 @@ mod wasm {
     const WASM_API: &'static str = r#"
 export type ObjectAllValuesFrom = {
     objectProperty: ObjectPropertyConstructor, 
-    class_iri: IRI,
+    cls: ClassConstructor,
     annotations: Array<Annotation>,
 };
 "#;
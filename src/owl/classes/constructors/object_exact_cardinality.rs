@@ -1,19 +1,24 @@
-use crate::owl::{ClassConstructor, ClassIRI, ObjectPropertyIRI};
+use crate::owl::{ClassConstructor, ObjectPropertyIRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct ObjectExactCardinality {
     pub value: u64,
     #[serde(rename = "objectPropertyIRI")]
     pub object_property_iri: ObjectPropertyIRI,
-    pub class_iri: Option<ClassIRI>,
+    #[serde(rename = "cls")]
+    pub cls: Option<Box<ClassConstructor>>,
 }
 
 impl ObjectExactCardinality {
-    pub fn new(value: u64, object_property_iri: ObjectPropertyIRI, cls: Option<ClassIRI>) -> Self {
+    pub fn new(
+        value: u64,
+        object_property_iri: ObjectPropertyIRI,
+        cls: Option<Box<ClassConstructor>>,
+    ) -> Self {
         Self {
             value,
             object_property_iri,
-            class_iri: cls,
+            cls,
         }
     }
 }
@@ -50,7 +55,7 @@ mod wasm {
 export type ObjectExactCardinality = {
     value: number, 
     objectPropertyIRI: IRI, 
-    cls: IRI | undefined,
+    cls: ClassConstructor | undefined,
 }
 "#;
 }
@@ -1,24 +1,24 @@
-use crate::owl::{ClassConstructor, ClassIRI, ObjectPropertyIRI};
+use crate::owl::{ClassConstructor, ObjectPropertyIRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct ObjectMaxCardinality {
     pub value: u64,
     #[serde(rename = "objectPropertyIRI")]
     pub object_property_iri: ObjectPropertyIRI,
-    #[serde(rename = "classIRI")]
-    pub class_iri: Option<ClassIRI>,
+    #[serde(rename = "cls")]
+    pub cls: Option<Box<ClassConstructor>>,
 }
 
 impl ObjectMaxCardinality {
     pub fn new(
         value: u64,
         object_property_iri: ObjectPropertyIRI,
-        class_iri: Option<ClassIRI>,
+        cls: Option<Box<ClassConstructor>>,
     ) -> Self {
         Self {
             value,
             object_property_iri,
-            class_iri,
+            cls,
         }
     }
 }
@@ -53,7 +53,7 @@ mod wasm {
 export type ObjectMaxCardinality = {
     value: number, 
     objectPropertyIRI: IRI, 
-    classIRI: IRI | undefined
+    cls: ClassConstructor | undefined
 };
 "#;
 }
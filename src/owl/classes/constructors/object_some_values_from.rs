@@ -1,24 +1,24 @@
-use crate::owl::{Annotation, ClassConstructor, ClassIRI, ObjectPropertyConstructor};
+use crate::owl::{Annotation, ClassConstructor, ObjectPropertyConstructor};
 
 /// Class construction based on properties.
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct ObjectSomeValuesFrom {
     #[serde(rename = "objectProperty")]
     pub object_property: ObjectPropertyConstructor,
-    #[serde(rename = "classIRI")]
-    pub class_iri: ClassIRI,
+    #[serde(rename = "cls")]
+    pub cls: Box<ClassConstructor>,
     pub annotations: Vec<Annotation>,
 }
 
 impl ObjectSomeValuesFrom {
     pub fn new(
         object_property: ObjectPropertyConstructor,
-        cls: ClassIRI,
+        cls: Box<ClassConstructor>,
         annotations: Vec<Annotation>,
     ) -> Self {
         Self {
             object_property,
-            class_iri: cls,
+            cls,
             annotations,
         }
     }
@@ -53,7 +53,7 @@ mod wasm {
     const WASM_API: &'static str = r#"
     export type ObjectSomeValuesFrom = {
         objectProperty: ObjectPropertyConstructor,
-        classIRI: IRI,
+        cls: ClassConstructor,
         annotations: Array<Annotation>,
 };
     "#;
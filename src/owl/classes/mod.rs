@@ -1,4 +1,7 @@
-use crate::owl::{DataSomeValuesFrom, IRI};
+use crate::owl::{
+    DataAllValuesFrom, DataExactCardinality, DataHasValue, DataMaxCardinality, DataMinCardinality,
+    DataSomeValuesFrom, IRI,
+};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
@@ -34,6 +37,11 @@ pub enum ClassConstructor {
     IRI(ClassIRI),
     SubClassOf(SubClassOf),
     DataSomeValuesFrom(DataSomeValuesFrom),
+    DataAllValuesFrom(DataAllValuesFrom),
+    DataHasValue(DataHasValue),
+    DataMinCardinality(DataMinCardinality),
+    DataMaxCardinality(DataMaxCardinality),
+    DataExactCardinality(DataExactCardinality),
     EquivalentClasses(EquivalentClasses),
     DisjointClasses(DisjointClasses),
     ObjectComplementOf(ObjectComplementOf),
@@ -108,6 +116,11 @@ export interface ClassConstructor {
     IRI?: IRI
     SubClassOf?: SubClassOf
     DataSomeValuesFrom?: DataSomeValuesFrom
+    DataAllValuesFrom?: DataAllValuesFrom
+    DataHasValue?: DataHasValue
+    DataMinCardinality?: DataMinCardinality
+    DataMaxCardinality?: DataMaxCardinality
+    DataExactCardinality?: DataExactCardinality
     EquivalentClasses?: EquivalentClasses
     DisjointClasses?: DisjointClasses
     ObjectComplementOf?: ObjectComplementOf
@@ -127,6 +140,11 @@ interface ClassConstructorMatcher<R> {
     IRI?: (c: IRI) => R
     SubClassOf?: (c: SubClassOf) => R
     DataSomeValuesFrom?: (c: DataSomeValuesFrom) => R
+    DataAllValuesFrom?: (c: DataAllValuesFrom) => R
+    DataHasValue?: (c: DataHasValue) => R
+    DataMinCardinality?: (c: DataMinCardinality) => R
+    DataMaxCardinality?: (c: DataMaxCardinality) => R
+    DataExactCardinality?: (c: DataExactCardinality) => R
     EquivalentClasses?: (c: EquivalentClasses) => R
     DisjointClasses?: (c: DisjointClasses) => R
     ObjectComplementOf?: (c: ObjectComplementOf) => R
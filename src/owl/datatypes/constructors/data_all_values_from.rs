@@ -0,0 +1,58 @@
+use crate::owl::{Annotation, ClassConstructor, DataPropertyIRI, DatatypeIRI};
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct DataAllValuesFrom {
+    #[serde(rename = "dataPropertyIRI")]
+    pub data_property_iri: DataPropertyIRI,
+    #[serde(rename = "datatypeIRI")]
+    pub datatype_iri: DatatypeIRI,
+    #[serde(rename = "annotations")]
+    pub annotations: Vec<Annotation>,
+}
+
+impl DataAllValuesFrom {
+    pub fn new(
+        data_property_iri: DataPropertyIRI,
+        datatype_iri: DatatypeIRI,
+        annotations: Vec<Annotation>,
+    ) -> Self {
+        Self {
+            data_property_iri,
+            datatype_iri,
+            annotations,
+        }
+    }
+}
+
+impl From<DataAllValuesFrom> for Box<ClassConstructor> {
+    fn from(c: DataAllValuesFrom) -> Self {
+        Box::new(ClassConstructor::DataAllValuesFrom(c))
+    }
+}
+impl From<DataAllValuesFrom> for ClassConstructor {
+    fn from(c: DataAllValuesFrom) -> Self {
+        ClassConstructor::DataAllValuesFrom(c)
+    }
+}
+
+impl ClassConstructor {
+    pub fn data_all_values_from(&self) -> Option<&DataAllValuesFrom> {
+        match self {
+            ClassConstructor::DataAllValuesFrom(d) => Some(d),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+mod wasm {
+    use wasm_bindgen::prelude::wasm_bindgen;
+    #[wasm_bindgen(typescript_custom_section)]
+    const WASM_API: &'static str = r#"
+export type DataAllValuesFrom = {
+    dataPropertyIRI: IRI,
+    datatypeIRI: IRI,
+    annotations: Array<Annotation>,
+};
+"#;
+}
@@ -0,0 +1,57 @@
+use crate::owl::{ClassConstructor, DataPropertyIRI, DatatypeIRI};
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct DataExactCardinality {
+    pub value: u64,
+    #[serde(rename = "dataPropertyIRI")]
+    pub data_property_iri: DataPropertyIRI,
+    #[serde(rename = "datatypeIRI")]
+    pub datatype_iri: Option<DatatypeIRI>,
+}
+
+impl DataExactCardinality {
+    pub fn new(
+        value: u64,
+        data_property_iri: DataPropertyIRI,
+        datatype_iri: Option<DatatypeIRI>,
+    ) -> Self {
+        Self {
+            value,
+            data_property_iri,
+            datatype_iri,
+        }
+    }
+}
+
+impl From<DataExactCardinality> for Box<ClassConstructor> {
+    fn from(c: DataExactCardinality) -> Self {
+        Box::new(ClassConstructor::DataExactCardinality(c))
+    }
+}
+impl From<DataExactCardinality> for ClassConstructor {
+    fn from(c: DataExactCardinality) -> Self {
+        ClassConstructor::DataExactCardinality(c)
+    }
+}
+
+impl ClassConstructor {
+    pub fn data_exact_cardinality(&self) -> Option<&DataExactCardinality> {
+        match self {
+            ClassConstructor::DataExactCardinality(d) => Some(d),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+mod wasm {
+    use wasm_bindgen::prelude::wasm_bindgen;
+    #[wasm_bindgen(typescript_custom_section)]
+    const WASM_API: &'static str = r#"
+export type DataExactCardinality = {
+    value: number,
+    dataPropertyIRI: IRI,
+    datatypeIRI: IRI | undefined
+};
+"#;
+}
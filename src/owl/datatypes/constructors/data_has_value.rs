@@ -0,0 +1,58 @@
+use crate::owl::{Annotation, ClassConstructor, DataPropertyIRI, Literal};
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct DataHasValue {
+    #[serde(rename = "dataPropertyIRI")]
+    pub data_property_iri: DataPropertyIRI,
+    #[serde(rename = "value")]
+    pub value: Literal,
+    #[serde(rename = "annotations")]
+    pub annotations: Vec<Annotation>,
+}
+
+impl DataHasValue {
+    pub fn new(
+        data_property_iri: DataPropertyIRI,
+        value: Literal,
+        annotations: Vec<Annotation>,
+    ) -> Self {
+        Self {
+            data_property_iri,
+            value,
+            annotations,
+        }
+    }
+}
+
+impl From<DataHasValue> for Box<ClassConstructor> {
+    fn from(c: DataHasValue) -> Self {
+        Box::new(ClassConstructor::DataHasValue(c))
+    }
+}
+impl From<DataHasValue> for ClassConstructor {
+    fn from(c: DataHasValue) -> Self {
+        ClassConstructor::DataHasValue(c)
+    }
+}
+
+impl ClassConstructor {
+    pub fn data_has_value(&self) -> Option<&DataHasValue> {
+        match self {
+            ClassConstructor::DataHasValue(d) => Some(d),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+mod wasm {
+    use wasm_bindgen::prelude::wasm_bindgen;
+    #[wasm_bindgen(typescript_custom_section)]
+    const WASM_API: &'static str = r#"
+export type DataHasValue = {
+    dataPropertyIRI: IRI,
+    value: Value,
+    annotations: Array<Annotation>,
+};
+"#;
+}
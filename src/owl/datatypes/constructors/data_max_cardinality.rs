@@ -0,0 +1,57 @@
+use crate::owl::{ClassConstructor, DataPropertyIRI, DatatypeIRI};
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct DataMaxCardinality {
+    pub value: u64,
+    #[serde(rename = "dataPropertyIRI")]
+    pub data_property_iri: DataPropertyIRI,
+    #[serde(rename = "datatypeIRI")]
+    pub datatype_iri: Option<DatatypeIRI>,
+}
+
+impl DataMaxCardinality {
+    pub fn new(
+        value: u64,
+        data_property_iri: DataPropertyIRI,
+        datatype_iri: Option<DatatypeIRI>,
+    ) -> Self {
+        Self {
+            value,
+            data_property_iri,
+            datatype_iri,
+        }
+    }
+}
+
+impl From<DataMaxCardinality> for Box<ClassConstructor> {
+    fn from(c: DataMaxCardinality) -> Self {
+        Box::new(ClassConstructor::DataMaxCardinality(c))
+    }
+}
+impl From<DataMaxCardinality> for ClassConstructor {
+    fn from(c: DataMaxCardinality) -> Self {
+        ClassConstructor::DataMaxCardinality(c)
+    }
+}
+
+impl ClassConstructor {
+    pub fn data_max_cardinality(&self) -> Option<&DataMaxCardinality> {
+        match self {
+            ClassConstructor::DataMaxCardinality(d) => Some(d),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+mod wasm {
+    use wasm_bindgen::prelude::wasm_bindgen;
+    #[wasm_bindgen(typescript_custom_section)]
+    const WASM_API: &'static str = r#"
+export type DataMaxCardinality = {
+    value: number,
+    dataPropertyIRI: IRI,
+    datatypeIRI: IRI | undefined
+};
+"#;
+}
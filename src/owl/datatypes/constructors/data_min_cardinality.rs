@@ -0,0 +1,57 @@
+use crate::owl::{ClassConstructor, DataPropertyIRI, DatatypeIRI};
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct DataMinCardinality {
+    pub value: u64,
+    #[serde(rename = "dataPropertyIRI")]
+    pub data_property_iri: DataPropertyIRI,
+    #[serde(rename = "datatypeIRI")]
+    pub datatype_iri: Option<DatatypeIRI>,
+}
+
+impl DataMinCardinality {
+    pub fn new(
+        value: u64,
+        data_property_iri: DataPropertyIRI,
+        datatype_iri: Option<DatatypeIRI>,
+    ) -> Self {
+        Self {
+            value,
+            data_property_iri,
+            datatype_iri,
+        }
+    }
+}
+
+impl From<DataMinCardinality> for Box<ClassConstructor> {
+    fn from(c: DataMinCardinality) -> Self {
+        Box::new(ClassConstructor::DataMinCardinality(c))
+    }
+}
+impl From<DataMinCardinality> for ClassConstructor {
+    fn from(c: DataMinCardinality) -> Self {
+        ClassConstructor::DataMinCardinality(c)
+    }
+}
+
+impl ClassConstructor {
+    pub fn data_min_cardinality(&self) -> Option<&DataMinCardinality> {
+        match self {
+            ClassConstructor::DataMinCardinality(d) => Some(d),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+mod wasm {
+    use wasm_bindgen::prelude::wasm_bindgen;
+    #[wasm_bindgen(typescript_custom_section)]
+    const WASM_API: &'static str = r#"
+export type DataMinCardinality = {
+    value: number,
+    dataPropertyIRI: IRI,
+    datatypeIRI: IRI | undefined
+};
+"#;
+}
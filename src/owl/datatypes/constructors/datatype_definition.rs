@@ -1,5 +1,5 @@
 use super::{DataComplementOf, DataIntersectionOf, DataOneOf, DataUnionOf, DatatypeRestriction};
-use crate::owl::{Annotation, DataPropertyIRI};
+use crate::owl::{Annotation, Axiom, DataPropertyIRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub enum DatatypeDefinitionConstructor {
@@ -34,6 +34,12 @@ impl DatatypeDefinition {
     }
 }
 
+impl From<DatatypeDefinition> for Axiom {
+    fn from(a: DatatypeDefinition) -> Self {
+        Self::DatatypeDefinition(a)
+    }
+}
+
 #[cfg(feature = "wasm")]
 mod wasm {
     use wasm_bindgen::prelude::wasm_bindgen;
@@ -1,14 +1,78 @@
 use super::DatatypeDefinitionConstructor;
-use crate::owl::{Annotation, DatatypeIRI, Literal};
+use crate::owl::{well_known, Annotation, DatatypeIRI, Literal, IRI};
 
+/// A constraining facet of a datatype restriction, e.g. `xsd:minInclusive 18`.
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub enum Restriction {
-    Numeric {
-        #[serde(rename = "datatypeIRI")]
-        datatype_iri: DatatypeIRI,
-        #[serde(rename = "value")]
-        value: Literal,
-    },
+    Length(Literal),
+    MinLength(Literal),
+    MaxLength(Literal),
+    Pattern(Literal),
+    LangRange(Literal),
+    MinInclusive(Literal),
+    MinExclusive(Literal),
+    MaxInclusive(Literal),
+    MaxExclusive(Literal),
+    TotalDigits(Literal),
+    FractionDigits(Literal),
+}
+
+impl Restriction {
+    /// The restriction of the facet with the given IRI. Returns `None` for IRIs which are no
+    /// facets.
+    pub fn new(facet: &IRI, value: Literal) -> Option<Self> {
+        Some(match facet.as_str() {
+            well_known::xsd_length_str => Self::Length(value),
+            well_known::xsd_minLength_str => Self::MinLength(value),
+            well_known::xsd_maxLength_str => Self::MaxLength(value),
+            well_known::xsd_pattern_str => Self::Pattern(value),
+            // langRange is defined by rdf:PlainLiteral, but is sometimes used in the xsd namespace
+            well_known::rdf_langRange_str | "http://www.w3.org/2001/XMLSchema#langRange" => {
+                Self::LangRange(value)
+            }
+            well_known::xsd_minInclusive_str => Self::MinInclusive(value),
+            well_known::xsd_minExclusive_str => Self::MinExclusive(value),
+            well_known::xsd_maxInclusive_str => Self::MaxInclusive(value),
+            well_known::xsd_maxExclusive_str => Self::MaxExclusive(value),
+            well_known::xsd_totalDigits_str => Self::TotalDigits(value),
+            well_known::xsd_fractionDigits_str => Self::FractionDigits(value),
+            _ => return None,
+        })
+    }
+
+    /// IRI of the facet.
+    pub fn facet(&self) -> IRI {
+        IRI::new(match self {
+            Self::Length(_) => well_known::xsd_length_str,
+            Self::MinLength(_) => well_known::xsd_minLength_str,
+            Self::MaxLength(_) => well_known::xsd_maxLength_str,
+            Self::Pattern(_) => well_known::xsd_pattern_str,
+            Self::LangRange(_) => well_known::rdf_langRange_str,
+            Self::MinInclusive(_) => well_known::xsd_minInclusive_str,
+            Self::MinExclusive(_) => well_known::xsd_minExclusive_str,
+            Self::MaxInclusive(_) => well_known::xsd_maxInclusive_str,
+            Self::MaxExclusive(_) => well_known::xsd_maxExclusive_str,
+            Self::TotalDigits(_) => well_known::xsd_totalDigits_str,
+            Self::FractionDigits(_) => well_known::xsd_fractionDigits_str,
+        })
+        .unwrap()
+    }
+
+    pub fn value(&self) -> &Literal {
+        match self {
+            Self::Length(value)
+            | Self::MinLength(value)
+            | Self::MaxLength(value)
+            | Self::Pattern(value)
+            | Self::LangRange(value)
+            | Self::MinInclusive(value)
+            | Self::MinExclusive(value)
+            | Self::MaxInclusive(value)
+            | Self::MaxExclusive(value)
+            | Self::TotalDigits(value)
+            | Self::FractionDigits(value) => value,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -52,7 +116,21 @@ mod wasm {
 
     #[wasm_bindgen(typescript_custom_section)]
     const WASM_API1: &'static str = r#"
-export type Restriction = { Numeric: {datatypeIRI: IRI, value: number} };
+/**
+ * A constraining facet and its value.
+ */
+export type Restriction =
+    | { Length: Value }
+    | { MinLength: Value }
+    | { MaxLength: Value }
+    | { Pattern: Value }
+    | { LangRange: Value }
+    | { MinInclusive: Value }
+    | { MinExclusive: Value }
+    | { MaxInclusive: Value }
+    | { MaxExclusive: Value }
+    | { TotalDigits: Value }
+    | { FractionDigits: Value };
 "#;
 
     #[wasm_bindgen(typescript_custom_section)]
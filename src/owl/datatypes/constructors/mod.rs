@@ -4,6 +4,21 @@ pub use datatype_definition::*;
 mod data_some_values_from;
 pub use data_some_values_from::*;
 
+mod data_all_values_from;
+pub use data_all_values_from::*;
+
+mod data_has_value;
+pub use data_has_value::*;
+
+mod data_min_cardinality;
+pub use data_min_cardinality::*;
+
+mod data_max_cardinality;
+pub use data_max_cardinality::*;
+
+mod data_exact_cardinality;
+pub use data_exact_cardinality::*;
+
 mod datatype_restriction;
 pub use datatype_restriction::*;
 
@@ -1,4 +1,4 @@
-use crate::owl::{IndividualIRI, IRI, ResourceId};
+use crate::owl::{ResourceId, IRI};
 
 mod constructors;
 pub use constructors::*;
@@ -41,8 +41,8 @@ pub struct DataPropertyAssertion {
     pub resource_ids: Vec<ResourceId>,
     #[serde(rename = "dataPropertyIRI")]
     pub iri: DataPropertyIRI,
-    #[serde(rename = "subjectIRI")]
-    pub subject: IndividualIRI,
+    #[serde(rename = "subject")]
+    pub subject: ResourceId,
     #[serde(rename = "value")]
     pub value: Literal,
     #[serde(rename = "annotations")]
@@ -58,7 +58,7 @@ impl From<DataPropertyAssertion> for Axiom {
 impl DataPropertyAssertion {
     pub fn new(
         iri: DataPropertyIRI,
-        subject: IndividualIRI,
+        subject: ResourceId,
         value: Literal,
         annotations: Vec<Annotation>,
         resource_ids: Vec<ResourceId>,
@@ -77,8 +77,8 @@ impl DataPropertyAssertion {
 pub struct NegativeDataPropertyAssertion {
     #[serde(rename = "dataPropertyIRI")]
     pub data_property_iri: DataPropertyIRI,
-    #[serde(rename = "subjectIRI")]
-    pub subject: IndividualIRI,
+    #[serde(rename = "subject")]
+    pub subject: ResourceId,
     #[serde(rename = "value")]
     pub value: Literal,
     #[serde(rename = "annotations")]
@@ -88,7 +88,7 @@ pub struct NegativeDataPropertyAssertion {
 impl NegativeDataPropertyAssertion {
     pub fn new(
         iri: DataPropertyIRI,
-        subject: IndividualIRI,
+        subject: ResourceId,
         value: Literal,
         annotations: Vec<Annotation>,
     ) -> Self {
@@ -101,6 +101,12 @@ impl NegativeDataPropertyAssertion {
     }
 }
 
+impl From<NegativeDataPropertyAssertion> for Axiom {
+    fn from(a: NegativeDataPropertyAssertion) -> Self {
+        Self::NegativeDataPropertyAssertion(a)
+    }
+}
+
 #[cfg(feature = "wasm")]
 mod wasm {
     use wasm_bindgen::prelude::wasm_bindgen;
@@ -116,9 +122,9 @@ export type DataPropertyAssertion = {
      */
     dataPropertyIRI: IRI,
     /**
-     * IRI of the subject Individual.
+     * The subject Individual.
      */
-    subjectIRI: IRI,
+    subject: ResourceId,
     value: Value,
     annotations: Array<Annotation>,
 };
@@ -135,9 +141,9 @@ export type NegativeDataPropertyAssertion = {
      */
     dataPropertyIRI: IRI,
     /**
-     * IRI of the subject Individual.
+     * The subject Individual.
      */
-    subjectIRI: IRI,
+    subject: ResourceId,
     value: Value,
     annotations: Array<Annotation>,
 }
@@ -1,59 +1,57 @@
-use crate::owl::{Annotation, Axiom, ClassConstructor, EquivalentClasses, IndividualIRI};
+use crate::owl::{Annotation, Axiom, ClassConstructor, EquivalentClasses, ResourceId};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct SameIndividual {
-    #[serde(rename = "individualIRI1")]
-    pub individual1: IndividualIRI,
-    #[serde(rename = "individualIRI2")]
-    pub individual2: IndividualIRI,
+    #[serde(rename = "individuals")]
+    pub individuals: Vec<ResourceId>,
     #[serde(rename = "annotations")]
     pub annotations: Vec<Annotation>,
 }
 
 impl SameIndividual {
-    pub fn new(
-        individual1: IndividualIRI,
-        individual2: IndividualIRI,
-        annotations: Vec<Annotation>,
-    ) -> Self {
+    pub fn new(individuals: Vec<ResourceId>, annotations: Vec<Annotation>) -> Self {
         Self {
-            individual1,
-            individual2,
+            individuals,
             annotations,
         }
     }
 }
 
+impl From<SameIndividual> for Axiom {
+    fn from(si: SameIndividual) -> Self {
+        Axiom::SameIndividual(si)
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct DifferentIndividuals {
-    #[serde(rename = "individualIRI1")]
-    pub individual1: IndividualIRI,
-    #[serde(rename = "individualIRI2")]
-    pub individual2: IndividualIRI,
+    #[serde(rename = "individuals")]
+    pub individuals: Vec<ResourceId>,
     #[serde(rename = "annotations")]
     pub annotations: Vec<Annotation>,
 }
 
 impl DifferentIndividuals {
-    pub fn new(
-        individual1: IndividualIRI,
-        individual2: IndividualIRI,
-        annotations: Vec<Annotation>,
-    ) -> Self {
+    pub fn new(individuals: Vec<ResourceId>, annotations: Vec<Annotation>) -> Self {
         Self {
-            individual1,
-            individual2,
+            individuals,
             annotations,
         }
     }
 }
 
+impl From<DifferentIndividuals> for Axiom {
+    fn from(di: DifferentIndividuals) -> Self {
+        Axiom::DifferentIndividuals(di)
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct ClassAssertion {
     #[serde(rename = "cls")]
     pub cls: ClassConstructor,
-    #[serde(rename = "individualIRI")]
-    pub individual: IndividualIRI,
+    #[serde(rename = "individual")]
+    pub individual: ResourceId,
     #[serde(rename = "annotations")]
     pub annotations: Vec<Annotation>,
 }
@@ -61,7 +59,7 @@ pub struct ClassAssertion {
 impl ClassAssertion {
     pub fn new(
         cls: ClassConstructor,
-        individual: IndividualIRI,
+        individual: ResourceId,
         annotations: Vec<Annotation>,
     ) -> Self {
         Self {
@@ -91,8 +89,7 @@ mod wasm {
     #[wasm_bindgen(typescript_custom_section)]
     const WASM_API1: &'static str = r#"
 export type SameIndividual = {
-    individualIRI1: IRI,
-    individualIRI2: IRI,
+    individuals: Array<ResourceId>,
     annotations: Array<Annotation>,
 };
 "#;
@@ -100,8 +97,7 @@ export type SameIndividual = {
     #[wasm_bindgen(typescript_custom_section)]
     const WASM_API2: &'static str = r#"
 export type DifferentIndividuals = {
-    individualIRI1: IRI,
-    individualIRI2: IRI,
+    individuals: Array<ResourceId>,
     annotations: Array<Annotation>,
 };
 "#;
@@ -110,7 +106,7 @@ export type DifferentIndividuals = {
     const WASM_API3: &'static str = r#"
 export type ClassAssertion = {
     cls: ClassConstructor,
-    individualIRI: IRI,
+    individual: ResourceId,
     annotations: Array<Annotation>,
 };
 "#;
@@ -250,6 +250,16 @@ impl BlankNode {
     pub fn new() -> Self {
         Self(snowflake::ProcessUniqueId::new())
     }
+
+    pub(crate) fn internal_id(&self) -> snowflake::ProcessUniqueId {
+        self.0
+    }
+}
+
+impl Display for BlankNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
 
 impl From<RdfBlankNode> for BlankNode {
@@ -337,6 +347,23 @@ impl ResourceId {
     pub fn is_blank_node(&self) -> bool {
         matches!(&self, Self::BlankNode(_))
     }
+
+    /// The IRI of a named resource, `None` for blank nodes.
+    pub fn as_iri(&self) -> Option<&IRI> {
+        match self {
+            Self::IRI(iri) => Some(iri),
+            Self::BlankNode(_) => None,
+        }
+    }
+}
+
+impl Display for ResourceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IRI(iri) => write!(f, "{}", iri),
+            Self::BlankNode(bn) => write!(f, "_:{}", bn),
+        }
+    }
 }
 
 impl From<IRI> for ResourceId {
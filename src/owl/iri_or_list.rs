@@ -2,18 +2,29 @@ use std::collections::HashMap;
 
 use crate::serializer::IriToTtl;
 
-use super::IRI;
+use super::{BlankNode, ResourceId, IRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub enum IRIList {
     IRI(IRI),
+    BlankNode(BlankNode),
     List(Vec<IRI>),
 }
 
+impl From<ResourceId> for IRIList {
+    fn from(value: ResourceId) -> Self {
+        match value {
+            ResourceId::IRI(iri) => Self::IRI(iri),
+            ResourceId::BlankNode(bn) => Self::BlankNode(bn),
+        }
+    }
+}
+
 impl IriToTtl for IRIList {
     fn ttl(&self, imports: &HashMap<String, IRI>) -> String {
         match self {
             IRIList::IRI(iri) => iri.ttl(imports),
+            IRIList::BlankNode(bn) => format!("_:{}", bn),
             IRIList::List(iris) => format!(
                 "( {} )",
                 iris.iter()
@@ -47,10 +58,11 @@ mod wasm {
     #[wasm_bindgen(typescript_custom_section)]
     const WASM_API: &'static str = r#"
 /**
- * Either a single IRI or a list of IRIs.
+ * Either a single IRI, an anonymous individual or a list of IRIs.
  */
 export interface IRIList {
     IRI?: IRI,
+    BlankNode?: BlankNode,
     List?: IRI[],
 }
 "#;
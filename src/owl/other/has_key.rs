@@ -1,38 +1,52 @@
-use crate::owl::{Annotation, ClassIRI, ObjectPropertyIRI};
+use crate::owl::{Annotation, Axiom, ClassConstructor, DataPropertyIRI, ObjectPropertyIRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct HasKey {
-    #[serde(rename = "iri")]
-    pub iri: ClassIRI,
+    #[serde(rename = "cls")]
+    pub cls: ClassConstructor,
     #[serde(rename = "objectProperties")]
     pub object_properties: Vec<ObjectPropertyIRI>,
+    #[serde(rename = "dataProperties")]
+    pub data_properties: Vec<DataPropertyIRI>,
     #[serde(rename = "annotations")]
     pub annotations: Vec<Annotation>,
 }
 
 impl HasKey {
     pub fn new(
-        iri: ClassIRI,
+        cls: ClassConstructor,
         object_properties: Vec<ObjectPropertyIRI>,
+        data_properties: Vec<DataPropertyIRI>,
         annotations: Vec<Annotation>,
     ) -> Self {
         Self {
-            iri,
+            cls,
             object_properties,
+            data_properties,
             annotations,
         }
     }
 }
 
+impl From<HasKey> for Axiom {
+    fn from(a: HasKey) -> Self {
+        Self::HasKey(a)
+    }
+}
+
 #[cfg(feature = "wasm")]
 mod wasm {
     use wasm_bindgen::prelude::wasm_bindgen;
 
     #[wasm_bindgen(typescript_custom_section)]
     const WASM_API: &'static str = r#"
+/**
+ * The individuals of a class are identified by the values of the key properties.
+ */
 export type HasKey = {
-    iri: IRI, 
+    cls: ClassConstructor,
     objectProperties: Array<IRI>,
+    dataProperties: Array<IRI>,
     annotations: Array<Annotation>,
 };
 "#;
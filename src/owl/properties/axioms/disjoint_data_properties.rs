@@ -0,0 +1,37 @@
+use crate::owl::{Annotation, Axiom, DataPropertyIRI};
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct DisjointDataProperties {
+    #[serde(rename = "dataPropertyIRIs")]
+    pub data_property_iris: Vec<DataPropertyIRI>,
+    #[serde(rename = "annotations")]
+    pub annotations: Vec<Annotation>,
+}
+
+impl DisjointDataProperties {
+    pub fn new(data_property_iris: Vec<DataPropertyIRI>, annotations: Vec<Annotation>) -> Self {
+        Self {
+            data_property_iris,
+            annotations,
+        }
+    }
+}
+
+impl From<DisjointDataProperties> for Axiom {
+    fn from(a: DisjointDataProperties) -> Self {
+        Self::DisjointDataProperties(a)
+    }
+}
+
+#[cfg(feature = "wasm")]
+mod wasm {
+    use wasm_bindgen::prelude::wasm_bindgen;
+
+    #[wasm_bindgen(typescript_custom_section)]
+    const WASM_API: &'static str = r#"
+export type DisjointDataProperties = {
+    dataPropertyIRIs: Array<IRI>,
+    annotations: Array<Annotation>,
+};
+"#;
+}
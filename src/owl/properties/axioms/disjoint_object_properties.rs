@@ -1,29 +1,28 @@
-use crate::owl::{Annotation, ObjectPropertyIRI};
+use crate::owl::{Annotation, Axiom, ObjectPropertyIRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct DisjointObjectProperties {
-    #[serde(rename = "objectPropertyIRI1")]
-    pub object_property_iri_1: ObjectPropertyIRI,
-    #[serde(rename = "objectPropertyIRI2")]
-    pub object_property_iri_2: ObjectPropertyIRI,
+    #[serde(rename = "objectPropertyIRIs")]
+    pub object_property_iris: Vec<ObjectPropertyIRI>,
     #[serde(rename = "annotations")]
     pub annotations: Vec<Annotation>,
 }
 
 impl DisjointObjectProperties {
-    pub fn new(
-        object_property_iri_1: ObjectPropertyIRI,
-        object_property_iri_2: ObjectPropertyIRI,
-        annotations: Vec<Annotation>,
-    ) -> Self {
+    pub fn new(object_property_iris: Vec<ObjectPropertyIRI>, annotations: Vec<Annotation>) -> Self {
         Self {
-            object_property_iri_1,
-            object_property_iri_2,
+            object_property_iris,
             annotations,
         }
     }
 }
 
+impl From<DisjointObjectProperties> for Axiom {
+    fn from(a: DisjointObjectProperties) -> Self {
+        Self::DisjointObjectProperties(a)
+    }
+}
+
 #[cfg(feature = "wasm")]
 mod wasm {
     use wasm_bindgen::prelude::wasm_bindgen;
@@ -31,8 +30,7 @@ mod wasm {
     #[wasm_bindgen(typescript_custom_section)]
     const WASM_API: &'static str = r#"
 export type DisjointObjectProperties = {
-    objectPropertyIRI1: IRI,
-    objectPropertyIRI2: IRI,
+    objectPropertyIRIs: Array<IRI>,
     annotations: Array<Annotation>,
 };
 "#;
@@ -1,4 +1,4 @@
-use crate::owl::{Annotation, DataPropertyIRI};
+use crate::owl::{Annotation, Axiom, DataPropertyIRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct EquivalentDataProperties {
@@ -24,6 +24,12 @@ impl EquivalentDataProperties {
     }
 }
 
+impl From<EquivalentDataProperties> for Axiom {
+    fn from(a: EquivalentDataProperties) -> Self {
+        Self::EquivalentDataProperties(a)
+    }
+}
+
 #[cfg(feature = "wasm")]
 mod wasm {
     use wasm_bindgen::prelude::wasm_bindgen;
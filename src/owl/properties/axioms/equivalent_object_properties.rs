@@ -1,4 +1,4 @@
-use crate::owl::{Annotation, ObjectPropertyIRI};
+use crate::owl::{Annotation, Axiom, ObjectPropertyIRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct EquivalentObjectProperties {
@@ -24,6 +24,12 @@ impl EquivalentObjectProperties {
     }
 }
 
+impl From<EquivalentObjectProperties> for Axiom {
+    fn from(a: EquivalentObjectProperties) -> Self {
+        Self::EquivalentObjectProperties(a)
+    }
+}
+
 #[cfg(feature = "wasm")]
 mod wasm {
     use wasm_bindgen::prelude::wasm_bindgen;
@@ -1,4 +1,4 @@
-use crate::owl::{Annotation, DataPropertyIRI};
+use crate::owl::{Annotation, Axiom, DataPropertyIRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct FunctionalDataProperty {
@@ -17,6 +17,12 @@ impl FunctionalDataProperty {
     }
 }
 
+impl From<FunctionalDataProperty> for Axiom {
+    fn from(a: FunctionalDataProperty) -> Self {
+        Self::FunctionalDataProperty(a)
+    }
+}
+
 #[cfg(feature = "wasm")]
 mod wasm {
     use wasm_bindgen::prelude::wasm_bindgen;
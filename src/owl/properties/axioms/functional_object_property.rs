@@ -1,4 +1,4 @@
-use crate::owl::{Annotation, ObjectPropertyIRI};
+use crate::owl::{Annotation, Axiom, ObjectPropertyIRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct FunctionalObjectProperty {
@@ -17,6 +17,12 @@ impl FunctionalObjectProperty {
     }
 }
 
+impl From<FunctionalObjectProperty> for Axiom {
+    fn from(a: FunctionalObjectProperty) -> Self {
+        Self::FunctionalObjectProperty(a)
+    }
+}
+
 #[cfg(feature = "wasm")]
 mod wasm {
     use wasm_bindgen::prelude::wasm_bindgen;
@@ -1,4 +1,4 @@
-use crate::owl::{Annotation, ObjectPropertyIRI};
+use crate::owl::{Annotation, Axiom, ObjectPropertyIRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct InverseFunctionalObjectProperty {
@@ -17,6 +17,12 @@ impl InverseFunctionalObjectProperty {
     }
 }
 
+impl From<InverseFunctionalObjectProperty> for Axiom {
+    fn from(a: InverseFunctionalObjectProperty) -> Self {
+        Self::InverseFunctionalObjectProperty(a)
+    }
+}
+
 #[cfg(feature = "wasm")]
 mod wasm {
     use wasm_bindgen::prelude::wasm_bindgen;
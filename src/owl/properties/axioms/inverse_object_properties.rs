@@ -1,4 +1,4 @@
-use crate::owl::{Annotation, ObjectPropertyIRI};
+use crate::owl::{Annotation, Axiom, ObjectPropertyIRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct InverseObjectProperties {
@@ -24,6 +24,12 @@ impl InverseObjectProperties {
     }
 }
 
+impl From<InverseObjectProperties> for Axiom {
+    fn from(a: InverseObjectProperties) -> Self {
+        Self::InverseObjectProperties(a)
+    }
+}
+
 #[cfg(feature = "wasm")]
 mod wasm {
     use wasm_bindgen::prelude::wasm_bindgen;
@@ -1,4 +1,4 @@
-use crate::owl::{Annotation, ObjectPropertyIRI};
+use crate::owl::{Annotation, Axiom, ObjectPropertyIRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct IrreflexiveObjectProperty {
@@ -17,6 +17,12 @@ impl IrreflexiveObjectProperty {
     }
 }
 
+impl From<IrreflexiveObjectProperty> for Axiom {
+    fn from(a: IrreflexiveObjectProperty) -> Self {
+        Self::IrreflexiveObjectProperty(a)
+    }
+}
+
 #[cfg(feature = "wasm")]
 mod wasm {
     use wasm_bindgen::prelude::wasm_bindgen;
@@ -13,6 +13,9 @@ pub use equivalent_object_properties::*;
 mod disjoint_object_properties;
 pub use disjoint_object_properties::*;
 
+mod disjoint_data_properties;
+pub use disjoint_data_properties::*;
+
 mod object_property_domain;
 pub use object_property_domain::*;
 
@@ -1,4 +1,4 @@
-use crate::owl::{Annotation, ObjectPropertyIRI};
+use crate::owl::{Annotation, Axiom, ObjectPropertyIRI};
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct ReflexiveObjectProperty {
@@ -17,6 +17,12 @@ impl ReflexiveObjectProperty {
     }
 }
 
+impl From<ReflexiveObjectProperty> for Axiom {
+    fn from(a: ReflexiveObjectProperty) -> Self {
+        Self::ReflexiveObjectProperty(a)
+    }
+}
+
 #[cfg(feature = "wasm")]
 mod wasm {
     use wasm_bindgen::prelude::wasm_bindgen;
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     error::Error,
-    owl::{Axiom, IRIList, IndividualIRI, ObjectPropertyConstructor, IRI},
+    owl::{Axiom, IRIList, ObjectPropertyConstructor, IRI},
 };
 use crate::owl::ResourceId;
 
@@ -39,7 +39,7 @@ pub struct ObjectPropertyAssertion {
     /// Known IDs of reifications of this assertion.
     #[serde(rename = "resourceIds")]
     pub resource_ids: Vec<ResourceId>,
-    pub subject: IndividualIRI,
+    pub subject: ResourceId,
     pub iri: ObjectPropertyIRI,
     pub object: IRIList,
     pub annotations: Vec<Annotation>,
@@ -48,22 +48,22 @@ pub struct ObjectPropertyAssertion {
 impl ObjectPropertyAssertion {
     pub fn new(
         iri: ObjectPropertyIRI,
-        subject: IndividualIRI,
-        object: IndividualIRI,
+        subject: ResourceId,
+        object: ResourceId,
         annotations: Vec<Annotation>,
         resource_ids: Vec<ResourceId>,
     ) -> Self {
         Self {
             iri,
             subject,
-            object: IRIList::IRI(object.as_iri().clone()),
+            object: object.into(),
             annotations,
             resource_ids,
         }
     }
     pub fn new_with_list(
         iri: ObjectPropertyIRI,
-        subject: IndividualIRI,
+        subject: ResourceId,
         object: Vec<IRI>,
         annotations: Vec<Annotation>,
         resource_ids: Vec<ResourceId>,
@@ -81,16 +81,16 @@ impl ObjectPropertyAssertion {
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct NegativeObjectPropertyAssertion {
     pub iri: ObjectPropertyIRI,
-    pub subject: IndividualIRI,
-    pub object: IndividualIRI,
+    pub subject: ResourceId,
+    pub object: ResourceId,
     pub annotations: Vec<Annotation>,
 }
 
 impl NegativeObjectPropertyAssertion {
     pub fn new(
         iri: ObjectPropertyIRI,
-        subject: IndividualIRI,
-        object: IndividualIRI,
+        subject: ResourceId,
+        object: ResourceId,
         annotations: Vec<Annotation>,
     ) -> Self {
         Self {
@@ -101,6 +101,13 @@ impl NegativeObjectPropertyAssertion {
         }
     }
 }
+
+impl From<NegativeObjectPropertyAssertion> for Axiom {
+    fn from(a: NegativeObjectPropertyAssertion) -> Self {
+        Self::NegativeObjectPropertyAssertion(a)
+    }
+}
+
 impl From<ObjectPropertyAssertion> for Axiom {
     fn from(opa: ObjectPropertyAssertion) -> Self {
         Self::ObjectPropertyAssertion(opa)
@@ -124,7 +131,7 @@ export type ObjectPropertyAssertion = {
     /**
      * The subject Individual.
      */
-    subject: IRI,
+    subject: ResourceId,
     /**
      * The object Individual(s).
      */
@@ -146,11 +153,11 @@ export type NegativeObjectPropertyAssertion = {
     /**
      * The subject Individual.
      */
-    subject: IRI,
+    subject: ResourceId,
     /**
      * The object Individual
      */
-    object: IRI,
+    object: ResourceId,
     annotations: Array<Annotation>,
 };
 "#;
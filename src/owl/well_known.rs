@@ -83,6 +83,14 @@ pub fn xsd_float() -> DatatypeIRI {
     IRI::new(xsd_float_str).unwrap().into()
 }
 
+#[allow(non_upper_case_globals)]
+pub const xsd_double_str: &str = "http://www.w3.org/2001/XMLSchema#double";
+
+#[allow(non_snake_case)]
+pub fn xsd_double() -> DatatypeIRI {
+    IRI::new(xsd_double_str).unwrap().into()
+}
+
 #[allow(non_upper_case_globals)]
 pub const xsd_nonNegativeInteger_str: &str = "http://www.w3.org/2001/XMLSchema#nonNegativeInteger";
 
@@ -123,6 +131,54 @@ pub fn xsd_maxExclusive() -> DatatypeIRI {
     IRI::new(xsd_maxExclusive_str).unwrap().into()
 }
 
+#[allow(non_upper_case_globals)]
+pub const xsd_length_str: &str = "http://www.w3.org/2001/XMLSchema#length";
+
+#[allow(non_snake_case)]
+pub fn xsd_length() -> DatatypeIRI {
+    IRI::new(xsd_length_str).unwrap().into()
+}
+
+#[allow(non_upper_case_globals)]
+pub const xsd_minLength_str: &str = "http://www.w3.org/2001/XMLSchema#minLength";
+
+#[allow(non_snake_case)]
+pub fn xsd_minLength() -> DatatypeIRI {
+    IRI::new(xsd_minLength_str).unwrap().into()
+}
+
+#[allow(non_upper_case_globals)]
+pub const xsd_maxLength_str: &str = "http://www.w3.org/2001/XMLSchema#maxLength";
+
+#[allow(non_snake_case)]
+pub fn xsd_maxLength() -> DatatypeIRI {
+    IRI::new(xsd_maxLength_str).unwrap().into()
+}
+
+#[allow(non_upper_case_globals)]
+pub const xsd_pattern_str: &str = "http://www.w3.org/2001/XMLSchema#pattern";
+
+#[allow(non_snake_case)]
+pub fn xsd_pattern() -> DatatypeIRI {
+    IRI::new(xsd_pattern_str).unwrap().into()
+}
+
+#[allow(non_upper_case_globals)]
+pub const xsd_totalDigits_str: &str = "http://www.w3.org/2001/XMLSchema#totalDigits";
+
+#[allow(non_snake_case)]
+pub fn xsd_totalDigits() -> DatatypeIRI {
+    IRI::new(xsd_totalDigits_str).unwrap().into()
+}
+
+#[allow(non_upper_case_globals)]
+pub const xsd_fractionDigits_str: &str = "http://www.w3.org/2001/XMLSchema#fractionDigits";
+
+#[allow(non_snake_case)]
+pub fn xsd_fractionDigits() -> DatatypeIRI {
+    IRI::new(xsd_fractionDigits_str).unwrap().into()
+}
+
 // RDFS
 #[allow(non_upper_case_globals)]
 pub const rdfs_base_str: &str = "http://www.w3.org/2000/01/rdf-schema#";
@@ -176,6 +232,14 @@ pub fn rdfs_subClassOf() -> AnnotationPropertyIRI {
     IRI::new(rdfs_subClassOf_str).unwrap().into()
 }
 
+#[allow(non_upper_case_globals)]
+pub const rdfs_subPropertyOf_str: &str = "http://www.w3.org/2000/01/rdf-schema#subPropertyOf";
+
+#[allow(non_snake_case)]
+pub fn rdfs_subPropertyOf() -> IRI {
+    IRI::new(rdfs_subPropertyOf_str).unwrap()
+}
+
 // RDF
 #[allow(non_upper_case_globals)]
 pub const rdf_base_str: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
@@ -198,6 +262,13 @@ pub fn rdf_rest() -> IRI {
     IRI::new(rdf_rest_str).unwrap()
 }
 
+#[allow(non_upper_case_globals)]
+pub const rdf_nil_str: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#nil";
+#[allow(non_snake_case)]
+pub fn rdf_nil() -> IRI {
+    IRI::new(rdf_nil_str).unwrap()
+}
+
 #[allow(non_upper_case_globals)]
 pub const rdf_type_str: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
 
@@ -206,6 +277,14 @@ pub fn rdf_type() -> AnnotationPropertyIRI {
     IRI::new(rdf_type_str).unwrap().into()
 }
 
+#[allow(non_upper_case_globals)]
+pub const rdf_langRange_str: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langRange";
+
+#[allow(non_snake_case)]
+pub fn rdf_langRange() -> DatatypeIRI {
+    IRI::new(rdf_langRange_str).unwrap().into()
+}
+
 // OWL
 #[allow(non_upper_case_globals)]
 pub const owl_base_str: &str = "http://www.w3.org/2002/07/owl#";
@@ -243,6 +322,55 @@ pub fn owl_inverseOf() -> IRI {
     IRI::new(owl_inverseOf_str).unwrap()
 }
 
+#[allow(non_upper_case_globals)]
+pub const owl_equivalentProperty_str: &str = "http://www.w3.org/2002/07/owl#equivalentProperty";
+#[allow(non_snake_case)]
+pub fn owl_equivalentProperty() -> IRI {
+    IRI::new(owl_equivalentProperty_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_propertyDisjointWith_str: &str = "http://www.w3.org/2002/07/owl#propertyDisjointWith";
+#[allow(non_snake_case)]
+pub fn owl_propertyDisjointWith() -> IRI {
+    IRI::new(owl_propertyDisjointWith_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_propertyChainAxiom_str: &str = "http://www.w3.org/2002/07/owl#propertyChainAxiom";
+#[allow(non_snake_case)]
+pub fn owl_propertyChainAxiom() -> IRI {
+    IRI::new(owl_propertyChainAxiom_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_members_str: &str = "http://www.w3.org/2002/07/owl#members";
+#[allow(non_snake_case)]
+pub fn owl_members() -> IRI {
+    IRI::new(owl_members_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_distinctMembers_str: &str = "http://www.w3.org/2002/07/owl#distinctMembers";
+#[allow(non_snake_case)]
+pub fn owl_distinctMembers() -> IRI {
+    IRI::new(owl_distinctMembers_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_disjointWith_str: &str = "http://www.w3.org/2002/07/owl#disjointWith";
+#[allow(non_snake_case)]
+pub fn owl_disjointWith() -> IRI {
+    IRI::new(owl_disjointWith_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_disjointUnionOf_str: &str = "http://www.w3.org/2002/07/owl#disjointUnionOf";
+#[allow(non_snake_case)]
+pub fn owl_disjointUnionOf() -> IRI {
+    IRI::new(owl_disjointUnionOf_str).unwrap()
+}
+
 #[allow(non_upper_case_globals)]
 pub const owl_someValuesFrom_str: &str = "http://www.w3.org/2002/07/owl#someValuesFrom";
 #[allow(non_snake_case)]
@@ -277,6 +405,34 @@ pub fn owl_annotatedSource() -> IRI {
     IRI::new(owl_annotatedSource_str).unwrap()
 }
 
+#[allow(non_upper_case_globals)]
+pub const owl_annotatedProperty_str: &str = "http://www.w3.org/2002/07/owl#annotatedProperty";
+#[allow(non_snake_case)]
+pub fn owl_annotatedProperty() -> IRI {
+    IRI::new(owl_annotatedProperty_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_annotatedTarget_str: &str = "http://www.w3.org/2002/07/owl#annotatedTarget";
+#[allow(non_snake_case)]
+pub fn owl_annotatedTarget() -> IRI {
+    IRI::new(owl_annotatedTarget_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_Axiom_str: &str = "http://www.w3.org/2002/07/owl#Axiom";
+#[allow(non_snake_case)]
+pub fn owl_Axiom() -> IRI {
+    IRI::new(owl_Axiom_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_Annotation_str: &str = "http://www.w3.org/2002/07/owl#Annotation";
+#[allow(non_snake_case)]
+pub fn owl_Annotation() -> IRI {
+    IRI::new(owl_Annotation_str).unwrap()
+}
+
 #[allow(non_upper_case_globals)]
 pub const owl_hasValue_str: &str = "http://www.w3.org/2002/07/owl#hasValue";
 #[allow(non_snake_case)]
@@ -402,7 +558,7 @@ pub fn owl_Class() -> ClassIRI {
 pub const owl_NamedIndividual_str: &str = "http://www.w3.org/2002/07/owl#NamedIndividual";
 #[allow(non_snake_case)]
 pub fn owl_NamedIndividual() -> IndividualIRI {
-    IRI::new(owl_Class_str).unwrap().into()
+    IRI::new(owl_NamedIndividual_str).unwrap().into()
 }
 
 #[allow(non_upper_case_globals)]
@@ -419,6 +575,135 @@ pub fn owl_SymmetricProperty() -> IRI {
     IRI::new(owl_SymmetricProperty_str).unwrap()
 }
 
+#[allow(non_upper_case_globals)]
+pub const owl_TransitiveProperty_str: &str = "http://www.w3.org/2002/07/owl#TransitiveProperty";
+#[allow(non_snake_case)]
+pub fn owl_TransitiveProperty() -> IRI {
+    IRI::new(owl_TransitiveProperty_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_ReflexiveProperty_str: &str = "http://www.w3.org/2002/07/owl#ReflexiveProperty";
+#[allow(non_snake_case)]
+pub fn owl_ReflexiveProperty() -> IRI {
+    IRI::new(owl_ReflexiveProperty_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_IrreflexiveProperty_str: &str = "http://www.w3.org/2002/07/owl#IrreflexiveProperty";
+#[allow(non_snake_case)]
+pub fn owl_IrreflexiveProperty() -> IRI {
+    IRI::new(owl_IrreflexiveProperty_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_FunctionalProperty_str: &str = "http://www.w3.org/2002/07/owl#FunctionalProperty";
+#[allow(non_snake_case)]
+pub fn owl_FunctionalProperty() -> IRI {
+    IRI::new(owl_FunctionalProperty_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_InverseFunctionalProperty_str: &str =
+    "http://www.w3.org/2002/07/owl#InverseFunctionalProperty";
+#[allow(non_snake_case)]
+pub fn owl_InverseFunctionalProperty() -> IRI {
+    IRI::new(owl_InverseFunctionalProperty_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_AllDisjointProperties_str: &str =
+    "http://www.w3.org/2002/07/owl#AllDisjointProperties";
+#[allow(non_snake_case)]
+pub fn owl_AllDisjointProperties() -> IRI {
+    IRI::new(owl_AllDisjointProperties_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_AllDisjointClasses_str: &str = "http://www.w3.org/2002/07/owl#AllDisjointClasses";
+#[allow(non_snake_case)]
+pub fn owl_AllDisjointClasses() -> IRI {
+    IRI::new(owl_AllDisjointClasses_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_AllDifferent_str: &str = "http://www.w3.org/2002/07/owl#AllDifferent";
+#[allow(non_snake_case)]
+pub fn owl_AllDifferent() -> IRI {
+    IRI::new(owl_AllDifferent_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_differentFrom_str: &str = "http://www.w3.org/2002/07/owl#differentFrom";
+#[allow(non_snake_case)]
+pub fn owl_differentFrom() -> IRI {
+    IRI::new(owl_differentFrom_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_sameAs_str: &str = "http://www.w3.org/2002/07/owl#sameAs";
+#[allow(non_snake_case)]
+pub fn owl_sameAs() -> IRI {
+    IRI::new(owl_sameAs_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_equivalentClass_str: &str = "http://www.w3.org/2002/07/owl#equivalentClass";
+#[allow(non_snake_case)]
+pub fn owl_equivalentClass() -> IRI {
+    IRI::new(owl_equivalentClass_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_hasKey_str: &str = "http://www.w3.org/2002/07/owl#hasKey";
+#[allow(non_snake_case)]
+pub fn owl_hasKey() -> IRI {
+    IRI::new(owl_hasKey_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_datatypeComplementOf_str: &str = "http://www.w3.org/2002/07/owl#datatypeComplementOf";
+#[allow(non_snake_case)]
+pub fn owl_datatypeComplementOf() -> IRI {
+    IRI::new(owl_datatypeComplementOf_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_NegativePropertyAssertion_str: &str =
+    "http://www.w3.org/2002/07/owl#NegativePropertyAssertion";
+#[allow(non_snake_case)]
+pub fn owl_NegativePropertyAssertion() -> IRI {
+    IRI::new(owl_NegativePropertyAssertion_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_sourceIndividual_str: &str = "http://www.w3.org/2002/07/owl#sourceIndividual";
+#[allow(non_snake_case)]
+pub fn owl_sourceIndividual() -> IRI {
+    IRI::new(owl_sourceIndividual_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_assertionProperty_str: &str = "http://www.w3.org/2002/07/owl#assertionProperty";
+#[allow(non_snake_case)]
+pub fn owl_assertionProperty() -> IRI {
+    IRI::new(owl_assertionProperty_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_targetIndividual_str: &str = "http://www.w3.org/2002/07/owl#targetIndividual";
+#[allow(non_snake_case)]
+pub fn owl_targetIndividual() -> IRI {
+    IRI::new(owl_targetIndividual_str).unwrap()
+}
+
+#[allow(non_upper_case_globals)]
+pub const owl_targetValue_str: &str = "http://www.w3.org/2002/07/owl#targetValue";
+#[allow(non_snake_case)]
+pub fn owl_targetValue() -> IRI {
+    IRI::new(owl_targetValue_str).unwrap()
+}
+
 #[allow(non_upper_case_globals)]
 pub const owl_ObjectProperty_str: &str = "http://www.w3.org/2002/07/owl#ObjectProperty";
 #[allow(non_snake_case)]
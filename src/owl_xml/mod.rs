@@ -0,0 +1,24 @@
+//! Reading and writing ontologies in the [OWL 2 XML Serialization](https://www.w3.org/TR/owl2-xml-serialization/).
+//!
+//! OWL/XML has the same structure as the functional-style syntax: every element corresponds to
+//! a construct of [`crate::ofn`]. Documents are therefore read by the functional-style parser,
+//! which gives both syntaxes the same handling of declarations, annotations and imports.
+//!
+//! ```rust
+//! use owlish::{api::Ontology, owl_xml::ToOwlXml};
+//!
+//! let ontology = Ontology::parse_owl_xml(r#"<?xml version="1.0"?>
+//! <Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/">
+//!     <Prefix name="" IRI="http://example.com/"/>
+//!     <Declaration>
+//!         <Class abbreviatedIRI=":Person"/>
+//!     </Declaration>
+//! </Ontology>
+//! "#, Default::default()).unwrap();
+//! assert!(ontology.owl_xml().contains(r#"<Class IRI="http://example.com/Person"/>"#));
+//! ```
+
+mod parser;
+mod serializer;
+
+pub use serializer::ToOwlXml;
@@ -0,0 +1,247 @@
+use std::borrow::Cow;
+
+use roxmltree::Node;
+
+use crate::{
+    api::Ontology,
+    error::Error,
+    ofn::parser::{OfnParser, Token},
+    owl::well_known,
+    parser::{Diagnostic, ParserOptions},
+    rdf_xml::parser::{element_children, parse_xml, resolve, syntax_error, XML_NS},
+};
+
+impl Ontology {
+    /// Parses an ontology in OWL/XML.
+    ///
+    /// As in [`Ontology::parse_ofn`], declared prefixes which belong to an `Import` become
+    /// imports of the ontology, annotations of the ontology itself become annotation assertions
+    /// on the ontology IRI.
+    ///
+    /// With [`crate::parser::ParserOptionsBuilder::lenient`] axioms which can't be read are
+    /// skipped. The kind of every entity is stated in the document, so known declarations are not
+    /// needed.
+    pub fn parse_owl_xml(xml: &str, options: ParserOptions) -> Result<Self, Error> {
+        Self::parse_owl_xml_with_diagnostics(xml, options).map(|(ontology, _)| ontology)
+    }
+
+    /// Parses an ontology in OWL/XML and reports the axioms which were skipped in lenient mode
+    /// (see [`Ontology::parse_with_diagnostics`]).
+    pub fn parse_owl_xml_with_diagnostics(
+        xml: &str,
+        options: ParserOptions,
+    ) -> Result<(Self, Vec<Diagnostic>), Error> {
+        let document = parse_xml(xml)?;
+        let mut reader = Reader {
+            source: xml,
+            base: String::new(),
+            tokens: Vec::new(),
+        };
+        reader.ontology(document.root_element())?;
+        OfnParser::from_tokens(xml, reader.tokens)
+            .lenient(options.lenient)
+            .ontology()
+    }
+}
+
+/// Translates the elements of an OWL/XML document into functional-style tokens. The tokens
+/// point to the elements they were read from, so errors of the parser are reported in the XML.
+struct Reader<'a> {
+    source: &'a str,
+    /// The base of relative IRIs.
+    base: String,
+    tokens: Vec<(Token<'a>, usize)>,
+}
+
+impl<'a> Reader<'a> {
+    fn push(&mut self, token: Token<'a>, node: &Node) {
+        self.tokens.push((token, node.range().start));
+    }
+
+    /// Closes the construct of an element at its end tag.
+    fn close(&mut self, node: &Node) {
+        let range = node.range();
+        let end_tag = self.source[range.clone()].rfind('<').unwrap_or(0);
+        self.tokens.push((Token::Close, range.start + end_tag));
+    }
+
+    fn attribute(&self, node: &Node<'a, '_>, name: &str) -> Result<&'a str, Error> {
+        node.attribute(name).ok_or_else(|| {
+            syntax_error(
+                self.source,
+                node,
+                format!(
+                    "Element '{}' has no attribute '{}'",
+                    node.tag_name().name(),
+                    name
+                ),
+            )
+        })
+    }
+
+    fn ontology(&mut self, root: Node<'a, '_>) -> Result<(), Error> {
+        if !is_owl(&root, "Ontology") {
+            return Err(syntax_error(
+                self.source,
+                &root,
+                format!(
+                    "Expected an OWL/XML ontology but found '{}'",
+                    root.tag_name().name()
+                ),
+            ));
+        }
+        let children = element_children(&root, self.source)?;
+
+        // prefixes are declared before the ontology in functional-style syntax
+        for prefix in children.iter().filter(|c| is_owl(c, "Prefix")) {
+            let name = self.attribute(prefix, "name")?;
+            let iri = self.attribute(prefix, "IRI")?;
+            for token in [
+                Token::Keyword("Prefix"),
+                Token::Open,
+                Token::PrefixedName(name, ""),
+                Token::Equals,
+                Token::FullIri(Cow::Borrowed(iri)),
+                Token::Close,
+            ] {
+                self.push(token, prefix);
+            }
+        }
+
+        self.push(Token::Keyword("Ontology"), &root);
+        self.push(Token::Open, &root);
+        let iri = root.attribute("ontologyIRI");
+        self.base = root
+            .attribute((XML_NS, "base"))
+            .or(iri)
+            .unwrap_or_default()
+            .to_string();
+        // without an ontology IRI the parser reports the missing IRI
+        for iri in [iri, root.attribute("versionIRI")].into_iter().flatten() {
+            self.push(Token::FullIri(Cow::Owned(resolve(&self.base, iri))), &root);
+        }
+        for child in children.iter().filter(|c| !is_owl(c, "Prefix")) {
+            self.element(child, false)?;
+        }
+        self.close(&root);
+        Ok(())
+    }
+
+    fn element(&mut self, node: &Node<'a, '_>, in_declaration: bool) -> Result<(), Error> {
+        let name = node.tag_name().name();
+        if node.tag_name().namespace() != Some(well_known::owl_base_str) {
+            return Err(syntax_error(
+                self.source,
+                node,
+                format!("Element '{}' is not part of OWL/XML", name),
+            ));
+        }
+        match name {
+            "Class" | "Datatype" | "ObjectProperty" | "DataProperty" | "AnnotationProperty"
+            | "NamedIndividual" => {
+                if in_declaration {
+                    self.push(Token::Keyword(name), node);
+                    self.push(Token::Open, node);
+                }
+                let token = match (node.attribute("IRI"), node.attribute("abbreviatedIRI")) {
+                    (Some(iri), _) => Token::FullIri(Cow::Owned(resolve(&self.base, iri))),
+                    (None, Some(name)) => prefixed_name(name),
+                    (None, None) => {
+                        return Err(syntax_error(
+                            self.source,
+                            node,
+                            format!("Entity '{}' has no IRI", name),
+                        ))
+                    }
+                };
+                self.push(token, node);
+                if in_declaration {
+                    self.close(node);
+                }
+            }
+            "IRI" => {
+                let iri = resolve(&self.base, node.text().unwrap_or_default().trim());
+                self.push(Token::FullIri(Cow::Owned(iri)), node);
+            }
+            "AbbreviatedIRI" => {
+                self.push(prefixed_name(node.text().unwrap_or_default().trim()), node);
+            }
+            "AnonymousIndividual" => {
+                let label = self.attribute(node, "nodeID")?;
+                self.push(Token::BlankNode(label), node);
+            }
+            "Literal" => {
+                let lexical_form = node.text().unwrap_or_default().to_string();
+                self.push(Token::String(lexical_form), node);
+                if let Some(lang) = node.attribute((XML_NS, "lang")) {
+                    self.push(Token::LangTag(lang), node);
+                } else if let Some(datatype) = node.attribute("datatypeIRI") {
+                    self.push(Token::DoubleCaret, node);
+                    let datatype = resolve(&self.base, datatype);
+                    self.push(Token::FullIri(Cow::Owned(datatype)), node);
+                }
+            }
+            "Import" => {
+                let iri = resolve(&self.base, node.text().unwrap_or_default().trim());
+                self.push(Token::Keyword(name), node);
+                self.push(Token::Open, node);
+                self.push(Token::FullIri(Cow::Owned(iri)), node);
+                self.close(node);
+            }
+            // a facet is followed by its value in a datatype restriction
+            "FacetRestriction" => {
+                let facet = resolve(&self.base, self.attribute(node, "facet")?);
+                self.push(Token::FullIri(Cow::Owned(facet)), node);
+                for child in element_children(node, self.source)? {
+                    self.element(&child, false)?;
+                }
+            }
+            // the key properties are grouped into object and data properties
+            "HasKey" => {
+                self.push(Token::Keyword(name), node);
+                self.push(Token::Open, node);
+                let mut children = element_children(node, self.source)?.into_iter().peekable();
+                while let Some(annotation) = children.next_if(|c| is_owl(c, "Annotation")) {
+                    self.element(&annotation, false)?;
+                }
+                if let Some(cls) = children.next() {
+                    self.element(&cls, false)?;
+                }
+                let (data, objects): (Vec<_>, Vec<_>) =
+                    children.partition(|c| is_owl(c, "DataProperty"));
+                for properties in [objects, data] {
+                    self.push(Token::Open, node);
+                    for property in properties {
+                        self.element(&property, false)?;
+                    }
+                    self.close(node);
+                }
+                self.close(node);
+            }
+            _ => {
+                self.push(Token::Keyword(name), node);
+                self.push(Token::Open, node);
+                if let Some(cardinality) = node.attribute("cardinality") {
+                    self.push(Token::Number(cardinality), node);
+                }
+                for child in element_children(node, self.source)? {
+                    self.element(&child, name == "Declaration")?;
+                }
+                self.close(node);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_owl(node: &Node, name: &str) -> bool {
+    node.tag_name().namespace() == Some(well_known::owl_base_str) && node.tag_name().name() == name
+}
+
+fn prefixed_name(name: &str) -> Token<'_> {
+    match name.split_once(':') {
+        Some((prefix, local)) => Token::PrefixedName(prefix, local),
+        // abbreviated IRIs without a colon use the default prefix
+        None => Token::PrefixedName("", name),
+    }
+}
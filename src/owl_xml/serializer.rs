@@ -0,0 +1,710 @@
+use crate::{
+    api::Ontology,
+    ofn::{
+        serializer::{abbreviate, Prefixes},
+        well_known_prefixes,
+    },
+    owl::{
+        well_known, Annotation, Axiom, BlankNode, ClassConstructor, DatatypeDefinitionConstructor,
+        DatatypeRestriction, Declaration, IRIList, Literal, LiteralOrIRI,
+        ObjectPropertyConstructor, ResourceId, IRI,
+    },
+    rdf,
+    rdf_xml::serializer::{escape_attribute, escape_comment, escape_text, is_ncname},
+};
+
+pub trait ToOwlXml {
+    fn owl_xml(&self) -> String;
+}
+
+impl ToOwlXml for Ontology {
+    fn owl_xml(&self) -> String {
+        let mut prefixes: Prefixes = well_known_prefixes()
+            .into_iter()
+            .map(|(prefix, iri)| (prefix.into(), iri.into()))
+            .collect();
+        // imports which are not named by a prefix can't abbreviate IRIs
+        for (name, iri) in self.imports.iter().filter(|(name, _)| is_ncname(name)) {
+            prefixes.insert(name.clone(), iri.to_string());
+        }
+        let p = &prefixes;
+
+        let mut children: Vec<Xml> = prefixes
+            .iter()
+            .map(|(prefix, iri)| Xml::Element {
+                name: "Prefix",
+                attributes: vec![("name", prefix.clone()), ("IRI", iri.clone())],
+                children: vec![],
+            })
+            .collect();
+
+        let mut imports: Vec<&IRI> = self.imports.values().collect();
+        imports.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        for iri in imports {
+            children.push(element("Import", vec![Xml::Text(iri.to_string())]));
+        }
+
+        for d in self.declarations() {
+            let (entity, iri, annotations) = match d {
+                Declaration::Class { iri, annotations } => ("Class", iri.as_iri(), annotations),
+                Declaration::NamedIndividual { iri, annotations } => {
+                    ("NamedIndividual", iri.as_iri(), annotations)
+                }
+                Declaration::ObjectProperty { iri, annotations } => {
+                    ("ObjectProperty", iri.as_iri(), annotations)
+                }
+                Declaration::DataProperty { iri, annotations } => {
+                    ("DataProperty", iri.as_iri(), annotations)
+                }
+                Declaration::AnnotationProperty { iri, annotations } => {
+                    ("AnnotationProperty", iri.as_iri(), annotations)
+                }
+                Declaration::Datatype { iri, annotations } => {
+                    ("Datatype", iri.as_iri(), annotations)
+                }
+            };
+            let mut declaration = annotations_xml(annotations, p);
+            declaration.push(entity_xml(entity, iri, p));
+            children.push(element("Declaration", declaration));
+        }
+
+        for a in self.axioms() {
+            children.extend(axiom_xml(a, p));
+        }
+
+        if !self.unmapped.is_empty() {
+            children.push(Xml::Comment(
+                "Triples which are not part of any OWL construct are omitted.".into(),
+            ));
+        }
+
+        let ontology = Xml::Element {
+            name: "Ontology",
+            attributes: vec![
+                ("xmlns", well_known::owl_base_str.into()),
+                ("ontologyIRI", self.iri.to_string()),
+            ],
+            children,
+        };
+        let mut out = String::from("<?xml version=\"1.0\"?>\n");
+        ontology.write(&mut out, 0);
+        out
+    }
+}
+
+/// A node of the written document.
+enum Xml {
+    Element {
+        name: &'static str,
+        attributes: Vec<(&'static str, String)>,
+        children: Vec<Xml>,
+    },
+    Text(String),
+    Comment(String),
+}
+
+impl Xml {
+    fn write(&self, out: &mut String, level: usize) {
+        let indent = "    ".repeat(level);
+        match self {
+            Xml::Element {
+                name,
+                attributes,
+                children,
+            } => {
+                out.push_str(&indent);
+                out.push('<');
+                out.push_str(name);
+                for (attribute, value) in attributes {
+                    out.push_str(&format!(" {}=\"{}\"", attribute, escape_attribute(value)));
+                }
+                if children.is_empty() {
+                    out.push_str("/>\n");
+                    return;
+                }
+                out.push('>');
+                // text content is written inline to keep its whitespace
+                if children.iter().all(|c| matches!(c, Xml::Text(_))) {
+                    for child in children {
+                        child.write(out, 0);
+                    }
+                } else {
+                    out.push('\n');
+                    for child in children {
+                        child.write(out, level + 1);
+                    }
+                    out.push_str(&indent);
+                }
+                out.push_str(&format!("</{}>\n", name));
+            }
+            Xml::Text(text) => out.push_str(&escape_text(text)),
+            Xml::Comment(comment) => {
+                out.push_str(&format!("{}<!-- {} -->\n", indent, escape_comment(comment)))
+            }
+        }
+    }
+}
+
+fn element(name: &'static str, children: Vec<Xml>) -> Xml {
+    Xml::Element {
+        name,
+        attributes: vec![],
+        children,
+    }
+}
+
+/// An entity, e.g. `<Class IRI="..."/>`. IRIs in known namespaces are abbreviated.
+fn entity_xml(name: &'static str, iri: &IRI, prefixes: &Prefixes) -> Xml {
+    let attribute = match abbreviate(iri.as_str(), prefixes) {
+        Some(abbreviated) => ("abbreviatedIRI", abbreviated),
+        None => ("IRI", iri.to_string()),
+    };
+    Xml::Element {
+        name,
+        attributes: vec![attribute],
+        children: vec![],
+    }
+}
+
+/// An anonymous individual, identified by its node ID within the document.
+fn anonymous_individual(bn: &BlankNode) -> Xml {
+    Xml::Element {
+        name: "AnonymousIndividual",
+        attributes: vec![("nodeID", bn.to_string())],
+        children: vec![],
+    }
+}
+
+/// An IRI which is not an entity, e.g. the subject of an annotation assertion.
+fn iri_xml(iri: &IRI, prefixes: &Prefixes) -> Xml {
+    match abbreviate(iri.as_str(), prefixes) {
+        Some(abbreviated) => element("AbbreviatedIRI", vec![Xml::Text(abbreviated)]),
+        None => element("IRI", vec![Xml::Text(iri.to_string())]),
+    }
+}
+
+fn literal_xml(literal: &Literal) -> Xml {
+    let literal = rdf::Literal::from(literal);
+    let attributes = match (literal.datatype, literal.lang) {
+        (_, Some(lang)) => vec![("xml:lang", lang)],
+        (Some(datatype), None) => vec![("datatypeIRI", datatype.to_string())],
+        (None, None) => vec![],
+    };
+    let children = if literal.lexical_form.is_empty() {
+        vec![]
+    } else {
+        vec![Xml::Text(literal.lexical_form)]
+    };
+    Xml::Element {
+        name: "Literal",
+        attributes,
+        children,
+    }
+}
+
+fn value_xml(value: &LiteralOrIRI, prefixes: &Prefixes) -> Xml {
+    match value {
+        LiteralOrIRI::IRI(iri) => iri_xml(iri, prefixes),
+        LiteralOrIRI::Literal(l) => literal_xml(l),
+    }
+}
+
+fn annotations_xml(annotations: &[Annotation], prefixes: &Prefixes) -> Vec<Xml> {
+    annotations
+        .iter()
+        .map(|a| annotation_xml(a, prefixes))
+        .collect()
+}
+
+fn annotation_xml(annotation: &Annotation, prefixes: &Prefixes) -> Xml {
+    let nested: Vec<Annotation> = annotation.annotations.iter().map(|a| *a.clone()).collect();
+    let mut children = annotations_xml(&nested, prefixes);
+    children.push(entity_xml(
+        "AnnotationProperty",
+        annotation.iri.as_iri(),
+        prefixes,
+    ));
+    children.push(value_xml(&annotation.value, prefixes));
+    element("Annotation", children)
+}
+
+/// Serializes an axiom, followed by the axioms which are nested in its class expressions.
+fn axiom_xml(axiom: &Axiom, prefixes: &Prefixes) -> Vec<Xml> {
+    let p = prefixes;
+    let class = |iri: &IRI| entity_xml("Class", iri, p);
+    let object_property = |iri: &IRI| entity_xml("ObjectProperty", iri, p);
+    let data_property = |iri: &IRI| entity_xml("DataProperty", iri, p);
+    let annotation_property = |iri: &IRI| entity_xml("AnnotationProperty", iri, p);
+    let individual = |individual: &ResourceId| match individual {
+        ResourceId::IRI(iri) => entity_xml("NamedIndividual", iri, p),
+        ResourceId::BlankNode(bn) => anonymous_individual(bn),
+    };
+    let mut context = Vec::new();
+    let (name, args) = match axiom {
+        Axiom::AnnotationAssertion(a) => (
+            "AnnotationAssertion",
+            vec![
+                annotation_property(a.iri.as_iri()),
+                match &a.subject {
+                    ResourceId::IRI(iri) => iri_xml(iri, p),
+                    ResourceId::BlankNode(bn) => anonymous_individual(bn),
+                },
+                value_xml(&a.value, p),
+            ],
+        ),
+        Axiom::AnnotationPropertyDomain(a) => (
+            "AnnotationPropertyDomain",
+            vec![
+                annotation_property(a.iri.as_iri()),
+                iri_xml(a.class_iri.as_iri(), p),
+            ],
+        ),
+        Axiom::AnnotationPropertyRange(a) => (
+            "AnnotationPropertyRange",
+            vec![
+                annotation_property(a.iri.as_iri()),
+                iri_xml(a.datatype_iri.as_iri(), p),
+            ],
+        ),
+        Axiom::SubAnnotationPropertyOf(a) => (
+            "SubAnnotationPropertyOf",
+            vec![
+                annotation_property(a.subject_iri.as_iri()),
+                annotation_property(a.parent_iri.as_iri()),
+            ],
+        ),
+
+        Axiom::SubClassOf(a) => (
+            "SubClassOf",
+            vec![
+                class_xml(&a.cls, p, &mut context),
+                class_xml(&a.parent_class, p, &mut context),
+            ],
+        ),
+        Axiom::EquivalentClasses(a) => (
+            "EquivalentClasses",
+            vec![
+                class(a.class_iri.as_iri()),
+                class_xml(&a.cls, p, &mut context),
+            ],
+        ),
+        Axiom::DisjointClasses(a) => (
+            "DisjointClasses",
+            a.classes
+                .iter()
+                .map(|c| class_xml(c, p, &mut context))
+                .collect(),
+        ),
+        Axiom::DisjointUnion(a) => (
+            "DisjointUnion",
+            std::iter::once(class(a.class_iri.as_iri()))
+                .chain(a.classes.iter().map(|c| class_xml(c, p, &mut context)))
+                .collect(),
+        ),
+
+        Axiom::SubObjectPropertyOf(a) => (
+            "SubObjectPropertyOf",
+            vec![
+                property_xml(&a.object_property, p),
+                object_property(a.parent_object_property_iri.as_iri()),
+            ],
+        ),
+        Axiom::EquivalentObjectProperties(a) => (
+            "EquivalentObjectProperties",
+            vec![
+                object_property(a.object_property_iri_1.as_iri()),
+                object_property(a.object_property_iri_2.as_iri()),
+            ],
+        ),
+        Axiom::DisjointObjectProperties(a) => (
+            "DisjointObjectProperties",
+            a.object_property_iris
+                .iter()
+                .map(|iri| object_property(iri.as_iri()))
+                .collect(),
+        ),
+        Axiom::InverseObjectProperties(a) => (
+            "InverseObjectProperties",
+            vec![
+                object_property(a.object_property_iri_1.as_iri()),
+                object_property(a.object_property_iri_2.as_iri()),
+            ],
+        ),
+        Axiom::ObjectPropertyDomain(a) => (
+            "ObjectPropertyDomain",
+            vec![
+                object_property(a.iri.as_iri()),
+                class_xml(&a.cls, p, &mut context),
+            ],
+        ),
+        Axiom::ObjectPropertyRange(a) => (
+            "ObjectPropertyRange",
+            vec![
+                object_property(a.iri.as_iri()),
+                class_xml(&a.cls, p, &mut context),
+            ],
+        ),
+        Axiom::FunctionalObjectProperty(a) => (
+            "FunctionalObjectProperty",
+            vec![object_property(a.object_property_iri.as_iri())],
+        ),
+        Axiom::InverseFunctionalObjectProperty(a) => (
+            "InverseFunctionalObjectProperty",
+            vec![object_property(a.object_property_iri.as_iri())],
+        ),
+        Axiom::ReflexiveObjectProperty(a) => (
+            "ReflexiveObjectProperty",
+            vec![object_property(a.object_property_iri.as_iri())],
+        ),
+        Axiom::IrreflexiveObjectProperty(a) => (
+            "IrreflexiveObjectProperty",
+            vec![object_property(a.object_property_iri.as_iri())],
+        ),
+        Axiom::SymmetricObjectProperty(a) => (
+            "SymmetricObjectProperty",
+            vec![object_property(a.object_property_iri.as_iri())],
+        ),
+        Axiom::AsymmetricObjectProperty(a) => (
+            "AsymmetricObjectProperty",
+            vec![object_property(a.object_property_iri.as_iri())],
+        ),
+        Axiom::TransitiveObjectProperty(a) => (
+            "TransitiveObjectProperty",
+            vec![object_property(a.object_property_iri.as_iri())],
+        ),
+
+        Axiom::SubDataPropertyOf(a) => (
+            "SubDataPropertyOf",
+            vec![
+                data_property(a.subject_iri.as_iri()),
+                data_property(a.parent_iri.as_iri()),
+            ],
+        ),
+        Axiom::EquivalentDataProperties(a) => (
+            "EquivalentDataProperties",
+            vec![
+                data_property(a.data_property_iri_1.as_iri()),
+                data_property(a.data_property_iri_2.as_iri()),
+            ],
+        ),
+        Axiom::DisjointDataProperties(a) => (
+            "DisjointDataProperties",
+            a.data_property_iris
+                .iter()
+                .map(|iri| data_property(iri.as_iri()))
+                .collect(),
+        ),
+        Axiom::DataPropertyDomain(a) => (
+            "DataPropertyDomain",
+            vec![
+                data_property(a.iri.as_iri()),
+                class_xml(&a.cls, p, &mut context),
+            ],
+        ),
+        Axiom::DataPropertyRange(a) => (
+            "DataPropertyRange",
+            vec![
+                data_property(a.iri.as_iri()),
+                entity_xml("Datatype", a.datatype_iri.as_iri(), p),
+            ],
+        ),
+        Axiom::FunctionalDataProperty(a) => (
+            "FunctionalDataProperty",
+            vec![data_property(a.data_property_iri.as_iri())],
+        ),
+
+        Axiom::DatatypeDefinition(a) => (
+            "DatatypeDefinition",
+            vec![
+                entity_xml("Datatype", a.data_property_iri.as_iri(), p),
+                data_range_xml(&a.datatype, p),
+            ],
+        ),
+        Axiom::HasKey(a) => (
+            "HasKey",
+            std::iter::once(class_xml(&a.cls, p, &mut context))
+                .chain(
+                    a.object_properties
+                        .iter()
+                        .map(|iri| object_property(iri.as_iri())),
+                )
+                .chain(
+                    a.data_properties
+                        .iter()
+                        .map(|iri| data_property(iri.as_iri())),
+                )
+                .collect(),
+        ),
+
+        Axiom::SameIndividual(a) => (
+            "SameIndividual",
+            a.individuals.iter().map(individual).collect(),
+        ),
+        Axiom::DifferentIndividuals(a) => (
+            "DifferentIndividuals",
+            a.individuals.iter().map(individual).collect(),
+        ),
+        Axiom::ClassAssertion(a) => (
+            "ClassAssertion",
+            vec![
+                class_xml(&a.cls, p, &mut context),
+                individual(&a.individual),
+            ],
+        ),
+        Axiom::ObjectPropertyAssertion(a) => match &a.object {
+            IRIList::IRI(object) => (
+                "ObjectPropertyAssertion",
+                vec![
+                    object_property(a.iri.as_iri()),
+                    individual(&a.subject),
+                    entity_xml("NamedIndividual", object, p),
+                ],
+            ),
+            IRIList::BlankNode(object) => (
+                "ObjectPropertyAssertion",
+                vec![
+                    object_property(a.iri.as_iri()),
+                    individual(&a.subject),
+                    anonymous_individual(object),
+                ],
+            ),
+            IRIList::List(_) => {
+                return vec![Xml::Comment(format!(
+                    "ObjectPropertyAssertion of {} for {} with a list object is not expressible",
+                    a.iri.as_iri(),
+                    a.subject
+                ))]
+            }
+        },
+        Axiom::NegativeObjectPropertyAssertion(a) => (
+            "NegativeObjectPropertyAssertion",
+            vec![
+                object_property(a.iri.as_iri()),
+                individual(&a.subject),
+                individual(&a.object),
+            ],
+        ),
+        Axiom::DataPropertyAssertion(a) => (
+            "DataPropertyAssertion",
+            vec![
+                data_property(a.iri.as_iri()),
+                individual(&a.subject),
+                literal_xml(&a.value),
+            ],
+        ),
+        Axiom::NegativeDataPropertyAssertion(a) => (
+            "NegativeDataPropertyAssertion",
+            vec![
+                data_property(a.data_property_iri.as_iri()),
+                individual(&a.subject),
+                literal_xml(&a.value),
+            ],
+        ),
+    };
+    let mut children = annotations_xml(axiom.annotations(), p);
+    children.extend(args);
+    let mut elements = vec![element(name, children)];
+    elements.extend(context);
+    elements
+}
+
+fn property_xml(property: &ObjectPropertyConstructor, prefixes: &Prefixes) -> Xml {
+    let object_property = |iri: &IRI| entity_xml("ObjectProperty", iri, prefixes);
+    match property {
+        ObjectPropertyConstructor::IRI(iri) => object_property(iri.as_iri()),
+        ObjectPropertyConstructor::ObjectInverseOf(inverse) => {
+            element("ObjectInverseOf", vec![object_property(inverse.0.as_iri())])
+        }
+        ObjectPropertyConstructor::ObjectPropertyChain(chain) => element(
+            "ObjectPropertyChain",
+            chain
+                .0
+                .iter()
+                .map(|iri| object_property(iri.as_iri()))
+                .collect(),
+        ),
+    }
+}
+
+/// Serializes a class expression. Class constructors which are axioms on their own are
+/// represented by their first class, the axiom itself is added to `context`.
+fn class_xml(cls: &ClassConstructor, prefixes: &Prefixes, context: &mut Vec<Xml>) -> Xml {
+    let p = prefixes;
+    let classes = |classes: &[ClassConstructor], context: &mut Vec<Xml>| {
+        classes
+            .iter()
+            .map(|c| class_xml(c, p, context))
+            .collect::<Vec<Xml>>()
+    };
+    let cardinality =
+        |name: &'static str, value: u64, property: Xml, filler: Option<Xml>| Xml::Element {
+            name,
+            attributes: vec![("cardinality", value.to_string())],
+            children: std::iter::once(property).chain(filler).collect(),
+        };
+    let object_property = |iri: &IRI| entity_xml("ObjectProperty", iri, p);
+    let data_property = |iri: &IRI| entity_xml("DataProperty", iri, p);
+    let datatype = |iri: &IRI| entity_xml("Datatype", iri, p);
+    match cls {
+        ClassConstructor::IRI(iri) => entity_xml("Class", iri.as_iri(), p),
+        ClassConstructor::SubClassOf(a) => {
+            context.extend(axiom_xml(&Axiom::SubClassOf(a.clone()), p));
+            class_xml(&a.cls, p, &mut Vec::new())
+        }
+        ClassConstructor::EquivalentClasses(a) => {
+            context.extend(axiom_xml(&Axiom::EquivalentClasses(a.clone()), p));
+            entity_xml("Class", a.class_iri.as_iri(), p)
+        }
+        ClassConstructor::DisjointClasses(a) => {
+            context.extend(axiom_xml(&Axiom::DisjointClasses(a.clone()), p));
+            match a.classes.first() {
+                Some(first) => class_xml(first, p, &mut Vec::new()),
+                None => entity_xml("Class", well_known::owl_Thing().as_iri(), p),
+            }
+        }
+        ClassConstructor::ObjectIntersectionOf(c) => {
+            element("ObjectIntersectionOf", classes(&c.classes, context))
+        }
+        ClassConstructor::ObjectUnionOf(c) => {
+            element("ObjectUnionOf", classes(&c.classes, context))
+        }
+        ClassConstructor::ObjectComplementOf(c) => {
+            element("ObjectComplementOf", vec![class_xml(&c.cls, p, context)])
+        }
+        ClassConstructor::ObjectOneOf(c) => element(
+            "ObjectOneOf",
+            c.individuals
+                .iter()
+                .map(|iri| entity_xml("NamedIndividual", iri.as_iri(), p))
+                .collect(),
+        ),
+        ClassConstructor::ObjectSomeValuesFrom(c) => element(
+            "ObjectSomeValuesFrom",
+            vec![
+                property_xml(&c.object_property, p),
+                class_xml(&c.cls, p, context),
+            ],
+        ),
+        ClassConstructor::ObjectAllValuesFrom(c) => element(
+            "ObjectAllValuesFrom",
+            vec![
+                property_xml(&c.object_property, p),
+                class_xml(&c.cls, p, context),
+            ],
+        ),
+        ClassConstructor::ObjectHasValue(c) => element(
+            "ObjectHasValue",
+            vec![
+                property_xml(&c.object_property, p),
+                match &c.value_or_iri {
+                    LiteralOrIRI::IRI(iri) => entity_xml("NamedIndividual", iri, p),
+                    LiteralOrIRI::Literal(l) => literal_xml(l),
+                },
+            ],
+        ),
+        ClassConstructor::ObjectHasSelf(c) => {
+            element("ObjectHasSelf", vec![property_xml(&c.object_property, p)])
+        }
+        ClassConstructor::ObjectMinCardinality(c) => cardinality(
+            "ObjectMinCardinality",
+            c.value,
+            object_property(c.object_property_iri.as_iri()),
+            c.cls.as_ref().map(|c| class_xml(c, p, context)),
+        ),
+        ClassConstructor::ObjectMaxCardinality(c) => cardinality(
+            "ObjectMaxCardinality",
+            c.value,
+            object_property(c.object_property_iri.as_iri()),
+            c.cls.as_ref().map(|c| class_xml(c, p, context)),
+        ),
+        ClassConstructor::ObjectExactCardinality(c) => cardinality(
+            "ObjectExactCardinality",
+            c.value,
+            object_property(c.object_property_iri.as_iri()),
+            c.cls.as_ref().map(|c| class_xml(c, p, context)),
+        ),
+        ClassConstructor::DataSomeValuesFrom(c) => element(
+            "DataSomeValuesFrom",
+            vec![
+                data_property(c.data_property_iri.as_iri()),
+                restriction_xml(&c.restriction, p),
+            ],
+        ),
+        ClassConstructor::DataAllValuesFrom(c) => element(
+            "DataAllValuesFrom",
+            vec![
+                data_property(c.data_property_iri.as_iri()),
+                datatype(c.datatype_iri.as_iri()),
+            ],
+        ),
+        ClassConstructor::DataHasValue(c) => element(
+            "DataHasValue",
+            vec![
+                data_property(c.data_property_iri.as_iri()),
+                literal_xml(&c.value),
+            ],
+        ),
+        ClassConstructor::DataMinCardinality(c) => cardinality(
+            "DataMinCardinality",
+            c.value,
+            data_property(c.data_property_iri.as_iri()),
+            c.datatype_iri.as_ref().map(|iri| datatype(iri.as_iri())),
+        ),
+        ClassConstructor::DataMaxCardinality(c) => cardinality(
+            "DataMaxCardinality",
+            c.value,
+            data_property(c.data_property_iri.as_iri()),
+            c.datatype_iri.as_ref().map(|iri| datatype(iri.as_iri())),
+        ),
+        ClassConstructor::DataExactCardinality(c) => cardinality(
+            "DataExactCardinality",
+            c.value,
+            data_property(c.data_property_iri.as_iri()),
+            c.datatype_iri.as_ref().map(|iri| datatype(iri.as_iri())),
+        ),
+    }
+}
+
+fn restriction_xml(restriction: &DatatypeRestriction, prefixes: &Prefixes) -> Xml {
+    let datatype = entity_xml("Datatype", restriction.datatype_iri.as_iri(), prefixes);
+    if restriction.restrictions.is_empty() {
+        return datatype;
+    }
+    let facets = restriction.restrictions.iter().map(|r| Xml::Element {
+        name: "FacetRestriction",
+        attributes: vec![("facet", r.facet().to_string())],
+        children: vec![literal_xml(r.value())],
+    });
+    element(
+        "DatatypeRestriction",
+        std::iter::once(datatype).chain(facets).collect(),
+    )
+}
+
+fn data_range_xml(range: &DatatypeDefinitionConstructor, prefixes: &Prefixes) -> Xml {
+    let datatype = |iri: &IRI| entity_xml("Datatype", iri, prefixes);
+    match range {
+        DatatypeDefinitionConstructor::DatatypeRestriction(r) => restriction_xml(r, prefixes),
+        DatatypeDefinitionConstructor::DataComplementOf(c) => element(
+            "DataComplementOf",
+            vec![datatype(c.data_property_iri.as_iri())],
+        ),
+        DatatypeDefinitionConstructor::DataIntersectionOf(c) => element(
+            "DataIntersectionOf",
+            vec![
+                datatype(c.data_property_iri.as_iri()),
+                data_range_xml(&c.datatype, prefixes),
+            ],
+        ),
+        DatatypeDefinitionConstructor::DataUnionOf(c) => element(
+            "DataUnionOf",
+            vec![
+                datatype(c.data_property_iri.as_iri()),
+                data_range_xml(&c.datatype, prefixes),
+            ],
+        ),
+        DatatypeDefinitionConstructor::DataOneOf(c) => {
+            element("DataOneOf", c.literals.iter().map(literal_xml).collect())
+        }
+    }
+}
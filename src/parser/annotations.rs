@@ -2,7 +2,7 @@ use std::{collections::HashMap, convert::TryInto};
 
 use crate::owl::{BlankNode, ResourceId};
 use crate::{
-    error::Error,
+    error::{Error, ErrorKind},
     owl::{well_known, Annotation, AnnotationAssertion, LiteralOrIRI, IRI},
     parser::matcher::{RdfMatcher, Value},
     rdf_match,
@@ -10,13 +10,13 @@ use crate::{
 
 use super::{
     collector::{
-        get_iri_var, CollectedReification, CollectedReificationKey, MatcherHandler,
+        get_iri_var, AxiomTriple, CollectedReification, CollectedReificationKey, MatcherHandler,
         OntologyCollector,
     },
     matcher::MatcherState,
 };
 
-const WELL_KNOWN_ANNOTATIONS: [&str; 2] = [
+pub(crate) const WELL_KNOWN_ANNOTATIONS: [&str; 2] = [
     well_known::rdfs_label_str,
     well_known::rdfs_comment_str,
     //
@@ -41,30 +41,17 @@ pub(crate) fn match_reifications<'a>(
             let Some(reification_id) = mstate.last("a") else {
                 return Ok(false)
             };
-            // TODO: adjust to also take blank nodes
-            let Some(Value::Iri(subject)) = mstate.last("subject") else {
+            let Some(subject) = mstate.last("subject") else {
                 return Ok(false)
             };
             let Some(Value::Iri(predicate)) = mstate.last("predicate") else {
                 return Ok(false)
             };
-            let Some(raw_object) = mstate.last("object") else {
+            let Some(object) = mstate.last("object") else {
                 return Ok(false)
             };
-            let object = match raw_object {
-                Value::Iri(object) => object.clone(),
-                // TODO: Reification should probably take more than just lexical form into account.
-                Value::Literal {
-                    lexical_form,
-                    datatype_iri: _,
-                    language_tag: _,
-                } => lexical_form.clone(),
-                Value::Blank(_) => {
-                    todo!("Blank nodes as annotatedTarget in reification is not supported yet.")
-                }
-            };
-            let subject: ResourceId = IRI::new(subject)?.into();
-
+            // Source and target are resolved once all declarations are known, as they may be
+            // anonymous class expressions.
             let collected_reification = CollectedReification {
                 subject: subject.clone(),
                 predicate: predicate.clone(),
@@ -74,15 +61,15 @@ pub(crate) fn match_reifications<'a>(
             let reification_key = match reification_id {
                 Value::Blank(reification_bn) => CollectedReificationKey::Bn(reification_bn.clone()),
                 Value::Iri(reification_iri) => {
+                    IRI::new(reification_iri)?;
                     CollectedReificationKey::Iri(reification_iri.clone())
                 }
-                Value::Literal { .. } => {
-                    unreachable!("Literals can't be reification IDs.")
-                }
+                // Literals can't be reification IDs.
+                Value::Literal { .. } => return Ok(false),
             };
             o.insert_reification(reification_key, collected_reification);
 
-            Ok(false)
+            Ok(true)
         }),
     ));
     Ok(())
@@ -115,10 +102,12 @@ pub(crate) fn match_simple_annotation_assertions<'a>(
                     Value::Iri(subject_iri) => {
                         return push_annotation_assertion(IRI::new(subject_iri)?.into(), predicate_iri, mstate, o);
                     }
+                    // annotations of n-ary and negative axiom nodes are part of the axiom
+                    Value::Blank(subject_bn) if o.is_axiom_node(subject_bn) => return Ok(true),
                     Value::Blank(subject_bn) => {
                         return push_annotation_assertion(BlankNode::from(subject_bn.clone()).into(), predicate_iri, mstate, o);
                     }
-                    Value::Literal { .. } => unreachable!(),
+                    Value::Literal { .. } => return Ok(false),
                 }
             }
 
@@ -148,11 +137,6 @@ pub(crate) fn match_annotation_assertions<'a>(
                 return Ok(false);
             };
 
-            let value: LiteralOrIRI = match obj.clone().try_into() {
-                Ok(l) => l,
-                Err(_) => unreachable!(),
-            };
-
             // Predicate not known as AnnotationProperty
             if !(o.annotation_property_declaration(&predicate_iri).is_some()
                 || options.is_annotation_prop(&predicate_iri)
@@ -161,6 +145,10 @@ pub(crate) fn match_annotation_assertions<'a>(
                 return Ok(false);
             }
 
+            let Ok(value) = TryInto::<LiteralOrIRI>::try_into(obj.clone()) else {
+                return Ok(false);
+            };
+
             match subject {
                 Value::Iri(subject_iri) => {
                     // Here we handle AnnotationAssertions like <A> <p> <V> where <A> is an iri
@@ -182,7 +170,7 @@ pub(crate) fn match_annotation_assertions<'a>(
                 Value::Blank(subject_bn) => {
                     return handle_annotation_on_bn(o, subject_bn.clone(), predicate_iri, value);
                 }
-                Value::Literal { .. } => unreachable!(),
+                Value::Literal { .. } => return Ok(false),
             }
 
             Ok(false)
@@ -197,30 +185,26 @@ fn handle_annotation_on_bn(
     predicate_iri: IRI,
     value: LiteralOrIRI,
 ) -> Result<bool, Error> {
-    let annotate = o
+    let Some(annotate) = o
         .reification(CollectedReificationKey::Bn(subject_bn.clone()))
-        .cloned();
-    if annotate.is_none() {
+        .cloned()
+    else {
         return Ok(false);
-    }
-    let annotate = annotate.unwrap();
-    let subject = annotate.subject;
-    let predicate = annotate.predicate;
-    let object = annotate.object;
+    };
 
     // Either apply now, or save for later
-    if let Some((axiom, _)) = o.get_from_axiom_index_mut(&subject, &predicate, &object) {
+    if let Some((axiom, _)) = o.get_from_axiom_index_mut(&annotate) {
         axiom
             .annotations_mut()
             .push(Annotation::new(predicate_iri.into(), value.into(), vec![]))
     } else {
         o.annotations_for_later
-            .entry((subject.into(), predicate.into(), object.into()))
+            .entry(annotate)
             .or_insert_with(Vec::new)
             .push(Annotation::new(predicate_iri.into(), value.into(), vec![]));
     }
 
-    Ok(false)
+    Ok(true)
 }
 
 fn push_annotation_assertion(
@@ -247,14 +231,22 @@ fn push_annotation_assertion(
                 return Ok(false);
             }
         }
-        Value::Blank(_) => todo!(),
+        Value::Blank(_) => {
+            return Err(Error::with_kind(
+                ErrorKind::UnsupportedConstruct,
+                format!(
+                    "Anonymous annotation values are not supported ({})",
+                    predicate_iri
+                ),
+            ))
+        }
     };
 
-    if let Some((axiom, _)) = o.get_from_axiom_index_mut(
-        &subject_resource_id,
-        &predicate_iri.to_string(),
-        &object.to_string(),
-    ) {
+    if let Some((axiom, _)) = o.get_from_axiom_index_mut(&AxiomTriple::new(
+        subject_resource_id.clone(),
+        predicate_iri.as_str(),
+        &object,
+    )) {
         axiom
             .annotations_mut()
             .push(Annotation::new(predicate_iri.into(), object.into(), vec![]))
@@ -1,26 +1,55 @@
-use super::collector::CollectedBlankNode;
+use super::collector::get_individual_var;
+use super::collector::get_iri_var;
+use super::collector::individual;
 use super::collector::MatcherHandler;
-use crate::error::Error;
+use super::collector::OntologyCollector;
+use super::matcher::MatcherState;
+use super::IndexedParserOptions;
+use crate::error::{Error, ErrorKind};
 use crate::get_vars;
+use crate::owl::well_known;
+use crate::owl::Annotation;
 use crate::owl::AnnotationPropertyDomain;
 use crate::owl::AnnotationPropertyRange;
+use crate::owl::AsymmetricObjectProperty;
 use crate::owl::ClassAssertion;
 use crate::owl::ClassConstructor;
 use crate::owl::DataPropertyDomain;
 use crate::owl::DataPropertyRange;
+use crate::owl::DatatypeDefinition;
+use crate::owl::DifferentIndividuals;
+use crate::owl::DisjointClasses;
+use crate::owl::DisjointDataProperties;
+use crate::owl::DisjointObjectProperties;
+use crate::owl::DisjointUnion;
 use crate::owl::EquivalentClasses;
+use crate::owl::EquivalentDataProperties;
+use crate::owl::EquivalentObjectProperties;
+use crate::owl::FunctionalDataProperty;
+use crate::owl::FunctionalObjectProperty;
+use crate::owl::HasKey;
+use crate::owl::InverseFunctionalObjectProperty;
+use crate::owl::InverseObjectProperties;
+use crate::owl::IrreflexiveObjectProperty;
+use crate::owl::Literal;
+use crate::owl::NegativeDataPropertyAssertion;
+use crate::owl::NegativeObjectPropertyAssertion;
+use crate::owl::ObjectPropertyChain;
 use crate::owl::ObjectPropertyDomain;
 use crate::owl::ObjectPropertyRange;
+use crate::owl::ReflexiveObjectProperty;
+use crate::owl::SameIndividual;
 use crate::owl::SubAnnotationPropertyOf;
 use crate::owl::SubClassOf;
 use crate::owl::SubDataPropertyOf;
 use crate::owl::SubObjectPropertyOf;
+use crate::owl::SymmetricObjectProperty;
+use crate::owl::TransitiveObjectProperty;
 use crate::owl::IRI;
 use crate::parser::matcher::RdfMatcher;
 use crate::parser::matcher::Value;
 use crate::rdf_match;
 use std::collections::HashMap;
-use std::ops::Deref;
 
 pub(crate) fn match_axioms(
     matchers: &mut Vec<(RdfMatcher, MatcherHandler)>,
@@ -28,25 +57,33 @@ pub(crate) fn match_axioms(
 ) -> Result<(), Error> {
     matchers.push((
         rdf_match!("ClassAssertions", prefixes,
-            [*:x] [rdf:type] [*:cls] .
+            [+:x] [rdf:type] [iob:cls] .
         )?,
         Box::new(|mstate, o, options| {
-            if let Some(individual_iri) = mstate.last_iri("x") {
-                if let Some(cls) = mstate.last_iri("cls") {
-                    let individual_iri = IRI::new(individual_iri)?;
-                    let cls = IRI::new(cls)?;
-                    if o.class_declaration(&cls).is_some() || options.is_class(&cls) {
-                        o.push_axiom(
-                            ClassAssertion::new(cls.into(), individual_iri.clone().into(), vec![])
-                                .into(),
-                        );
-                        // if o.individual_declaration(&individual_iri).is_none() {
-                        //     o.push_declaration(crate::owl::Declaration::NamedIndividual {
-                        //         iri: individual_iri.into(),
-                        //         annotations: vec![],
-                        //     })
-                        // }
+            if let Some(individual) = get_individual_var("x", mstate)? {
+                match mstate.get("cls") {
+                    Some(Value::Iri(cls)) => {
+                        let cls = IRI::new(cls)?;
+                        if o.class_declaration(&cls).is_some() || options.is_class(&cls) {
+                            o.push_axiom(
+                                ClassAssertion::new(cls.into(), individual, vec![]).into(),
+                            );
+                            // if o.individual_declaration(&individual_iri).is_none() {
+                            //     o.push_declaration(crate::owl::Declaration::NamedIndividual {
+                            //         iri: individual_iri.into(),
+                            //         annotations: vec![],
+                            //     })
+                            // }
+                            return Ok(true);
+                        }
                     }
+                    Some(Value::Blank(bn)) => {
+                        if let Some(cls) = o.class_constructor(bn, options)? {
+                            o.push_axiom(ClassAssertion::new(cls, individual, vec![]).into());
+                            return Ok(true);
+                        }
+                    }
+                    _ => {}
                 }
             }
 
@@ -57,45 +94,16 @@ pub(crate) fn match_axioms(
         rdf_match!("SubClassOf", prefixes,
             [:x] [rdfs:subClassOf] [:object] .
         )?,
-        Box::new(|mstate, o, _| {
+        Box::new(|mstate, o, options| {
             if let Some(vars) = get_vars!(mstate, x, object) {
-                match vars.x {
-                    Value::Iri(subject_iri_str) => match vars.object {
-                        Value::Iri(object_iri_str) => {
-                            o.push_axiom(
-                                SubClassOf::new(
-                                    ClassConstructor::IRI(IRI::new(subject_iri_str)?.into()).into(),
-                                    ClassConstructor::IRI(IRI::new(object_iri_str)?.into()).into(),
-                                    vec![],
-                                )
-                                .into(),
-                            );
-                        }
-                        Value::Blank(bn) => {
-                            if let Some(CollectedBlankNode::ClassConstructor(constr)) =
-                                o.get_blank(bn)
-                            {
-                                o.push_axiom(
-                                    SubClassOf::new(
-                                        ClassConstructor::IRI(IRI::new(subject_iri_str)?.into())
-                                            .into(),
-                                        constr.clone(),
-                                        vec![],
-                                    )
-                                    .into(),
-                                );
-                            }
-                        }
-                        Value::Literal { .. } => {
-                            // TODO
-                        }
-                    },
-                    Value::Blank(_) => {
-                        // TODO
-                    }
-                    Value::Literal { .. } => {
-                        // TODO
-                    }
+                if let (Some(cls), Some(parent_class)) = (
+                    o.class_expression(vars.x, options)?,
+                    o.class_expression(vars.object, options)?,
+                ) {
+                    o.push_axiom(
+                        SubClassOf::new(Box::new(cls), Box::new(parent_class), vec![]).into(),
+                    );
+                    return Ok(true);
                 }
             }
             Ok(false)
@@ -105,33 +113,34 @@ pub(crate) fn match_axioms(
         rdf_match!("EquivalentClasses", prefixes,
             [:subject] [owl:equivalentClass] [:object] .
         )?,
-        Box::new(|mstate, o, _| {
+        Box::new(|mstate, o, options| {
             if let Some(vars) = get_vars!(mstate, subject, object) {
-                match vars.subject {
-                    Value::Iri(subject_iri_str) => match vars.object {
-                        Value::Iri(object_iri_str) => {
-                            o.push_axiom(
-                                EquivalentClasses::new(
-                                    IRI::new(subject_iri_str)?.into(),
-                                    IRI::new(object_iri_str)?.into(),
-                                    vec![],
-                                )
-                                .into(),
-                            );
-                        }
-                        Value::Blank(_) => {
-                            // TODO
-                        }
-                        Value::Literal { .. } => {
-                            // TODO
-                        }
-                    },
-                    Value::Blank(_) => {
-                        // TODO
+                // equivalence is symmetric, so the named class may be on either side
+                let (class_iri, cls) = match (vars.subject, vars.object) {
+                    (Value::Iri(iri), other) | (other @ Value::Blank(_), Value::Iri(iri)) => {
+                        (iri, other)
                     }
-                    Value::Literal { .. } => {
-                        // TODO
+                    _ => {
+                        // TODO: equivalence of two anonymous class expressions
+                        return Ok(false);
                     }
+                };
+                let class_iri = IRI::new(class_iri)?;
+                // datatypes are defined by an equivalent data range
+                if o.datatype_declaration(&class_iri).is_some() || options.is_datatype(&class_iri) {
+                    if let Some(range) = o.data_range(cls)? {
+                        o.push_axiom(
+                            DatatypeDefinition::new(class_iri.into(), range, vec![]).into(),
+                        );
+                        return Ok(true);
+                    }
+                    return Ok(false);
+                }
+                if let Some(cls) = o.class_expression(cls, options)? {
+                    o.push_axiom(
+                        EquivalentClasses::new(class_iri.into(), Box::new(cls), vec![]).into(),
+                    );
+                    return Ok(true);
                 }
             }
             Ok(false)
@@ -161,6 +170,7 @@ pub(crate) fn match_axioms(
                                                 )
                                                 .into(),
                                             );
+                                            return Ok(true);
                                         } else if o.object_property_declaration(&op_iri).is_some()
                                             || options.is_object_prop(&op_iri)
                                         {
@@ -172,6 +182,7 @@ pub(crate) fn match_axioms(
                                                 )
                                                 .into(),
                                             );
+                                            return Ok(true);
                                         } else if o
                                             .annotation_property_declaration(&op_iri)
                                             .is_some()
@@ -185,35 +196,36 @@ pub(crate) fn match_axioms(
                                                 )
                                                 .into(),
                                             );
+                                            return Ok(true);
                                         }
                                     }
                                 }
                                 Value::Blank(bn) => {
-                                    if let Some(CollectedBlankNode::ClassConstructor(cc)) =
-                                        o.get_blank(bn)
-                                    {
+                                    if let Some(cc) = o.class_constructor(bn, options)? {
                                         if o.data_property_declaration(&op_iri).is_some()
                                             || options.is_data_prop(&op_iri)
                                         {
                                             o.push_axiom(
                                                 DataPropertyDomain::new(
                                                     op_iri.into(),
-                                                    cc.deref().clone(),
+                                                    cc.clone(),
                                                     vec![],
                                                 )
                                                 .into(),
                                             );
+                                            return Ok(true);
                                         } else if o.object_property_declaration(&op_iri).is_some()
                                             || options.is_object_prop(&op_iri)
                                         {
                                             o.push_axiom(
                                                 ObjectPropertyDomain::new(
                                                     op_iri.into(),
-                                                    cc.deref().clone(),
+                                                    cc.clone(),
                                                     vec![],
                                                 )
                                                 .into(),
                                             );
+                                            return Ok(true);
                                         }
                                     }
                                 }
@@ -258,6 +270,7 @@ pub(crate) fn match_axioms(
                                                 )
                                                 .into(),
                                             );
+                                            return Ok(true);
                                         } else if o.object_property_declaration(&op_iri).is_some()
                                             || options.is_object_prop(&op_iri)
                                         {
@@ -269,6 +282,7 @@ pub(crate) fn match_axioms(
                                                 )
                                                 .into(),
                                             );
+                                            return Ok(true);
                                         } else if o
                                             .annotation_property_declaration(&op_iri)
                                             .is_some()
@@ -282,24 +296,20 @@ pub(crate) fn match_axioms(
                                                 )
                                                 .into(),
                                             );
+                                            return Ok(true);
                                         }
                                     }
                                 }
                                 Value::Blank(bn) => {
-                                    if let Some(cbn) = o.get_blank(bn) {
-                                        if let CollectedBlankNode::ClassConstructor(cc) = cbn {
-                                            if o.object_property_declaration(&op_iri).is_some()
-                                                || options.is_object_prop(&op_iri)
-                                            {
-                                                o.push_axiom(
-                                                    ObjectPropertyRange::new(
-                                                        op_iri.into(),
-                                                        cc.deref().clone(),
-                                                        vec![],
-                                                    )
+                                    if let Some(cc) = o.class_constructor(bn, options)? {
+                                        if o.object_property_declaration(&op_iri).is_some()
+                                            || options.is_object_prop(&op_iri)
+                                        {
+                                            o.push_axiom(
+                                                ObjectPropertyRange::new(op_iri.into(), cc, vec![])
                                                     .into(),
-                                                );
-                                            }
+                                            );
+                                            return Ok(true);
                                         }
                                     }
                                 }
@@ -361,6 +371,7 @@ pub(crate) fn match_axioms(
                                             )
                                             .into(),
                                         );
+                                        return Ok(true);
                                     } else if subject_is_data_prop && object_is_data_prop {
                                         o.push_axiom(
                                             SubDataPropertyOf::new(
@@ -370,6 +381,7 @@ pub(crate) fn match_axioms(
                                             )
                                             .into(),
                                         );
+                                        return Ok(true);
                                     } else if subject_is_object_prop && object_is_object_prop {
                                         o.push_axiom(
                                             SubObjectPropertyOf::new(
@@ -381,6 +393,7 @@ pub(crate) fn match_axioms(
                                             )
                                             .into(),
                                         );
+                                        return Ok(true);
                                     }
                                 }
                             }
@@ -404,5 +417,501 @@ pub(crate) fn match_axioms(
         }),
     ));
 
+    // https://www.w3.org/TR/2012/REC-owl2-mapping-to-rdf-20121211/#Parsing_of_Axioms (Table 16)
+    matchers.push((
+        rdf_match!("PropertyCharacteristics", prefixes,
+            [*:subject] [rdf:type] [*:characteristic] .
+        )?,
+        Box::new(|mstate, o, options| {
+            let Some(property) = get_iri_var("subject", mstate)? else {
+                return Ok(false);
+            };
+            let Some(characteristic) = mstate.last_iri("characteristic") else {
+                return Ok(false);
+            };
+            let is_data_prop =
+                o.data_property_declaration(&property).is_some() || options.is_data_prop(&property);
+            let is_object_prop = o.object_property_declaration(&property).is_some()
+                || options.is_object_prop(&property);
+
+            match &characteristic[..] {
+                well_known::owl_FunctionalProperty_str => {
+                    if is_data_prop {
+                        o.push_axiom(FunctionalDataProperty::new(property.into(), vec![]).into());
+                    } else if is_object_prop {
+                        o.push_axiom(FunctionalObjectProperty::new(property.into(), vec![]).into());
+                    } else {
+                        return Ok(false);
+                    }
+                }
+                well_known::owl_InverseFunctionalProperty_str => o.push_axiom(
+                    InverseFunctionalObjectProperty::new(property.into(), vec![]).into(),
+                ),
+                well_known::owl_ReflexiveProperty_str => {
+                    o.push_axiom(ReflexiveObjectProperty::new(property.into(), vec![]).into())
+                }
+                well_known::owl_IrreflexiveProperty_str => {
+                    o.push_axiom(IrreflexiveObjectProperty::new(property.into(), vec![]).into())
+                }
+                well_known::owl_SymmetricProperty_str => {
+                    o.push_axiom(SymmetricObjectProperty::new(property.into(), vec![]).into())
+                }
+                well_known::owl_AsymmetricProperty_str => {
+                    o.push_axiom(AsymmetricObjectProperty::new(property.into(), vec![]).into())
+                }
+                well_known::owl_TransitiveProperty_str => {
+                    o.push_axiom(TransitiveObjectProperty::new(property.into(), vec![]).into())
+                }
+                _ => return Ok(false),
+            }
+            Ok(true)
+        }),
+    ));
+
+    matchers.push((
+        rdf_match!("EquivalentProperties", prefixes,
+            [*:subject] [owl:equivalentProperty] [*:object] .
+        )?,
+        Box::new(|mstate, o, options| {
+            let Some(subject) = get_iri_var("subject", mstate)? else {
+                return Ok(false);
+            };
+            let Some(object) = get_iri_var("object", mstate)? else {
+                return Ok(false);
+            };
+            if (o.data_property_declaration(&subject).is_some() || options.is_data_prop(&subject))
+                && (o.data_property_declaration(&object).is_some() || options.is_data_prop(&object))
+            {
+                o.push_axiom(
+                    EquivalentDataProperties::new(subject.into(), object.into(), vec![]).into(),
+                );
+            } else if (o.object_property_declaration(&subject).is_some()
+                || options.is_object_prop(&subject))
+                && (o.object_property_declaration(&object).is_some()
+                    || options.is_object_prop(&object))
+            {
+                o.push_axiom(
+                    EquivalentObjectProperties::new(subject.into(), object.into(), vec![]).into(),
+                );
+            } else {
+                return Ok(false);
+            }
+            Ok(true)
+        }),
+    ));
+
+    matchers.push((
+        rdf_match!("InverseObjectProperties", prefixes,
+            [*:subject] [owl:inverseOf] [*:object] .
+        )?,
+        Box::new(|mstate, o, _| {
+            let Some(subject) = get_iri_var("subject", mstate)? else {
+                return Ok(false);
+            };
+            let Some(object) = get_iri_var("object", mstate)? else {
+                return Ok(false);
+            };
+            o.push_axiom(
+                InverseObjectProperties::new(subject.into(), object.into(), vec![]).into(),
+            );
+            Ok(true)
+        }),
+    ));
+
+    matchers.push((
+        rdf_match!("DisjointProperties", prefixes,
+            [*:subject] [owl:propertyDisjointWith] [*:object] .
+        )?,
+        Box::new(|mstate, o, options| {
+            let Some(subject) = get_iri_var("subject", mstate)? else {
+                return Ok(false);
+            };
+            let Some(object) = get_iri_var("object", mstate)? else {
+                return Ok(false);
+            };
+            push_disjoint_properties(vec![subject, object], vec![], o, options);
+            Ok(true)
+        }),
+    ));
+
+    matchers.push((
+        rdf_match!("AllDisjointProperties", prefixes,
+            [_:x] [rdf:type] [owl:AllDisjointProperties] .
+            [_:x] [owl:members] [_:members] .
+        )?,
+        Box::new(|mstate, o, options| {
+            let (Some(Value::Blank(x)), Some(Value::Blank(members))) =
+                (mstate.get("x"), mstate.get("members"))
+            else {
+                return Ok(false);
+            };
+            let Some(seq) = o.get_sequence(members)? else {
+                return Err(Error::with_kind(
+                    ErrorKind::DanglingList,
+                    format!("Could not find referenced sequence: {:?}", members),
+                ));
+            };
+            let Some(properties) = iris(&seq)? else {
+                return Ok(false);
+            };
+            let annotations = o.blank_node_annotations(x, options)?;
+            push_disjoint_properties(properties, annotations, o, options);
+            Ok(true)
+        }),
+    ));
+
+    matchers.push((
+        rdf_match!("DisjointClasses", prefixes,
+            [*:subject] [owl:disjointWith] [iob:object] .
+        )?,
+        Box::new(|mstate, o, options| {
+            let Some(subject) = get_iri_var("subject", mstate)? else {
+                return Ok(false);
+            };
+            let Some(object) = mstate.get("object") else {
+                return Ok(false);
+            };
+            let Some(mut classes) = class_constructors(std::slice::from_ref(object), o, options)?
+            else {
+                return Ok(false);
+            };
+            classes.insert(0, ClassConstructor::IRI(subject.into()));
+            o.push_axiom(DisjointClasses::new(classes, vec![]).into());
+            Ok(true)
+        }),
+    ));
+
+    matchers.push((
+        rdf_match!("AllDisjointClasses", prefixes,
+            [_:x] [rdf:type] [owl:AllDisjointClasses] .
+            [_:x] [owl:members] [_:members] .
+        )?,
+        Box::new(|mstate, o, options| {
+            let (Some(Value::Blank(x)), Some(Value::Blank(members))) =
+                (mstate.get("x"), mstate.get("members"))
+            else {
+                return Ok(false);
+            };
+            let Some(seq) = o.get_sequence(members)? else {
+                return Err(Error::with_kind(
+                    ErrorKind::DanglingList,
+                    format!("Could not find referenced sequence: {:?}", members),
+                ));
+            };
+            let Some(classes) = class_constructors(&seq, o, options)? else {
+                return Ok(false);
+            };
+            let annotations = o.blank_node_annotations(x, options)?;
+            o.push_axiom(DisjointClasses::new(classes, annotations).into());
+            Ok(true)
+        }),
+    ));
+
+    matchers.push((
+        rdf_match!("DisjointUnion", prefixes,
+            [*:subject] [owl:disjointUnionOf] [_:members] .
+        )?,
+        Box::new(|mstate, o, options| {
+            let Some(subject) = get_iri_var("subject", mstate)? else {
+                return Ok(false);
+            };
+            let Some(Value::Blank(members)) = mstate.get("members") else {
+                return Ok(false);
+            };
+            let Some(seq) = o.get_sequence(members)? else {
+                return Err(Error::with_kind(
+                    ErrorKind::DanglingList,
+                    format!("Could not find referenced sequence: {:?}", members),
+                ));
+            };
+            let Some(classes) = class_constructors(&seq, o, options)? else {
+                return Ok(false);
+            };
+            o.push_axiom(DisjointUnion::new(subject.into(), classes, vec![]).into());
+            Ok(true)
+        }),
+    ));
+
+    matchers.push((
+        rdf_match!("HasKey", prefixes,
+            [:subject] [owl:hasKey] [_:properties] .
+        )?,
+        Box::new(|mstate, o, options| {
+            let Some(subject) = mstate.get("subject") else {
+                return Ok(false);
+            };
+            let Some(Value::Blank(properties)) = mstate.get("properties") else {
+                return Ok(false);
+            };
+            let Some(seq) = o.get_sequence(properties)? else {
+                return Err(Error::with_kind(
+                    ErrorKind::DanglingList,
+                    format!("Could not find referenced sequence: {:?}", properties),
+                ));
+            };
+            let Some(cls) = o.class_expression(subject, options)? else {
+                return Ok(false);
+            };
+            let mut object_properties = Vec::new();
+            let mut data_properties = Vec::new();
+            for property in seq {
+                let Value::Iri(property) = property else {
+                    return Ok(false);
+                };
+                let property = IRI::new(&property)?;
+                if o.data_property_declaration(&property).is_some()
+                    || options.is_data_prop(&property)
+                {
+                    data_properties.push(property.into());
+                } else if o.object_property_declaration(&property).is_some()
+                    || options.is_object_prop(&property)
+                {
+                    object_properties.push(property.into());
+                } else {
+                    return Ok(false);
+                }
+            }
+            o.push_axiom(HasKey::new(cls, object_properties, data_properties, vec![]).into());
+            Ok(true)
+        }),
+    ));
+
+    matchers.push((
+        rdf_match!("SameIndividual", prefixes,
+            [+:subject] [owl:sameAs] [+:object] .
+        )?,
+        Box::new(|mstate, o, _| {
+            let Some(subject) = get_individual_var("subject", mstate)? else {
+                return Ok(false);
+            };
+            let Some(object) = get_individual_var("object", mstate)? else {
+                return Ok(false);
+            };
+            o.push_axiom(SameIndividual::new(vec![subject, object], vec![]).into());
+            Ok(true)
+        }),
+    ));
+
+    matchers.push((
+        rdf_match!("DifferentIndividuals", prefixes,
+            [+:subject] [owl:differentFrom] [+:object] .
+        )?,
+        Box::new(|mstate, o, _| {
+            let Some(subject) = get_individual_var("subject", mstate)? else {
+                return Ok(false);
+            };
+            let Some(object) = get_individual_var("object", mstate)? else {
+                return Ok(false);
+            };
+            o.push_axiom(DifferentIndividuals::new(vec![subject, object], vec![]).into());
+            Ok(true)
+        }),
+    ));
+
+    matchers.push((
+        rdf_match!("AllDifferentMembers", prefixes,
+            [_:x] [rdf:type] [owl:AllDifferent] .
+            [_:x] [owl:members] [_:members] .
+        )?,
+        Box::new(push_all_different),
+    ));
+
+    matchers.push((
+        rdf_match!("AllDifferentDistinctMembers", prefixes,
+            [_:x] [rdf:type] [owl:AllDifferent] .
+            [_:x] [owl:distinctMembers] [_:members] .
+        )?,
+        Box::new(push_all_different),
+    ));
+
+    matchers.push((
+        rdf_match!("NegativeObjectPropertyAssertion", prefixes,
+            [_:x] [rdf:type] [owl:NegativePropertyAssertion] .
+            [_:x] [owl:sourceIndividual] [+:subject] .
+            [_:x] [owl:assertionProperty] [*:predicate] .
+            [_:x] [owl:targetIndividual] [+:object] .
+        )?,
+        Box::new(|mstate, o, options| {
+            let Some(Value::Blank(x)) = mstate.get("x") else {
+                return Ok(false);
+            };
+            let (Some(subject), Some(predicate), Some(object)) = (
+                get_individual_var("subject", mstate)?,
+                get_iri_var("predicate", mstate)?,
+                get_individual_var("object", mstate)?,
+            ) else {
+                return Ok(false);
+            };
+            let annotations = o.blank_node_annotations(x, options)?;
+            o.push_axiom(
+                NegativeObjectPropertyAssertion::new(
+                    predicate.into(),
+                    subject,
+                    object,
+                    annotations,
+                )
+                .into(),
+            );
+            Ok(true)
+        }),
+    ));
+
+    matchers.push((
+        rdf_match!("NegativeDataPropertyAssertion", prefixes,
+            [_:x] [rdf:type] [owl:NegativePropertyAssertion] .
+            [_:x] [owl:sourceIndividual] [+:subject] .
+            [_:x] [owl:assertionProperty] [*:predicate] .
+            [_:x] [owl:targetValue] [lt:value] .
+        )?,
+        Box::new(|mstate, o, options| {
+            let Some(Value::Blank(x)) = mstate.get("x") else {
+                return Ok(false);
+            };
+            let (Some(subject), Some(predicate)) = (
+                get_individual_var("subject", mstate)?,
+                get_iri_var("predicate", mstate)?,
+            ) else {
+                return Ok(false);
+            };
+            let Some(value) = mstate.get("value") else {
+                return Ok(false);
+            };
+            let Ok(value) = TryInto::<Literal>::try_into(value.clone()) else {
+                return Ok(false);
+            };
+            let annotations = o.blank_node_annotations(x, options)?;
+            o.push_axiom(
+                NegativeDataPropertyAssertion::new(predicate.into(), subject, value, annotations)
+                    .into(),
+            );
+            Ok(true)
+        }),
+    ));
+
+    matchers.push((
+        rdf_match!("PropertyChain", prefixes,
+            [*:subject] [owl:propertyChainAxiom] [_:chain] .
+        )?,
+        Box::new(|mstate, o, _| {
+            let Some(subject) = get_iri_var("subject", mstate)? else {
+                return Ok(false);
+            };
+            let Some(Value::Blank(chain)) = mstate.get("chain") else {
+                return Ok(false);
+            };
+            let Some(seq) = o.get_sequence(chain)? else {
+                return Err(Error::with_kind(
+                    ErrorKind::DanglingList,
+                    format!("Could not find referenced sequence: {:?}", chain),
+                ));
+            };
+            let mut properties = Vec::new();
+            for v in seq {
+                let Value::Iri(iri) = v else {
+                    return Ok(false);
+                };
+                properties.push(IRI::new(&iri)?.into());
+            }
+            o.push_axiom(
+                SubObjectPropertyOf::new(
+                    ObjectPropertyChain(properties).into(),
+                    subject.into(),
+                    vec![],
+                )
+                .into(),
+            );
+            Ok(true)
+        }),
+    ));
+
     Ok(())
 }
+
+/// Pushes a DisjointDataProperties axiom if all properties are data properties
+/// and a DisjointObjectProperties axiom otherwise.
+fn push_disjoint_properties(
+    properties: Vec<IRI>,
+    annotations: Vec<Annotation>,
+    o: &mut OntologyCollector,
+    options: &IndexedParserOptions,
+) {
+    if properties
+        .iter()
+        .all(|p| o.data_property_declaration(p).is_some() || options.is_data_prop(p))
+    {
+        o.push_axiom(
+            DisjointDataProperties::new(
+                properties.into_iter().map(|p| p.into()).collect(),
+                annotations,
+            )
+            .into(),
+        );
+    } else {
+        o.push_axiom(
+            DisjointObjectProperties::new(
+                properties.into_iter().map(|p| p.into()).collect(),
+                annotations,
+            )
+            .into(),
+        );
+    }
+}
+
+/// Pushes a DifferentIndividuals axiom, annotations are stated on the owl:AllDifferent node
+/// itself.
+fn push_all_different(
+    mstate: &MatcherState,
+    o: &mut OntologyCollector,
+    options: &IndexedParserOptions,
+) -> Result<bool, Error> {
+    let (Some(Value::Blank(x)), Some(Value::Blank(members))) =
+        (mstate.get("x"), mstate.get("members"))
+    else {
+        return Ok(false);
+    };
+    let Some(seq) = o.get_sequence(members)? else {
+        return Err(Error::with_kind(
+            ErrorKind::DanglingList,
+            format!("Could not find referenced sequence: {:?}", members),
+        ));
+    };
+    let mut individuals = Vec::new();
+    for member in &seq {
+        let Some(member) = individual(member)? else {
+            return Ok(false);
+        };
+        individuals.push(member);
+    }
+    let annotations = o.blank_node_annotations(x, options)?;
+    o.push_axiom(DifferentIndividuals::new(individuals, annotations).into());
+    Ok(true)
+}
+
+/// Collects the IRIs of a sequence. Returns None if the sequence contains anything else.
+fn iris(seq: &[Value]) -> Result<Option<Vec<IRI>>, Error> {
+    let mut iris = Vec::new();
+    for v in seq {
+        let Value::Iri(iri) = v else {
+            return Ok(None);
+        };
+        iris.push(IRI::new(iri)?);
+    }
+    Ok(Some(iris))
+}
+
+/// Resolves named classes and anonymous class expressions of a sequence.
+/// Returns None if any of the members could not be resolved.
+fn class_constructors(
+    seq: &[Value],
+    o: &OntologyCollector,
+    options: &IndexedParserOptions,
+) -> Result<Option<Vec<ClassConstructor>>, Error> {
+    let mut classes = Vec::new();
+    for v in seq {
+        let Some(cls) = o.class_expression(v, options)? else {
+            return Ok(None);
+        };
+        classes.push(cls);
+    }
+    Ok(Some(classes))
+}
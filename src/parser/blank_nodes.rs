@@ -1,104 +1,511 @@
+use crate::owl::well_known;
 use crate::owl::ClassConstructor;
-
-use crate::owl::IRI;
-
+use crate::owl::DataAllValuesFrom;
+use crate::owl::DataComplementOf;
+use crate::owl::DataExactCardinality;
+use crate::owl::DataHasValue;
+use crate::owl::DataIntersectionOf;
+use crate::owl::DataMaxCardinality;
+use crate::owl::DataMinCardinality;
+use crate::owl::DataOneOf;
+use crate::owl::DataSomeValuesFrom;
+use crate::owl::DataUnionOf;
+use crate::owl::DatatypeDefinitionConstructor;
+use crate::owl::DatatypeRestriction;
+use crate::owl::ObjectAllValuesFrom;
+use crate::owl::ObjectComplementOf;
+use crate::owl::ObjectExactCardinality;
+use crate::owl::ObjectHasSelf;
+use crate::owl::ObjectHasValue;
 use crate::owl::ObjectIntersectionOf;
+use crate::owl::ObjectInverseOf;
+use crate::owl::ObjectMaxCardinality;
+use crate::owl::ObjectMinCardinality;
+use crate::owl::ObjectOneOf;
+use crate::owl::ObjectPropertyConstructor;
+use crate::owl::ObjectSomeValuesFrom;
 use crate::owl::ObjectUnionOf;
+use crate::owl::Restriction;
+use crate::owl::IRI;
 use crate::parser::matcher::Value;
+use crate::parser::IndexedParserOptions;
+
+use crate::error::{Error, ErrorKind};
+
+use harriet::triple_production::RdfBlankNode;
+use std::borrow::Cow;
+use std::convert::TryInto;
+
+use super::collector::OntologyCollector;
+
+/// Anonymous class expressions are not matched triple by triple. Instead all triples
+/// with a blank node subject are collected up front and class expressions are built
+/// recursively when an axiom refers to them. This way class expressions can be nested
+/// arbitrarily deep and their triples may appear in any order.
+impl<'a> OntologyCollector<'a> {
+    /// Builds the class expression described by the given blank node (including all
+    /// nested class expressions). Returns `None` if the blank node does not describe a
+    /// class expression.
+    pub(crate) fn class_constructor(
+        &self,
+        bn: &RdfBlankNode,
+        options: &IndexedParserOptions,
+    ) -> Result<Option<ClassConstructor>, Error> {
+        self.resolve_blank_node(bn, options, &mut Vec::new())
+    }
+
+    /// Builds the class expression of a named class or a blank node.
+    pub(crate) fn class_expression(
+        &self,
+        value: &Value,
+        options: &IndexedParserOptions,
+    ) -> Result<Option<ClassConstructor>, Error> {
+        self.resolve_value(value, options, &mut Vec::new())
+    }
+
+    fn resolve_value(
+        &self,
+        value: &Value,
+        options: &IndexedParserOptions,
+        visited: &mut Vec<RdfBlankNode>,
+    ) -> Result<Option<ClassConstructor>, Error> {
+        match value {
+            Value::Iri(iri) => Ok(Some(ClassConstructor::IRI(IRI::new(iri)?.into()))),
+            Value::Blank(bn) => self.resolve_blank_node(bn, options, visited),
+            Value::Literal { .. } => Ok(None),
+        }
+    }
 
-use crate::rdf_match;
-
-use crate::error::Error;
-
-use std::collections::HashMap;
-
-use crate::parser::matcher::RdfMatcher;
-
-use super::collector::CollectedBlankNode;
-use super::collector::MatcherHandler;
-
-pub(crate) fn match_blank_nodes(
-    matchers: &mut Vec<(RdfMatcher, MatcherHandler)>,
-    prefixes: &HashMap<String, String>,
-) -> Result<(), Error> {
-    // TODO: parse all kinds of blank nodes to something like `Map<blank_node, AxiomOrDecl>`.
-
-    matchers.push((
-        rdf_match!("ObjectIntersectionOf", prefixes,
-            [_:x] [rdf:type] [owl:Class] .
-            [_:x] [owl:intersectionOf] [:object] .
-        )?,
-        Box::new(|mstate, o, _| {
-            if let Some(Value::Blank(bn)) = mstate.get("x") {
-                if let Some(Value::Blank(obj)) = mstate.get("object") {
-                    if let Some(seq) = o.get_sequence(obj) {
-                        if seq.iter().all(|v| matches!(v, Value::Iri(_))) {
-                            let mut classes = Vec::new();
-                            for v in seq {
-                                if let Value::Iri(iri) = v {
-                                    let iri = IRI::new(&iri)?;
-                                    classes.push(ClassConstructor::IRI(iri.into()));
-                                }
-                            }
-                            o.insert_blank_node(
-                                bn.clone(),
-                                CollectedBlankNode::ClassConstructor(Box::new(
-                                    ClassConstructor::ObjectIntersectionOf(
-                                        ObjectIntersectionOf::new(classes, vec![]),
-                                    ),
-                                )),
-                            );
-                            return Ok(true);
-                        } else {
-                            todo!("support deeper nested blank nodes")
-                        }
-                    } else {
-                        return Err(Error::new("Could not find referenced sequence".into()));
-                    }
+    fn resolve_blank_node(
+        &self,
+        bn: &RdfBlankNode,
+        options: &IndexedParserOptions,
+        visited: &mut Vec<RdfBlankNode>,
+    ) -> Result<Option<ClassConstructor>, Error> {
+        if visited.contains(bn) {
+            return Err(Error::with_kind(
+                ErrorKind::CyclicBlankNode,
+                format!("Class expression references itself: {:?}", bn),
+            ));
+        }
+        visited.push(bn.clone());
+        let cls = self.build_class_constructor(bn, options, visited);
+        visited.pop();
+        cls
+    }
+
+    /// Resolves all members of a sequence. Returns `None` if any member is not a class
+    /// expression.
+    fn resolve_sequence(
+        &self,
+        value: &Value,
+        options: &IndexedParserOptions,
+        visited: &mut Vec<RdfBlankNode>,
+    ) -> Result<Option<Vec<ClassConstructor>>, Error> {
+        let Value::Blank(bn) = value else {
+            return Ok(None);
+        };
+        let Some(seq) = self.get_sequence(bn)? else {
+            return Err(Error::with_kind(
+                ErrorKind::DanglingList,
+                format!("Could not find referenced sequence: {:?}", bn),
+            ));
+        };
+        let mut classes = Vec::new();
+        for v in &seq {
+            let Some(cls) = self.resolve_value(v, options, visited)? else {
+                return Ok(None);
+            };
+            classes.push(cls);
+        }
+        Ok(Some(classes))
+    }
+
+    fn build_class_constructor(
+        &self,
+        bn: &RdfBlankNode,
+        options: &IndexedParserOptions,
+        visited: &mut Vec<RdfBlankNode>,
+    ) -> Result<Option<ClassConstructor>, Error> {
+        let triples = BlankNodeTriples(self.blank_node_triples(bn));
+        // unions, intersections and enumerations of data ranges are no class expressions
+        if triples.is_datatype() {
+            return Ok(None);
+        }
+
+        if let Some(list) = triples.object(well_known::owl_intersectionOf_str) {
+            return Ok(self
+                .resolve_sequence(list, options, visited)?
+                .map(|classes| ObjectIntersectionOf::new(classes, vec![]).into()));
+        }
+        if let Some(list) = triples.object(well_known::owl_unionOf_str) {
+            return Ok(self
+                .resolve_sequence(list, options, visited)?
+                .map(|classes| ObjectUnionOf::new(classes, vec![]).into()));
+        }
+        if let Some(cls) = triples.object(well_known::owl_complementOf_str) {
+            return Ok(self
+                .resolve_value(cls, options, visited)?
+                .map(|cls| ObjectComplementOf::new(Box::new(cls), vec![]).into()));
+        }
+        if let Some(Value::Blank(list)) = triples.object(well_known::owl_oneOf_str) {
+            let Some(seq) = self.get_sequence(list)? else {
+                return Err(Error::with_kind(
+                    ErrorKind::DanglingList,
+                    format!("Could not find referenced sequence: {:?}", list),
+                ));
+            };
+            let mut individuals = Vec::new();
+            for v in seq {
+                let Value::Iri(iri) = v else {
+                    return Ok(None);
+                };
+                individuals.push(IRI::new(&iri)?.into());
+            }
+            return Ok(Some(ObjectOneOf::new(individuals, vec![]).into()));
+        }
+
+        let Some(property) = triples.object(well_known::owl_onProperty_str) else {
+            return Ok(None);
+        };
+        match property {
+            Value::Iri(property) => {
+                let property = IRI::new(property)?;
+                let is_data_prop = self.data_property_declaration(&property).is_some()
+                    || options.is_data_prop(&property);
+                if is_data_prop
+                    && (self.object_property_declaration(&property).is_some()
+                        || options.is_object_prop(&property))
+                {
+                    return Err(Error::with_kind(
+                        ErrorKind::TypePunningConflict,
+                        format!(
+                            "{} is declared as data property and as object property",
+                            property
+                        ),
+                    ));
                 }
+                if is_data_prop || triples.object(well_known::owl_onDataRange_str).is_some() {
+                    self.data_restriction(&triples, property)
+                } else {
+                    self.object_restriction(
+                        &triples,
+                        ObjectPropertyConstructor::IRI(property.into()),
+                        options,
+                        visited,
+                    )
+                }
+            }
+            Value::Blank(property) => {
+                let Some(Value::Iri(inverse)) = BlankNodeTriples(self.blank_node_triples(property))
+                    .object(well_known::owl_inverseOf_str)
+                else {
+                    return Ok(None);
+                };
+                self.object_restriction(
+                    &triples,
+                    ObjectInverseOf(IRI::new(inverse)?.into()).into(),
+                    options,
+                    visited,
+                )
+            }
+            Value::Literal { .. } => Ok(None),
+        }
+    }
+
+    fn object_restriction(
+        &self,
+        triples: &BlankNodeTriples,
+        property: ObjectPropertyConstructor,
+        options: &IndexedParserOptions,
+        visited: &mut Vec<RdfBlankNode>,
+    ) -> Result<Option<ClassConstructor>, Error> {
+        if let Some(cls) = triples.object(well_known::owl_someValuesFrom_str) {
+            return Ok(self
+                .resolve_value(cls, options, visited)?
+                .map(|cls| ObjectSomeValuesFrom::new(property, Box::new(cls), vec![]).into()));
+        }
+        if let Some(cls) = triples.object(well_known::owl_allValuesFrom_str) {
+            return Ok(self
+                .resolve_value(cls, options, visited)?
+                .map(|cls| ObjectAllValuesFrom::new(property, Box::new(cls), vec![]).into()));
+        }
+        if let Some(value) = triples.object(well_known::owl_hasValue_str) {
+            let Ok(value) = value.clone().try_into() else {
+                return Ok(None);
+            };
+            return Ok(Some(ObjectHasValue::new(property, value, vec![]).into()));
+        }
+        if triples.object(well_known::owl_hasSelf_str).is_some() {
+            return Ok(Some(ObjectHasSelf::new(property, vec![]).into()));
+        }
+
+        // cardinality restrictions are only defined on named object properties
+        let ObjectPropertyConstructor::IRI(property) = property else {
+            return Ok(None);
+        };
+        let Some((predicate, cardinality)) = triples.cardinality() else {
+            return Ok(None);
+        };
+        let cls = match triples.object(well_known::owl_onClass_str) {
+            Some(cls) => match self.resolve_value(cls, options, visited)? {
+                Some(cls) => Some(Box::new(cls)),
+                None => return Ok(None),
+            },
+            None => None,
+        };
+        Ok(match predicate {
+            Cardinality::Min => Some(ObjectMinCardinality::new(cardinality, property, cls).into()),
+            Cardinality::Max => Some(ObjectMaxCardinality::new(cardinality, property, cls).into()),
+            Cardinality::Exact => {
+                Some(ObjectExactCardinality::new(cardinality, property, cls).into())
             }
-            Ok(false)
-        }),
-    ));
-
-    matchers.push((
-        rdf_match!("ObjectUnionOf", prefixes,
-            [_:x] [rdf:type] [owl:Class] .
-            [_:x] [owl:unionOf] [:object] .
-        )?,
-        Box::new(|mstate, o, _| {
-            if let Some(Value::Blank(bn)) = mstate.get("x") {
-                if let Some(Value::Blank(obj)) = mstate.get("object") {
-                    if let Some(seq) = o.get_sequence(obj) {
-                        if seq.iter().all(|v| matches!(v, Value::Iri(_))) {
-                            let mut classes = Vec::new();
-                            for v in seq {
-                                if let Value::Iri(iri) = v {
-                                    let iri = IRI::new(&iri)?;
-                                    classes.push(ClassConstructor::IRI(iri.into()));
-                                }
-                            }
-                            o.insert_blank_node(
-                                bn.clone(),
-                                CollectedBlankNode::ClassConstructor(Box::new(
-                                    ObjectUnionOf::new(classes, vec![]).into(),
-                                )),
-                            );
-                            return Ok(true);
-                        } else {
-                            todo!("support deeper nested blank nodes")
-                        }
-                    } else {
-                        return Err(Error::new(format!(
-                            "Could not find referenced sequence: {:?}",
-                            bn
-                        )));
-                    }
+        })
+    }
+
+    fn data_restriction(
+        &self,
+        triples: &BlankNodeTriples,
+        property: IRI,
+    ) -> Result<Option<ClassConstructor>, Error> {
+        if let Some(range) = triples.object(well_known::owl_someValuesFrom_str) {
+            return Ok(self
+                .datatype_restriction(range)?
+                .map(|range| DataSomeValuesFrom::new(property.into(), range, vec![]).into()));
+        }
+        if let Some(Value::Iri(datatype)) = triples.object(well_known::owl_allValuesFrom_str) {
+            return Ok(Some(
+                DataAllValuesFrom::new(property.into(), IRI::new(datatype)?.into(), vec![]).into(),
+            ));
+        }
+        if let Some(value) = triples.object(well_known::owl_hasValue_str) {
+            let Ok(value) = value.clone().try_into() else {
+                return Ok(None);
+            };
+            return Ok(Some(
+                DataHasValue::new(property.into(), value, vec![]).into(),
+            ));
+        }
+
+        let Some((predicate, cardinality)) = triples.cardinality() else {
+            return Ok(None);
+        };
+        let datatype = match triples.object(well_known::owl_onDataRange_str) {
+            Some(Value::Iri(datatype)) => Some(IRI::new(datatype)?.into()),
+            Some(_) => return Ok(None),
+            None => None,
+        };
+        let property = property.into();
+        Ok(match predicate {
+            Cardinality::Min => {
+                Some(DataMinCardinality::new(cardinality, property, datatype).into())
+            }
+            Cardinality::Max => {
+                Some(DataMaxCardinality::new(cardinality, property, datatype).into())
+            }
+            Cardinality::Exact => {
+                Some(DataExactCardinality::new(cardinality, property, datatype).into())
+            }
+        })
+    }
+
+    /// A named datatype or a datatype restricted by facets
+    /// (`[ owl:onDatatype xsd:integer ; owl:withRestrictions ( [ xsd:minExclusive 12 ] ) ]`).
+    fn datatype_restriction(&self, range: &Value) -> Result<Option<DatatypeRestriction>, Error> {
+        match range {
+            Value::Iri(datatype) => Ok(Some(DatatypeRestriction::new(
+                IRI::new(datatype)?.into(),
+                vec![],
+                vec![],
+            ))),
+            Value::Blank(bn) => {
+                let triples = BlankNodeTriples(self.blank_node_triples(bn));
+                let Some(Value::Iri(datatype)) = triples.object(well_known::owl_onDatatype_str)
+                else {
+                    return Ok(None);
+                };
+                let Some(Value::Blank(list)) = triples.object(well_known::owl_withRestrictions_str)
+                else {
+                    return Ok(None);
+                };
+                let Some(seq) = self.get_sequence(list)? else {
+                    return Err(Error::with_kind(
+                        ErrorKind::DanglingList,
+                        format!("Could not find referenced sequence: {:?}", list),
+                    ));
+                };
+                let mut restrictions = Vec::new();
+                for v in seq {
+                    let Value::Blank(facet) = v else {
+                        return Ok(None);
+                    };
+                    let Some((facet, value)) = self.blank_node_triples(&facet).first() else {
+                        return Ok(None);
+                    };
+                    let Ok(value) = value.clone().try_into() else {
+                        return Ok(None);
+                    };
+                    let Some(restriction) = Restriction::new(&IRI::new(facet)?, value) else {
+                        return Ok(None);
+                    };
+                    restrictions.push(restriction);
                 }
+                Ok(Some(DatatypeRestriction::new(
+                    IRI::new(datatype)?.into(),
+                    restrictions,
+                    vec![],
+                )))
             }
-            Ok(false)
-        }),
-    ));
+            Value::Literal { .. } => Ok(None),
+        }
+    }
+
+    /// Builds the data range of a datatype or a blank node (including all nested data ranges).
+    /// Returns `None` if the value does not describe a data range.
+    pub(crate) fn data_range(
+        &self,
+        range: &Value,
+    ) -> Result<Option<DatatypeDefinitionConstructor>, Error> {
+        self.resolve_data_range(range, &mut Vec::new())
+    }
+
+    fn resolve_data_range(
+        &self,
+        range: &Value,
+        visited: &mut Vec<RdfBlankNode>,
+    ) -> Result<Option<DatatypeDefinitionConstructor>, Error> {
+        let Value::Blank(bn) = range else {
+            return Ok(self.datatype_restriction(range)?.map(|r| r.into()));
+        };
+        if visited.contains(bn) {
+            return Err(Error::with_kind(
+                ErrorKind::CyclicBlankNode,
+                format!("Data range references itself: {:?}", bn),
+            ));
+        }
+        visited.push(bn.clone());
+        let range = self.build_data_range(bn, visited);
+        visited.pop();
+        range
+    }
 
-    Ok(())
+    fn build_data_range(
+        &self,
+        bn: &RdfBlankNode,
+        visited: &mut Vec<RdfBlankNode>,
+    ) -> Result<Option<DatatypeDefinitionConstructor>, Error> {
+        let triples = BlankNodeTriples(self.blank_node_triples(bn));
+        if triples.object(well_known::owl_onDatatype_str).is_some() {
+            return Ok(self
+                .datatype_restriction(&Value::Blank(bn.clone()))?
+                .map(|r| r.into()));
+        }
+        if let Some(datatype) = triples.object(well_known::owl_datatypeComplementOf_str) {
+            let Value::Iri(datatype) = datatype else {
+                return Ok(None);
+            };
+            return Ok(Some(
+                DataComplementOf::new(IRI::new(datatype)?.into(), vec![]).into(),
+            ));
+        }
+        if !triples.is_datatype() {
+            return Ok(None);
+        }
+        if let Some(Value::Blank(list)) = triples.object(well_known::owl_oneOf_str) {
+            let mut literals = Vec::new();
+            for v in self.sequence(list)? {
+                let Ok(literal) = v.try_into() else {
+                    return Ok(None);
+                };
+                literals.push(literal);
+            }
+            return Ok(Some(DataOneOf::new(literals, vec![]).into()));
+        }
+        for (predicate, union) in [
+            (well_known::owl_intersectionOf_str, false),
+            (well_known::owl_unionOf_str, true),
+        ] {
+            let Some(Value::Blank(list)) = triples.object(predicate) else {
+                continue;
+            };
+            let mut members = self.sequence(list)?;
+            // The members are nested from the right, each but the last has to be a datatype.
+            let Some(last) = members.pop() else {
+                return Ok(None);
+            };
+            let Some(mut range) = self.resolve_data_range(&last, visited)? else {
+                return Ok(None);
+            };
+            for member in members.into_iter().rev() {
+                let Value::Iri(datatype) = member else {
+                    return Ok(None);
+                };
+                let datatype = IRI::new(&datatype)?.into();
+                range = if union {
+                    DataUnionOf::new(datatype, Box::new(range), vec![]).into()
+                } else {
+                    DataIntersectionOf::new(datatype, Box::new(range), vec![]).into()
+                };
+            }
+            return Ok(Some(range));
+        }
+        Ok(None)
+    }
+
+    fn sequence(&self, list: &RdfBlankNode) -> Result<Vec<Value<'a>>, Error> {
+        self.get_sequence(list)?.ok_or_else(|| {
+            Error::with_kind(
+                ErrorKind::DanglingList,
+                format!("Could not find referenced sequence: {:?}", list),
+            )
+        })
+    }
+}
+
+enum Cardinality {
+    Min,
+    Max,
+    Exact,
+}
+
+/// The (predicate, object) pairs of a blank node.
+struct BlankNodeTriples<'a, 'b>(&'b [(Cow<'a, str>, Value<'a>)]);
+
+impl<'a, 'b> BlankNodeTriples<'a, 'b> {
+    fn object(&self, predicate: &str) -> Option<&'b Value<'a>> {
+        self.0.iter().find(|(p, _)| p == predicate).map(|(_, o)| o)
+    }
+
+    /// Whether the blank node is typed as `rdfs:Datatype`.
+    fn is_datatype(&self) -> bool {
+        self.0.iter().any(|(p, o)| {
+            p == well_known::rdf_type_str
+                && matches!(o, Value::Iri(iri) if iri == well_known::rdfs_Datatype_str)
+        })
+    }
+
+    /// The kind and value of a (qualified) cardinality restriction.
+    fn cardinality(&self) -> Option<(Cardinality, u64)> {
+        [
+            (well_known::owl_minCardinality_str, Cardinality::Min),
+            (
+                well_known::owl_minQualifiedCardinality_str,
+                Cardinality::Min,
+            ),
+            (well_known::owl_maxCardinality_str, Cardinality::Max),
+            (
+                well_known::owl_maxQualifiedCardinality_str,
+                Cardinality::Max,
+            ),
+            (well_known::owl_cardinality_str, Cardinality::Exact),
+            (well_known::owl_qualifiedCardinality_str, Cardinality::Exact),
+        ]
+        .into_iter()
+        .find_map(|(predicate, kind)| match self.object(predicate) {
+            Some(Value::Literal { lexical_form, .. }) => {
+                lexical_form.parse::<u64>().ok().map(|n| (kind, n))
+            }
+            _ => None,
+        })
+    }
 }
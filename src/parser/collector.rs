@@ -1,10 +1,20 @@
-use std::{borrow::Cow, collections::HashMap};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    convert::TryInto,
+};
 
-use harriet::triple_production::RdfBlankNode;
+use harriet::triple_production::{RdfBlankNode, RdfObject, RdfPredicate, RdfSubject, RdfTriple};
 
-use crate::{api::Ontology, error::Error, owl::*, parser::matcher::Value};
+use crate::{
+    api::Ontology,
+    error::{Error, ErrorKind},
+    owl::*,
+    parser::matcher::Value,
+    rdf,
+};
 
-use super::{matcher::MatcherState, IndexedParserOptions};
+use super::{annotations::WELL_KNOWN_ANNOTATIONS, matcher::MatcherState, IndexedParserOptions};
 
 /// Handle when a matcher matched. Returns whether the matched rules where actually
 pub(crate) type MatcherHandler<'a> = Box<
@@ -17,7 +27,6 @@ pub(crate) type MatcherHandler<'a> = Box<
 
 #[derive(Debug, Clone)]
 pub(crate) enum CollectedBlankNode<'a> {
-    ClassConstructor(Box<ClassConstructor>),
     Sequence {
         first: Option<Value<'a>>,
         rest: Option<RdfBlankNode>,
@@ -31,19 +40,205 @@ pub(crate) enum CollectedReificationKey<'a> {
 }
 
 impl<'a> CollectedReificationKey<'a> {
-    pub(crate) fn into_resource_id(self) -> ResourceId {
-        match self {
+    pub(crate) fn into_resource_id(self) -> Result<ResourceId, Error> {
+        Ok(match self {
             CollectedReificationKey::Bn(bn) => ResourceId::BlankNode(BlankNode::from(bn)),
-            CollectedReificationKey::Iri(iri) => ResourceId::IRI(IRI::new(iri.as_ref()).unwrap())
-        }
+            CollectedReificationKey::Iri(iri) => ResourceId::IRI(IRI::new(iri.as_ref())?),
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A reification as stated, i.e. before its source and target are resolved.
+#[derive(Debug, Clone)]
 pub(crate) struct CollectedReification<'a> {
-    pub(crate) subject: ResourceId,
+    pub(crate) subject: Value<'a>,
     pub(crate) predicate: Cow<'a, str>,
-    pub(crate) object: Cow<'a, str>,
+    pub(crate) object: Value<'a>,
+}
+
+/// Subject or object of the triple which states an axiom. Literals and anonymous class
+/// expressions are identified by their complete value, so e.g. the datatype and language of
+/// a literal or the structure of a class expression are taken into account. Lists are
+/// identified by their members.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum AxiomNode {
+    Resource(ResourceId),
+    Value(String),
+    List(Vec<AxiomNode>),
+}
+
+impl From<ResourceId> for AxiomNode {
+    fn from(value: ResourceId) -> Self {
+        Self::Resource(value)
+    }
+}
+
+impl From<&IRI> for AxiomNode {
+    fn from(value: &IRI) -> Self {
+        Self::Resource(value.clone().into())
+    }
+}
+
+impl From<&Literal> for AxiomNode {
+    fn from(value: &Literal) -> Self {
+        Self::Value(format!("{:?}", value))
+    }
+}
+
+impl From<&LiteralOrIRI> for AxiomNode {
+    fn from(value: &LiteralOrIRI) -> Self {
+        match value {
+            LiteralOrIRI::IRI(iri) => iri.into(),
+            LiteralOrIRI::Literal(literal) => literal.into(),
+        }
+    }
+}
+
+impl From<&ClassConstructor> for AxiomNode {
+    fn from(value: &ClassConstructor) -> Self {
+        match value {
+            ClassConstructor::IRI(iri) => iri.as_iri().into(),
+            cls => Self::Value(format!("{:?}", cls)),
+        }
+    }
+}
+
+impl From<&DatatypeDefinitionConstructor> for AxiomNode {
+    fn from(value: &DatatypeDefinitionConstructor) -> Self {
+        match value {
+            DatatypeDefinitionConstructor::DatatypeRestriction(r) if r.restrictions.is_empty() => {
+                r.datatype_iri.as_iri().into()
+            }
+            range => Self::Value(format!("{:?}", range)),
+        }
+    }
+}
+
+/// Types of blank nodes which stand for an axiom themselves. Unlike reifications, axioms don't
+/// keep these nodes as resource ids.
+const AXIOM_NODE_TYPES: [&str; 4] = [
+    well_known::owl_NegativePropertyAssertion_str,
+    well_known::owl_AllDifferent_str,
+    well_known::owl_AllDisjointClasses_str,
+    well_known::owl_AllDisjointProperties_str,
+];
+
+/// The triple which states an axiom. Reifications refer to axioms by this triple.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct AxiomTriple {
+    pub(crate) subject: AxiomNode,
+    pub(crate) predicate: String,
+    pub(crate) object: AxiomNode,
+}
+
+impl AxiomTriple {
+    pub(crate) fn new<S: Into<AxiomNode>, O: Into<AxiomNode>>(
+        subject: S,
+        predicate: &str,
+        object: O,
+    ) -> Self {
+        Self {
+            subject: subject.into(),
+            predicate: predicate.into(),
+            object: object.into(),
+        }
+    }
+
+    /// The triple stating the given axiom, if the axiom can be reified.
+    fn of(axiom: &Axiom) -> Option<Self> {
+        match axiom {
+            Axiom::SubClassOf(sco) => Some(Self::new(
+                sco.cls.as_ref(),
+                well_known::rdfs_subClassOf_str,
+                sco.parent_class.as_ref(),
+            )),
+            Axiom::EquivalentClasses(ec) => Some(Self::new(
+                ec.class_iri.as_iri(),
+                well_known::owl_equivalentClass_str,
+                ec.cls.as_ref(),
+            )),
+            Axiom::ClassAssertion(ca) => Some(Self::new(
+                ca.individual.clone(),
+                well_known::rdf_type_str,
+                &ca.cls,
+            )),
+            Axiom::ObjectPropertyDomain(opd) => Some(Self::new(
+                opd.iri.as_iri(),
+                well_known::rdfs_domain_str,
+                &opd.cls,
+            )),
+            Axiom::ObjectPropertyRange(opr) => Some(Self::new(
+                opr.iri.as_iri(),
+                well_known::rdfs_range_str,
+                &opr.cls,
+            )),
+            Axiom::DatatypeDefinition(dd) => Some(Self::new(
+                dd.data_property_iri.as_iri(),
+                well_known::owl_equivalentClass_str,
+                &dd.datatype,
+            )),
+            Axiom::HasKey(hk) => Some(Self::new(
+                &hk.cls,
+                well_known::owl_hasKey_str,
+                AxiomNode::List(
+                    hk.object_properties
+                        .iter()
+                        .map(|p| p.as_iri().into())
+                        .chain(hk.data_properties.iter().map(|p| p.as_iri().into()))
+                        .collect(),
+                ),
+            )),
+            Axiom::DataPropertyDomain(dpd) => Some(Self::new(
+                dpd.iri.as_iri(),
+                well_known::rdfs_domain_str,
+                &dpd.cls,
+            )),
+            Axiom::AnnotationAssertion(ann) => Some(Self::new(
+                ann.subject.clone(),
+                ann.iri.as_iri().as_str(),
+                &ann.value,
+            )),
+            Axiom::DataPropertyAssertion(dpa) => Some(Self::new(
+                dpa.subject.clone(),
+                dpa.iri.as_iri().as_str(),
+                &dpa.value,
+            )),
+            Axiom::SameIndividual(si) => match si.individuals.as_slice() {
+                [i1, i2] => Some(Self::new(
+                    i1.clone(),
+                    well_known::owl_sameAs_str,
+                    i2.clone(),
+                )),
+                _ => None,
+            },
+            Axiom::DifferentIndividuals(di) => match di.individuals.as_slice() {
+                [i1, i2] => Some(Self::new(
+                    i1.clone(),
+                    well_known::owl_differentFrom_str,
+                    i2.clone(),
+                )),
+                _ => None,
+            },
+            Axiom::ObjectPropertyAssertion(opa) => match &opa.object {
+                IRIList::IRI(object) => Some(Self::new(
+                    opa.subject.clone(),
+                    opa.iri.as_iri().as_str(),
+                    object,
+                )),
+                IRIList::BlankNode(object) => Some(Self::new(
+                    opa.subject.clone(),
+                    opa.iri.as_iri().as_str(),
+                    ResourceId::from(object.clone()),
+                )),
+                // ObjectPropertyAssertions with List in object positions are not supported yet.
+                IRIList::List(_) => None,
+            },
+            _ => {
+                // TODO
+                None
+            }
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -51,22 +246,38 @@ pub(crate) struct OntologyCollector<'a> {
     iri: Option<IRI>,
     declarations: Vec<Declaration>,
     axioms: Vec<Axiom>,
+    // index of the triple which was mapped when the axiom was pushed, by axiom
+    axiom_positions: Vec<usize>,
+    // index of the triple which is currently mapped
+    position: usize,
 
+    // reifications which are resolved once all declarations are known
+    pending_reifications: Vec<(CollectedReificationKey<'a>, CollectedReification<'a>)>,
     // reified triples
-    reifications: HashMap<CollectedReificationKey<'a>, CollectedReification<'a>>,
+    reifications: HashMap<CollectedReificationKey<'a>, AxiomTriple>,
+    // subjects of the reified triples, as they are stated
+    reified_subjects: HashMap<CollectedReificationKey<'a>, Value<'a>>,
     // TODO: we probably don't need both. There may be a conceptional bug
-    reifications_rev: HashMap<CollectedReification<'a>, CollectedReificationKey<'a>>,
+    reifications_rev: HashMap<AxiomTriple, CollectedReificationKey<'a>>,
 
     // annotation definitions that were assigned to other things
     // (to handle multiple assertions for one annotation which is assigned to e.g. one data prop assertion)
     used_annotations: HashMap<String, Vec<usize>>,
     // During matching, an axiom may not have been parsed yet, but we already have found an annotation for it.
-    pub(crate) annotations_for_later: HashMap<(ResourceId, String, String), Vec<Annotation>>,
+    pub(crate) annotations_for_later: HashMap<AxiomTriple, Vec<Annotation>>,
+    // anonymous reifications of axioms which can't keep them as resource ids. Annotations on
+    // them are only kept by the reified axiom.
+    folded_reifications: HashSet<ResourceId>,
 
     pub blank_nodes: HashMap<RdfBlankNode, CollectedBlankNode<'a>>,
+    // (predicate, object) pairs of all triples with a blank node subject
+    blank_node_triples: HashMap<RdfBlankNode, Vec<(Cow<'a, str>, Value<'a>)>>,
 
-    axiom_index: HashMap<(ResourceId, String, String), usize>,
+    axiom_index: HashMap<AxiomTriple, usize>,
     declaration_index: HashMap<String, Vec<usize>>,
+
+    // triples which are not part of any OWL construct
+    unmapped: Vec<rdf::Triple>,
 }
 
 impl<'a> OntologyCollector<'a> {
@@ -76,6 +287,12 @@ impl<'a> OntologyCollector<'a> {
         }
     }
 
+    /// Sets the index of the triple which is mapped next. Axioms are written in the order of
+    /// the triples they were mapped from, independent of the order of mapping.
+    pub(crate) fn set_position(&mut self, triple_index: usize) {
+        self.position = triple_index;
+    }
+
     pub(crate) fn set_iri(&mut self, iri: IRI) {
         self.iri = Some(iri);
     }
@@ -117,62 +334,10 @@ impl<'a> OntologyCollector<'a> {
     }
 
     pub(crate) fn push_axiom(&mut self, mut axiom: Axiom) {
-        let axiom_triple: Option<(ResourceId, String, String)> = match &axiom {
-            Axiom::SubClassOf(sco) => {
-                if let ClassConstructor::IRI(subject) = sco.cls.as_ref() {
-                    if let ClassConstructor::IRI(parent) = sco.parent_class.as_ref() {
-                        Some((
-                            subject.as_iri().to_owned().into(),
-                            well_known::rdfs_subClassOf_str.to_string(),
-                            parent.as_iri().to_string(),
-                        ))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
-            }
-            Axiom::AnnotationAssertion(ann) => {
-                let sub = &ann.subject;
-                let iri = &ann.iri;
-                let val = &ann.value;
-                Some((sub.clone(), iri.to_string(), val.to_string()))
-            }
-            Axiom::DataPropertyAssertion(ann) => {
-                let sub = &ann.subject;
-                let iri = &ann.iri;
-                let val = &ann.value;
-                Some((
-                    sub.as_iri().to_owned().into(),
-                    iri.as_iri().to_string(),
-                    val.to_string(),
-                ))
-            }
-            Axiom::ObjectPropertyAssertion(assertion) => {
-                let sub = &assertion.subject;
-                let iri = &assertion.iri;
-                let val = &assertion.object;
-                match val {
-                    IRIList::IRI(val) => Some((
-                        sub.as_iri().to_owned().into(),
-                        iri.as_iri().to_string(),
-                        val.to_string(),
-                    )),
-                    IRIList::List(_) => {
-                        None
-                        // unimplemented!("ObjectPropertyAssertions with List in object positions are not supported yet.")
-                    }
-                }
-            }
-            _ => {
-                // TODO
-                None
-            }
-        };
+        let axiom_triple = AxiomTriple::of(&axiom);
 
         if let Some(ref axiom_triple) = axiom_triple {
-            if let Some(mut annotations_to_apply) = self.annotations_for_later.remove(&axiom_triple)
+            if let Some(mut annotations_to_apply) = self.annotations_for_later.remove(axiom_triple)
             {
                 axiom.annotations_mut().append(&mut annotations_to_apply);
             }
@@ -180,58 +345,35 @@ impl<'a> OntologyCollector<'a> {
                 .insert(axiom_triple.clone(), self.axioms.len());
         }
 
-        dbg!(&self);
         // Add resource_ids
-        if let Some(ref axiom_triple) = axiom_triple {
-            match &mut axiom {
-                Axiom::AnnotationAssertion(apa) => {
-                    if let Some(reification_id) = self
-                        .reification_on_triple(&CollectedReification {
-                            subject: axiom_triple.0.clone(),
-                            predicate: axiom_triple.1.as_str().into(),
-                            object: axiom_triple.2.as_str().into(),
-                        })
-                    {
-                        apa.resource_ids.push(reification_id.clone().into_resource_id());
-                    }
-                }
-                Axiom::DataPropertyAssertion(dpa) => {
-                    if let Some(reification_id) = self
-                        .reification_on_triple(&CollectedReification {
-                            subject: axiom_triple.0.clone(),
-                            predicate: axiom_triple.1.as_str().into(),
-                            object: axiom_triple.2.as_str().into(),
-                        })
-                    {
-                        dpa.resource_ids.push(reification_id.clone().into_resource_id());
-                    }
-                }
-                Axiom::ObjectPropertyAssertion(opa) => {
-                    if let Some(reification_id) = self
-                        .reification_on_triple(&CollectedReification {
-                            subject: axiom_triple.0.clone(),
-                            predicate: axiom_triple.1.as_str().into(),
-                            object: axiom_triple.2.as_str().into(),
-                        })
-                    {
-                        opa.resource_ids.push(reification_id.clone().into_resource_id());
+        if let Some(reification_id) = axiom_triple
+            .as_ref()
+            .and_then(|axiom_triple| self.reification_on_triple(axiom_triple))
+        {
+            // Reification IRIs are validated when they are collected.
+            if let Ok(reification_id) = reification_id.clone().into_resource_id() {
+                match &mut axiom {
+                    Axiom::AnnotationAssertion(apa) => apa.resource_ids.push(reification_id),
+                    Axiom::DataPropertyAssertion(dpa) => dpa.resource_ids.push(reification_id),
+                    Axiom::ObjectPropertyAssertion(opa) => opa.resource_ids.push(reification_id),
+                    _ if reification_id.is_blank_node() => {
+                        self.folded_reifications.insert(reification_id);
                     }
+                    _ => {}
                 }
-                _ => { }
             }
         }
 
         self.axioms.push(axiom);
+        self.axiom_positions.push(self.position);
     }
 
     pub(crate) fn get_from_axiom_index_mut(
         &mut self,
-        s: &ResourceId,
-        p: &str,
-        o: &str,
+        axiom_triple: &AxiomTriple,
     ) -> Option<(&mut Axiom, usize)> {
         self.axiom_index
-            .get(&(s.clone(), p.into(), o.into()))
+            .get(axiom_triple)
             .and_then(|index| self.axioms.get_mut(*index).map(|a| (a, *index)))
     }
 
@@ -239,40 +381,228 @@ impl<'a> OntologyCollector<'a> {
         self.blank_nodes.insert(bn, bnh);
     }
 
+    pub(crate) fn insert_blank_node_triple(
+        &mut self,
+        bn: RdfBlankNode,
+        predicate: Cow<'a, str>,
+        object: Value<'a>,
+    ) {
+        self.blank_node_triples
+            .entry(bn)
+            .or_default()
+            .push((predicate, object));
+    }
+
+    pub(crate) fn blank_node_triples(&self, bn: &RdfBlankNode) -> &[(Cow<'a, str>, Value<'a>)] {
+        self.blank_node_triples
+            .get(bn)
+            .map(|triples| triples.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Whether the blank node stands for an axiom, like the node of an owl:AllDifferent axiom.
+    /// Annotations on such nodes are annotations of the axiom.
+    pub(crate) fn is_axiom_node(&self, bn: &RdfBlankNode) -> bool {
+        self.blank_node_triples(bn)
+            .iter()
+            .any(|(predicate, object)| {
+                predicate == well_known::rdf_type_str
+                    && matches!(object, Value::Iri(t) if AXIOM_NODE_TYPES.contains(&t.as_ref()))
+            })
+    }
+
+    /// Annotations which are stated on a blank node directly, like on the node of an
+    /// owl:AllDifferent axiom.
+    pub(crate) fn blank_node_annotations(
+        &self,
+        bn: &RdfBlankNode,
+        options: &IndexedParserOptions,
+    ) -> Result<Vec<Annotation>, Error> {
+        let mut annotations = Vec::new();
+        for (predicate, object) in self.blank_node_triples(bn) {
+            let predicate = IRI::new(predicate)?;
+            if !(self.annotation_property_declaration(&predicate).is_some()
+                || options.is_annotation_prop(&predicate)
+                || WELL_KNOWN_ANNOTATIONS.contains(&predicate.as_str()))
+            {
+                continue;
+            }
+            let value = match object {
+                Value::Iri(iri) => LiteralOrIRI::IRI(IRI::new(iri)?),
+                Value::Literal { .. } => match object.clone().try_into() {
+                    Ok(literal) => LiteralOrIRI::Literal(literal),
+                    Err(_) => continue,
+                },
+                Value::Blank(_) => continue,
+            };
+            annotations.push(Annotation::new(predicate.into(), value, vec![]));
+        }
+        Ok(annotations)
+    }
+
     pub(crate) fn insert_reification(
         &mut self,
         key: CollectedReificationKey<'a>,
         value: CollectedReification<'a>,
     ) {
-        self.reifications.insert(key.clone(), value.clone());
-        self.reifications_rev.insert(value, key);
+        self.pending_reifications.push((key, value));
     }
 
-    pub(crate) fn reification(
+    /// Resolves the source and target of all collected reifications. Anonymous class
+    /// expressions can only be built once all declarations are known.
+    pub(crate) fn resolve_reifications(
+        &mut self,
+        options: &IndexedParserOptions,
+    ) -> Result<(), Error> {
+        for (key, reification) in std::mem::take(&mut self.pending_reifications) {
+            self.reified_subjects
+                .insert(key.clone(), reification.subject.clone());
+            let (Some(subject), Some(object)) = (
+                self.axiom_node(&reification.subject, options)?,
+                self.axiom_node(&reification.object, options)?,
+            ) else {
+                continue;
+            };
+            let axiom_triple = AxiomTriple::new(subject, &reification.predicate, object);
+            self.reifications.insert(key.clone(), axiom_triple.clone());
+            self.reifications_rev.insert(axiom_triple, key);
+        }
+        Ok(())
+    }
+
+    /// Subject or object of an axiom triple, as it is referenced by a reification.
+    fn axiom_node(
         &self,
-        ann: CollectedReificationKey<'a>,
-    ) -> Option<&CollectedReification<'a>> {
+        value: &Value<'a>,
+        options: &IndexedParserOptions,
+    ) -> Result<Option<AxiomNode>, Error> {
+        Ok(match value {
+            Value::Iri(iri) => Some((&IRI::new(iri)?).into()),
+            Value::Literal { .. } => value
+                .clone()
+                .try_into()
+                .ok()
+                .map(|literal: Literal| (&literal).into()),
+            Value::Blank(bn) => match self.get_sequence(bn)? {
+                Some(seq) => {
+                    let mut members = Vec::new();
+                    for member in &seq {
+                        let Some(member) = self.axiom_node(member, options)? else {
+                            return Ok(None);
+                        };
+                        members.push(member);
+                    }
+                    Some(AxiomNode::List(members))
+                }
+                None => Some(match self.class_constructor(bn, options)? {
+                    Some(cls) => (&cls).into(),
+                    None => match self.data_range(value)? {
+                        Some(range) => (&range).into(),
+                        None => ResourceId::from(BlankNode::from(bn.clone())).into(),
+                    },
+                }),
+            },
+        })
+    }
+
+    pub(crate) fn reification(&self, ann: CollectedReificationKey<'a>) -> Option<&AxiomTriple> {
         self.reifications.get(&ann)
     }
 
+    /// How many reifications the statements about the subject depend on. Statements about a
+    /// reification annotate the reified axiom, which may be stated by a reification itself.
+    pub(crate) fn reification_depth(&self, subject: &RdfSubject<'a>) -> usize {
+        let mut key = match subject {
+            RdfSubject::IRI(iri) => CollectedReificationKey::Iri(iri.iri.clone()),
+            RdfSubject::BlankNode(bn) => CollectedReificationKey::Bn(bn.clone()),
+        };
+        let mut visited = HashSet::new();
+        while let Some(source) = self.reified_subjects.get(&key) {
+            if !visited.insert(key.clone()) {
+                break;
+            }
+            key = match source {
+                Value::Iri(iri) => CollectedReificationKey::Iri(iri.clone()),
+                Value::Blank(bn) => CollectedReificationKey::Bn(bn.clone()),
+                Value::Literal { .. } => break,
+            };
+        }
+        visited.len()
+    }
+
     pub(crate) fn reification_on_triple(
         &self,
-        ann: &CollectedReification<'a>,
+        axiom_triple: &AxiomTriple,
     ) -> Option<&CollectedReificationKey<'a>> {
-        self.reifications_rev.get(ann)
+        self.reifications_rev.get(axiom_triple)
+    }
+
+    /// Keeps a triple which was not consumed by any matcher. Triples with invalid IRIs are
+    /// dropped (they are reported as diagnostics anyway).
+    pub(crate) fn push_unmapped_triple(&mut self, triple: &RdfTriple) {
+        let subject = match &triple.subject {
+            RdfSubject::IRI(iri) => match IRI::new(&iri.iri) {
+                Ok(iri) => ResourceId::IRI(iri),
+                Err(_) => return,
+            },
+            RdfSubject::BlankNode(bn) => ResourceId::BlankNode(bn.clone().into()),
+        };
+        let RdfPredicate::IRI(predicate) = &triple.predicate;
+        let Ok(predicate) = IRI::new(&predicate.iri) else {
+            return;
+        };
+        let object: rdf::Term = match &triple.object {
+            RdfObject::IRI(iri) => match IRI::new(&iri.iri) {
+                Ok(iri) => iri.into(),
+                Err(_) => return,
+            },
+            RdfObject::BlankNode(bn) => BlankNode::from(bn.clone()).into(),
+            RdfObject::Literal(literal) => {
+                // The lexical form is kept as it is, e.g. "1.50"^^xsd:decimal is not normalized.
+                let lexical_form = literal.lexical_form.to_string();
+                match (&literal.language_tag, &literal.datatype_iri) {
+                    (Some(lang), _) => rdf::Literal::lang_string(lexical_form, lang.as_ref()),
+                    (None, Some(datatype)) => match IRI::new(&datatype.iri) {
+                        Ok(iri) => rdf::Literal::typed(lexical_form, iri),
+                        Err(_) => return,
+                    },
+                    (None, None) => rdf::Literal::string(lexical_form),
+                }
+                .into()
+            }
+        };
+        self.unmapped
+            .push(rdf::Triple::new(subject, predicate, object));
     }
 
-    pub(crate) fn ontology(self) -> Ontology {
-        let mut o = Ontology::new(self.iri.unwrap());
+    pub(crate) fn ontology(self) -> Result<Ontology, Error> {
+        let Some(iri) = self.iri else {
+            return Err(Error::with_kind(
+                ErrorKind::MissingOntologyIri,
+                "No resource is declared as owl:Ontology".into(),
+            ));
+        };
+        let mut o = Ontology::new(iri);
 
         for d in self.declarations {
             o.push_declaration(d)
         }
-        for a in self.axioms {
+        let mut axioms: Vec<(usize, Axiom)> =
+            self.axiom_positions.into_iter().zip(self.axioms).collect();
+        axioms.sort_by_key(|(position, _)| *position);
+        for (_, a) in axioms {
+            if let Axiom::AnnotationAssertion(aa) = &a {
+                if self.folded_reifications.contains(&aa.subject) {
+                    continue;
+                }
+            }
             o.push_axiom(a);
         }
+        for t in self.unmapped {
+            o.push_unmapped_triple(t);
+        }
 
-        o
+        Ok(o)
     }
 
     // pub(crate) fn set_sequence_root(&mut self, root: &RdfBlankNode, value: Value<'a>) {
@@ -413,14 +743,41 @@ impl<'a> OntologyCollector<'a> {
             })
     }
 
-    pub(crate) fn get_sequence(&self, bn: &RdfBlankNode) -> Option<Vec<Value<'a>>> {
+    pub(crate) fn datatype_declaration(&self, iri: &IRI) -> Option<&Declaration> {
+        self.declaration_index
+            .get(iri.as_str())
+            .map(|indexes| {
+                indexes
+                    .iter()
+                    .filter_map(|i| self.declarations.get(*i))
+                    .collect::<Vec<&Declaration>>()
+            })
+            .and_then(|ds| {
+                for d in ds {
+                    if let Declaration::Datatype { .. } = d {
+                        return Some(d);
+                    }
+                }
+                None
+            })
+    }
+
+    /// Members of the RDF list starting at the blank node, `None` if the blank node is no list.
+    pub(crate) fn get_sequence(&self, bn: &RdfBlankNode) -> Result<Option<Vec<Value<'a>>>, Error> {
         if let Some(CollectedBlankNode::Sequence { first, rest }) = self.blank_nodes.get(bn) {
             let mut values: Vec<Value<'a>> = Vec::new();
             if let Some(value) = first {
                 values.push(value.clone());
             }
+            let mut visited = HashSet::from([bn]);
             let mut the_rest = rest;
             while let Some(r) = the_rest {
+                if !visited.insert(r) {
+                    return Err(Error::with_kind(
+                        ErrorKind::CyclicBlankNode,
+                        format!("Sequence references itself: {:?}", bn),
+                    ));
+                }
                 the_rest = &None;
                 if let Some(CollectedBlankNode::Sequence { first, rest }) = self.blank_nodes.get(r)
                 {
@@ -430,9 +787,9 @@ impl<'a> OntologyCollector<'a> {
                     the_rest = rest;
                 }
             }
-            Some(values)
+            Ok(Some(values))
         } else {
-            None
+            Ok(None)
         }
     }
 
@@ -467,6 +824,25 @@ impl<'a> OntologyCollector<'a> {
     }
 }
 
+/// A named or an anonymous individual. Blank nodes keep their identity within the document.
+pub(crate) fn individual(value: &Value) -> Result<Option<ResourceId>, Error> {
+    match value {
+        Value::Iri(iri) => Ok(Some(IRI::new(iri)?.into())),
+        Value::Blank(bn) => Ok(Some(BlankNode::from(bn.clone()).into())),
+        Value::Literal { .. } => Ok(None),
+    }
+}
+
+pub(crate) fn get_individual_var(
+    name: &str,
+    mstate: &MatcherState,
+) -> Result<Option<ResourceId>, Error> {
+    match mstate.get(name) {
+        Some(var) => individual(var),
+        None => Ok(None),
+    }
+}
+
 pub(crate) fn get_iri_var(name: &str, mstate: &MatcherState) -> Result<Option<IRI>, Error> {
     if let Some(var) = mstate.get(name) {
         match var {
@@ -489,29 +865,18 @@ macro_rules! get_vars {
         $mstate:ident,
         $($($variable:ident)+$(,)?)+
     ) => {{
-        let mut result = true;
-        $(
-            let $($variable)+ = $mstate.get(stringify!($($variable)+));
-            if $($variable)+.is_none() {
-                result = false;
-            }
-            let $($variable)+ = $($variable)+.unwrap();
-        )+
         struct Vars<'a> {
             $(
                 $($variable)+: &'a Value<'a>,
             )+
         }
-        if result {
-            // Some((
-                // ))
-            Some(Vars {
+        match ($($mstate.get(stringify!($($variable)+)),)+) {
+            ($(Some($($variable)+),)+) => Some(Vars {
                 $(
                     $($variable)+,
                 )+
-            })
-        } else {
-            None
+            }),
+            _ => None,
         }
     }};
 }
@@ -1,8 +1,8 @@
 use std::{collections::HashMap, convert::TryInto};
 
 use crate::{
-    error::Error,
-    owl::{Annotation, DataPropertyAssertion, Literal, IRI},
+    error::{Error, ErrorKind},
+    owl::{Annotation, BlankNode, DataPropertyAssertion, Literal, IRI},
     parser::matcher::{RdfMatcher, Value},
     rdf_match,
 };
@@ -37,7 +37,7 @@ pub(crate) fn match_simple_dataprop_assertions<'a>(
                                 );
                             }
                             Value::Blank(_subject_bn) => {}
-                            Value::Literal { .. } => unreachable!(),
+                            Value::Literal { .. } => {}
                         }
                     }
                 }
@@ -59,27 +59,36 @@ pub(crate) fn match_dataprop_assertions<'a>(
             [iob:subject] [*:predicate] [lt:object] .)?,
         Box::new(|mstate, o, options| {
             if let Some(obj) = mstate.get("object") {
-                let value: Literal = match obj.clone().try_into() {
-                    Ok(l) => l,
-                    Err(_) => unreachable!(),
-                };
                 if let Some(predicate_iri) = get_iri_var("predicate", mstate)? {
                     if o.data_property_declaration(&predicate_iri).is_some()
                         || options.is_data_prop(&predicate_iri)
                     {
-                        if let Some(subject) = mstate.get("subject") {
-                            match subject {
-                                Value::Iri(_subject_iri) => {}
-                                Value::Blank(subject_bn) => {
-                                    return handle_dataprop_on_bn(
-                                        o,
-                                        subject_bn.clone(),
-                                        predicate_iri,
-                                        value,
-                                    );
-                                }
-                                Value::Literal { .. } => unreachable!(),
+                        let Ok(value) = TryInto::<Literal>::try_into(obj.clone()) else {
+                            return Ok(false);
+                        };
+                        if let Some(Value::Blank(subject_bn)) = mstate.get("subject") {
+                            if o.reification(CollectedReificationKey::Bn(subject_bn.clone()))
+                                .is_some()
+                            {
+                                return handle_dataprop_on_bn(
+                                    o,
+                                    subject_bn.clone(),
+                                    predicate_iri,
+                                    value,
+                                );
                             }
+                            // any other blank node is an anonymous individual
+                            o.push_axiom(
+                                DataPropertyAssertion::new(
+                                    predicate_iri.into(),
+                                    BlankNode::from(subject_bn.clone()).into(),
+                                    value,
+                                    vec![],
+                                    vec![],
+                                )
+                                .into(),
+                            );
+                            return Ok(true);
                         }
                     }
                 }
@@ -97,24 +106,21 @@ pub(crate) fn handle_dataprop_on_bn(
     predicate_iri: IRI,
     value: Literal,
 ) -> Result<bool, Error> {
-    let annotate = o
+    let Some(annotate) = o
         .reification(CollectedReificationKey::Bn(subject_bn))
-        .cloned();
-    if annotate.is_none() {
+        .cloned()
+    else {
         return Ok(false);
-    }
-    let annotate = annotate.unwrap();
-    let subject = annotate.subject;
-    let predicate = annotate.predicate;
-    let object = annotate.object;
+    };
 
-    if let Some((axiom, _)) = o.get_from_axiom_index_mut(&subject, &predicate, &object) {
-        axiom
-            .annotations_mut()
-            .push(Annotation::new(predicate_iri.into(), value.into(), vec![]))
-    }
+    let Some((axiom, _)) = o.get_from_axiom_index_mut(&annotate) else {
+        return Ok(false);
+    };
+    axiom
+        .annotations_mut()
+        .push(Annotation::new(predicate_iri.into(), value.into(), vec![]));
 
-    Ok(false)
+    Ok(true)
 }
 
 fn push_dataprop_assertion(
@@ -151,7 +157,15 @@ fn push_dataprop_assertion(
                 }
                 return Ok(false);
             }
-            Value::Blank(_) => todo!(),
+            Value::Blank(_) => {
+                return Err(Error::with_kind(
+                    ErrorKind::UnsupportedConstruct,
+                    format!(
+                        "Anonymous data property values are not supported ({})",
+                        predicate_iri
+                    ),
+                ))
+            }
         }
     }
     Ok(false)
@@ -0,0 +1,164 @@
+use std::collections::{HashMap, HashSet};
+
+use harriet::triple_production::{RdfObject, RdfPredicate, RdfSubject, RdfTriple};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{Error, Position},
+    owl::{well_known, BlankNode, IRI},
+};
+
+use super::{
+    collector::OntologyCollector, matcher::display, source::SourceMap, IndexedParserOptions,
+};
+
+/// The category of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DiagnosticKind {
+    /// A triple that was not mapped to any OWL construct.
+    UnconsumedTriple,
+    /// A blank node whose triples could not be resolved to an OWL construct.
+    UnresolvedBlankNode,
+    /// A triple whose predicate is not known as object, data or annotation property.
+    UnknownPropertyKind,
+    /// A construct that was skipped because of an error (lenient mode only).
+    SkippedConstruct,
+}
+
+/// Something the parser could not understand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    pub message: String,
+    pub triple: Option<String>,
+    pub position: Option<Position>,
+}
+
+impl Diagnostic {
+    pub(crate) fn skipped(error: Error) -> Self {
+        Self {
+            kind: DiagnosticKind::SkippedConstruct,
+            message: format!("{}: {}", error.kind(), error.message()),
+            triple: error.triple().map(String::from),
+            position: error.position(),
+        }
+    }
+}
+
+/// Marks the triples which describe consumed blank nodes (e.g. restrictions or lists that were
+/// resolved as part of an axiom) as consumed as well.
+pub(crate) fn consume_blank_nodes(triples: &[&RdfTriple], consumed: &mut [bool]) {
+    let mut by_subject: HashMap<_, Vec<usize>> = HashMap::new();
+    for (i, triple) in triples.iter().enumerate() {
+        if let RdfSubject::BlankNode(bn) = &triple.subject {
+            by_subject.entry(bn).or_default().push(i);
+        }
+    }
+
+    let mut visited = HashSet::new();
+    let mut stack: Vec<_> = triples
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| consumed[*i])
+        .filter_map(|(_, t)| match &t.object {
+            RdfObject::BlankNode(bn) => Some(bn),
+            _ => None,
+        })
+        .collect();
+    while let Some(bn) = stack.pop() {
+        if !visited.insert(bn) {
+            continue;
+        }
+        for i in by_subject.get(bn).into_iter().flatten() {
+            let RdfPredicate::IRI(predicate) = &triples[*i].predicate;
+            // custom statements (and annotations) about a blank node are not part of the OWL
+            // construct, facets of datatype restrictions are
+            if !(predicate.iri.starts_with(well_known::owl_base_str)
+                || predicate.iri.starts_with(well_known::rdf_base_str)
+                || predicate.iri.starts_with(well_known::xsd_base_str))
+            {
+                continue;
+            }
+            consumed[*i] = true;
+            if let RdfObject::BlankNode(object) = &triples[*i].object {
+                stack.push(object);
+            }
+        }
+    }
+}
+
+/// Creates diagnostics for all triples which were not consumed.
+pub(crate) fn unconsumed(
+    triples: &[&RdfTriple],
+    consumed: &[bool],
+    collector: &OntologyCollector,
+    options: &IndexedParserOptions,
+    source_map: &SourceMap,
+) -> Vec<Diagnostic> {
+    let position = |i: usize| {
+        if source_map.covers(triples.len()) {
+            source_map.position(i)
+        } else {
+            None
+        }
+    };
+
+    let mut diagnostics = Vec::new();
+    let mut reported_blank_nodes = HashSet::new();
+    for (i, triple) in triples.iter().enumerate() {
+        if consumed[i] {
+            continue;
+        }
+        if let RdfSubject::BlankNode(bn) = &triple.subject {
+            if reported_blank_nodes.insert(bn) {
+                diagnostics.push(Diagnostic {
+                    kind: DiagnosticKind::UnresolvedBlankNode,
+                    message: format!(
+                        "Blank node {} could not be resolved",
+                        BlankNode::from(bn.clone())
+                    ),
+                    triple: Some(display(triple)),
+                    position: position(i),
+                });
+            }
+            continue;
+        }
+        let RdfPredicate::IRI(predicate) = &triple.predicate;
+        let known = is_reserved_vocabulary(&predicate.iri)
+            || IRI::new(&predicate.iri)
+                .map(|iri| {
+                    collector.object_property_declaration(&iri).is_some()
+                        || collector.data_property_declaration(&iri).is_some()
+                        || collector.annotation_property_declaration(&iri).is_some()
+                        || options.is_object_prop(&iri)
+                        || options.is_data_prop(&iri)
+                        || options.is_annotation_prop(&iri)
+                })
+                .unwrap_or(false);
+        diagnostics.push(if known {
+            Diagnostic {
+                kind: DiagnosticKind::UnconsumedTriple,
+                message: "Triple could not be mapped to OWL".into(),
+                triple: Some(display(triple)),
+                position: position(i),
+            }
+        } else {
+            Diagnostic {
+                kind: DiagnosticKind::UnknownPropertyKind,
+                message: format!(
+                    "{} is not declared as object, data or annotation property",
+                    predicate.iri
+                ),
+                triple: Some(display(triple)),
+                position: position(i),
+            }
+        });
+    }
+    diagnostics
+}
+
+fn is_reserved_vocabulary(iri: &str) -> bool {
+    iri.starts_with(well_known::owl_base_str)
+        || iri.starts_with(well_known::rdf_base_str)
+        || iri.starts_with(well_known::rdfs_base_str)
+}
@@ -0,0 +1,26 @@
+//! Maps the triples of an [`rdf::Graph`](crate::rdf::Graph) to OWL, e.g. triples of another
+//! parser or the results of a SPARQL query.
+
+use std::collections::HashMap;
+
+use crate::{api::Ontology, error::Error, rdf::Graph};
+
+use super::{source::SourceMap, Diagnostic, ParserOptions};
+
+impl Ontology {
+    /// Builds an ontology from the triples of a graph.
+    pub fn from_graph(graph: &Graph, options: ParserOptions) -> Result<Self, Error> {
+        Self::from_graph_with_diagnostics(graph, options).map(|(ontology, _)| ontology)
+    }
+
+    /// Builds an ontology from the triples of a graph and reports everything that could not be
+    /// understood (see [`Ontology::parse_with_diagnostics`]).
+    pub fn from_graph_with_diagnostics(
+        graph: &Graph,
+        options: ParserOptions,
+    ) -> Result<(Self, Vec<Diagnostic>), Error> {
+        let triples: Vec<_> = graph.iter().map(|t| t.to_rdf()).collect();
+        let source_map = SourceMap::for_triples(vec![None; triples.len()]);
+        Ontology::from_triples(triples, HashMap::new(), &source_map, options)
+    }
+}
@@ -1,7 +1,7 @@
 use std::{
     borrow::Cow,
     collections::{HashMap, LinkedList},
-    convert::TryInto,
+    convert::{TryFrom, TryInto},
     hash::Hash,
     rc::Rc,
 };
@@ -9,7 +9,7 @@ use std::str::FromStr;
 use harriet::triple_production::{RdfBlankNode, RdfLiteral, RdfObject, RdfSubject, RdfTriple};
 use oxsdatatypes::{DayTimeDuration, Duration, YearMonthDuration};
 use crate::{
-    owl::{well_known, Literal, LiteralOrIRI, IRI},
+    owl::{well_known, Lang, Literal, LiteralOrIRI, IRI},
     parser_debug,
 };
 
@@ -32,9 +32,14 @@ impl<'a> TryInto<Literal> for Value<'a> {
             Value::Literal {
                 lexical_form,
                 datatype_iri,
-                language_tag: _,
+                language_tag,
             } => {
-                if let Some(datatype_iri) = datatype_iri {
+                if let Some(lang) = language_tag.and_then(|tag| Lang::try_from(tag.as_ref()).ok()) {
+                    Ok(Literal::LangString {
+                        string: lexical_form.to_string(),
+                        lang,
+                    })
+                } else if let Some(datatype_iri) = datatype_iri {
                     if datatype_iri == well_known::xsd_string_str {
                         Ok(Literal::String(lexical_form.to_string()))
                     } else if datatype_iri == well_known::xsd_integer_str {
@@ -137,6 +142,16 @@ impl<'a> From<RdfLiteral<'a>> for Value<'a> {
     }
 }
 
+impl<'a> From<RdfObject<'a>> for Value<'a> {
+    fn from(obj: RdfObject<'a>) -> Self {
+        match obj {
+            RdfObject::IRI(iri) => Value::Iri(iri.iri),
+            RdfObject::BlankNode(bn) => Value::Blank(bn),
+            RdfObject::Literal(lit) => lit.into(),
+        }
+    }
+}
+
 impl<'a> std::fmt::Display for Value<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -733,6 +748,44 @@ macro_rules! triple_match {
     }};
 }
 
+/// The base IRI and the prefixes of a turtle document.
+#[derive(Debug, Default)]
+pub struct Directives {
+    pub base: Option<String>,
+    /// in the order they are declared, redeclared prefixes keep their position
+    pub prefixes: Vec<(String, String)>,
+}
+
+pub fn get_directives(ttl: &harriet::TurtleDocument) -> Directives {
+    let mut directives = Directives::default();
+
+    for s in &ttl.statements {
+        let harriet::Statement::Directive(d) = s else {
+            continue;
+        };
+        let (prefix, iri) = match d {
+            harriet::Directive::Base(base) => {
+                directives.base.get_or_insert(base.iri.iri.to_string());
+                continue;
+            }
+            harriet::Directive::SparqlBase(base) => {
+                directives.base.get_or_insert(base.iri.iri.to_string());
+                continue;
+            }
+            harriet::Directive::Prefix(prefix) => (&prefix.prefix, &prefix.iri),
+            harriet::Directive::SparqlPrefix(prefix) => (&prefix.prefix, &prefix.iri),
+        };
+        let prefix = prefix.as_deref().unwrap_or_default();
+        match directives.prefixes.iter_mut().find(|(p, _)| p == prefix) {
+            Some((_, existing)) => *existing = iri.iri.to_string(),
+            None => directives
+                .prefixes
+                .push((prefix.to_string(), iri.iri.to_string())),
+        }
+    }
+    directives
+}
+
 pub fn get_prefixes(ttl: harriet::TurtleDocument) -> HashMap<String, String> {
     let mut prefixes: HashMap<String, String> = HashMap::new();
 
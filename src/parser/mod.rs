@@ -1,6 +1,6 @@
 use std::{collections::HashMap, rc::Rc};
 
-use harriet::triple_production::RdfTriple;
+use harriet::triple_production::{RdfPredicate, RdfSubject, RdfTriple};
 
 use log::debug;
 use serde::{Deserialize, Serialize};
@@ -12,10 +12,10 @@ use crate::{
     parser::matcher::{display, print, IRIOrBlank, MatchResult, RdfMatcher},
 };
 
-use self::matcher::{get_prefixes, MatcherState};
+use self::matcher::{get_directives, get_prefixes, MatcherState};
 
 mod collector;
-mod matcher;
+pub(crate) mod matcher;
 use collector::*;
 
 mod annotations;
@@ -23,8 +23,13 @@ mod axioms;
 mod blank_nodes;
 mod data_props;
 mod declarations;
+mod diagnostics;
+pub use diagnostics::{Diagnostic, DiagnosticKind};
+mod graph;
 mod object_property_assertions;
 mod sequences;
+pub(crate) mod source;
+use source::SourceMap;
 pub mod triple;
 
 static mut RDF_MATCHER: Option<Option<String>> = None;
@@ -49,20 +54,82 @@ macro_rules! parser_debug {
 
 impl Ontology {
     pub fn parse(ttl: &str, options: ParserOptions) -> Result<Self, Error> {
+        Self::parse_with_diagnostics(ttl, options).map(|(ontology, _)| ontology)
+    }
+
+    /// Parses the ontology and reports everything that could not be understood.
+    ///
+    /// With [`ParserOptionsBuilder::lenient`] errors in single constructs are reported as
+    /// diagnostics instead of aborting the parse.
+    pub fn parse_with_diagnostics(
+        ttl: &str,
+        options: ParserOptions,
+    ) -> Result<(Self, Vec<Diagnostic>), Error> {
+        let (ttl, source_map) = source::parse_document(ttl)?;
+        let triples = source::produce_triples(&ttl, &source_map)?;
+        let directives = get_directives(&ttl);
+        let comments = if options.comments {
+            source::subject_comments(&ttl, &triples, &source_map)
+        } else {
+            vec![]
+        };
+        let (mut ontology, diagnostics) =
+            Self::from_triples(triples, get_prefixes(ttl), &source_map, options)?;
+
+        // the layout of the document is reproduced by the turtle serialization
+        ontology.base = directives.base.and_then(|base| IRI::new(&base).ok());
+        for (name, iri) in directives.prefixes {
+            // prefixes relative to the base are not resolved
+            if let Ok(iri) = IRI::new(&iri) {
+                ontology.push_prefix(&name, iri);
+            }
+        }
+        ontology.comments = comments;
+        Ok((ontology, diagnostics))
+    }
+
+    /// Maps the triples of an RDF graph to OWL, independent of the syntax they were read from.
+    ///
+    /// The source map is used to attach positions to errors and diagnostics.
+    pub(crate) fn from_triples(
+        triples: Vec<RdfTriple>,
+        mut prefixes: HashMap<String, String>,
+        source_map: &SourceMap,
+        options: ParserOptions,
+    ) -> Result<(Self, Vec<Diagnostic>), Error> {
         let indexed_options: IndexedParserOptions = options.into();
-        let ttl =
-            harriet::TurtleDocument::parse_full(ttl).map_err(|e| Error::new(format!("{:?}", e)))?;
+        let triples: Vec<Rc<RdfTriple>> = triples.into_iter().map(Rc::new).collect();
+
+        // Attaches the triple and its position in the source to errors of the handlers.
+        let locate = |e: Error, index: usize, triple: &RdfTriple| {
+            let e = e.at_triple(display(triple));
+            match source_map.position(index) {
+                Some(position) if source_map.covers(triples.len()) => e.at_position(position),
+                _ => e,
+            }
+        };
 
-        let triples: Vec<Rc<RdfTriple>> =
-            harriet::triple_production::TripleProducer::produce_for_document(&ttl)
-                .map_err(|e| Error::new(format!("Failed to emit triples: {}", e)))?
-                .into_iter()
-                .map(Rc::new)
-                .collect();
+        let mut diagnostics = Vec::new();
+        // In lenient mode errors of single constructs don't abort the parse.
+        let handled = |result: Result<bool, Error>,
+                       index: usize,
+                       triple: &RdfTriple,
+                       diagnostics: &mut Vec<Diagnostic>| {
+            result.or_else(|e| {
+                let e = locate(e, index, triple);
+                if indexed_options.lenient {
+                    diagnostics.push(Diagnostic::skipped(e));
+                    Ok(false)
+                } else {
+                    Err(e)
+                }
+            })
+        };
+        // whether a triple was mapped to OWL by some matcher
+        let mut consumed = vec![false; triples.len()];
 
         let mut collector = OntologyCollector::new();
 
-        let mut prefixes = get_prefixes(ttl);
         // handle non-existing well known prefixes
         if !prefixes.contains_key("rdf") {
             prefixes.insert("rdf".into(), well_known::rdf_base_str.into());
@@ -80,35 +147,67 @@ impl Ontology {
         let mut matchers: Vec<(RdfMatcher, MatcherHandler)> = Vec::new();
 
         type MatcherID = usize;
-        type MatcherStateEntry<'a> = (MatcherID, Vec<Rc<RdfTriple<'a>>>, MatcherState<'a>, bool);
+        type TripleIndex = usize;
+        type MatcherStateEntry<'a> = (MatcherID, Vec<TripleIndex>, MatcherState<'a>, bool);
         // let mut finished_matches: Vec<(MatcherID, Vec<TripleID>, MatcherState)> = Vec::new();
         // subject node -> [matcher_id, matched_triples]
         let mut matcher_instances: HashMap<usize, MatcherStateEntry> = HashMap::new();
         // let mut matcher_instance_id = 0;
 
-        for phase in 0..3 {
+        // anonymous class expressions are resolved on demand from their triples
+        for triple in triples.iter() {
+            if let (RdfSubject::BlankNode(bn), RdfPredicate::IRI(predicate)) =
+                (&triple.subject, &triple.predicate)
+            {
+                collector.insert_blank_node_triple(
+                    bn.clone(),
+                    predicate.iri.clone(),
+                    triple.object.clone().into(),
+                );
+            }
+        }
+
+        // The mapping of triples depends on the declarations, lists and reifications, so those
+        // are collected first.
+        enum Stage {
+            Collect,
+            Map,
+        }
+        for stage in [Stage::Collect, Stage::Map] {
             matchers.clear();
             matcher_instances.clear();
-            match phase {
-                0 => {
+            let order: Vec<usize> = match stage {
+                Stage::Collect => {
                     declarations::match_declarations(&mut matchers, &prefixes)?;
                     sequences::match_sequences(&mut matchers, &prefixes)?;
                     annotations::match_reifications(&mut matchers, &prefixes)?;
+                    (0..triples.len()).collect()
                 }
-                1 => {
-                    blank_nodes::match_blank_nodes(&mut matchers, &prefixes)?;
+                Stage::Map => {
+                    if let Err(e) = collector.resolve_reifications(&indexed_options) {
+                        if !indexed_options.lenient {
+                            return Err(e);
+                        }
+                        diagnostics.push(Diagnostic::skipped(e));
+                    }
                     annotations::match_simple_annotation_assertions(&mut matchers, &prefixes)?;
                     data_props::match_simple_dataprop_assertions(&mut matchers, &prefixes)?;
-                }
-                _ => {
                     axioms::match_axioms(&mut matchers, &prefixes)?;
                     object_property_assertions::push(&mut matchers, &prefixes)?;
                     annotations::match_annotation_assertions(&mut matchers, &prefixes)?;
                     data_props::match_dataprop_assertions(&mut matchers, &prefixes)?;
+                    // Statements about a reification annotate the reified axiom, so they are
+                    // mapped after the statements they depend on. The sort is stable, the
+                    // triples of a subject stay together.
+                    let mut order: Vec<usize> = (0..triples.len()).collect();
+                    order.sort_by_cached_key(|i| collector.reification_depth(&triples[*i].subject));
+                    order
                 }
-            }
+            };
 
-            for triple in triples.iter() {
+            for index in order {
+                let triple = &triples[index];
+                collector.set_position(index);
                 let subject: IRIOrBlank = triple.subject.clone().into();
 
                 debug!("===============");
@@ -127,14 +226,19 @@ impl Ontology {
                         // (2) If matching already finished -> call handler and continue
                         if finished {
                             let (_m, handler) = &matchers[matcher_id];
-                            if !handler(&mut mstate, &mut collector, &indexed_options)? {
-                                // todo: did not meet semantic criteria
+                            if handled(
+                                handler(&mut mstate, &mut collector, &indexed_options),
+                                index,
+                                triple,
+                                &mut diagnostics,
+                            )? {
+                                consumed[index] = true;
                             }
                         } else {
                             parser_debug!(m, "Check for ongoing matchers",);
                             // (3) Check if there is an existing matcher instance
                             match matcher_instances.get_mut(&matcher_id) {
-                                Some((_, _, ongoing_mstate, _)) => {
+                                Some((_, matched_triples, ongoing_mstate, _)) => {
                                     parser_debug!(m, "{}", print(m, &mstate));
                                     let (matcher, handler) = &matchers[matcher_id];
                                     // (4) If that does match as well -> it's state is now extended (by the new match)
@@ -148,13 +252,21 @@ impl Ontology {
                                                 matcher.name(),
                                                 ongoing_mstate.vars()
                                             );
+                                            matched_triples.push(index);
                                             if finished {
-                                                if !handler(
-                                                    ongoing_mstate,
-                                                    &mut collector,
-                                                    &indexed_options,
+                                                if handled(
+                                                    handler(
+                                                        ongoing_mstate,
+                                                        &mut collector,
+                                                        &indexed_options,
+                                                    ),
+                                                    index,
+                                                    triple,
+                                                    &mut diagnostics,
                                                 )? {
-                                                    // todo: did not meet semantic criteria
+                                                    for i in matched_triples.iter() {
+                                                        consumed[*i] = true;
+                                                    }
                                                 }
                                                 matcher_instances.remove(&matcher_id);
                                             } else {
@@ -174,7 +286,7 @@ impl Ontology {
                                             );
                                             matcher_instances.insert(
                                                 matcher_id,
-                                                (matcher_id, vec![], mstate, finished),
+                                                (matcher_id, vec![index], mstate, finished),
                                             );
                                         }
                                     }
@@ -182,8 +294,10 @@ impl Ontology {
                                 None => {
                                     // (6) If there is no ongoing matcher, save a new one
                                     parser_debug!(m, "No ongoing matchers",);
-                                    matcher_instances
-                                        .insert(matcher_id, (matcher_id, vec![], mstate, finished));
+                                    matcher_instances.insert(
+                                        matcher_id,
+                                        (matcher_id, vec![index], mstate, finished),
+                                    );
                                 }
                             }
                         }
@@ -191,7 +305,25 @@ impl Ontology {
                 }
             }
         }
-        Ok(collector.ontology())
+
+        let triples: Vec<&RdfTriple> = triples.iter().map(|t| t.as_ref()).collect();
+        diagnostics::consume_blank_nodes(&triples, &mut consumed);
+        diagnostics.append(&mut diagnostics::unconsumed(
+            &triples,
+            &consumed,
+            &collector,
+            &indexed_options,
+            source_map,
+        ));
+        for (triple, _) in triples
+            .iter()
+            .zip(consumed)
+            .filter(|(_, consumed)| !consumed)
+        {
+            collector.push_unmapped_triple(triple);
+        }
+
+        Ok((collector.ontology()?, diagnostics))
     }
 }
 
@@ -204,6 +336,12 @@ impl From<Vec<String>> for Error {
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ParserOptions {
     known: Vec<Declaration>,
+    /// Report errors in single constructs as diagnostics instead of failing.
+    #[serde(default)]
+    pub(crate) lenient: bool,
+    /// Retain the comments in front of subjects.
+    #[serde(default)]
+    comments: bool,
 }
 
 impl ParserOptions {
@@ -218,6 +356,7 @@ impl ParserOptions {
 pub struct IndexedParserOptions {
     known: Vec<Declaration>,
     index: HashMap<IRI, usize>,
+    lenient: bool,
 }
 impl IndexedParserOptions {
     pub fn is_annotation_prop(&self, iri: &IRI) -> bool {
@@ -254,6 +393,14 @@ impl IndexedParserOptions {
             false
         }
     }
+
+    pub fn is_datatype(&self, iri: &IRI) -> bool {
+        if let Some(i) = self.index.get(iri) {
+            matches!(self.known.get(*i), Some(Declaration::Datatype { .. }))
+        } else {
+            false
+        }
+    }
 }
 
 impl From<ParserOptions> for IndexedParserOptions {
@@ -291,6 +438,7 @@ impl From<ParserOptions> for IndexedParserOptions {
         Self {
             known: po.known,
             index,
+            lenient: po.lenient,
         }
     }
 }
@@ -305,6 +453,18 @@ impl ParserOptionsBuilder {
         self.options.known.push(declaration);
         self
     }
+    /// Collect errors in single constructs as diagnostics instead of failing, see
+    /// [`Ontology::parse_with_diagnostics`].
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.options.lenient = lenient;
+        self
+    }
+    /// Retain the comments in front of the statements about a subject, see
+    /// [`Ontology::comments`]. Turtle documents only.
+    pub fn comments(mut self, comments: bool) -> Self {
+        self.options.comments = comments;
+        self
+    }
     pub fn build(self) -> ParserOptions {
         self.options
     }
@@ -317,11 +477,23 @@ mod tests {
     use crate::{
         api::Ontology,
         owl::{
-            well_known, AnnotationAssertion, Axiom, ClassAssertion,
-            DataPropertyAssertion, DataPropertyDomain, DataPropertyRange, Declaration,
-            EquivalentClasses, Literal, LiteralOrIRI, ObjectIntersectionOf,
-            ObjectPropertyAssertion, ObjectPropertyDomain, ObjectPropertyRange, ObjectUnionOf,
-            SubAnnotationPropertyOf, SubClassOf, SubDataPropertyOf, SubObjectPropertyOf, IRI,
+            well_known, Annotation, AnnotationAssertion, AsymmetricObjectProperty, Axiom,
+            ClassAssertion, ClassConstructor, DataAllValuesFrom, DataComplementOf,
+            DataExactCardinality, DataHasValue, DataMaxCardinality, DataMinCardinality, DataOneOf,
+            DataPropertyAssertion, DataPropertyDomain, DataPropertyRange, DataSomeValuesFrom,
+            DataUnionOf, DatatypeDefinition, DatatypeDefinitionConstructor, DatatypeIRI,
+            DatatypeRestriction, Declaration, DifferentIndividuals, DisjointClasses,
+            DisjointDataProperties, DisjointObjectProperties, DisjointUnion, EquivalentClasses,
+            EquivalentDataProperties, EquivalentObjectProperties, FunctionalDataProperty,
+            FunctionalObjectProperty, InverseFunctionalObjectProperty, InverseObjectProperties,
+            IrreflexiveObjectProperty, Literal, LiteralOrIRI, NegativeDataPropertyAssertion,
+            NegativeObjectPropertyAssertion, ObjectAllValuesFrom, ObjectComplementOf,
+            ObjectHasSelf, ObjectIntersectionOf, ObjectInverseOf, ObjectMinCardinality,
+            ObjectOneOf, ObjectPropertyAssertion, ObjectPropertyChain, ObjectPropertyConstructor,
+            ObjectPropertyDomain, ObjectPropertyRange, ObjectSomeValuesFrom, ObjectUnionOf,
+            ReflexiveObjectProperty, Restriction, SameIndividual, SubAnnotationPropertyOf,
+            SubClassOf, SubDataPropertyOf, SubObjectPropertyOf, SymmetricObjectProperty,
+            TransitiveObjectProperty, IRI,
         },
         parser::ParserOptions,
     };
@@ -849,9 +1021,8 @@ mod tests {
         println!("{:#?}", o);
         assert_eq!(o.declarations().len(), 2);
         assert_eq!(o.axioms().len(), 3);
-        assert_eq!(
-            o.axioms()[1],
-            ObjectPropertyRange::new(
+        assert!(o.axioms().contains(
+            &ObjectPropertyRange::new(
                 IRI::new("http://field33.com/ontologies/@schmolo/ppr/CFAFBD")
                     .unwrap()
                     .into(),
@@ -870,10 +1041,9 @@ mod tests {
                 vec![]
             )
             .into()
-        );
-        assert_eq!(
-            o.axioms()[2],
-            ObjectPropertyDomain::new(
+        ));
+        assert!(o.axioms().contains(
+            &ObjectPropertyDomain::new(
                 IRI::new("http://field33.com/ontologies/@schmolo/ppr/CFAFBD")
                     .unwrap()
                     .into(),
@@ -883,7 +1053,7 @@ mod tests {
                 vec![]
             )
             .into()
-        );
+        ));
     }
 
     #[test]
@@ -1133,9 +1303,8 @@ mod tests {
 
         assert_eq!(o.declarations().len(), 3);
         assert_eq!(o.axioms().len(), 6);
-        assert_eq!(
-            o.axioms()[0],
-            DataPropertyAssertion::new(
+        assert!(o.axioms().contains(
+            &DataPropertyAssertion::new(
                 IRI::new("http://test#hasAge").unwrap().into(),
                 IRI::new("http://test#Bob").unwrap().into(),
                 Literal::Number {
@@ -1146,10 +1315,9 @@ mod tests {
                 vec![]
             )
             .into()
-        );
-        assert_eq!(
-            o.axioms()[1],
-            DataPropertyAssertion::new(
+        ));
+        assert!(o.axioms().contains(
+            &DataPropertyAssertion::new(
                 IRI::new("http://test#hasAge").unwrap().into(),
                 IRI::new("http://test#Bob").unwrap().into(),
                 Literal::Number {
@@ -1160,10 +1328,9 @@ mod tests {
                 vec![]
             )
             .into()
-        );
-        assert_eq!(
-            o.axioms()[2],
-            DataPropertyAssertion::new(
+        ));
+        assert!(o.axioms().contains(
+            &DataPropertyAssertion::new(
                 IRI::new("http://test#hasAge").unwrap().into(),
                 IRI::new("http://test#Bob").unwrap().into(),
                 Literal::Number {
@@ -1174,10 +1341,9 @@ mod tests {
                 vec![]
             )
             .into()
-        );
-        assert_eq!(
-            o.axioms()[3],
-            DataPropertyAssertion::new(
+        ));
+        assert!(o.axioms().contains(
+            &DataPropertyAssertion::new(
                 IRI::new("http://test#hasAge").unwrap().into(),
                 IRI::new("http://test#Bob").unwrap().into(),
                 Literal::Bool(true),
@@ -1185,10 +1351,9 @@ mod tests {
                 vec![]
             )
             .into()
-        );
-        assert_eq!(
-            o.axioms()[4],
-            DataPropertyAssertion::new(
+        ));
+        assert!(o.axioms().contains(
+            &DataPropertyAssertion::new(
                 IRI::new("http://test#hasAge").unwrap().into(),
                 IRI::new("http://test#Bob").unwrap().into(),
                 Literal::Bool(false),
@@ -1196,7 +1361,7 @@ mod tests {
                 vec![]
             )
             .into()
-        );
+        ));
     }
 
     #[test]
@@ -1515,9 +1680,8 @@ mod tests {
                 annotations: vec![],
             }
         );
-        assert_eq!(
-            o.axioms()[8],
-            DataPropertyDomain::new(
+        assert!(o.axioms().contains(
+            &DataPropertyDomain::new(
                 IRI::new("http://field33.com/ontologies/@fld33/people/FirstName")
                     .unwrap()
                     .into(),
@@ -1527,10 +1691,9 @@ mod tests {
                 vec![]
             )
             .into()
-        );
-        assert_eq!(
-            o.axioms()[9],
-            DataPropertyRange::new(
+        ));
+        assert!(o.axioms().contains(
+            &DataPropertyRange::new(
                 IRI::new("http://field33.com/ontologies/@fld33/people/FirstName")
                     .unwrap()
                     .into(),
@@ -1540,7 +1703,7 @@ mod tests {
                 vec![]
             )
             .into()
-        );
+        ));
     }
 
     #[test]
@@ -1640,6 +1803,784 @@ mod tests {
         );
     }
 
+    #[test]
+    fn property_characteristics() {
+        env_logger::try_init().ok();
+        let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+
+        :oProp rdf:type owl:ObjectProperty .
+        :dProp rdf:type owl:DatatypeProperty .
+
+        :oProp rdf:type owl:FunctionalProperty .
+        :oProp rdf:type owl:InverseFunctionalProperty .
+        :oProp rdf:type owl:ReflexiveProperty .
+        :oProp rdf:type owl:IrreflexiveProperty .
+        :oProp rdf:type owl:SymmetricProperty .
+        :oProp rdf:type owl:AsymmetricProperty .
+        :oProp rdf:type owl:TransitiveProperty .
+        :dProp rdf:type owl:FunctionalProperty .
+        "##;
+
+        harriet::TurtleDocument::parse_full(turtle).unwrap();
+        let o = Ontology::parse(turtle, Default::default()).unwrap();
+
+        assert_eq!(o.declarations().len(), 2);
+        assert_eq!(o.axioms().len(), 8);
+
+        let o_prop = IRI::new("http://test#oProp").unwrap();
+        let d_prop = IRI::new("http://test#dProp").unwrap();
+        assert_eq!(
+            o.axioms()[0],
+            FunctionalObjectProperty::new(o_prop.clone().into(), vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[1],
+            InverseFunctionalObjectProperty::new(o_prop.clone().into(), vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[2],
+            ReflexiveObjectProperty::new(o_prop.clone().into(), vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[3],
+            IrreflexiveObjectProperty::new(o_prop.clone().into(), vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[4],
+            SymmetricObjectProperty::new(o_prop.clone().into(), vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[5],
+            AsymmetricObjectProperty::new(o_prop.clone().into(), vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[6],
+            TransitiveObjectProperty::new(o_prop.into(), vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[7],
+            FunctionalDataProperty::new(d_prop.into(), vec![]).into()
+        );
+    }
+
+    #[test]
+    fn property_relations() {
+        env_logger::try_init().ok();
+        let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+
+        :p rdf:type owl:ObjectProperty .
+        :q rdf:type owl:ObjectProperty .
+        :r rdf:type owl:ObjectProperty .
+        :s rdf:type owl:ObjectProperty .
+        :d1 rdf:type owl:DatatypeProperty .
+        :d2 rdf:type owl:DatatypeProperty .
+
+        :p owl:equivalentProperty :q .
+        :d1 owl:equivalentProperty :d2 .
+        :p owl:inverseOf :r .
+        :q owl:propertyDisjointWith :r .
+        [] rdf:type owl:AllDisjointProperties ;
+            owl:members ( :p :q :s ) .
+        :s owl:propertyChainAxiom ( :p :r ) .
+        "##;
+
+        harriet::TurtleDocument::parse_full(turtle).unwrap();
+        let o = Ontology::parse(turtle, Default::default()).unwrap();
+
+        assert_eq!(o.declarations().len(), 6);
+        assert_eq!(o.axioms().len(), 6);
+
+        let iri = |name: &str| IRI::new(&format!("http://test#{}", name)).unwrap();
+        assert_eq!(
+            o.axioms()[0],
+            EquivalentObjectProperties::new(iri("p").into(), iri("q").into(), vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[1],
+            EquivalentDataProperties::new(iri("d1").into(), iri("d2").into(), vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[2],
+            InverseObjectProperties::new(iri("p").into(), iri("r").into(), vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[3],
+            DisjointObjectProperties::new(vec![iri("q").into(), iri("r").into()], vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[4],
+            DisjointObjectProperties::new(
+                vec![iri("p").into(), iri("q").into(), iri("s").into()],
+                vec![]
+            )
+            .into()
+        );
+        assert_eq!(
+            o.axioms()[5],
+            SubObjectPropertyOf::new(
+                ObjectPropertyChain(vec![iri("p").into(), iri("r").into()]).into(),
+                iri("s").into(),
+                vec![]
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn data_restrictions() {
+        env_logger::try_init().ok();
+        let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+
+        :d rdf:type owl:DatatypeProperty .
+
+        :A rdfs:subClassOf [
+            rdf:type owl:Restriction ;
+            owl:onProperty :d ;
+            owl:allValuesFrom xsd:string
+        ] .
+        :B rdfs:subClassOf [
+            rdf:type owl:Restriction ;
+            owl:onProperty :d ;
+            owl:hasValue "foo"
+        ] .
+        :C rdfs:subClassOf [
+            rdf:type owl:Restriction ;
+            owl:onProperty :d ;
+            owl:qualifiedCardinality "1"^^xsd:nonNegativeInteger ;
+            owl:onDataRange xsd:date
+        ] .
+        :D rdfs:subClassOf [
+            rdf:type owl:Restriction ;
+            owl:onProperty :d ;
+            owl:maxCardinality "2"^^xsd:nonNegativeInteger
+        ] .
+        :E rdfs:subClassOf [
+            rdf:type owl:Restriction ;
+            owl:onProperty :d ;
+            owl:minQualifiedCardinality "3"^^xsd:nonNegativeInteger ;
+            owl:onDataRange xsd:integer
+        ] .
+        "##;
+
+        harriet::TurtleDocument::parse_full(turtle).unwrap();
+        let o = Ontology::parse(turtle, Default::default()).unwrap();
+
+        assert_eq!(o.declarations().len(), 1);
+        assert_eq!(o.axioms().len(), 5);
+
+        let iri = |name: &str| IRI::new(&format!("http://test#{}", name)).unwrap();
+        assert_eq!(
+            o.axioms()[0],
+            SubClassOf::new(
+                iri("A").into(),
+                DataAllValuesFrom::new(iri("d").into(), well_known::xsd_string(), vec![]).into(),
+                vec![]
+            )
+            .into()
+        );
+        assert_eq!(
+            o.axioms()[1],
+            SubClassOf::new(
+                iri("B").into(),
+                DataHasValue::new(iri("d").into(), Literal::String("foo".into()), vec![]).into(),
+                vec![]
+            )
+            .into()
+        );
+        assert_eq!(
+            o.axioms()[2],
+            SubClassOf::new(
+                iri("C").into(),
+                DataExactCardinality::new(
+                    1,
+                    iri("d").into(),
+                    Some(
+                        IRI::new("http://www.w3.org/2001/XMLSchema#date")
+                            .unwrap()
+                            .into()
+                    )
+                )
+                .into(),
+                vec![]
+            )
+            .into()
+        );
+        assert_eq!(
+            o.axioms()[3],
+            SubClassOf::new(
+                iri("D").into(),
+                DataMaxCardinality::new(2, iri("d").into(), None).into(),
+                vec![]
+            )
+            .into()
+        );
+        assert_eq!(
+            o.axioms()[4],
+            SubClassOf::new(
+                iri("E").into(),
+                DataMinCardinality::new(3, iri("d").into(), Some(well_known::xsd_integer())).into(),
+                vec![]
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn disjointness() {
+        env_logger::try_init().ok();
+        let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+
+        :A rdf:type owl:Class .
+        :B rdf:type owl:Class .
+        :C rdf:type owl:Class .
+        :d1 rdf:type owl:DatatypeProperty .
+        :d2 rdf:type owl:DatatypeProperty .
+        :d3 rdf:type owl:DatatypeProperty .
+
+        :A owl:disjointWith :B .
+        [] rdf:type owl:AllDisjointClasses ;
+            owl:members ( :A :B [
+                rdf:type owl:Class ;
+                owl:intersectionOf ( :A :C )
+            ] ) .
+        :C owl:disjointUnionOf ( :A :B ) .
+        :d1 owl:propertyDisjointWith :d2 .
+        [] rdf:type owl:AllDisjointProperties ;
+            owl:members ( :d1 :d2 :d3 ) .
+        [] rdf:type owl:AllDifferent ;
+            owl:distinctMembers ( :x :y :z ) .
+        [] rdf:type owl:AllDifferent ;
+            owl:members ( :x :y ) .
+        :y owl:differentFrom :z .
+        "##;
+
+        harriet::TurtleDocument::parse_full(turtle).unwrap();
+        let o = Ontology::parse(turtle, Default::default()).unwrap();
+
+        assert_eq!(o.declarations().len(), 6);
+        assert_eq!(o.axioms().len(), 8);
+
+        let iri = |name: &str| IRI::new(&format!("http://test#{}", name)).unwrap();
+        let cls = |name: &str| ClassConstructor::IRI(iri(name).into());
+        assert_eq!(
+            o.axioms()[0],
+            DisjointClasses::new(vec![cls("A"), cls("B")], vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[1],
+            DisjointClasses::new(
+                vec![
+                    cls("A"),
+                    cls("B"),
+                    ObjectIntersectionOf::new(vec![cls("A"), cls("C")], vec![]).into()
+                ],
+                vec![]
+            )
+            .into()
+        );
+        assert_eq!(
+            o.axioms()[2],
+            DisjointUnion::new(iri("C").into(), vec![cls("A"), cls("B")], vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[3],
+            DisjointDataProperties::new(vec![iri("d1").into(), iri("d2").into()], vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[4],
+            DisjointDataProperties::new(
+                vec![iri("d1").into(), iri("d2").into(), iri("d3").into()],
+                vec![]
+            )
+            .into()
+        );
+        assert_eq!(
+            o.axioms()[5],
+            DifferentIndividuals::new(
+                vec![iri("x").into(), iri("y").into(), iri("z").into()],
+                vec![]
+            )
+            .into()
+        );
+        assert_eq!(
+            o.axioms()[6],
+            DifferentIndividuals::new(vec![iri("x").into(), iri("y").into()], vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[7],
+            DifferentIndividuals::new(vec![iri("y").into(), iri("z").into()], vec![]).into()
+        );
+    }
+
+    #[test]
+    fn individual_equality() {
+        env_logger::try_init().ok();
+        let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+
+        :x owl:sameAs :y .
+        :y owl:differentFrom :z .
+        [] rdf:type owl:Axiom ;
+            owl:annotatedSource :y ;
+            owl:annotatedProperty owl:differentFrom ;
+            owl:annotatedTarget :z ;
+            rdfs:comment "y is not z" .
+        [] rdf:type owl:AllDifferent ;
+            owl:members ( :x :z :w ) ;
+            rdfs:label "all different" .
+        "##;
+
+        let o = Ontology::parse(turtle, Default::default()).unwrap();
+        let iri = |name: &str| IRI::new(&format!("http://test#{}", name)).unwrap();
+        let comment = |value: &str| {
+            vec![Annotation::new(
+                well_known::rdfs_comment(),
+                Literal::String(value.into()).into(),
+                vec![],
+            )]
+        };
+        let axioms: Vec<&Axiom> = o.axioms().iter().collect();
+        assert_eq!(axioms.len(), 3);
+        assert_eq!(
+            axioms[0],
+            &SameIndividual::new(vec![iri("x").into(), iri("y").into()], vec![]).into()
+        );
+        assert_eq!(
+            axioms[1],
+            &DifferentIndividuals::new(
+                vec![iri("y").into(), iri("z").into()],
+                comment("y is not z")
+            )
+            .into()
+        );
+        assert_eq!(
+            axioms[2],
+            &DifferentIndividuals::new(
+                vec![iri("x").into(), iri("z").into(), iri("w").into()],
+                vec![Annotation::new(
+                    well_known::rdfs_label(),
+                    Literal::String("all different".into()).into(),
+                    vec![],
+                )]
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn negative_property_assertions() {
+        env_logger::try_init().ok();
+        let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+
+        :hasChild rdf:type owl:ObjectProperty .
+        :age rdf:type owl:DataProperty .
+
+        [] rdf:type owl:NegativePropertyAssertion ;
+            owl:sourceIndividual :Bob ;
+            owl:assertionProperty :hasChild ;
+            owl:targetIndividual :Alice ;
+            rdfs:comment "Alice is not a child of Bob" .
+        [] rdf:type owl:NegativePropertyAssertion ;
+            owl:sourceIndividual :Bob ;
+            owl:assertionProperty :age ;
+            owl:targetValue "42"^^xsd:integer .
+        "##;
+
+        let o = Ontology::parse(turtle, Default::default()).unwrap();
+        let iri = |name: &str| IRI::new(&format!("http://test#{}", name)).unwrap();
+        let axioms: Vec<&Axiom> = o.axioms().iter().collect();
+        assert_eq!(axioms.len(), 2);
+        assert_eq!(
+            axioms[0],
+            &NegativeObjectPropertyAssertion::new(
+                iri("hasChild").into(),
+                iri("Bob").into(),
+                iri("Alice").into(),
+                vec![Annotation::new(
+                    well_known::rdfs_comment(),
+                    Literal::String("Alice is not a child of Bob".into()).into(),
+                    vec![],
+                )]
+            )
+            .into()
+        );
+        assert_eq!(
+            axioms[1],
+            &NegativeDataPropertyAssertion::new(
+                iri("age").into(),
+                iri("Bob").into(),
+                Literal::Number {
+                    number: 42.into(),
+                    type_iri: well_known::xsd_integer().into(),
+                },
+                vec![]
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn datatype_definitions() {
+        env_logger::try_init().ok();
+        let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+
+        :age rdf:type rdfs:Datatype ;
+            owl:equivalentClass [
+                rdf:type rdfs:Datatype ;
+                owl:onDatatype xsd:integer ;
+                owl:withRestrictions (
+                    [ xsd:minInclusive 0 ] [ xsd:maxExclusive 150 ]
+                    [ xsd:minExclusive -1 ] [ xsd:maxInclusive 149 ]
+                    [ xsd:totalDigits 3 ] [ xsd:fractionDigits 0 ]
+                )
+            ] .
+        :code rdf:type rdfs:Datatype ;
+            owl:equivalentClass [
+                rdf:type rdfs:Datatype ;
+                owl:onDatatype xsd:string ;
+                owl:withRestrictions (
+                    [ xsd:pattern "[A-Z]+" ] [ xsd:length 3 ]
+                    [ xsd:minLength 3 ] [ xsd:maxLength 3 ]
+                )
+            ] .
+        :english rdf:type rdfs:Datatype ;
+            owl:equivalentClass [
+                rdf:type rdfs:Datatype ;
+                owl:onDatatype rdf:PlainLiteral ;
+                owl:withRestrictions ( [ rdf:langRange "en" ] )
+            ] .
+        :text rdf:type rdfs:Datatype ;
+            owl:equivalentClass xsd:string .
+        :notAnInteger rdf:type rdfs:Datatype ;
+            owl:equivalentClass [
+                rdf:type rdfs:Datatype ;
+                owl:datatypeComplementOf xsd:integer
+            ] .
+        :small rdf:type rdfs:Datatype ;
+            owl:equivalentClass [ rdf:type rdfs:Datatype ; owl:oneOf ( 1 2 ) ] .
+        :number rdf:type rdfs:Datatype ;
+            owl:equivalentClass [
+                rdf:type rdfs:Datatype ;
+                owl:unionOf ( xsd:integer xsd:decimal xsd:float )
+            ] .
+        [] rdf:type owl:Axiom ;
+            owl:annotatedSource :text ;
+            owl:annotatedProperty owl:equivalentClass ;
+            owl:annotatedTarget xsd:string ;
+            rdfs:comment "just a string" .
+        "##;
+
+        let o = Ontology::parse(turtle, Default::default()).unwrap();
+        let iri = |name: &str| IRI::new(&format!("http://test#{}", name)).unwrap();
+        let integer = |n: i64| Literal::Number {
+            number: n.into(),
+            type_iri: Some(well_known::xsd_integer()),
+        };
+        let string = |s: &str| Literal::String(s.into());
+        let restriction = |datatype: DatatypeIRI, restrictions: Vec<Restriction>| {
+            DatatypeRestriction::new(datatype, restrictions, vec![])
+        };
+        let definition = |name: &str, range: DatatypeDefinitionConstructor| -> Axiom {
+            DatatypeDefinition::new(iri(name).into(), range, vec![]).into()
+        };
+        assert_eq!(o.declarations().len(), 7);
+        let axioms: Vec<Axiom> = o.axioms().to_vec();
+        assert_eq!(
+            axioms,
+            vec![
+                definition(
+                    "age",
+                    restriction(
+                        well_known::xsd_integer(),
+                        vec![
+                            Restriction::MinInclusive(integer(0)),
+                            Restriction::MaxExclusive(integer(150)),
+                            Restriction::MinExclusive(integer(-1)),
+                            Restriction::MaxInclusive(integer(149)),
+                            Restriction::TotalDigits(integer(3)),
+                            Restriction::FractionDigits(integer(0)),
+                        ]
+                    )
+                    .into()
+                ),
+                definition(
+                    "code",
+                    restriction(
+                        well_known::xsd_string(),
+                        vec![
+                            Restriction::Pattern(string("[A-Z]+")),
+                            Restriction::Length(integer(3)),
+                            Restriction::MinLength(integer(3)),
+                            Restriction::MaxLength(integer(3)),
+                        ]
+                    )
+                    .into()
+                ),
+                definition(
+                    "english",
+                    restriction(
+                        IRI::new("http://www.w3.org/1999/02/22-rdf-syntax-ns#PlainLiteral")
+                            .unwrap()
+                            .into(),
+                        vec![Restriction::LangRange(string("en"))]
+                    )
+                    .into()
+                ),
+                DatatypeDefinition::new(
+                    iri("text").into(),
+                    restriction(well_known::xsd_string(), vec![]).into(),
+                    vec![Annotation::new(
+                        well_known::rdfs_comment(),
+                        string("just a string").into(),
+                        vec![],
+                    )]
+                )
+                .into(),
+                definition(
+                    "notAnInteger",
+                    DataComplementOf::new(
+                        well_known::xsd_integer().as_iri().clone().into(),
+                        vec![]
+                    )
+                    .into()
+                ),
+                definition(
+                    "small",
+                    DataOneOf::new(vec![integer(1), integer(2)], vec![]).into()
+                ),
+                definition(
+                    "number",
+                    DataUnionOf::new(
+                        well_known::xsd_integer().as_iri().clone().into(),
+                        DataUnionOf::new(
+                            IRI::new("http://www.w3.org/2001/XMLSchema#decimal")
+                                .unwrap()
+                                .into(),
+                            restriction(well_known::xsd_float(), vec![]).into(),
+                            vec![]
+                        )
+                        .into(),
+                        vec![]
+                    )
+                    .into()
+                ),
+            ]
+        );
+        assert!(
+            o.unmapped_triples().is_empty(),
+            "{:?}",
+            o.unmapped_triples()
+        );
+    }
+
+    #[test]
+    fn nested_class_expressions() {
+        env_logger::try_init().ok();
+        // nested class expressions are listed before the class expressions referencing them
+        let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+
+        :A rdf:type owl:Class .
+        :B rdf:type owl:Class .
+        :C rdf:type owl:Class .
+        :hasPart rdf:type owl:ObjectProperty .
+        :p rdf:type owl:ObjectProperty .
+        :age rdf:type owl:DatatypeProperty .
+
+        _:all rdf:type owl:Restriction ;
+            owl:onProperty :p ;
+            owl:allValuesFrom :B .
+        _:and rdf:type owl:Class ;
+            owl:intersectionOf ( :A _:all ) .
+        _:some rdf:type owl:Restriction ;
+            owl:onProperty :hasPart ;
+            owl:someValuesFrom _:and .
+        :C rdfs:subClassOf _:some .
+
+        _:inverse owl:inverseOf :hasPart .
+        _:not rdf:type owl:Class ;
+            owl:complementOf [
+                rdf:type owl:Restriction ;
+                owl:onProperty _:inverse ;
+                owl:hasSelf true
+            ] .
+        _:or rdfs:subClassOf _:not .
+        _:or rdf:type owl:Class ;
+            owl:unionOf ( :A [
+                rdf:type owl:Restriction ;
+                owl:onProperty :age ;
+                owl:someValuesFrom [
+                    rdf:type rdfs:Datatype ;
+                    owl:onDatatype xsd:integer ;
+                    owl:withRestrictions ( [ xsd:minExclusive 18 ] )
+                ]
+            ] ) .
+
+        :x rdf:type _:not .
+
+        :B owl:equivalentClass [
+            rdf:type owl:Restriction ;
+            owl:onProperty :hasPart ;
+            owl:minQualifiedCardinality "2"^^xsd:nonNegativeInteger ;
+            owl:onClass [
+                rdf:type owl:Class ;
+                owl:oneOf ( :x :y )
+            ]
+        ] .
+        "##;
+
+        harriet::TurtleDocument::parse_full(turtle).unwrap();
+        let o = Ontology::parse(turtle, Default::default()).unwrap();
+
+        assert_eq!(o.declarations().len(), 6);
+        assert_eq!(o.axioms().len(), 4);
+
+        let iri = |name: &str| IRI::new(&format!("http://test#{}", name)).unwrap();
+        let cls = |name: &str| ClassConstructor::IRI(iri(name).into());
+        let not: ClassConstructor = ObjectComplementOf::new(
+            Box::new(
+                ObjectHasSelf::new(ObjectInverseOf(iri("hasPart").into()).into(), vec![]).into(),
+            ),
+            vec![],
+        )
+        .into();
+        assert_eq!(
+            o.axioms()[0],
+            SubClassOf::new(
+                Box::new(cls("C")),
+                Box::new(
+                    ObjectSomeValuesFrom::new(
+                        ObjectPropertyConstructor::IRI(iri("hasPart").into()),
+                        Box::new(
+                            ObjectIntersectionOf::new(
+                                vec![
+                                    cls("A"),
+                                    ObjectAllValuesFrom::new(
+                                        ObjectPropertyConstructor::IRI(iri("p").into()),
+                                        Box::new(cls("B")),
+                                        vec![]
+                                    )
+                                    .into()
+                                ],
+                                vec![]
+                            )
+                            .into()
+                        ),
+                        vec![]
+                    )
+                    .into()
+                ),
+                vec![]
+            )
+            .into()
+        );
+        assert_eq!(
+            o.axioms()[1],
+            SubClassOf::new(
+                Box::new(
+                    ObjectUnionOf::new(
+                        vec![
+                            cls("A"),
+                            DataSomeValuesFrom::new(
+                                iri("age").into(),
+                                DatatypeRestriction::new(
+                                    well_known::xsd_integer(),
+                                    vec![Restriction::MinExclusive(Literal::Number {
+                                        number: 18.into(),
+                                        type_iri: Some(well_known::xsd_integer())
+                                    })],
+                                    vec![]
+                                ),
+                                vec![]
+                            )
+                            .into()
+                        ],
+                        vec![]
+                    )
+                    .into()
+                ),
+                Box::new(not.clone()),
+                vec![]
+            )
+            .into()
+        );
+        assert_eq!(
+            o.axioms()[2],
+            ClassAssertion::new(not, iri("x").into(), vec![]).into()
+        );
+        assert_eq!(
+            o.axioms()[3],
+            EquivalentClasses::new(
+                iri("B").into(),
+                Box::new(
+                    ObjectMinCardinality::new(
+                        2,
+                        iri("hasPart").into(),
+                        Some(Box::new(
+                            ObjectOneOf::new(vec![iri("x").into(), iri("y").into()], vec![]).into()
+                        ))
+                    )
+                    .into()
+                ),
+                vec![]
+            )
+            .into()
+        );
+    }
+
     #[test]
     fn computation() {
         env_logger::try_init().ok();
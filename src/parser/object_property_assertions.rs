@@ -1,6 +1,9 @@
+use super::collector::individual;
 use super::collector::MatcherHandler;
 use crate::error::Error;
 use crate::get_vars;
+use crate::owl::well_known;
+use crate::owl::BlankNode;
 use crate::owl::ObjectPropertyAssertion;
 use crate::owl::IRI;
 use crate::parser::matcher::RdfMatcher;
@@ -26,72 +29,90 @@ pub(crate) fn push(
             let Some(vars) = get_vars!(mstate, subject, predicate, object) else {
                 return Ok(false);
             };
-            let Value::Iri(iri) = vars.subject else {
-                return Ok(false);
-            };
-            let Ok(subject) = IRI::new(iri) else {
+            let Ok(Some(subject)) = individual(vars.subject) else {
                 return Ok(false);
             };
             let Ok(predicate) = vars.predicate.clone().try_into() else {
                 return Ok(false);
             };
+            let is_object_prop = o.object_property_declaration(&predicate).is_some()
+                || options.is_object_prop(&predicate);
 
             match vars.object {
                 Value::Iri(iri) => {
                     if let Ok(object) = IRI::new(iri) {
-                        if o.object_property_declaration(&predicate).is_some()
-                            || options.is_object_prop(&predicate)
-                        {
+                        if is_object_prop {
                             o.push_axiom(
                                 ObjectPropertyAssertion::new(
                                     predicate.into(),
-                                    subject.into(),
+                                    subject,
                                     object.into(),
                                     vec![],
                                     vec![]
                                 )
                                 .into(),
-                            )
+                            );
+                            return Ok(true);
                         }
                     }
                 }
+                // anonymous individuals, e.g. `:order :hasLine [ a :OrderLine ]`
+                Value::Blank(bn) if is_object_prop && o.get_sequence(bn)?.is_none() => {
+                    o.push_axiom(
+                        ObjectPropertyAssertion::new(
+                            predicate.into(),
+                            subject,
+                            BlankNode::from(bn.clone()).into(),
+                            vec![],
+                            vec![],
+                        )
+                        .into(),
+                    );
+                    return Ok(true);
+                }
                 Value::Blank(bn) => {
-                    let mut object = Vec::new();
-                    let mut b = Some(bn);
-                    while let Some(bn) = b {
-                        b = None;
-                        if let Some(super::collector::CollectedBlankNode::Sequence {
-                            first,
-                            rest,
-                        }) = o.get_blank(bn)
-                        {
-                            if let Some(Value::Iri(iri)) = first {
-                                if let Ok(iri) = IRI::new(iri) {
-                                    object.push(iri);
-                                    b = rest.as_ref();
-                                }
-                            }
-                        }
+                    // lists behind reserved vocabulary (e.g. owl:propertyChainAxiom) are
+                    // handled by their own matchers
+                    if is_reserved_vocabulary(&predicate) {
+                        return Ok(false);
                     }
-                    if object.len() > 0 {
+                    let Some(seq) = o.get_sequence(bn)? else {
+                        return Ok(false);
+                    };
+                    // lists with other items than IRIs are kept as unmapped triples
+                    let object: Option<Vec<IRI>> = seq
+                        .iter()
+                        .map(|v| match v {
+                            Value::Iri(iri) => IRI::new(iri).ok(),
+                            _ => None,
+                        })
+                        .collect();
+                    if let Some(object) = object.filter(|object| !object.is_empty()) {
                         o.push_axiom(
                             ObjectPropertyAssertion::new_with_list(
                                 predicate.into(),
-                                subject.into(),
+                                subject,
                                 object,
                                 vec![],
                                 vec![],
                             )
                             .into(),
-                        )
+                        );
+                        return Ok(true);
                     }
                 }
-                Value::Literal { .. } => {
-                    unreachable!("Branch should be unreachable, as matcher shouldn't match literal objects.")
-                }
+                // The matcher doesn't match literal objects.
+                Value::Literal { .. } => {}
             }
             Ok(false)
         }),
     ));
     Ok(())
 }
+
+fn is_reserved_vocabulary(iri: &IRI) -> bool {
+    let iri = iri.as_str();
+    iri.starts_with(well_known::owl_base_str)
+        || iri.starts_with(well_known::rdf_base_str)
+        || iri.starts_with(well_known::rdfs_base_str)
+}
@@ -0,0 +1,337 @@
+use harriet::{
+    triple_production::{RdfSubject, RdfTriple, TripleProducer},
+    BlankNodePropertyList, Collection, Literal, Object, ParseError, PredicateObjectList, Statement,
+    Subject, Triples, TurtleDocument, TurtleString,
+};
+
+use crate::{
+    error::{Error, ErrorKind, Position},
+    owl::IRI,
+};
+
+/// Parses the given turtle document.
+///
+/// In contrast to harriet this never panics (e.g. on invalid escape sequences in literals)
+/// and reports the position of the offending statement on failure.
+pub(crate) fn parse_document(source: &str) -> Result<(TurtleDocument<'_>, SourceMap), Error> {
+    let document = TurtleDocument::parse_full(source).map_err(|e| syntax_error(source, e))?;
+    let source_map = SourceMap::new(source, &document)?;
+    Ok((document, source_map))
+}
+
+/// Produces the triples of a document which was created by [`parse_document`].
+pub(crate) fn produce_triples<'a>(
+    document: &TurtleDocument<'a>,
+    source_map: &SourceMap,
+) -> Result<Vec<RdfTriple<'a>>, Error> {
+    TripleProducer::produce_for_document(document).map_err(|e| {
+        let failing = failing_statement(document);
+        let kind = match failing.map(|i| &document.statements[i]) {
+            Some(statement) if has_unsupported_subject(statement) => {
+                ErrorKind::UnsupportedConstruct
+            }
+            _ => ErrorKind::Syntax,
+        };
+        let error = Error::with_kind(kind, format!("Failed to emit triples: {}", e));
+        match failing.and_then(|i| source_map.statement_position(i)) {
+            Some(position) => error.at_position(position),
+            None => error,
+        }
+    })
+}
+
+/// Whether the subject of the statement is a blank node property list or a collection, which
+/// harriet can't produce triples for.
+fn has_unsupported_subject(statement: &Statement) -> bool {
+    matches!(
+        statement,
+        Statement::Triples(Triples::Blank(..))
+            | Statement::Triples(Triples::Labeled(_, Subject::Collection(_), _))
+    )
+}
+
+/// The comments right in front of the statements about IRI subjects, see
+/// [`crate::parser::ParserOptionsBuilder::comments`].
+pub(crate) fn subject_comments(
+    document: &TurtleDocument,
+    triples: &[RdfTriple],
+    source_map: &SourceMap,
+) -> Vec<(IRI, String)> {
+    let mut comments = Vec::new();
+    if !source_map.covers(triples.len()) {
+        return comments;
+    }
+    for (i, (first, statement)) in source_map.triples.iter().enumerate() {
+        let Some(Statement::Triples(Triples::Labeled(Some(whitespace), Subject::IRI(_), _))) =
+            document.statements.get(*statement)
+        else {
+            continue;
+        };
+        let Some(comment) = comment(&whitespace.whitespace, *statement == 0) else {
+            continue;
+        };
+        let end = source_map
+            .triples
+            .get(i + 1)
+            .map(|(next, _)| *next)
+            .unwrap_or(source_map.triple_count);
+        // nested blank nodes and collections are produced before the triple they are the
+        // object of, so the last triple of a statement is about its subject
+        let subject = triples[*first..end].last().map(|t| &t.subject);
+        if let Some(RdfSubject::IRI(iri)) = subject {
+            if let Ok(iri) = IRI::new(&iri.iri) {
+                comments.push((iri, comment));
+            }
+        }
+    }
+    comments
+}
+
+/// The comment lines which directly precede a statement, without their leading `#`.
+fn comment(whitespace: &str, first_statement: bool) -> Option<String> {
+    let mut lines: Vec<&str> = whitespace.split('\n').collect();
+    // the line the statement starts in
+    lines.pop();
+    if !first_statement && !lines.is_empty() {
+        // the line the previous statement ends in
+        lines.remove(0);
+    }
+    let mut comment: Vec<&str> = lines
+        .iter()
+        .rev()
+        .map(|line| line.trim())
+        .take_while(|line| line.starts_with('#'))
+        .map(|line| &line[1..])
+        .collect();
+    if comment.is_empty() {
+        return None;
+    }
+    comment.reverse();
+    Some(comment.join("\n"))
+}
+
+fn syntax_error(source: &str, e: ParseError) -> Error {
+    match e {
+        ParseError::ParseError(e) => {
+            Error::with_kind(ErrorKind::Syntax, format!("Invalid turtle syntax: {}", e))
+        }
+        ParseError::NotFullyParsed(remaining) => {
+            let error = Error::with_kind(
+                ErrorKind::Syntax,
+                format!(
+                    "Could not parse statement starting with '{}'",
+                    skip_whitespace(remaining)
+                        .chars()
+                        .take_while(|c| *c != '\n')
+                        .take(40)
+                        .collect::<String>()
+                ),
+            );
+            match offset_of(source, remaining) {
+                Some(offset) => {
+                    let skipped = remaining.len() - skip_whitespace(remaining).len();
+                    error.at_position(Position::from_offset(source, offset + skipped))
+                }
+                None => error,
+            }
+        }
+    }
+}
+
+/// Binary searches for the index of the first statement the triples can not be produced for.
+fn failing_statement(document: &TurtleDocument) -> Option<usize> {
+    let produces = |count: usize| {
+        TripleProducer::produce_for_document(&TurtleDocument {
+            statements: document.statements[..count].to_vec(),
+            trailing_whitespace: None,
+        })
+        .is_ok()
+    };
+    let (mut ok, mut failing) = (0, document.statements.len());
+    if produces(failing) {
+        return None;
+    }
+    while failing - ok > 1 {
+        let middle = (ok + failing) / 2;
+        if produces(middle) {
+            ok = middle;
+        } else {
+            failing = middle;
+        }
+    }
+    Some(failing - 1)
+}
+
+/// Maps statements and the triples produced for them back to positions in the source.
+#[derive(Debug, Default)]
+pub(crate) struct SourceMap {
+    /// Position of every statement of the document (`None` if it could not be determined).
+    positions: Vec<Option<Position>>,
+    /// (index of the first triple of a statement, index of the statement)
+    triples: Vec<(usize, usize)>,
+    triple_count: usize,
+}
+
+impl SourceMap {
+    /// Creates the source map and validates all literals of the document.
+    fn new(source: &str, document: &TurtleDocument) -> Result<Self, Error> {
+        let mut source_map = SourceMap::default();
+
+        // harriet preserves whitespace and comments, so serializing the statements one by one
+        // yields their offsets in the source.
+        let mut offset = 0;
+        for statement in &document.statements {
+            let text = TurtleDocument {
+                statements: vec![statement.clone()],
+                trailing_whitespace: None,
+            }
+            .to_string();
+            let start = offset + text.len() - skip_whitespace(&text).len();
+            source_map
+                .positions
+                .push(Some(Position::from_offset(source, start)));
+            offset += text.len();
+        }
+        let trailing = document
+            .trailing_whitespace
+            .as_ref()
+            .map(|ws| ws.whitespace.len())
+            .unwrap_or(0);
+        if offset + trailing != source.len() {
+            source_map.positions.iter_mut().for_each(|p| *p = None);
+        }
+
+        for (i, statement) in document.statements.iter().enumerate() {
+            let Statement::Triples(triples) = statement else {
+                continue;
+            };
+            let count = match triples {
+                Triples::Labeled(_, subject, pol) => {
+                    let subject = match subject {
+                        Subject::Collection(c) => collection(source, c)?,
+                        Subject::IRI(_) | Subject::BlankNode(_) => 0,
+                    };
+                    subject + predicate_object_list(source, pol)?
+                }
+                Triples::Blank(_, bnpl, pol) => {
+                    predicate_object_list(source, &bnpl.list)?
+                        + match pol {
+                            Some(pol) => predicate_object_list(source, pol)?,
+                            None => 0,
+                        }
+                }
+            };
+            source_map.triples.push((source_map.triple_count, i));
+            source_map.triple_count += count;
+        }
+        Ok(source_map)
+    }
+
+    /// Creates a source map for syntaxes which locate every triple on its own.
+    pub(crate) fn for_triples(positions: Vec<Option<Position>>) -> Self {
+        Self {
+            triples: (0..positions.len()).map(|i| (i, i)).collect(),
+            triple_count: positions.len(),
+            positions,
+        }
+    }
+
+    fn statement_position(&self, statement_index: usize) -> Option<Position> {
+        self.positions.get(statement_index).copied().flatten()
+    }
+
+    /// Position of the statement which produced the triple with the given index.
+    pub(crate) fn position(&self, triple_index: usize) -> Option<Position> {
+        if triple_index >= self.triple_count {
+            return None;
+        }
+        let i = self
+            .triples
+            .partition_point(|(first, _)| *first <= triple_index);
+        let (_, statement) = self.triples.get(i.checked_sub(1)?)?;
+        self.statement_position(*statement)
+    }
+
+    /// Whether the map describes exactly the given number of triples.
+    pub(crate) fn covers(&self, triple_count: usize) -> bool {
+        self.triple_count == triple_count
+    }
+}
+
+fn predicate_object_list(source: &str, pol: &PredicateObjectList) -> Result<usize, Error> {
+    let mut count = 0;
+    for (_, _, objects, _) in &pol.list {
+        for (_, _, object) in &objects.list {
+            count += 1 + nested_object(source, object)?;
+        }
+    }
+    Ok(count)
+}
+
+/// Number of triples an object produces in addition to the triple it is the object of.
+fn nested_object(source: &str, object: &Object) -> Result<usize, Error> {
+    match object {
+        Object::Collection(c) => collection(source, c),
+        Object::BlankNodePropertyList(BlankNodePropertyList { list, .. }) => {
+            predicate_object_list(source, list)
+        }
+        Object::Literal(Literal::RDFLiteral(literal)) => {
+            validate_string(source, &literal.string)?;
+            Ok(0)
+        }
+        Object::Literal(_) | Object::IRI(_) | Object::BlankNode(_) => Ok(0),
+    }
+}
+
+/// Each member of a collection produces an rdf:first and an rdf:rest triple.
+fn collection(source: &str, collection: &Collection) -> Result<usize, Error> {
+    let mut count = 2 * collection.list.len();
+    for (_, object, _) in &collection.list {
+        count += nested_object(source, object)?;
+    }
+    Ok(count)
+}
+
+fn validate_string(source: &str, string: &TurtleString) -> Result<(), Error> {
+    let (raw, quotes, lexical_form) = match string {
+        TurtleString::StringLiteralQuote(s) => (&s.string, 1, s.lexical_form()),
+        TurtleString::StringLiteralSingleQuote(s) => (&s.string, 1, s.lexical_form()),
+        TurtleString::StringLiteralLongQuote(s) => (&s.string, 3, s.lexical_form()),
+        TurtleString::StringLiteralLongSingleQuote(s) => (&s.string, 3, s.lexical_form()),
+    };
+    let Err(e) = lexical_form else {
+        return Ok(());
+    };
+    let error = Error::with_kind(
+        ErrorKind::InvalidLiteral,
+        format!("Invalid string literal \"{}\": {}", raw, e),
+    );
+    Err(match offset_of(source, raw) {
+        Some(offset) => {
+            error.at_position(Position::from_offset(source, offset.saturating_sub(quotes)))
+        }
+        None => error,
+    })
+}
+
+/// Skips whitespace and comments.
+fn skip_whitespace(mut text: &str) -> &str {
+    loop {
+        text = text.trim_start();
+        if !text.starts_with('#') {
+            return text;
+        }
+        text = text.find('\n').map(|i| &text[i..]).unwrap_or("");
+    }
+}
+
+/// Byte offset of a slice that was borrowed from `source`.
+fn offset_of(source: &str, slice: &str) -> Option<usize> {
+    let start = source.as_ptr() as usize;
+    let ptr = slice.as_ptr() as usize;
+    if ptr >= start && ptr + slice.len() <= start + source.len() {
+        Some(ptr - start)
+    } else {
+        None
+    }
+}
@@ -15,14 +15,15 @@ pub fn parse_triple(ttl: &str) -> Option<Triple> {
     if !ttl.ends_with('.') {
         ttl_string = format!("{} .", ttl)
     }
-    let ttl = harriet::TurtleDocument::parse_full(&ttl_string);
-    if ttl.is_err() {
-        eprintln!("{:?}", ttl.unwrap_err());
-        return None;
-    }
-    let ttl = ttl.unwrap();
+    let (ttl, source_map) = match super::source::parse_document(&ttl_string) {
+        Ok(document) => document,
+        Err(e) => {
+            eprintln!("{}", e);
+            return None;
+        }
+    };
 
-    if let Ok(triples) = harriet::triple_production::TripleProducer::produce_for_document(&ttl) {
+    if let Ok(triples) = super::source::produce_triples(&ttl, &source_map) {
         if let Some(t) = triples.into_iter().next() {
             return Triple::from_rdf(t);
         }
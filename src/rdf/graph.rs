@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use crate::owl::{ResourceId, IRI};
+
+use super::{Term, Triple};
+
+/// A set of triples with indices on their subjects, predicates and objects.
+///
+/// The triples keep the order in which they were inserted.
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    triples: Vec<Triple>,
+    by_subject: HashMap<ResourceId, Vec<usize>>,
+    by_predicate: HashMap<IRI, Vec<usize>>,
+    by_object: HashMap<Term, Vec<usize>>,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a triple to the graph. Returns `false` if the graph already contains it.
+    pub fn insert(&mut self, triple: Triple) -> bool {
+        if self.contains(&triple) {
+            return false;
+        }
+        let index = self.triples.len();
+        self.by_subject
+            .entry(triple.subject.clone())
+            .or_default()
+            .push(index);
+        self.by_predicate
+            .entry(triple.predicate.clone())
+            .or_default()
+            .push(index);
+        self.by_object
+            .entry(triple.object.clone())
+            .or_default()
+            .push(index);
+        self.triples.push(triple);
+        true
+    }
+
+    pub fn contains(&self, triple: &Triple) -> bool {
+        self.with_subject(&triple.subject).any(|t| t == triple)
+    }
+
+    pub fn len(&self) -> usize {
+        self.triples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.triples.is_empty()
+    }
+
+    pub fn triples(&self) -> &[Triple] {
+        &self.triples
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Triple> {
+        self.triples.iter()
+    }
+
+    pub fn with_subject<'a>(
+        &'a self,
+        subject: &ResourceId,
+    ) -> impl Iterator<Item = &'a Triple> + 'a {
+        self.lookup(self.by_subject.get(subject))
+    }
+
+    pub fn with_predicate<'a>(&'a self, predicate: &IRI) -> impl Iterator<Item = &'a Triple> + 'a {
+        self.lookup(self.by_predicate.get(predicate))
+    }
+
+    pub fn with_object<'a>(&'a self, object: &Term) -> impl Iterator<Item = &'a Triple> + 'a {
+        self.lookup(self.by_object.get(object))
+    }
+
+    /// All triples which match the given pattern, `None` matches everything.
+    pub fn matching<'a>(
+        &'a self,
+        subject: Option<&'a ResourceId>,
+        predicate: Option<&'a IRI>,
+        object: Option<&'a Term>,
+    ) -> Box<dyn Iterator<Item = &'a Triple> + 'a> {
+        // the most selective index is used for the lookup
+        let candidates: Box<dyn Iterator<Item = &'a Triple>> = match (subject, object, predicate) {
+            (Some(s), _, _) => Box::new(self.with_subject(s)),
+            (None, Some(o), _) => Box::new(self.with_object(o)),
+            (None, None, Some(p)) => Box::new(self.with_predicate(p)),
+            (None, None, None) => Box::new(self.iter()),
+        };
+        Box::new(candidates.filter(move |t| {
+            subject.is_none_or(|s| t.subject == *s)
+                && predicate.is_none_or(|p| t.predicate == *p)
+                && object.is_none_or(|o| t.object == *o)
+        }))
+    }
+
+    /// The objects of the statements about `subject` with the given predicate.
+    pub fn objects<'a>(
+        &'a self,
+        subject: &ResourceId,
+        predicate: &'a IRI,
+    ) -> impl Iterator<Item = &'a Term> + 'a {
+        self.with_subject(subject)
+            .filter(move |t| t.predicate == *predicate)
+            .map(|t| &t.object)
+    }
+
+    fn lookup<'a>(&'a self, indices: Option<&'a Vec<usize>>) -> impl Iterator<Item = &'a Triple> {
+        indices
+            .into_iter()
+            .flatten()
+            .map(move |index| &self.triples[*index])
+    }
+}
+
+impl PartialEq for Graph {
+    /// Graphs are equal if they contain the same triples, independent of their order.
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|t| other.contains(t))
+    }
+}
+
+impl Eq for Graph {}
+
+impl Extend<Triple> for Graph {
+    fn extend<T: IntoIterator<Item = Triple>>(&mut self, iter: T) {
+        for triple in iter {
+            self.insert(triple);
+        }
+    }
+}
+
+impl FromIterator<Triple> for Graph {
+    fn from_iter<T: IntoIterator<Item = Triple>>(iter: T) -> Self {
+        let mut graph = Graph::new();
+        graph.extend(iter);
+        graph
+    }
+}
+
+impl IntoIterator for Graph {
+    type Item = Triple;
+    type IntoIter = std::vec::IntoIter<Triple>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.triples.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Graph {
+    type Item = &'a Triple;
+    type IntoIter = std::slice::Iter<'a, Triple>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.triples.iter()
+    }
+}
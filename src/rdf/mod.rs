@@ -0,0 +1,234 @@
+//! A syntax independent representation of RDF graphs.
+//!
+//! Every reader of owlish maps the triples of its document to OWL, and every RDF based writer
+//! produces triples before they are written as text. This module makes these triples available,
+//! so that ontologies can be built from triples of any source (other parsers, databases, SPARQL
+//! results) and written to any target.
+//!
+//! ```rust
+//! use owlish::{
+//!     api::Ontology,
+//!     owl::{well_known, IRI},
+//!     rdf::{Graph, Triple},
+//! };
+//!
+//! let person = IRI::new("http://example.com#Person").unwrap();
+//! let mut graph = Graph::new();
+//! graph.insert(Triple::new(
+//!     IRI::new("http://example.com#").unwrap(),
+//!     well_known::rdf_type().as_iri().clone(),
+//!     well_known::owl_Ontology(),
+//! ));
+//! graph.insert(Triple::new(
+//!     person.clone(),
+//!     well_known::rdf_type().as_iri().clone(),
+//!     well_known::owl_Class().as_iri().clone(),
+//! ));
+//!
+//! let ontology = Ontology::from_graph(&graph, Default::default()).unwrap();
+//! assert_eq!(ontology.declarations().len(), 1);
+//!
+//! let graph = Graph::from(&ontology);
+//! assert_eq!(graph.with_subject(&person.into()).count(), 1);
+//! ```
+
+use std::borrow::Cow;
+
+use harriet::triple_production::{
+    RdfBlankNode, RdfIri, RdfLiteral, RdfObject, RdfPredicate, RdfSubject, RdfTriple,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::Ontology,
+    owl::{self, well_known, BlankNode, DatatypeIRI, Lang, ResourceId, IRI},
+};
+
+mod graph;
+pub use graph::*;
+
+/// A literal as it is written in RDF: the lexical form is not normalized.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Literal {
+    pub lexical_form: String,
+    /// `None` for simple literals (`xsd:string`) and language tagged strings.
+    pub datatype: Option<IRI>,
+    pub lang: Option<String>,
+}
+
+impl Literal {
+    /// A simple literal.
+    pub fn string<S: Into<String>>(lexical_form: S) -> Self {
+        Self {
+            lexical_form: lexical_form.into(),
+            datatype: None,
+            lang: None,
+        }
+    }
+
+    /// A literal of the given datatype. `xsd:string` literals are simple literals.
+    pub fn typed<S: Into<String>>(lexical_form: S, datatype: IRI) -> Self {
+        let datatype = (datatype.as_str() != well_known::xsd_string_str).then_some(datatype);
+        Self {
+            lexical_form: lexical_form.into(),
+            datatype,
+            lang: None,
+        }
+    }
+
+    /// A language tagged string.
+    pub fn lang_string<S: Into<String>, L: Into<String>>(lexical_form: S, lang: L) -> Self {
+        Self {
+            lexical_form: lexical_form.into(),
+            datatype: None,
+            lang: Some(lang.into()),
+        }
+    }
+}
+
+impl From<&owl::Literal> for Literal {
+    fn from(literal: &owl::Literal) -> Self {
+        let typed = |lexical: String, datatype: DatatypeIRI| {
+            Self::typed(lexical, datatype.as_iri().clone())
+        };
+        match literal {
+            owl::Literal::Raw { data, type_iri } => {
+                typed(String::from_utf8_lossy(data).to_string(), type_iri.clone())
+            }
+            owl::Literal::String(s) => Self::string(s.clone()),
+            owl::Literal::DateTime(d) => typed(d.clone(), well_known::xsd_dateTime()),
+            owl::Literal::LangString { string, lang } => {
+                Self::lang_string(string.clone(), lang.to_string())
+            }
+            owl::Literal::Number { number, type_iri } => match type_iri {
+                Some(type_iri) => typed(number.to_string(), type_iri.clone()),
+                None if number.is_f64() => typed(number.to_string(), well_known::xsd_decimal()),
+                None => typed(number.to_string(), well_known::xsd_integer()),
+            },
+            owl::Literal::Duration(duration) => {
+                typed(duration.to_string(), well_known::xsd_duration())
+            }
+            owl::Literal::YearMonthDuration(duration) => {
+                typed(duration.to_string(), well_known::xsd_yearMonthDuration())
+            }
+            owl::Literal::DayTimeDuration(duration) => {
+                typed(duration.to_string(), well_known::xsd_dayTimeDuration())
+            }
+            owl::Literal::Bool(b) => typed(b.to_string(), well_known::xsd_boolean()),
+        }
+    }
+}
+
+/// The lexical form is kept as it is, e.g. "1.50"^^xsd:decimal is not normalized. Invalid
+/// language tags are dropped.
+impl From<&Literal> for owl::Literal {
+    fn from(literal: &Literal) -> Self {
+        let lexical_form = literal.lexical_form.clone();
+        let lang = literal
+            .lang
+            .as_deref()
+            .and_then(|tag| Lang::try_from(tag).ok());
+        match (lang, &literal.datatype) {
+            (Some(lang), _) => owl::Literal::LangString {
+                string: lexical_form,
+                lang,
+            },
+            (None, Some(datatype)) => owl::Literal::Raw {
+                data: lexical_form.into_bytes(),
+                type_iri: datatype.clone().into(),
+            },
+            (None, None) => owl::Literal::String(lexical_form),
+        }
+    }
+}
+
+/// A node of an RDF graph.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Term {
+    IRI(IRI),
+    BlankNode(BlankNode),
+    Literal(Literal),
+}
+
+impl From<IRI> for Term {
+    fn from(iri: IRI) -> Self {
+        Self::IRI(iri)
+    }
+}
+
+impl From<BlankNode> for Term {
+    fn from(bn: BlankNode) -> Self {
+        Self::BlankNode(bn)
+    }
+}
+
+impl From<Literal> for Term {
+    fn from(literal: Literal) -> Self {
+        Self::Literal(literal)
+    }
+}
+
+impl From<ResourceId> for Term {
+    fn from(resource: ResourceId) -> Self {
+        match resource {
+            ResourceId::IRI(iri) => Self::IRI(iri),
+            ResourceId::BlankNode(bn) => Self::BlankNode(bn),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Triple {
+    pub subject: ResourceId,
+    pub predicate: IRI,
+    pub object: Term,
+}
+
+impl Triple {
+    pub fn new<S: Into<ResourceId>, O: Into<Term>>(subject: S, predicate: IRI, object: O) -> Self {
+        Self {
+            subject: subject.into(),
+            predicate,
+            object: object.into(),
+        }
+    }
+
+    /// Converts the triple for the OWL mapping of the parser.
+    pub(crate) fn to_rdf(&self) -> RdfTriple<'static> {
+        let iri = |iri: &IRI| RdfIri {
+            iri: Cow::Owned(iri.as_str().to_string()),
+        };
+        let blank_node = |bn: &BlankNode| RdfBlankNode {
+            internal_id: bn.internal_id(),
+        };
+        RdfTriple {
+            subject: match &self.subject {
+                ResourceId::IRI(i) => RdfSubject::IRI(iri(i)),
+                ResourceId::BlankNode(bn) => RdfSubject::BlankNode(blank_node(bn)),
+            },
+            predicate: RdfPredicate::IRI(iri(&self.predicate)),
+            object: match &self.object {
+                Term::IRI(i) => RdfObject::IRI(iri(i)),
+                Term::BlankNode(bn) => RdfObject::BlankNode(blank_node(bn)),
+                Term::Literal(literal) => RdfObject::Literal(RdfLiteral {
+                    lexical_form: Cow::Owned(literal.lexical_form.clone()),
+                    datatype_iri: literal.datatype.as_ref().map(iri),
+                    language_tag: literal.lang.clone().map(Cow::Owned),
+                }),
+            },
+        }
+    }
+}
+
+/// The mapping of an ontology to RDF, see https://www.w3.org/TR/owl2-mapping-to-rdf/
+pub trait ToTriples {
+    /// The triples of every declaration and axiom, including the reifications of annotated
+    /// axioms and the unmapped triples of the ontology.
+    fn to_triples(&self) -> Graph;
+}
+
+impl From<&Ontology> for Graph {
+    fn from(ontology: &Ontology) -> Self {
+        ontology.to_triples()
+    }
+}
@@ -0,0 +1,25 @@
+//! Reading and writing ontologies in [RDF/XML](https://www.w3.org/TR/rdf-syntax-grammar/).
+//!
+//! Documents are turned into the same triples the turtle parser produces, so the OWL mapping
+//! does not depend on the syntax the ontology was written in.
+//!
+//! ```rust
+//! use owlish::{api::Ontology, rdf_xml::ToRdfXml};
+//!
+//! let ontology = Ontology::parse(r#"
+//!     @prefix owl: <http://www.w3.org/2002/07/owl#> .
+//!     <http://example.com#> a owl:Ontology .
+//!     <http://example.com#Person> a owl:Class .
+//! "#, Default::default()).unwrap();
+//!
+//! let xml = ontology.rdf_xml();
+//! assert!(xml.contains(r#"<owl:Class rdf:about="http://example.com#Person"/>"#));
+//!
+//! let parsed = Ontology::parse_rdf_xml(&xml, Default::default()).unwrap();
+//! assert_eq!(parsed.declarations(), ontology.declarations());
+//! ```
+
+pub(crate) mod parser;
+pub(crate) mod serializer;
+
+pub use serializer::ToRdfXml;
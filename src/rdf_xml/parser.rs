@@ -0,0 +1,482 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use harriet::triple_production::{
+    RdfBlankNode, RdfIri, RdfLiteral, RdfObject, RdfPredicate, RdfSubject, RdfTriple,
+};
+use roxmltree::{Document, Node, ParsingOptions};
+use snowflake::ProcessUniqueId;
+
+use crate::{
+    api::Ontology,
+    error::{Error, ErrorKind, Position},
+    owl::well_known,
+    parser::{source::SourceMap, Diagnostic, ParserOptions},
+};
+
+pub(crate) const XML_NS: &str = "http://www.w3.org/XML/1998/namespace";
+
+impl Ontology {
+    /// Parses an ontology from an RDF/XML document.
+    pub fn parse_rdf_xml(xml: &str, options: ParserOptions) -> Result<Self, Error> {
+        Self::parse_rdf_xml_with_diagnostics(xml, options).map(|(ontology, _)| ontology)
+    }
+
+    /// Parses an ontology from an RDF/XML document and reports everything that could not be
+    /// understood (see [`Ontology::parse_with_diagnostics`]).
+    pub fn parse_rdf_xml_with_diagnostics(
+        xml: &str,
+        options: ParserOptions,
+    ) -> Result<(Self, Vec<Diagnostic>), Error> {
+        let document = parse_xml(xml)?;
+
+        let mut reader = Reader {
+            source: xml,
+            triples: Vec::new(),
+            positions: Vec::new(),
+            blank_nodes: HashMap::new(),
+        };
+        let root = document.root_element();
+        let context = Context::default().enter(&root);
+        if is_rdf(&root, "RDF") {
+            for node in element_children(&root, xml)? {
+                reader.node_element(&node, &context)?;
+            }
+        } else {
+            reader.node_element(&root, &Context::default())?;
+        }
+
+        let prefixes = root
+            .namespaces()
+            .filter_map(|ns| Some((ns.name()?.to_string(), ns.uri().to_string())))
+            .collect();
+        let source_map = SourceMap::for_triples(reader.positions);
+        Self::from_triples(reader.triples, prefixes, &source_map, options)
+    }
+}
+
+/// The inherited `xml:base` and `xml:lang` of an element.
+#[derive(Debug, Clone, Default)]
+struct Context {
+    base: String,
+    lang: Option<String>,
+}
+
+impl Context {
+    fn enter(&self, node: &Node) -> Self {
+        let mut context = self.clone();
+        if let Some(base) = node.attribute((XML_NS, "base")) {
+            context.base = resolve(&self.base, base);
+        }
+        if let Some(lang) = node.attribute((XML_NS, "lang")) {
+            context.lang = Some(lang.to_string()).filter(|l| !l.is_empty());
+        }
+        context
+    }
+}
+
+struct Reader<'s> {
+    source: &'s str,
+    triples: Vec<RdfTriple<'static>>,
+    positions: Vec<Option<Position>>,
+    blank_nodes: HashMap<String, RdfBlankNode>,
+}
+
+impl<'s> Reader<'s> {
+    fn push(
+        &mut self,
+        node: &Node,
+        subject: RdfSubject<'static>,
+        predicate: String,
+        object: RdfObject<'static>,
+    ) {
+        self.triples.push(RdfTriple {
+            subject,
+            predicate: RdfPredicate::IRI(iri(predicate)),
+            object,
+        });
+        self.positions.push(Some(position(self.source, node)));
+    }
+
+    fn blank_node(&mut self, label: Option<&str>) -> RdfBlankNode {
+        let fresh = || RdfBlankNode {
+            internal_id: ProcessUniqueId::new(),
+        };
+        match label {
+            Some(label) => self
+                .blank_nodes
+                .entry(label.to_string())
+                .or_insert_with(fresh)
+                .clone(),
+            None => fresh(),
+        }
+    }
+
+    /// The node a node element describes.
+    fn subject(&mut self, node: &Node, context: &Context) -> RdfSubject<'static> {
+        if let Some(about) = node.attribute((rdf(), "about")) {
+            RdfSubject::IRI(iri(resolve(&context.base, about)))
+        } else if let Some(id) = node.attribute((rdf(), "ID")) {
+            RdfSubject::IRI(iri(resolve(&context.base, &format!("#{}", id))))
+        } else {
+            RdfSubject::BlankNode(self.blank_node(node.attribute((rdf(), "nodeID"))))
+        }
+    }
+
+    /// Reads a node element with its property elements.
+    fn node_element(&mut self, node: &Node, parent: &Context) -> Result<(), Error> {
+        let context = parent.enter(node);
+        let subject = self.subject(node, &context);
+        self.describe(node, &context, subject)
+    }
+
+    fn describe(
+        &mut self,
+        node: &Node,
+        context: &Context,
+        subject: RdfSubject<'static>,
+    ) -> Result<(), Error> {
+        if !is_rdf(node, "Description") {
+            self.push(
+                node,
+                subject.clone(),
+                rdf_iri("type"),
+                RdfObject::IRI(iri(element_iri(node, self.source)?)),
+            );
+        }
+        for attribute in node.attributes() {
+            let (namespace, name) = (attribute.namespace(), attribute.name());
+            if namespace == Some(XML_NS)
+                || (namespace == Some(rdf()) && ["about", "ID", "nodeID"].contains(&name))
+            {
+                continue;
+            }
+            let namespace = namespace.ok_or_else(|| {
+                syntax_error(
+                    self.source,
+                    node,
+                    format!("Attribute '{}' has no namespace", name),
+                )
+            })?;
+            let object = if namespace == rdf() && name == "type" {
+                RdfObject::IRI(iri(resolve(&context.base, attribute.value())))
+            } else {
+                literal(attribute.value(), None, context.lang.as_deref())
+            };
+            self.push(
+                node,
+                subject.clone(),
+                format!("{}{}", namespace, name),
+                object,
+            );
+        }
+
+        // Nested nodes are described after the node itself, so the triples of a node stay
+        // together.
+        let mut nested = Vec::new();
+        let mut li = 0;
+        for property in element_children(node, self.source)? {
+            let mut predicate = element_iri(&property, self.source)?;
+            if predicate == rdf_iri("li") {
+                li += 1;
+                predicate = rdf_iri(&format!("_{}", li));
+            }
+            self.property_element(&property, &subject, predicate, context, &mut nested)?;
+        }
+        for (node, context, subject) in nested {
+            self.describe(&node, &context, subject)?;
+        }
+
+        Ok(())
+    }
+
+    fn property_element<'a, 'i>(
+        &mut self,
+        node: &Node<'a, 'i>,
+        subject: &RdfSubject<'static>,
+        predicate: String,
+        parent: &Context,
+        nested: &mut Vec<(Node<'a, 'i>, Context, RdfSubject<'static>)>,
+    ) -> Result<(), Error> {
+        let context = parent.enter(node);
+        let children = element_children(node, self.source)?;
+
+        match node.attribute((rdf(), "parseType")) {
+            Some("Resource") => {
+                let bn = self.blank_node(None);
+                let object = RdfSubject::BlankNode(bn.clone());
+                self.state(node, &context, subject, predicate, RdfObject::BlankNode(bn));
+                for property in children {
+                    let predicate = element_iri(&property, self.source)?;
+                    self.property_element(&property, &object, predicate, &context, nested)?;
+                }
+            }
+            Some("Collection") => {
+                let cells: Vec<RdfBlankNode> =
+                    children.iter().map(|_| self.blank_node(None)).collect();
+                let list = |i: usize| match cells.get(i) {
+                    Some(bn) => RdfObject::BlankNode(bn.clone()),
+                    None => RdfObject::IRI(iri(rdf_iri("nil"))),
+                };
+                self.state(node, &context, subject, predicate, list(0));
+                for (i, item) in children.into_iter().enumerate() {
+                    let item_context = context.enter(&item);
+                    let object = self.subject(&item, &item_context);
+                    let cell = RdfSubject::BlankNode(cells[i].clone());
+                    self.push(node, cell.clone(), rdf_iri("first"), object_of(&object));
+                    self.push(node, cell, rdf_iri("rest"), list(i + 1));
+                    nested.push((item, item_context, object));
+                }
+            }
+            Some(_) => {
+                let literal = RdfObject::Literal(RdfLiteral {
+                    lexical_form: Cow::Owned(inner_xml(node, self.source).to_string()),
+                    datatype_iri: Some(iri(rdf_iri("XMLLiteral"))),
+                    language_tag: None,
+                });
+                self.state(node, &context, subject, predicate, literal);
+            }
+            None if !children.is_empty() => {
+                if children.len() > 1 {
+                    return Err(syntax_error(
+                        self.source,
+                        node,
+                        "A property element can only contain a single node".into(),
+                    ));
+                }
+                let child = children[0];
+                let child_context = context.enter(&child);
+                let object = self.subject(&child, &child_context);
+                self.state(node, &context, subject, predicate, object_of(&object));
+                nested.push((child, child_context, object));
+            }
+            None => {
+                let resource = node
+                    .attribute((rdf(), "resource"))
+                    .map(|r| RdfSubject::IRI(iri(resolve(&context.base, r))))
+                    .or_else(|| {
+                        node.attribute((rdf(), "nodeID"))
+                            .map(|l| RdfSubject::BlankNode(self.blank_node(Some(l))))
+                    });
+                let properties: Vec<_> = node
+                    .attributes()
+                    .filter(|a| {
+                        a.namespace() != Some(XML_NS)
+                            && !(a.namespace() == Some(rdf())
+                                && ["resource", "nodeID", "ID", "datatype"].contains(&a.name()))
+                    })
+                    .collect();
+                if resource.is_none() && properties.is_empty() {
+                    let literal = literal(
+                        node.text().unwrap_or_default(),
+                        node.attribute((rdf(), "datatype"))
+                            .map(|dt| resolve(&context.base, dt)),
+                        context.lang.as_deref(),
+                    );
+                    self.state(node, &context, subject, predicate, literal);
+                    return Ok(());
+                }
+
+                // property attributes describe the (possibly anonymous) object
+                let object =
+                    resource.unwrap_or_else(|| RdfSubject::BlankNode(self.blank_node(None)));
+                self.state(node, &context, subject, predicate, object_of(&object));
+                for attribute in properties {
+                    let Some(namespace) = attribute.namespace() else {
+                        return Err(syntax_error(
+                            self.source,
+                            node,
+                            format!("Attribute '{}' has no namespace", attribute.name()),
+                        ));
+                    };
+                    let value = if namespace == rdf() && attribute.name() == "type" {
+                        RdfObject::IRI(iri(resolve(&context.base, attribute.value())))
+                    } else {
+                        literal(attribute.value(), None, context.lang.as_deref())
+                    };
+                    self.push(
+                        node,
+                        object.clone(),
+                        format!("{}{}", namespace, attribute.name()),
+                        value,
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// States the triple of a property element.
+    fn state(
+        &mut self,
+        node: &Node,
+        context: &Context,
+        subject: &RdfSubject<'static>,
+        predicate: String,
+        object: RdfObject<'static>,
+    ) {
+        self.push(node, subject.clone(), predicate.clone(), object.clone());
+        self.reify(node, context, subject, &predicate, &object);
+    }
+
+    /// A property element with an `rdf:ID` reifies the triple it states.
+    fn reify(
+        &mut self,
+        node: &Node,
+        context: &Context,
+        subject: &RdfSubject<'static>,
+        predicate: &str,
+        object: &RdfObject<'static>,
+    ) {
+        let Some(id) = node.attribute((rdf(), "ID")) else {
+            return;
+        };
+        let statement = RdfSubject::IRI(iri(resolve(&context.base, &format!("#{}", id))));
+        for (p, o) in [
+            ("type", RdfObject::IRI(iri(rdf_iri("Statement")))),
+            ("subject", object_of(subject)),
+            ("predicate", RdfObject::IRI(iri(predicate.to_string()))),
+            ("object", object.clone()),
+        ] {
+            self.push(node, statement.clone(), rdf_iri(p), o);
+        }
+    }
+}
+
+/// Parses an XML document, which may declare entities in its DTD.
+pub(crate) fn parse_xml(xml: &str) -> Result<Document<'_>, Error> {
+    Document::parse_with_options(
+        xml,
+        ParsingOptions {
+            allow_dtd: true,
+            ..Default::default()
+        },
+    )
+    .map_err(|e| {
+        let pos = e.pos();
+        Error::with_kind(ErrorKind::Syntax, format!("Invalid XML: {}", e)).at_position(Position {
+            line: pos.row as usize,
+            column: pos.col as usize,
+        })
+    })
+}
+
+fn rdf() -> &'static str {
+    well_known::rdf_base_str
+}
+
+fn rdf_iri(name: &str) -> String {
+    format!("{}{}", rdf(), name)
+}
+
+fn is_rdf(node: &Node, name: &str) -> bool {
+    node.tag_name().namespace() == Some(rdf()) && node.tag_name().name() == name
+}
+
+fn iri(iri: String) -> RdfIri<'static> {
+    RdfIri {
+        iri: Cow::Owned(iri),
+    }
+}
+
+fn object_of(node: &RdfSubject<'static>) -> RdfObject<'static> {
+    match node {
+        RdfSubject::IRI(iri) => RdfObject::IRI(iri.clone()),
+        RdfSubject::BlankNode(bn) => RdfObject::BlankNode(bn.clone()),
+    }
+}
+
+fn literal(value: &str, datatype: Option<String>, lang: Option<&str>) -> RdfObject<'static> {
+    RdfObject::Literal(RdfLiteral {
+        lexical_form: Cow::Owned(value.to_string()),
+        language_tag: match datatype {
+            Some(_) => None,
+            None => lang.map(|l| Cow::Owned(l.to_string())),
+        },
+        datatype_iri: datatype.map(iri),
+    })
+}
+
+fn position(source: &str, node: &Node) -> Position {
+    Position::from_offset(source, node.range().start)
+}
+
+pub(crate) fn syntax_error(source: &str, node: &Node, message: String) -> Error {
+    Error::with_kind(ErrorKind::Syntax, message).at_position(position(source, node))
+}
+
+fn element_iri(node: &Node, source: &str) -> Result<String, Error> {
+    let name = node.tag_name();
+    match name.namespace() {
+        Some(namespace) => Ok(format!("{}{}", namespace, name.name())),
+        None => Err(syntax_error(
+            source,
+            node,
+            format!("Element '{}' has no namespace", name.name()),
+        )),
+    }
+}
+
+/// The element children of a node. Other than whitespace and comments, text is not allowed
+/// next to them.
+pub(crate) fn element_children<'a, 'i>(
+    node: &Node<'a, 'i>,
+    source: &str,
+) -> Result<Vec<Node<'a, 'i>>, Error> {
+    let mut children = Vec::new();
+    let has_elements = node.children().any(|c| c.is_element());
+    for child in node.children() {
+        if child.is_element() {
+            children.push(child);
+        } else if has_elements && child.is_text() && !child.text().unwrap_or("").trim().is_empty() {
+            return Err(syntax_error(
+                source,
+                &child,
+                "Text is not allowed next to elements".into(),
+            ));
+        }
+    }
+    Ok(children)
+}
+
+/// The unparsed content of an element.
+fn inner_xml<'s>(node: &Node, source: &'s str) -> &'s str {
+    match (node.first_child(), node.last_child()) {
+        (Some(first), Some(last)) => &source[first.range().start..last.range().end],
+        _ => "",
+    }
+}
+
+/// Resolves a (possibly relative) IRI reference against a base IRI.
+pub(crate) fn resolve(base: &str, reference: &str) -> String {
+    let has_scheme = reference
+        .split_once(':')
+        .map(|(scheme, _)| {
+            !scheme.is_empty()
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+        })
+        .unwrap_or(false);
+    if has_scheme || base.is_empty() {
+        return reference.to_string();
+    }
+    let base = base.split('#').next().unwrap_or(base);
+    if reference.is_empty() {
+        base.to_string()
+    } else if reference.starts_with('#') {
+        format!("{}{}", base, reference)
+    } else if reference.starts_with("//") {
+        let scheme = base.split_once(':').map(|(s, _)| s).unwrap_or("http");
+        format!("{}:{}", scheme, reference)
+    } else if reference.starts_with('/') {
+        // keep scheme and authority
+        let authority_end = base
+            .find("://")
+            .and_then(|i| base[i + 3..].find('/').map(|j| i + 3 + j))
+            .unwrap_or(base.len());
+        format!("{}{}", &base[..authority_end], reference)
+    } else {
+        let directory = base.rfind('/').map(|i| &base[..=i]).unwrap_or(base);
+        format!("{}{}", directory, reference)
+    }
+}
@@ -0,0 +1,370 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use snowflake::ProcessUniqueId;
+
+use crate::{
+    api::Ontology,
+    owl::{well_known, ResourceId},
+    rdf::{Graph, Term, ToTriples},
+};
+
+pub trait ToRdfXml {
+    fn rdf_xml(&self) -> String;
+}
+
+impl ToRdfXml for Ontology {
+    fn rdf_xml(&self) -> String {
+        let triples = self.to_triples();
+
+        let mut namespaces: BTreeMap<String, String> = [
+            ("owl", well_known::owl_base_str),
+            ("rdf", well_known::rdf_base_str),
+            ("rdfs", well_known::rdfs_base_str),
+            ("xsd", well_known::xsd_base_str),
+        ]
+        .into_iter()
+        .map(|(prefix, iri)| (prefix.to_string(), iri.to_string()))
+        .collect();
+        for (prefix, iri) in self.imports() {
+            if is_ncname(prefix) && !prefix.to_lowercase().starts_with("xml") {
+                namespaces
+                    .entry(prefix.clone())
+                    .or_insert_with(|| iri.as_str().to_string());
+            }
+        }
+
+        Writer::new(&triples, namespaces).write()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Node {
+    Iri(String),
+    Blank(ProcessUniqueId),
+}
+
+#[derive(Debug, Clone)]
+enum Object {
+    Node(Node),
+    Literal {
+        lexical_form: String,
+        datatype: Option<String>,
+        lang: Option<String>,
+    },
+}
+
+struct Writer {
+    namespaces: BTreeMap<String, String>,
+    /// subjects in the order of their first statement
+    subjects: Vec<Node>,
+    statements: HashMap<Node, Vec<(String, Object)>>,
+    /// how often a blank node is used as object
+    references: HashMap<ProcessUniqueId, usize>,
+    /// element names of predicates and types (`None` if the IRI can't be written as such)
+    qnames: HashMap<String, Option<String>>,
+    labels: HashMap<ProcessUniqueId, String>,
+    written: HashSet<Node>,
+    out: String,
+}
+
+impl Writer {
+    fn new(triples: &Graph, namespaces: BTreeMap<String, String>) -> Self {
+        let mut writer = Writer {
+            namespaces,
+            subjects: Vec::new(),
+            statements: HashMap::new(),
+            references: HashMap::new(),
+            qnames: HashMap::new(),
+            labels: HashMap::new(),
+            written: HashSet::new(),
+            out: String::new(),
+        };
+        let rdf_type = well_known::rdf_type().as_str().to_string();
+        for triple in triples {
+            let subject = match &triple.subject {
+                ResourceId::IRI(iri) => Node::Iri(iri.as_str().to_string()),
+                ResourceId::BlankNode(bn) => Node::Blank(bn.internal_id()),
+            };
+            let predicate = triple.predicate.as_str().to_string();
+            let object = match &triple.object {
+                Term::IRI(iri) => Object::Node(Node::Iri(iri.as_str().to_string())),
+                Term::BlankNode(bn) => {
+                    *writer.references.entry(bn.internal_id()).or_default() += 1;
+                    Object::Node(Node::Blank(bn.internal_id()))
+                }
+                Term::Literal(l) => Object::Literal {
+                    lexical_form: l.lexical_form.clone(),
+                    datatype: l.datatype.as_ref().map(|dt| dt.as_str().to_string()),
+                    lang: l.lang.clone(),
+                },
+            };
+            writer.qname(&predicate);
+            if let (true, Object::Node(Node::Iri(class))) = (predicate == rdf_type, &object) {
+                writer.qname(class);
+            }
+            if !writer.statements.contains_key(&subject) {
+                writer.subjects.push(subject.clone());
+            }
+            writer
+                .statements
+                .entry(subject)
+                .or_default()
+                .push((predicate, object));
+        }
+        writer
+    }
+
+    /// Determines the element name of an IRI and declares a namespace for it if necessary.
+    fn qname(&mut self, iri: &str) {
+        if self.qnames.contains_key(iri) {
+            return;
+        }
+        let mut qname = self
+            .namespaces
+            .iter()
+            .filter(|(_, ns)| iri.starts_with(ns.as_str()) && is_ncname(&iri[ns.len()..]))
+            .max_by_key(|(_, ns)| ns.len())
+            .map(|(prefix, ns)| format!("{}:{}", prefix, &iri[ns.len()..]));
+        if qname.is_none() {
+            let split = iri.rfind(['#', '/']).map(|i| i + 1).unwrap_or(0);
+            if split > 0 && is_ncname(&iri[split..]) {
+                let prefix = (1..)
+                    .map(|i| format!("ns{}", i))
+                    .find(|p| !self.namespaces.contains_key(p))
+                    .unwrap();
+                self.namespaces
+                    .insert(prefix.clone(), iri[..split].to_string());
+                qname = Some(format!("{}:{}", prefix, &iri[split..]));
+            }
+        }
+        self.qnames.insert(iri.to_string(), qname);
+    }
+
+    fn label(&mut self, bn: ProcessUniqueId) -> String {
+        let next = self.labels.len() + 1;
+        self.labels
+            .entry(bn)
+            .or_insert_with(|| format!("b{}", next))
+            .clone()
+    }
+
+    fn write(mut self) -> String {
+        self.out
+            .push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<rdf:RDF");
+        let namespaces: Vec<String> = self
+            .namespaces
+            .iter()
+            .map(|(prefix, ns)| format!("xmlns:{}=\"{}\"", prefix, escape_attribute(ns)))
+            .collect();
+        self.out.push(' ');
+        self.out.push_str(&namespaces.join("\n         "));
+        self.out.push_str(">\n");
+
+        // Blank nodes which are used exactly once are nested into the property element
+        // referencing them, all other nodes are written at the top level.
+        let subjects = self.subjects.clone();
+        for subject in &subjects {
+            let nested = match subject {
+                Node::Blank(bn) => self.references.get(bn) == Some(&1),
+                Node::Iri(_) => false,
+            };
+            if !nested {
+                self.node(subject, 1, false);
+            }
+        }
+        // blank nodes that only reference each other
+        for subject in &subjects {
+            if !self.written.contains(subject) {
+                self.node(subject, 1, false);
+            }
+        }
+
+        self.out.push_str("</rdf:RDF>\n");
+        self.out
+    }
+
+    /// Writes a node element.
+    fn node(&mut self, node: &Node, level: usize, nested: bool) {
+        self.written.insert(node.clone());
+        let mut statements = self.statements.get(node).cloned().unwrap_or_default();
+        let rdf_type = well_known::rdf_type().as_str().to_string();
+
+        let typed = statements.iter().position(|(p, o)| match o {
+            Object::Node(Node::Iri(class)) if *p == rdf_type => self.qnames[class].is_some(),
+            _ => false,
+        });
+        let element = match typed.map(|i| statements.remove(i)) {
+            Some((_, Object::Node(Node::Iri(class)))) => self.qnames[&class].clone().unwrap(),
+            _ => "rdf:Description".to_string(),
+        };
+        let attribute = match node {
+            Node::Iri(iri) => format!(" rdf:about=\"{}\"", escape_attribute(iri)),
+            Node::Blank(_) if nested => String::new(),
+            Node::Blank(bn) => format!(" rdf:nodeID=\"{}\"", self.label(*bn)),
+        };
+
+        self.indent(level);
+        if statements.is_empty() {
+            self.out.push_str(&format!("<{}{}/>\n", element, attribute));
+            return;
+        }
+        self.out.push_str(&format!("<{}{}>\n", element, attribute));
+        for (predicate, object) in statements {
+            self.property(&predicate, &object, level + 1);
+        }
+        self.indent(level);
+        self.out.push_str(&format!("</{}>\n", element));
+    }
+
+    /// Writes a property element.
+    fn property(&mut self, predicate: &str, object: &Object, level: usize) {
+        let Some(element) = self.qnames[predicate].clone() else {
+            self.indent(level);
+            self.out.push_str(&format!(
+                "<!-- omitted statement: <{}> can not be written as element name -->\n",
+                escape_comment(predicate)
+            ));
+            return;
+        };
+        self.indent(level);
+        match object {
+            Object::Node(Node::Iri(iri)) => {
+                self.out.push_str(&format!(
+                    "<{} rdf:resource=\"{}\"/>\n",
+                    element,
+                    escape_attribute(iri)
+                ));
+            }
+            Object::Node(node @ Node::Blank(bn)) => {
+                if !self.nestable(node) {
+                    let label = self.label(*bn);
+                    self.out
+                        .push_str(&format!("<{} rdf:nodeID=\"{}\"/>\n", element, label));
+                } else if let Some(items) = self.collection(node) {
+                    self.out
+                        .push_str(&format!("<{} rdf:parseType=\"Collection\">\n", element));
+                    // the cells of the list are written as part of the collection
+                    for (cell, item) in items {
+                        self.written.insert(cell);
+                        self.list_item(&item, level + 1);
+                    }
+                    self.indent(level);
+                    self.out.push_str(&format!("</{}>\n", element));
+                } else {
+                    self.out.push_str(&format!("<{}>\n", element));
+                    self.node(node, level + 1, true);
+                    self.indent(level);
+                    self.out.push_str(&format!("</{}>\n", element));
+                }
+            }
+            Object::Literal {
+                lexical_form,
+                datatype,
+                lang,
+            } => {
+                let attribute = match (lang, datatype) {
+                    (Some(lang), _) => format!(" xml:lang=\"{}\"", escape_attribute(lang)),
+                    (None, Some(datatype)) => {
+                        format!(" rdf:datatype=\"{}\"", escape_attribute(datatype))
+                    }
+                    (None, None) => String::new(),
+                };
+                self.out.push_str(&format!(
+                    "<{}{}>{}</{}>\n",
+                    element,
+                    attribute,
+                    escape_text(lexical_form),
+                    element
+                ));
+            }
+        }
+    }
+
+    fn list_item(&mut self, item: &Node, level: usize) {
+        match item {
+            Node::Blank(_) if self.nestable(item) => self.node(item, level, true),
+            Node::Blank(bn) => {
+                let label = self.label(*bn);
+                self.indent(level);
+                self.out
+                    .push_str(&format!("<rdf:Description rdf:nodeID=\"{}\"/>\n", label));
+            }
+            Node::Iri(iri) => {
+                self.indent(level);
+                self.out.push_str(&format!(
+                    "<rdf:Description rdf:about=\"{}\"/>\n",
+                    escape_attribute(iri)
+                ));
+            }
+        }
+    }
+
+    fn nestable(&self, node: &Node) -> bool {
+        match node {
+            Node::Blank(bn) => self.references.get(bn) == Some(&1) && !self.written.contains(node),
+            Node::Iri(_) => false,
+        }
+    }
+
+    /// The cells and items of a list which can be written with `rdf:parseType="Collection"`:
+    /// every cell is used once and consists of an `rdf:first` resource and an `rdf:rest` only.
+    fn collection(&self, node: &Node) -> Option<Vec<(Node, Node)>> {
+        let (first, rest) = (well_known::rdf_first(), well_known::rdf_rest());
+        let nil = Node::Iri(well_known::rdf_nil().as_str().to_string());
+        let mut cells = Vec::new();
+        let mut cell = node.clone();
+        while cell != nil {
+            if !self.nestable(&cell) || cells.iter().any(|(c, _)| *c == cell) {
+                return None;
+            }
+            let statements = self.statements.get(&cell)?;
+            let object = |predicate: &str| {
+                statements
+                    .iter()
+                    .filter(|(p, _)| p == predicate)
+                    .map(|(_, o)| o)
+                    .collect::<Vec<_>>()
+            };
+            let (firsts, rests) = (object(first.as_str()), object(rest.as_str()));
+            match (firsts.as_slice(), rests.as_slice()) {
+                ([Object::Node(item)], [Object::Node(next)]) if statements.len() == 2 => {
+                    cells.push((cell, item.clone()));
+                    cell = next.clone();
+                }
+                _ => return None,
+            }
+        }
+        Some(cells)
+    }
+
+    fn indent(&mut self, level: usize) {
+        self.out.push_str(&"    ".repeat(level));
+    }
+}
+
+pub(crate) fn is_ncname(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_alphanumeric() || "_-.".contains(c))
+}
+
+pub(crate) fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\r', "&#13;")
+}
+
+pub(crate) fn escape_attribute(text: &str) -> String {
+    escape_text(text)
+        .replace('"', "&quot;")
+        .replace('\n', "&#10;")
+        .replace('\t', "&#9;")
+}
+
+pub(crate) fn escape_comment(text: &str) -> String {
+    text.replace("--", "- -")
+}
@@ -1,377 +1,1039 @@
-use std::{cmp::Ordering, collections::HashMap};
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
 
+use crate::owl::ResourceId;
+use crate::rdf::{self, Graph, Term, ToTriples};
 use crate::{
     api::Ontology,
     owl::{
-        well_known, AnnotationPropertyIRI, ClassConstructor, ClassIRI, DataPropertyIRI,
-        DatatypeIRI, IndividualIRI, Literal, LiteralOrIRI, ObjectPropertyConstructor,
-        ObjectPropertyIRI, IRI,
+        well_known, Annotation, AnnotationPropertyIRI, Axiom, BlankNode, ClassConstructor,
+        ClassIRI, DataPropertyIRI, DatatypeDefinitionConstructor, DatatypeIRI, IRIList,
+        IndividualIRI, Literal, LiteralOrIRI, ObjectPropertyConstructor, ObjectPropertyIRI, IRI,
     },
 };
-use crate::owl::ResourceId;
+
+mod pretty;
+pub use pretty::*;
 
 pub trait ToTtl {
     fn ttl(&self) -> String;
+
+    /// Turtle written as configured, see [`TtlOptions::canonical`] for output that only depends
+    /// on the content of the ontology. Implementations without options write [`ToTtl::ttl`].
+    fn ttl_with_options(&self, _options: &TtlOptions) -> String {
+        self.ttl()
+    }
+}
+
+/// A node of the mapping of an ontology to RDF. Anonymous nodes and collections keep the layout
+/// they are written with in turtle.
+#[derive(Debug, Clone)]
+enum Node {
+    Iri(IRI),
+    Literal(Literal),
+    /// A blank node of the ontology, e.g. the reification of an axiom.
+    Blank(BlankNode),
+    /// A blank node labelled by the serializer, see [`BlankNodes`].
+    Label(usize),
+    /// `[]`
+    Empty,
+    /// `[ p o ; ... ]`
+    Anonymous(Vec<(IRI, Node)>, Layout),
+    /// `( ... )`
+    Collection(Vec<Node>, Layout),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Layout {
+    /// Every property on a line of its own.
+    Lines,
+    /// Like [`Layout::Lines`], with a space after the last object.
+    LinesTrailingSpace,
+    /// `[ p o ; p o ]`, `( a b )`
+    Inline,
+    /// `[p o]`, `( a b)`
+    Compact,
 }
 
 enum Triple {
-    T(String, String, String),
+    Base(IRI),
+    Prefix(String, IRI),
+    /// `<iri> rdf:type owl:Ontology .`, the IRI is never abbreviated.
+    Ontology(IRI),
+    /// `s p1 o1 ; p2 o2 .` with the objects being written at the given nesting level.
+    T {
+        subject: Node,
+        properties: Vec<(IRI, Node)>,
+        inline: bool,
+        level: usize,
+    },
     Comment(String),
     LB,
 }
 
-fn t(s: String, p: String, o: String) -> Triple {
-    Triple::T(s, p, o)
+fn t(s: Node, p: IRI, o: Node) -> Triple {
+    Triple::T {
+        subject: s,
+        properties: vec![(p, o)],
+        inline: false,
+        level: 1,
+    }
 }
 
-impl ToTtl for Ontology {
-    fn ttl(&self) -> String {
-        let mut triples: Vec<Triple> = Vec::new();
-
-        let mut sorted_imports: Vec<(&String, &IRI)> = self.imports.iter().collect();
-        sorted_imports.sort_by(|a, b| {
-            #[allow(clippy::comparison_chain)]
-            if a.0.len() > b.0.len() {
-                Ordering::Greater
-            } else if a.0.len() == b.0.len() {
-                a.0.cmp(b.0)
-            } else {
-                Ordering::Less
-            }
-        });
-
-        for (pre, iri) in sorted_imports {
-            triples.push(t(
-                "@prefix".into(),
-                format!("{}:", pre),
-                iri.ttl(&Default::default()),
-            ));
-        }
-
-        triples.push(Triple::LB);
-
-        triples.push(t(
-            self.iri.ttl(&Default::default()),
-            well_known::rdf_type().ttl(&self.imports),
-            well_known::owl_Ontology().ttl(&self.imports),
-        ));
+fn anonymous(properties: Vec<(IRI, Node)>) -> Node {
+    Node::Anonymous(properties, Layout::Lines)
+}
 
-        triples.push(Triple::LB);
-        triples.push(Triple::Comment("#### Declarations #####".into()));
-        triples.push(Triple::LB);
+/// Generates labels for anonymous nodes which have to be referenced, e.g. as subject or from a
+/// reification.
+#[derive(Default)]
+struct BlankNodes(Cell<usize>);
 
-        let imports = &self.imports;
+impl BlankNodes {
+    fn next(&self) -> Node {
+        self.0.set(self.0.get() + 1);
+        Node::Label(self.0.get())
+    }
 
-        for d in self.declarations() {
-            match d {
-                crate::owl::Declaration::Class {
-                    iri,
-                    annotations: _,
-                } => triples.push(t(
-                    iri.ttl(imports),
-                    well_known::rdf_type().ttl(imports),
-                    well_known::owl_Class().ttl(imports),
-                )),
-                crate::owl::Declaration::NamedIndividual {
-                    iri,
-                    annotations: _,
-                } => triples.push(t(
-                    iri.ttl(imports),
-                    well_known::rdf_type().ttl(imports),
-                    well_known::owl_NamedIndividual().ttl(imports),
-                )),
-                crate::owl::Declaration::ObjectProperty {
-                    iri,
-                    annotations: _,
-                } => triples.push(t(
-                    iri.ttl(imports),
-                    well_known::rdf_type().ttl(imports),
-                    well_known::owl_ObjectProperty().ttl(imports),
-                )),
-                crate::owl::Declaration::DataProperty {
-                    iri,
-                    annotations: _,
-                } => triples.push(t(
-                    iri.ttl(imports),
-                    well_known::rdf_type().ttl(imports),
-                    well_known::owl_DatatypeProperty().ttl(imports),
-                )),
-                crate::owl::Declaration::AnnotationProperty {
-                    iri,
-                    annotations: _,
-                } => triples.push(t(
-                    iri.ttl(imports),
-                    well_known::rdf_type().ttl(imports),
-                    well_known::owl_AnnotationProperty().ttl(imports),
-                )),
-                crate::owl::Declaration::Datatype {
-                    iri,
-                    annotations: _,
-                } => triples.push(t(
-                    iri.ttl(imports),
-                    well_known::rdf_type().ttl(imports),
-                    well_known::owl_Datatype().ttl(imports),
-                )),
+    /// Turtle neither allows anonymous nodes (`[ ... ]`) as subject nor references to them, so
+    /// they are described by a labelled blank node instead. Other nodes are returned as they are.
+    fn label(&self, node: Node, triples: &mut Vec<Triple>) -> Node {
+        match node {
+            Node::Anonymous(properties, layout) if !properties.is_empty() => {
+                let label = self.next();
+                triples.push(Triple::T {
+                    subject: label.clone(),
+                    properties,
+                    inline: matches!(layout, Layout::Inline | Layout::Compact),
+                    level: 2,
+                });
+                label
             }
+            node => node,
         }
+    }
+}
 
-        let mut class_assertions: Vec<(Triple, Vec<Triple>)> = Vec::new();
-        let mut sub_class_ofs: Vec<(Triple, Vec<Triple>)> = Vec::new();
-
-        let mut anno_prop_assertions: Vec<Triple> = Vec::new();
-        let mut anno_prop_domains_ranges: Vec<Triple> = Vec::new();
-
-        let mut data_prop_assertions: Vec<Triple> = Vec::new();
-        let mut data_prop_domains_ranges: Vec<Triple> = Vec::new();
-
-        let mut obj_prop_assertions: Vec<Triple> = Vec::new();
-        let mut obj_prop_domains_ranges: Vec<Triple> = Vec::new();
-
-        for a in self.axioms() {
-            match a {
-                crate::owl::Axiom::AnnotationAssertion(a) => {
-                    match &a.subject {
-                        ResourceId::IRI(subject_iri) => {
-                            anno_prop_assertions.push(t(
-                                subject_iri.ttl(imports),
-                                a.iri.ttl(imports),
-                                a.value.ttl(imports),
-                            ));
-                        }
-                        ResourceId::BlankNode(_) => {
-                            unimplemented!("Can't serialize AnnotationAssertion with blankNode subject")
-                        }
-                    }
+impl ToTtl for Ontology {
+    fn ttl(&self) -> String {
+        let blank_nodes = BlankNodes::default();
+        let imports: &HashMap<String, IRI> = &prefixes(self, |a, b| a.cmp(b))
+            .into_iter()
+            .map(|(name, iri)| (name.clone(), iri.clone()))
+            .collect();
+        let mut comments = comments(self);
+        let mut ttl = String::new();
+        for triple in mapping(self, &blank_nodes) {
+            match triple {
+                Triple::Base(iri) => {
+                    ttl.push_str(&format!("@base {} . \n", iri.ttl(&Default::default())));
                 }
-                crate::owl::Axiom::DataPropertyAssertion(d) => {
-                    data_prop_assertions.push(t(
-                        d.subject.ttl(imports),
-                        d.iri.ttl(imports),
-                        d.value.ttl(imports),
+                Triple::Prefix(prefix, iri) => {
+                    ttl.push_str(&format!(
+                        "@prefix {}: {} . \n",
+                        prefix,
+                        iri.ttl(&Default::default())
                     ));
                 }
-                crate::owl::Axiom::ClassAssertion(c) => {
-                    let (blank_node, triples) = class_triples(&c.cls, imports, 1);
-                    class_assertions.push((
-                        t(
-                            c.individual.ttl(imports),
-                            well_known::rdf_type().ttl(imports),
-                            blank_node,
-                        ),
-                        triples,
+                Triple::Ontology(iri) => {
+                    for comment in comments.remove(&iri).unwrap_or_default() {
+                        ttl.push_str(&comment_lines(comment));
+                    }
+                    ttl.push_str(&format!(
+                        "{} {} {} . \n",
+                        iri.ttl(&Default::default()),
+                        rdf_type().ttl(imports),
+                        well_known::owl_Ontology().ttl(imports)
                     ));
                 }
-                crate::owl::Axiom::ObjectPropertyAssertion(o) => {
-                    obj_prop_assertions.push(t(
-                        o.subject.ttl(imports),
-                        o.iri.ttl(imports),
-                        o.object.ttl(imports),
-                    ));
+                Triple::T {
+                    subject,
+                    properties,
+                    inline,
+                    level,
+                } => {
+                    let mut described = Vec::new();
+                    let subject = blank_nodes.label(subject, &mut described);
+                    described.push(Triple::T {
+                        subject,
+                        properties,
+                        inline,
+                        level,
+                    });
+                    for triple in described {
+                        if let Triple::T {
+                            subject,
+                            properties,
+                            inline,
+                            level,
+                        } = triple
+                        {
+                            if let Node::Iri(iri) = &subject {
+                                for comment in comments.remove(iri).unwrap_or_default() {
+                                    ttl.push_str(&comment_lines(comment));
+                                }
+                            }
+                            let separator = if inline { " ; " } else { " ;\n    " };
+                            let properties: Vec<String> = properties
+                                .iter()
+                                .map(|(p, o)| {
+                                    format!("{} {}", p.ttl(imports), o.ttl(imports, level))
+                                })
+                                .collect();
+                            ttl.push_str(&format!(
+                                "{} {} . \n",
+                                subject.ttl(imports, level),
+                                properties.join(separator)
+                            ));
+                        }
+                    }
                 }
-                crate::owl::Axiom::AnnotationPropertyRange(a) => {
-                    anno_prop_domains_ranges.push(t(
-                        a.iri.ttl(imports),
-                        well_known::rdfs_range().ttl(imports),
-                        a.datatype_iri.ttl(imports),
-                    ));
+                Triple::Comment(c) => {
+                    ttl.push_str(&format!("#{}\n", c));
                 }
-                crate::owl::Axiom::AnnotationPropertyDomain(a) => {
-                    anno_prop_domains_ranges.push(t(
-                        a.iri.ttl(imports),
-                        well_known::rdfs_range().ttl(imports),
-                        a.class_iri.ttl(imports),
-                    ));
+                Triple::LB => ttl.push('\n'),
+            }
+        }
+        ttl
+    }
+
+    fn ttl_with_options(&self, options: &TtlOptions) -> String {
+        pretty::write(self, options)
+    }
+}
+
+impl ToTriples for Ontology {
+    fn to_triples(&self) -> Graph {
+        sections(self).0
+    }
+}
+
+/// The triples of the mapping and the sections of [`ToTtl::ttl`] they are stated in. Every
+/// section starts at the given index of the graph's triples, the first one has no title.
+fn sections(ontology: &Ontology) -> (Graph, Vec<(Option<String>, usize)>) {
+    let mut graph = Triples {
+        graph: Graph::new(),
+        labels: HashMap::new(),
+    };
+    let mut sections = vec![(None, 0)];
+    for triple in mapping(ontology, &BlankNodes::default()) {
+        match triple {
+            Triple::Ontology(iri) => {
+                graph.graph.insert(rdf::Triple::new(
+                    iri,
+                    rdf_type(),
+                    well_known::owl_Ontology(),
+                ));
+            }
+            Triple::T {
+                subject,
+                properties,
+                ..
+            } => {
+                let subject = graph.resource(subject);
+                for (predicate, object) in properties {
+                    let object = graph.term(object);
+                    graph
+                        .graph
+                        .insert(rdf::Triple::new(subject.clone(), predicate, object));
                 }
+            }
+            Triple::Comment(title) => sections.push((Some(title), graph.graph.len())),
+            Triple::Base(..) | Triple::Prefix(..) | Triple::LB => {}
+        }
+    }
+    (graph.graph, sections)
+}
 
-                crate::owl::Axiom::DataPropertyDomain(a) => {
-                    data_prop_domains_ranges.push(t(
-                        a.iri.ttl(imports),
-                        well_known::rdfs_domain().ttl(imports),
-                        class_triples(&a.cls, imports, 1).0,
-                    ));
+/// Flattens the nodes of the mapping into triples.
+struct Triples {
+    graph: Graph,
+    labels: HashMap<usize, BlankNode>,
+}
+
+impl Triples {
+    fn resource(&mut self, node: Node) -> ResourceId {
+        match self.term(node) {
+            Term::IRI(iri) => iri.into(),
+            Term::BlankNode(bn) => bn.into(),
+            Term::Literal(_) => unreachable!("The mapping to RDF never uses literals as subject"),
+        }
+    }
+
+    /// The term of a node. The triples describing anonymous nodes and collections are added
+    /// before the node is used.
+    fn term(&mut self, node: Node) -> Term {
+        match node {
+            Node::Iri(iri) => iri.into(),
+            Node::Literal(literal) => rdf::Literal::from(&literal).into(),
+            Node::Blank(bn) => bn.into(),
+            Node::Label(label) => self
+                .labels
+                .entry(label)
+                .or_insert_with(BlankNode::new)
+                .clone()
+                .into(),
+            Node::Empty => BlankNode::new().into(),
+            Node::Anonymous(properties, _) => {
+                let bn = BlankNode::new();
+                for (predicate, object) in properties {
+                    let object = self.term(object);
+                    self.graph
+                        .insert(rdf::Triple::new(bn.clone(), predicate, object));
                 }
-                crate::owl::Axiom::DataPropertyRange(a) => {
-                    data_prop_domains_ranges.push(t(
-                        a.iri.ttl(imports),
-                        well_known::rdfs_range().ttl(imports),
-                        a.datatype_iri.ttl(imports),
+                bn.into()
+            }
+            Node::Collection(items, _) => {
+                let cells: Vec<BlankNode> = items.iter().map(|_| BlankNode::new()).collect();
+                for (i, item) in items.into_iter().enumerate() {
+                    let item = self.term(item);
+                    let rest: Term = match cells.get(i + 1) {
+                        Some(next) => next.clone().into(),
+                        None => well_known::rdf_nil().into(),
+                    };
+                    self.graph.insert(rdf::Triple::new(
+                        cells[i].clone(),
+                        well_known::rdf_first(),
+                        item,
                     ));
-                }
-
-                crate::owl::Axiom::ObjectPropertyDomain(a) => {
-                    obj_prop_domains_ranges.push(t(
-                        a.iri.ttl(imports),
-                        well_known::rdfs_domain().ttl(imports),
-                        class_triples(&a.cls, imports, 1).0,
+                    self.graph.insert(rdf::Triple::new(
+                        cells[i].clone(),
+                        well_known::rdf_rest(),
+                        rest,
                     ));
                 }
-                crate::owl::Axiom::ObjectPropertyRange(a) => {
-                    obj_prop_domains_ranges.push(t(
-                        a.iri.ttl(imports),
-                        well_known::rdfs_range().ttl(imports),
-                        class_triples(&a.cls, imports, 1).0,
-                    ));
+                match cells.into_iter().next() {
+                    Some(head) => head.into(),
+                    None => well_known::rdf_nil().into(),
                 }
-
-                crate::owl::Axiom::SubClassOf(sco) => {
-                    let mut context = Vec::new();
-                    let (cls, extra) = class_triples(&sco.cls, imports, 1);
-                    for t in extra {
-                        context.push(t);
-                    }
-                    let (pcls, extra) = class_triples(&sco.parent_class, imports, 1);
-                    for t in extra {
-                        context.push(t);
-                    }
-                    let subclass = well_known::rdfs_subClassOf().ttl(imports);
-                    sub_class_ofs.push((t(cls, subclass, pcls), context));
-                }
-
-                crate::owl::Axiom::SubObjectPropertyOf(_) => {}
-                crate::owl::Axiom::SubDataPropertyOf(_) => {}
-                crate::owl::Axiom::SubAnnotationPropertyOf(_) => {}
-                crate::owl::Axiom::EquivalentObjectProperties(_) => {}
-                crate::owl::Axiom::EquivalentDataProperties(_) => {}
-                crate::owl::Axiom::InverseObjectProperties(_) => {}
-                crate::owl::Axiom::DisjointObjectProperties(_) => {}
-                crate::owl::Axiom::SymmetricObjectProperty(_) => {}
-                crate::owl::Axiom::AsymmetricObjectProperty(_) => {}
-                crate::owl::Axiom::ReflexiveObjectProperty(_) => {}
-                crate::owl::Axiom::IrreflexiveObjectProperty(_) => {}
-                crate::owl::Axiom::FunctionalObjectProperty(_) => {}
-                crate::owl::Axiom::InverseFunctionalObjectProperty(_) => {}
-                crate::owl::Axiom::TransitiveObjectProperty(_) => {}
-                crate::owl::Axiom::FunctionalDataProperty(_) => {}
-                crate::owl::Axiom::EquivalentClasses(_) => {}
-                crate::owl::Axiom::DisjointClasses(_) => {}
-                crate::owl::Axiom::DatatypeDefinition(_) => {}
-                crate::owl::Axiom::SameIndividual(_) => {}
-                crate::owl::Axiom::DifferentIndividuals(_) => {}
-                crate::owl::Axiom::NegativeObjectPropertyAssertion(_) => {}
-                crate::owl::Axiom::NegativeDataPropertyAssertion(_) => {}
-                crate::owl::Axiom::HasKey(_) => {}
             }
         }
+    }
+}
+
+/// The prefixes of the document the ontology was parsed from in their order, followed by the
+/// remaining imports in the given order of their names.
+fn prefixes(
+    ontology: &Ontology,
+    mut order: impl FnMut(&String, &String) -> Ordering,
+) -> Vec<(&String, &IRI)> {
+    let mut prefixes: Vec<(&String, &IRI)> = ontology
+        .prefixes
+        .iter()
+        .map(|(name, iri)| (name, ontology.imports.get(name).unwrap_or(iri)))
+        .collect();
+    let mut imports: Vec<(&String, &IRI)> = ontology
+        .imports
+        .iter()
+        .filter(|(name, _)| !ontology.prefixes.iter().any(|(p, _)| &p == name))
+        .collect();
+    imports.sort_by(|a, b| order(a.0, b.0));
+    prefixes.append(&mut imports);
+    prefixes
+}
 
-        if !anno_prop_domains_ranges.is_empty() {
-            triples.push(Triple::LB);
-            triples.push(Triple::Comment("#### AnnotationProperties #####".into()));
-            triples.push(Triple::LB);
+/// The comments of the ontology by the subject they are written in front of.
+fn comments(ontology: &Ontology) -> HashMap<&IRI, Vec<&str>> {
+    let mut comments: HashMap<&IRI, Vec<&str>> = HashMap::new();
+    for (subject, comment) in &ontology.comments {
+        comments.entry(subject).or_default().push(comment);
+    }
+    comments
+}
 
-            for t in anno_prop_domains_ranges {
-                triples.push(t);
+/// The lines of a comment, `#` is followed by a space unless the line is empty or starts with
+/// a space or `#` already.
+fn comment_lines(comment: &str) -> String {
+    comment
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() || line.starts_with([' ', '#']) {
+                format!("#{}\n", line)
+            } else {
+                format!("# {}\n", line)
             }
-        }
+        })
+        .collect()
+}
 
-        if !data_prop_domains_ranges.is_empty() {
-            triples.push(Triple::LB);
-            triples.push(Triple::Comment("#### DataProperties #####".into()));
-            triples.push(Triple::LB);
+/// The mapping of an ontology to RDF, see https://www.w3.org/TR/owl2-mapping-to-rdf/
+fn mapping(ontology: &Ontology, blank_nodes: &BlankNodes) -> Vec<Triple> {
+    let mut triples: Vec<Triple> = Vec::new();
 
-            for t in data_prop_domains_ranges {
-                triples.push(t);
-            }
+    if let Some(base) = &ontology.base {
+        triples.push(Triple::Base(base.clone()));
+    }
+    for (pre, iri) in prefixes(ontology, |a, b| {
+        #[allow(clippy::comparison_chain)]
+        if a.len() > b.len() {
+            Ordering::Greater
+        } else if a.len() == b.len() {
+            a.cmp(b)
+        } else {
+            Ordering::Less
         }
+    }) {
+        triples.push(Triple::Prefix(pre.clone(), iri.clone()));
+    }
 
-        if !obj_prop_domains_ranges.is_empty() {
-            triples.push(Triple::LB);
-            triples.push(Triple::Comment("#### ObjectProperties #####".into()));
-            triples.push(Triple::LB);
+    triples.push(Triple::LB);
 
-            for t in obj_prop_domains_ranges {
-                triples.push(t);
-            }
-        }
+    triples.push(Triple::Ontology(ontology.iri.clone()));
 
-        triples.push(Triple::LB);
-        triples.push(Triple::Comment("#### ClassAssertions #####".into()));
-        triples.push(Triple::LB);
+    let imports = &ontology.imports;
 
-        for (t, context) in class_assertions {
-            triples.push(t);
-            for t in context {
-                triples.push(t);
+    let mut declarations: Vec<Triple> = Vec::new();
+    for d in ontology.declarations() {
+        let (iri, typ, annotations): (Node, IRI, _) = match d {
+            crate::owl::Declaration::Class { iri, annotations } => (
+                iri.into(),
+                well_known::owl_Class().as_iri().clone(),
+                annotations,
+            ),
+            crate::owl::Declaration::NamedIndividual { iri, annotations } => (
+                iri.into(),
+                well_known::owl_NamedIndividual().as_iri().clone(),
+                annotations,
+            ),
+            crate::owl::Declaration::ObjectProperty { iri, annotations } => {
+                (iri.into(), well_known::owl_ObjectProperty(), annotations)
             }
-        }
-        for (t, context) in sub_class_ofs {
-            triples.push(t);
-            for t in context {
-                triples.push(t);
+            crate::owl::Declaration::DataProperty { iri, annotations } => {
+                (iri.into(), well_known::owl_DatatypeProperty(), annotations)
             }
-        }
+            crate::owl::Declaration::AnnotationProperty { iri, annotations } => (
+                iri.into(),
+                well_known::owl_AnnotationProperty(),
+                annotations,
+            ),
+            crate::owl::Declaration::Datatype { iri, annotations } => {
+                (iri.into(), well_known::rdfs_Datatype(), annotations)
+            }
+        };
+        declarations.extend(axiom_triples(
+            iri,
+            rdf_type(),
+            Node::Iri(typ),
+            annotations,
+            &[],
+            blank_nodes,
+        ));
+    }
 
-        triples.push(Triple::LB);
-        triples.push(Triple::Comment("#### AnnotationAssertions #####".into()));
-        triples.push(Triple::LB);
+    // Annotations of reified assertions are part of the ontology as annotation assertions on
+    // the reification, too. They must not be stated twice.
+    let asserted: HashSet<(String, String, String)> = ontology
+        .axioms()
+        .iter()
+        .filter_map(|a| match a {
+            crate::owl::Axiom::AnnotationAssertion(a) => Some((
+                a.subject.ttl(imports),
+                a.iri.ttl(imports),
+                a.value.ttl(imports),
+            )),
+            _ => None,
+        })
+        .collect();
 
-        for t in anno_prop_assertions {
-            triples.push(t);
-        }
+    let mut class_axioms: Vec<Triple> = Vec::new();
+    let mut class_assertions: Vec<Triple> = Vec::new();
+    let mut individual_axioms: Vec<Triple> = Vec::new();
+    let mut datatype_definitions: Vec<Triple> = Vec::new();
 
-        triples.push(Triple::LB);
-        triples.push(Triple::Comment("#### DataPropertyAssertions #####".into()));
-        triples.push(Triple::LB);
+    let mut anno_prop_assertions: Vec<Triple> = Vec::new();
+    let mut anno_prop_domains_ranges: Vec<Triple> = Vec::new();
+    let mut anno_prop_axioms: Vec<Triple> = Vec::new();
 
-        for t in data_prop_assertions {
-            triples.push(t);
-        }
+    let mut data_prop_assertions: Vec<Triple> = Vec::new();
+    let mut data_prop_domains_ranges: Vec<Triple> = Vec::new();
+    let mut data_prop_axioms: Vec<Triple> = Vec::new();
 
-        triples.push(Triple::LB);
-        triples.push(Triple::Comment(
-            "#### ObjectPropertyAssertions #####".into(),
-        ));
-        triples.push(Triple::LB);
+    let mut obj_prop_assertions: Vec<Triple> = Vec::new();
+    let mut obj_prop_domains_ranges: Vec<Triple> = Vec::new();
+    let mut obj_prop_axioms: Vec<Triple> = Vec::new();
 
-        for t in obj_prop_assertions {
-            triples.push(t);
-        }
+    for a in ontology.axioms() {
+        let resource_ids = resource_ids(a);
+        let annotations: Vec<Annotation> = a
+            .annotations()
+            .iter()
+            .filter(|an| {
+                !resource_ids.iter().any(|id| {
+                    asserted.contains(&(
+                        id.ttl(imports),
+                        an.iri.ttl(imports),
+                        an.value.ttl(imports),
+                    ))
+                })
+            })
+            .cloned()
+            .collect();
+        let annotate = |s: Node, p: IRI, o: Node| {
+            axiom_triples(s, p, o, &annotations, resource_ids, blank_nodes)
+        };
+        match a {
+            crate::owl::Axiom::AnnotationAssertion(a) => {
+                anno_prop_assertions.extend(annotate(
+                    (&a.subject).into(),
+                    a.iri.as_iri().clone(),
+                    (&a.value).into(),
+                ));
+            }
+            crate::owl::Axiom::DataPropertyAssertion(d) => {
+                data_prop_assertions.extend(annotate(
+                    (&d.subject).into(),
+                    d.iri.as_iri().clone(),
+                    (&d.value).into(),
+                ));
+            }
+            crate::owl::Axiom::ClassAssertion(c) => {
+                let (cls, context) = class_triples(&c.cls, blank_nodes);
+                class_assertions.extend(annotate((&c.individual).into(), rdf_type(), cls));
+                class_assertions.extend(context);
+            }
+            crate::owl::Axiom::ObjectPropertyAssertion(o) => {
+                obj_prop_assertions.extend(annotate(
+                    (&o.subject).into(),
+                    o.iri.as_iri().clone(),
+                    (&o.object).into(),
+                ));
+            }
+            crate::owl::Axiom::AnnotationPropertyRange(a) => {
+                anno_prop_domains_ranges.extend(annotate(
+                    (&a.iri).into(),
+                    well_known::rdfs_range(),
+                    (&a.datatype_iri).into(),
+                ));
+            }
+            crate::owl::Axiom::AnnotationPropertyDomain(a) => {
+                anno_prop_domains_ranges.extend(annotate(
+                    (&a.iri).into(),
+                    well_known::rdfs_domain(),
+                    (&a.class_iri).into(),
+                ));
+            }
 
-        let mut ttl = "".to_string();
-        for triple in triples {
-            match triple {
-                Triple::T(s, p, o) => {
-                    ttl = format!("{}{} {} {} . \n", ttl, s, p, o);
+            crate::owl::Axiom::DataPropertyDomain(a) => {
+                let (cls, context) = class_triples(&a.cls, blank_nodes);
+                data_prop_domains_ranges.extend(annotate(
+                    (&a.iri).into(),
+                    well_known::rdfs_domain(),
+                    cls,
+                ));
+                data_prop_domains_ranges.extend(context);
+            }
+            crate::owl::Axiom::DataPropertyRange(a) => {
+                data_prop_domains_ranges.extend(annotate(
+                    (&a.iri).into(),
+                    well_known::rdfs_range(),
+                    (&a.datatype_iri).into(),
+                ));
+            }
+
+            crate::owl::Axiom::ObjectPropertyDomain(a) => {
+                let (cls, context) = class_triples(&a.cls, blank_nodes);
+                obj_prop_domains_ranges.extend(annotate(
+                    (&a.iri).into(),
+                    well_known::rdfs_domain(),
+                    cls,
+                ));
+                obj_prop_domains_ranges.extend(context);
+            }
+            crate::owl::Axiom::ObjectPropertyRange(a) => {
+                let (cls, context) = class_triples(&a.cls, blank_nodes);
+                obj_prop_domains_ranges.extend(annotate(
+                    (&a.iri).into(),
+                    well_known::rdfs_range(),
+                    cls,
+                ));
+                obj_prop_domains_ranges.extend(context);
+            }
+
+            crate::owl::Axiom::SubClassOf(sco) => {
+                let (cls, context) = class_triples(&sco.cls, blank_nodes);
+                let (pcls, parent_context) = class_triples(&sco.parent_class, blank_nodes);
+                class_axioms.extend(annotate(
+                    cls,
+                    well_known::rdfs_subClassOf().as_iri().clone(),
+                    pcls,
+                ));
+                class_axioms.extend(context);
+                class_axioms.extend(parent_context);
+            }
+
+            crate::owl::Axiom::SubObjectPropertyOf(a) => match &a.object_property {
+                ObjectPropertyConstructor::ObjectPropertyChain(chain) => {
+                    obj_prop_axioms.extend(annotate(
+                        (&a.parent_object_property_iri).into(),
+                        well_known::owl_propertyChainAxiom(),
+                        axiom_collection(chain.0.iter().map(Node::from).collect(), &annotations),
+                    ));
                 }
-                Triple::Comment(c) => {
-                    ttl = format!("{}#{}\n", ttl, c);
+                _ => {
+                    obj_prop_axioms.extend(annotate(
+                        property_triples(&a.object_property),
+                        well_known::rdfs_subPropertyOf(),
+                        (&a.parent_object_property_iri).into(),
+                    ));
+                }
+            },
+            crate::owl::Axiom::SubDataPropertyOf(a) => {
+                data_prop_axioms.extend(annotate(
+                    (&a.subject_iri).into(),
+                    well_known::rdfs_subPropertyOf(),
+                    (&a.parent_iri).into(),
+                ));
+            }
+            crate::owl::Axiom::SubAnnotationPropertyOf(a) => {
+                anno_prop_axioms.extend(annotate(
+                    (&a.subject_iri).into(),
+                    well_known::rdfs_subPropertyOf(),
+                    (&a.parent_iri).into(),
+                ));
+            }
+            crate::owl::Axiom::EquivalentObjectProperties(a) => {
+                obj_prop_axioms.extend(annotate(
+                    (&a.object_property_iri_1).into(),
+                    well_known::owl_equivalentProperty(),
+                    (&a.object_property_iri_2).into(),
+                ));
+            }
+            crate::owl::Axiom::EquivalentDataProperties(a) => {
+                data_prop_axioms.extend(annotate(
+                    (&a.data_property_iri_1).into(),
+                    well_known::owl_equivalentProperty(),
+                    (&a.data_property_iri_2).into(),
+                ));
+            }
+            crate::owl::Axiom::InverseObjectProperties(a) => {
+                obj_prop_axioms.extend(annotate(
+                    (&a.object_property_iri_1).into(),
+                    well_known::owl_inverseOf(),
+                    (&a.object_property_iri_2).into(),
+                ));
+            }
+            crate::owl::Axiom::DisjointObjectProperties(a) => {
+                match a.object_property_iris.as_slice() {
+                    [p1, p2] => obj_prop_axioms.extend(annotate(
+                        p1.into(),
+                        well_known::owl_propertyDisjointWith(),
+                        p2.into(),
+                    )),
+                    iris => obj_prop_axioms.extend(all_members(
+                        well_known::owl_AllDisjointProperties(),
+                        iris.iter().map(Node::from).collect(),
+                        &annotations,
+                        blank_nodes,
+                    )),
+                }
+            }
+            crate::owl::Axiom::DisjointDataProperties(a) => match a.data_property_iris.as_slice() {
+                [p1, p2] => data_prop_axioms.extend(annotate(
+                    p1.into(),
+                    well_known::owl_propertyDisjointWith(),
+                    p2.into(),
+                )),
+                iris => data_prop_axioms.extend(all_members(
+                    well_known::owl_AllDisjointProperties(),
+                    iris.iter().map(Node::from).collect(),
+                    &annotations,
+                    blank_nodes,
+                )),
+            },
+            crate::owl::Axiom::SymmetricObjectProperty(a) => {
+                obj_prop_axioms.extend(annotate(
+                    (&a.object_property_iri).into(),
+                    rdf_type(),
+                    well_known::owl_SymmetricProperty().into(),
+                ));
+            }
+            crate::owl::Axiom::AsymmetricObjectProperty(a) => {
+                obj_prop_axioms.extend(annotate(
+                    (&a.object_property_iri).into(),
+                    rdf_type(),
+                    well_known::owl_AsymmetricProperty().into(),
+                ));
+            }
+            crate::owl::Axiom::ReflexiveObjectProperty(a) => {
+                obj_prop_axioms.extend(annotate(
+                    (&a.object_property_iri).into(),
+                    rdf_type(),
+                    well_known::owl_ReflexiveProperty().into(),
+                ));
+            }
+            crate::owl::Axiom::IrreflexiveObjectProperty(a) => {
+                obj_prop_axioms.extend(annotate(
+                    (&a.object_property_iri).into(),
+                    rdf_type(),
+                    well_known::owl_IrreflexiveProperty().into(),
+                ));
+            }
+            crate::owl::Axiom::FunctionalObjectProperty(a) => {
+                obj_prop_axioms.extend(annotate(
+                    (&a.object_property_iri).into(),
+                    rdf_type(),
+                    well_known::owl_FunctionalProperty().into(),
+                ));
+            }
+            crate::owl::Axiom::InverseFunctionalObjectProperty(a) => {
+                obj_prop_axioms.extend(annotate(
+                    (&a.object_property_iri).into(),
+                    rdf_type(),
+                    well_known::owl_InverseFunctionalProperty().into(),
+                ));
+            }
+            crate::owl::Axiom::TransitiveObjectProperty(a) => {
+                obj_prop_axioms.extend(annotate(
+                    (&a.object_property_iri).into(),
+                    rdf_type(),
+                    well_known::owl_TransitiveProperty().into(),
+                ));
+            }
+            crate::owl::Axiom::FunctionalDataProperty(a) => {
+                data_prop_axioms.extend(annotate(
+                    (&a.data_property_iri).into(),
+                    rdf_type(),
+                    well_known::owl_FunctionalProperty().into(),
+                ));
+            }
+            crate::owl::Axiom::EquivalentClasses(a) => {
+                let (cls, context) = class_triples(&a.cls, blank_nodes);
+                class_axioms.extend(annotate(
+                    (&a.class_iri).into(),
+                    well_known::owl_equivalentClass(),
+                    cls,
+                ));
+                class_axioms.extend(context);
+            }
+            crate::owl::Axiom::DisjointClasses(a) => match a.classes.as_slice() {
+                [c1, c2] => {
+                    let (c1, context) = class_triples(c1, blank_nodes);
+                    let (c2, context2) = class_triples(c2, blank_nodes);
+                    class_axioms.extend(annotate(c1, well_known::owl_disjointWith(), c2));
+                    class_axioms.extend(context);
+                    class_axioms.extend(context2);
                 }
-                Triple::LB => ttl = format!("{}\n", ttl),
+                classes => {
+                    let mut context = Vec::new();
+                    let members = classes
+                        .iter()
+                        .map(|c| nested_class(c, blank_nodes, &mut context))
+                        .collect();
+                    class_axioms.extend(all_members(
+                        well_known::owl_AllDisjointClasses(),
+                        members,
+                        &annotations,
+                        blank_nodes,
+                    ));
+                    class_axioms.extend(context);
+                }
+            },
+            crate::owl::Axiom::DisjointUnion(a) => {
+                let mut context = Vec::new();
+                let members = a
+                    .classes
+                    .iter()
+                    .map(|c| nested_class(c, blank_nodes, &mut context))
+                    .collect();
+                class_axioms.extend(annotate(
+                    (&a.class_iri).into(),
+                    well_known::owl_disjointUnionOf(),
+                    axiom_collection(members, &annotations),
+                ));
+                class_axioms.extend(context);
+            }
+            crate::owl::Axiom::DatatypeDefinition(a) => {
+                datatype_definitions.extend(annotate(
+                    (&a.data_property_iri).into(),
+                    well_known::owl_equivalentClass(),
+                    data_range(&a.datatype),
+                ));
+            }
+            // a1 owl:sameAs a2 . ... an-1 owl:sameAs an .
+            crate::owl::Axiom::SameIndividual(a) => {
+                for pair in a.individuals.windows(2) {
+                    individual_axioms.extend(annotate(
+                        (&pair[0]).into(),
+                        well_known::owl_sameAs(),
+                        (&pair[1]).into(),
+                    ));
+                }
+            }
+            crate::owl::Axiom::DifferentIndividuals(a) => match a.individuals.as_slice() {
+                [i1, i2] => individual_axioms.extend(annotate(
+                    i1.into(),
+                    well_known::owl_differentFrom(),
+                    i2.into(),
+                )),
+                individuals => individual_axioms.extend(all_members(
+                    well_known::owl_AllDifferent(),
+                    individuals.iter().map(Node::from).collect(),
+                    &annotations,
+                    blank_nodes,
+                )),
+            },
+            crate::owl::Axiom::NegativeObjectPropertyAssertion(a) => {
+                obj_prop_assertions.extend(typed_node(
+                    Node::Empty,
+                    well_known::owl_NegativePropertyAssertion(),
+                    vec![
+                        (well_known::owl_sourceIndividual(), (&a.subject).into()),
+                        (well_known::owl_assertionProperty(), (&a.iri).into()),
+                        (well_known::owl_targetIndividual(), (&a.object).into()),
+                    ],
+                    &annotations,
+                    blank_nodes,
+                ));
+            }
+            crate::owl::Axiom::NegativeDataPropertyAssertion(a) => {
+                data_prop_assertions.extend(typed_node(
+                    Node::Empty,
+                    well_known::owl_NegativePropertyAssertion(),
+                    vec![
+                        (well_known::owl_sourceIndividual(), (&a.subject).into()),
+                        (
+                            well_known::owl_assertionProperty(),
+                            (&a.data_property_iri).into(),
+                        ),
+                        (well_known::owl_targetValue(), (&a.value).into()),
+                    ],
+                    &annotations,
+                    blank_nodes,
+                ));
+            }
+            // the object properties of the key are listed before the data properties
+            crate::owl::Axiom::HasKey(a) => {
+                let (cls, context) = class_triples(&a.cls, blank_nodes);
+                class_axioms.extend(annotate(
+                    cls,
+                    well_known::owl_hasKey(),
+                    axiom_collection(
+                        a.object_properties
+                            .iter()
+                            .map(Node::from)
+                            .chain(a.data_properties.iter().map(Node::from))
+                            .collect(),
+                        &annotations,
+                    ),
+                ));
+                class_axioms.extend(context);
             }
         }
-        ttl
     }
+
+    anno_prop_domains_ranges.extend(anno_prop_axioms);
+    data_prop_domains_ranges.extend(data_prop_axioms);
+    obj_prop_domains_ranges.extend(obj_prop_axioms);
+
+    section(&mut triples, "Declarations", declarations);
+    section(
+        &mut triples,
+        "AnnotationProperties",
+        anno_prop_domains_ranges,
+    );
+    section(&mut triples, "DataProperties", data_prop_domains_ranges);
+    section(&mut triples, "ObjectProperties", obj_prop_domains_ranges);
+    section(&mut triples, "Datatypes", datatype_definitions);
+    section(&mut triples, "Classes", class_axioms);
+    section(&mut triples, "ClassAssertions", class_assertions);
+    section(&mut triples, "Individuals", individual_axioms);
+    section(&mut triples, "AnnotationAssertions", anno_prop_assertions);
+    section(&mut triples, "DataPropertyAssertions", data_prop_assertions);
+    section(
+        &mut triples,
+        "ObjectPropertyAssertions",
+        obj_prop_assertions,
+    );
+
+    let unmapped = ontology
+        .unmapped_triples()
+        .iter()
+        .map(|u| t((&u.subject).into(), u.predicate.clone(), (&u.object).into()))
+        .collect();
+    section(&mut triples, "Other triples", unmapped);
+
+    triples
+}
+
+/// Adds a section with the given title, unless it has no triples.
+fn section(triples: &mut Vec<Triple>, title: &str, section: Vec<Triple>) {
+    if section.is_empty() {
+        return;
+    }
+    triples.push(Triple::LB);
+    triples.push(Triple::Comment(format!("#### {} #####", title)));
+    triples.push(Triple::LB);
+    triples.extend(section);
 }
 
 fn indentation(level: usize) -> String {
     String::from_utf8(vec![b' '; level * 4]).unwrap()
 }
 
-// fn bn() -> String {
-//     format!("_:{}", uuid::Uuid::new_v4())
-// }
+/// Known reifications of an axiom.
+fn resource_ids(axiom: &Axiom) -> &[ResourceId] {
+    match axiom {
+        Axiom::AnnotationAssertion(a) => &a.resource_ids,
+        Axiom::DataPropertyAssertion(a) => &a.resource_ids,
+        Axiom::ObjectPropertyAssertion(a) => &a.resource_ids,
+        _ => &[],
+    }
+}
+
+/// The triples stating an axiom. Annotated axioms are reified, see
+/// https://www.w3.org/TR/owl2-mapping-to-rdf/#Translation_of_Annotations
+fn axiom_triples(
+    s: Node,
+    p: IRI,
+    o: Node,
+    annotations: &[Annotation],
+    resource_ids: &[ResourceId],
+    blank_nodes: &BlankNodes,
+) -> Vec<Triple> {
+    if annotations.is_empty() && resource_ids.is_empty() {
+        return vec![t(s, p, o)];
+    }
+
+    let mut triples = Vec::new();
+    let s = blank_nodes.label(s, &mut triples);
+    let o = blank_nodes.label(o, &mut triples);
+    triples.push(t(s.clone(), p.clone(), o.clone()));
+
+    let reified = vec![
+        (well_known::owl_annotatedSource(), s),
+        (well_known::owl_annotatedProperty(), Node::Iri(p)),
+        (well_known::owl_annotatedTarget(), o),
+    ];
+    if resource_ids.is_empty() {
+        triples.extend(typed_node(
+            Node::Empty,
+            well_known::owl_Axiom(),
+            reified,
+            annotations,
+            blank_nodes,
+        ));
+    } else {
+        for (i, id) in resource_ids.iter().enumerate() {
+            triples.extend(typed_node(
+                id.into(),
+                well_known::owl_Axiom(),
+                reified.clone(),
+                if i == 0 { annotations } else { &[] },
+                blank_nodes,
+            ));
+        }
+    }
+    triples
+}
+
+/// States `<subject> rdf:type <typ> ; p1 o1 ; ... .` followed by the given annotations. Used
+/// for reifications, n-ary axioms and negative property assertions, which state their
+/// annotations on the (usually anonymous) node itself.
+fn typed_node(
+    subject: Node,
+    typ: IRI,
+    properties: Vec<(IRI, Node)>,
+    annotations: &[Annotation],
+    blank_nodes: &BlankNodes,
+) -> Vec<Triple> {
+    // annotations on annotations reference the node, so it needs a label
+    let subject = match subject {
+        Node::Empty if annotations.iter().any(|a| !a.annotations.is_empty()) => blank_nodes.next(),
+        subject => subject,
+    };
+
+    let mut statement = vec![(rdf_type(), Node::Iri(typ))];
+    statement.extend(properties);
+    let mut triples = Vec::new();
+    for a in annotations {
+        let (p, o): (IRI, Node) = (a.iri.as_iri().clone(), (&a.value).into());
+        statement.push((p.clone(), o.clone()));
+        if !a.annotations.is_empty() {
+            let nested: Vec<Annotation> =
+                a.annotations.iter().map(|a| a.as_ref().clone()).collect();
+            triples.extend(typed_node(
+                Node::Empty,
+                well_known::owl_Annotation(),
+                vec![
+                    (well_known::owl_annotatedSource(), subject.clone()),
+                    (well_known::owl_annotatedProperty(), Node::Iri(p)),
+                    (well_known::owl_annotatedTarget(), o),
+                ],
+                &nested,
+                blank_nodes,
+            ));
+        }
+    }
+    triples.insert(
+        0,
+        Triple::T {
+            subject,
+            properties: statement,
+            inline: false,
+            level: 1,
+        },
+    );
+    triples
+}
 
 pub trait IriToTtl {
     fn ttl(&self, imports: &HashMap<String, IRI>) -> String;
 }
 
+/// Quotes a string literal, escaping the characters that can't appear in it as they are.
+fn quoted(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '"' => quoted.push_str("\\\""),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
 impl IriToTtl for Literal {
     fn ttl(&self, imports: &HashMap<String, IRI>) -> String {
         match self {
             Literal::Raw { data, type_iri } => {
-                format!("\"{:?}\"^^{}", data, type_iri.ttl(imports))
+                format!(
+                    "{}^^{}",
+                    quoted(&String::from_utf8_lossy(data)),
+                    type_iri.ttl(imports)
+                )
             }
-            Literal::String(s) => format!("\"{}\"", s),
+            Literal::String(s) => quoted(s),
             Literal::DateTime(d) => {
-                format!("\"{}\"^^{}", d, well_known::xsd_dateTime().ttl(imports))
+                format!("{}^^{}", quoted(d), well_known::xsd_dateTime().ttl(imports))
             }
-            Literal::LangString { string, lang } => format!("\"{}\"@{}", string, lang),
+            Literal::LangString { string, lang } => format!("{}@{}", quoted(string), lang),
             Literal::Number { number, type_iri } => match type_iri {
                 Some(type_iri) => format!("\"{}\"^^{}", number, type_iri.ttl(imports)),
                 None => format!("{}", number),
             },
-            Literal::Duration(duration) => format!("\"{}\"^^{}", duration, well_known::xsd_duration().ttl(imports)),
-            Literal::YearMonthDuration(duration) => format!("\"{}\"^^{}", duration, well_known::xsd_yearMonthDuration().ttl(imports)),
-            Literal::DayTimeDuration(duration) => format!("\"{}\"^^{}", duration, well_known::xsd_dayTimeDuration().ttl(imports)),
+            Literal::Duration(duration) => format!(
+                "\"{}\"^^{}",
+                duration,
+                well_known::xsd_duration().ttl(imports)
+            ),
+            Literal::YearMonthDuration(duration) => format!(
+                "\"{}\"^^{}",
+                duration,
+                well_known::xsd_yearMonthDuration().ttl(imports)
+            ),
+            Literal::DayTimeDuration(duration) => format!(
+                "\"{}\"^^{}",
+                duration,
+                well_known::xsd_dayTimeDuration().ttl(imports)
+            ),
             Literal::Bool(b) => format!("{}", b),
         }
     }
@@ -388,14 +1050,7 @@ impl IriToTtl for LiteralOrIRI {
 
 impl IriToTtl for IRI {
     fn ttl(&self, imports: &HashMap<String, IRI>) -> String {
-        let s = self.to_string();
-        for (prefix, prefix_iri) in imports {
-            let p = prefix_iri.as_str();
-            if s.starts_with(p) {
-                return format!("{}:{}", prefix, s.replace(p, ""));
-            }
-        }
-        format!("<{}>", self.as_str())
+        pretty::prefixed_iri(self, imports)
     }
 }
 impl IriToTtl for AnnotationPropertyIRI {
@@ -429,342 +1084,589 @@ impl IriToTtl for ObjectPropertyIRI {
     }
 }
 
-fn property_triples(
-    prop: &ObjectPropertyConstructor,
-    imports: &HashMap<String, IRI>,
-) -> (String, Vec<Triple>) {
+impl IriToTtl for BlankNode {
+    fn ttl(&self, _imports: &HashMap<String, IRI>) -> String {
+        format!("_:{}", self)
+    }
+}
+impl IriToTtl for ResourceId {
+    fn ttl(&self, imports: &HashMap<String, IRI>) -> String {
+        match self {
+            ResourceId::IRI(iri) => iri.ttl(imports),
+            ResourceId::BlankNode(bn) => bn.ttl(imports),
+        }
+    }
+}
+
+impl Node {
+    /// Renders the node in turtle. Anonymous nodes are indented by their nesting level.
+    fn ttl(&self, imports: &HashMap<String, IRI>, level: usize) -> String {
+        match self {
+            Node::Iri(iri) => iri.ttl(imports),
+            Node::Literal(literal) => literal.ttl(imports),
+            Node::Blank(bn) => bn.ttl(imports),
+            Node::Label(label) => format!("_:b{}", label),
+            Node::Empty => "[]".into(),
+            Node::Anonymous(properties, layout) => {
+                let property = |(p, o): &(IRI, Node), level: usize| {
+                    format!("{} {}", p.ttl(imports), o.ttl(imports, level))
+                };
+                match layout {
+                    Layout::Lines | Layout::LinesTrailingSpace => {
+                        let indent = indentation(level);
+                        let properties: Vec<String> = properties
+                            .iter()
+                            .map(|p| format!("{indent}{}", property(p, level + 1)))
+                            .collect();
+                        format!(
+                            "[\n{}{}\n{}]",
+                            properties.join(" ;\n"),
+                            if *layout == Layout::LinesTrailingSpace {
+                                " "
+                            } else {
+                                ""
+                            },
+                            indentation(level.saturating_sub(1))
+                        )
+                    }
+                    Layout::Inline => {
+                        let properties: Vec<String> =
+                            properties.iter().map(|p| property(p, level)).collect();
+                        format!("[ {} ]", properties.join(" ; "))
+                    }
+                    Layout::Compact => {
+                        let properties: Vec<String> =
+                            properties.iter().map(|p| property(p, level)).collect();
+                        format!("[{}]", properties.join(" ; "))
+                    }
+                }
+            }
+            Node::Collection(items, layout) => {
+                let items = items.iter().map(|i| i.ttl(imports, level));
+                match layout {
+                    Layout::Inline => format!(
+                        "({} )",
+                        items.fold(String::new(), |acc, x| format!("{} {}", acc, x))
+                    ),
+                    Layout::Compact => format!(
+                        "({})",
+                        items.fold(String::new(), |acc, x| format!("{} {}", acc, x))
+                    ),
+                    Layout::Lines | Layout::LinesTrailingSpace => {
+                        let indent = indentation(level);
+                        format!(
+                            "({}\n{})",
+                            items.fold(String::new(), |acc, x| format!("{} \n{indent}{}", acc, x)),
+                            indentation(level.saturating_sub(1))
+                        )
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl From<IRI> for Node {
+    fn from(iri: IRI) -> Self {
+        Node::Iri(iri)
+    }
+}
+impl From<&IRI> for Node {
+    fn from(iri: &IRI) -> Self {
+        Node::Iri(iri.clone())
+    }
+}
+impl From<ClassIRI> for Node {
+    fn from(iri: ClassIRI) -> Self {
+        Node::Iri(iri.as_iri().clone())
+    }
+}
+impl From<AnnotationPropertyIRI> for Node {
+    fn from(iri: AnnotationPropertyIRI) -> Self {
+        Node::Iri(iri.as_iri().clone())
+    }
+}
+impl From<&AnnotationPropertyIRI> for Node {
+    fn from(iri: &AnnotationPropertyIRI) -> Self {
+        Node::Iri(iri.as_iri().clone())
+    }
+}
+impl From<&ClassIRI> for Node {
+    fn from(iri: &ClassIRI) -> Self {
+        Node::Iri(iri.as_iri().clone())
+    }
+}
+impl From<&DatatypeIRI> for Node {
+    fn from(iri: &DatatypeIRI) -> Self {
+        Node::Iri(iri.as_iri().clone())
+    }
+}
+impl From<&IndividualIRI> for Node {
+    fn from(iri: &IndividualIRI) -> Self {
+        Node::Iri(iri.as_iri().clone())
+    }
+}
+impl From<&DataPropertyIRI> for Node {
+    fn from(iri: &DataPropertyIRI) -> Self {
+        Node::Iri(iri.as_iri().clone())
+    }
+}
+impl From<&ObjectPropertyIRI> for Node {
+    fn from(iri: &ObjectPropertyIRI) -> Self {
+        Node::Iri(iri.as_iri().clone())
+    }
+}
+impl From<&ResourceId> for Node {
+    fn from(id: &ResourceId) -> Self {
+        match id {
+            ResourceId::IRI(iri) => Node::Iri(iri.clone()),
+            ResourceId::BlankNode(bn) => Node::Blank(bn.clone()),
+        }
+    }
+}
+impl From<&Literal> for Node {
+    fn from(literal: &Literal) -> Self {
+        Node::Literal(literal.clone())
+    }
+}
+impl From<&LiteralOrIRI> for Node {
+    fn from(value: &LiteralOrIRI) -> Self {
+        match value {
+            LiteralOrIRI::IRI(iri) => Node::Iri(iri.clone()),
+            LiteralOrIRI::Literal(l) => Node::Literal(l.clone()),
+        }
+    }
+}
+impl From<&IRIList> for Node {
+    fn from(value: &IRIList) -> Self {
+        match value {
+            IRIList::IRI(iri) => Node::Iri(iri.clone()),
+            IRIList::BlankNode(bn) => Node::Blank(bn.clone()),
+            IRIList::List(iris) => collection(iris.iter().map(Node::from).collect()),
+        }
+    }
+}
+impl From<&Term> for Node {
+    fn from(value: &Term) -> Self {
+        match value {
+            Term::IRI(iri) => Node::Iri(iri.clone()),
+            Term::BlankNode(bn) => Node::Blank(bn.clone()),
+            Term::Literal(l) => Node::Literal(l.into()),
+        }
+    }
+}
+
+fn property_triples(prop: &ObjectPropertyConstructor) -> Node {
     match prop {
-        ObjectPropertyConstructor::IRI(iri) => (iri.ttl(imports), Vec::new()),
-        ObjectPropertyConstructor::ObjectInverseOf(inv) => {
-            let inverse_of = well_known::owl_inverseOf().ttl(imports);
-            (
-                format!("[ {inverse_of} {} ]", inv.0.ttl(imports)),
-                Vec::new(),
-            )
+        ObjectPropertyConstructor::IRI(iri) => iri.into(),
+        ObjectPropertyConstructor::ObjectInverseOf(inv) => Node::Anonymous(
+            vec![(well_known::owl_inverseOf(), (&inv.0).into())],
+            Layout::Inline,
+        ),
+        ObjectPropertyConstructor::ObjectPropertyChain(chain) => {
+            collection(chain.0.iter().map(Node::from).collect())
         }
-        ObjectPropertyConstructor::ObjectPropertyChain(_) => todo!(),
     }
 }
 
-fn class_triples(
+/// The given items as an RDF collection.
+fn collection(items: Vec<Node>) -> Node {
+    Node::Collection(items, Layout::Inline)
+}
+
+/// The given items as an RDF collection which is the target of an axiom. Annotated axioms
+/// reference their target, so its head node is written out as `[ rdf:first ... ]`.
+fn axiom_collection(items: Vec<Node>, annotations: &[Annotation]) -> Node {
+    if annotations.is_empty() {
+        return collection(items);
+    }
+    let mut items = items.into_iter();
+    match items.next() {
+        Some(first) => Node::Anonymous(
+            vec![
+                (well_known::rdf_first(), first),
+                (well_known::rdf_rest(), collection(items.collect())),
+            ],
+            Layout::Inline,
+        ),
+        None => well_known::rdf_nil().into(),
+    }
+}
+
+/// `[] rdf:type <typ> ; owl:members ( ... )` as used by AllDisjointClasses,
+/// AllDisjointProperties and AllDifferent.
+fn all_members(
+    typ: IRI,
+    members: Vec<Node>,
+    annotations: &[Annotation],
+    blank_nodes: &BlankNodes,
+) -> Vec<Triple> {
+    typed_node(
+        Node::Empty,
+        typ,
+        vec![(well_known::owl_members(), collection(members))],
+        annotations,
+        blank_nodes,
+    )
+}
+
+/// `rdf:type <typ>`
+fn typ<N: Into<Node>>(typ: N) -> (IRI, Node) {
+    (rdf_type(), typ.into())
+}
+
+fn rdf_type() -> IRI {
+    well_known::rdf_type().as_iri().clone()
+}
+
+/// A class expression nested into another one, collecting its context.
+fn nested_class(
     cls: &ClassConstructor,
-    imports: &HashMap<String, IRI>,
-    level: usize,
-) -> (String, Vec<Triple>) {
-    let indent_sub1 = indentation(0.max(level - 1));
-    let indent = indentation(level);
-    match cls {
-        ClassConstructor::IRI(iri) => (iri.ttl(imports), Vec::new()),
-        ClassConstructor::ObjectIntersectionOf(inter) => {
-            // let root_bn = bn();
-            // let mut context = Vec::new();
-            // context.push(t(
-            //     root_bn.clone(),
-            //     well_known::rdf_type().ttl(imports),
-            //     well_known::owl_Class().ttl(imports),
-            // ));
-
-            // let first_bn = bn();
-            // context.push(t(
-            //     root_bn.clone(),
-            //     well_known::owl_intersectionOf().ttl(imports),
-            //     first_bn.clone(),
-            // ));
-            // let mut next_bn = first_bn.clone();
-            // for cls in &inter.classes {
-            //     let (object, ctx) = class_triples(cls, imports);
-
-            //     // add rest part of former iteration (the last one will have no rest)
-            //     if next_bn != first_bn {
-            //         context.push(t(
-            //             next_bn.clone(),
-            //             well_known::rdf_rest().ttl(imports),
-            //             object.clone(),
-            //         ));
-            //     }
-
-            //     // add first of this iteration
-            //     context.push(t(
-            //         next_bn.clone(),
-            //         well_known::rdf_first().ttl(imports),
-            //         object.clone(),
-            //     ));
-            //     next_bn = bn();
-            //     for c in ctx {
-            //         context.push(c);
-            //     }
-            // }
-            // let mut s = "(".into();
-            // for cls in &inter.classes {
-            //     let (cls, _) = class_triples(cls, imports);
-            //     s = format!("{} {}", s, cls)
-            // }
-            let typ = well_known::rdf_type().ttl(imports);
-            let cls = well_known::owl_Class().ttl(imports);
-            let owl_intersection_of = well_known::owl_intersectionOf().ttl(imports);
+    blank_nodes: &BlankNodes,
+    context: &mut Vec<Triple>,
+) -> Node {
+    let (cls, ctx) = class_triples(cls, blank_nodes);
+    context.extend(ctx);
+    cls
+}
+
+/// A class expression as object and the triples which have to be stated alongside it.
+fn class_triples(cls: &ClassConstructor, blank_nodes: &BlankNodes) -> (Node, Vec<Triple>) {
+    let mut context = Vec::new();
+    let cls = match cls {
+        ClassConstructor::IRI(iri) => iri.into(),
+        ClassConstructor::ObjectIntersectionOf(inter) => anonymous(vec![
+            typ(well_known::owl_Class()),
             (
-                format!(
-                    "[\n{indent}{typ} {cls} ;\n{indent}{owl_intersection_of} ({})\n{indent_sub1}]",
-                    inter.classes.iter().fold(String::new(), |acc, x| {
-                        let (c, _) = class_triples(x, imports, level + 1);
-                        format!("{} {}", acc, c)
-                    })
+                well_known::owl_intersectionOf(),
+                Node::Collection(
+                    inter
+                        .classes
+                        .iter()
+                        .map(|c| nested_class(c, blank_nodes, &mut context))
+                        .collect(),
+                    Layout::Compact,
                 ),
-                Default::default(),
-            )
-        }
-        ClassConstructor::SubClassOf(_) => todo!(),
-        ClassConstructor::DataSomeValuesFrom(d) => {
-            let typ = well_known::rdf_type().ttl(imports);
-            let owl_restriction = well_known::owl_Restriction().ttl(imports);
-            // let mut on_class = String::new();
-            // let owl_cardinality = if let Some(iri) = &d.class_iri {
-            //     on_class = format!(
-            //         ";\n{indent}{} {}",
-            //         well_known::owl_onClass().ttl(imports),
-            //         iri.ttl(imports),
-            //     );
-            //     well_known::owl_minCardinality().ttl(imports)
-            // } else {
-            //     well_known::owl_minQualifiedCardinality().ttl(imports)
-            // };
-            // let cardinality = Literal::Number {
-            //     number: d.value.into(),
-            //     type_iri: well_known::xsd_nonNegativeInteger().into(),
-            // }
-            // .ttl(imports);
-            let some_values_from = well_known::owl_someValuesFrom().ttl(imports);
-            let on_prop = well_known::owl_onProperty().ttl(imports);
-            let prop = d.data_property_iri.ttl(imports);
-            let restriction = restriction(&d.restriction, imports, level + 1);
+            ),
+        ]),
+        ClassConstructor::ObjectUnionOf(union) => anonymous(vec![
+            typ(well_known::owl_Class()),
             (
-                format!(
-                    "[\n{indent}{typ} {owl_restriction} ;\n{indent}{on_prop} {prop} ;\n{indent}{some_values_from} {restriction} \n{indent_sub1}]"
+                well_known::owl_unionOf(),
+                Node::Collection(
+                    union
+                        .classes
+                        .iter()
+                        .map(|c| nested_class(c, blank_nodes, &mut context))
+                        .collect(),
+                    Layout::Compact,
                 ),
-                Vec::new(),
-            )
+            ),
+        ]),
+        // The following are axioms rather than class expressions. Nested into another
+        // expression, they stand for their first class and are stated as context.
+        ClassConstructor::SubClassOf(sco) => {
+            let cls = nested_class(&sco.cls, blank_nodes, &mut context);
+            let cls = blank_nodes.label(cls, &mut context);
+            let parent = nested_class(&sco.parent_class, blank_nodes, &mut context);
+            context.push(t(
+                cls.clone(),
+                well_known::rdfs_subClassOf().as_iri().clone(),
+                parent,
+            ));
+            cls
         }
-        ClassConstructor::EquivalentClasses(_) => todo!(),
-        ClassConstructor::DisjointClasses(_) => todo!(),
-        ClassConstructor::ObjectComplementOf(oco) => {
-            let typ = well_known::rdf_type().ttl(imports);
-            let cls = well_known::owl_Class().ttl(imports);
-            let owl_complement_of = well_known::owl_complementOf().ttl(imports);
-
-            (
-                format!(
-                    "[\n{indent}{typ} {cls} ;\n{indent}{owl_complement_of} {}\n{indent_sub1}]",
-                    class_triples(&oco.cls, imports, level + 1).0
-                ),
-                Vec::new(),
-            )
+        ClassConstructor::EquivalentClasses(ec) => {
+            let cls = nested_class(&ec.cls, blank_nodes, &mut context);
+            context.push(t(
+                (&ec.class_iri).into(),
+                well_known::owl_equivalentClass(),
+                cls,
+            ));
+            (&ec.class_iri).into()
         }
-        ClassConstructor::ObjectMaxCardinality(omc) => {
-            // let root_bn = bn();
-            // let mut context = Vec::new();
-            // context.push(t(
-            //     root_bn.clone(),
-            //     well_known::rdf_type().ttl(imports),
-            //     well_known::owl_Restriction().ttl(imports),
-            // ));
-
-            // context.push(t(
-            //     root_bn.clone(),
-            //     well_known::owl_onProperty().ttl(imports),
-            //     omc.object_property_iri.ttl(imports),
-            // ));
-
-            // if let Some(iri) = &omc.class_iri {
-            //     context.push(t(
-            //         root_bn.clone(),
-            //         well_known::owl_maxQualifiedCardinality().ttl(imports),
-            //         Literal::Number {
-            //             number: omc.value.into(),
-            //             type_iri: well_known::xsd_nonNegativeInteger().into(),
-            //         }
-            //         .ttl(imports),
-            //     ));
-            //     context.push(t(
-            //         root_bn.clone(),
-            //         well_known::owl_onClass().ttl(imports),
-            //         iri.ttl(imports),
-            //     ));
-            // } else {
-            //     context.push(t(
-            //         root_bn.clone(),
-            //         well_known::owl_maxCardinality().ttl(imports),
-            //         Literal::Number {
-            //             number: omc.value.into(),
-            //             type_iri: well_known::xsd_nonNegativeInteger().into(),
-            //         }
-            //         .ttl(imports),
-            //     ));
-            // }
-            // (root_bn, context)
-            let indent_sub1 = indentation(0.max(level - 1));
-            let indent = indentation(level);
-            let typ = well_known::rdf_type().ttl(imports);
-            let restriction = well_known::owl_Restriction().ttl(imports);
-            let mut on_class = String::new();
-            let owl_cardinality = if let Some(iri) = &omc.class_iri {
-                on_class = format!(
-                    ";\n{indent}{} {}",
-                    well_known::owl_onClass().ttl(imports),
-                    iri.ttl(imports),
-                );
-                well_known::owl_maxCardinality().ttl(imports)
-            } else {
-                well_known::owl_maxQualifiedCardinality().ttl(imports)
+        ClassConstructor::DisjointClasses(dc) => {
+            let members: Vec<Node> = dc
+                .classes
+                .iter()
+                .map(|c| {
+                    let c = nested_class(c, blank_nodes, &mut context);
+                    blank_nodes.label(c, &mut context)
+                })
+                .collect();
+            // without classes, the axiom holds trivially
+            let Some(first) = members.first().cloned() else {
+                return (well_known::owl_Thing().into(), context);
             };
-            let cardinality = Literal::Number {
-                number: omc.value.into(),
-                type_iri: well_known::xsd_nonNegativeInteger().into(),
-            }
-            .ttl(imports);
-            let on_prop = well_known::owl_onProperty().ttl(imports);
-            let prop = omc.object_property_iri.ttl(imports);
-
-            (
-                format!(
-                    "[\n{indent}{typ} {restriction} ;\n{indent}{owl_cardinality} {cardinality} ;\n{indent}{on_prop} {prop} {on_class} \n{indent_sub1}]"
-                ),
-                Vec::new(),
-            )
+            context.extend(all_members(
+                well_known::owl_AllDisjointClasses(),
+                members,
+                &[],
+                blank_nodes,
+            ));
+            first
         }
-        ClassConstructor::ObjectUnionOf(_) => todo!(),
-        ClassConstructor::ObjectSomeValuesFrom(o) => {
-            let typ = well_known::rdf_type().ttl(imports);
-            let owl_restriction = well_known::owl_Restriction().ttl(imports);
-            let owl_some_values_from = well_known::owl_someValuesFrom().ttl(imports);
-            let on_property = well_known::owl_onProperty().ttl(imports);
+        ClassConstructor::DataSomeValuesFrom(d) => Node::Anonymous(
+            vec![
+                typ(well_known::owl_Restriction()),
+                (well_known::owl_onProperty(), (&d.data_property_iri).into()),
+                (
+                    well_known::owl_someValuesFrom(),
+                    restriction(&d.restriction),
+                ),
+            ],
+            Layout::LinesTrailingSpace,
+        ),
+        ClassConstructor::DataAllValuesFrom(d) => anonymous(vec![
+            typ(well_known::owl_Restriction()),
+            (well_known::owl_onProperty(), (&d.data_property_iri).into()),
+            (well_known::owl_allValuesFrom(), (&d.datatype_iri).into()),
+        ]),
+        ClassConstructor::DataHasValue(d) => anonymous(vec![
+            typ(well_known::owl_Restriction()),
+            (well_known::owl_onProperty(), (&d.data_property_iri).into()),
+            (well_known::owl_hasValue(), (&d.value).into()),
+        ]),
+        ClassConstructor::DataMinCardinality(d) => data_cardinality(
+            d.value,
+            &d.data_property_iri,
+            &d.datatype_iri,
             (
-                format!(
-                    "[\n{indent}{typ} {owl_restriction} ;\n{indent}{on_property} {} ;\n{indent}{owl_some_values_from} {}\n{indent_sub1}]",
-                    property_triples(&o.object_property, imports).0,
-                    o.class_iri.ttl(imports)
+                well_known::owl_minCardinality(),
+                well_known::owl_minQualifiedCardinality(),
+            ),
+        ),
+        ClassConstructor::DataMaxCardinality(d) => data_cardinality(
+            d.value,
+            &d.data_property_iri,
+            &d.datatype_iri,
+            (
+                well_known::owl_maxCardinality(),
+                well_known::owl_maxQualifiedCardinality(),
+            ),
+        ),
+        ClassConstructor::DataExactCardinality(d) => data_cardinality(
+            d.value,
+            &d.data_property_iri,
+            &d.datatype_iri,
+            (
+                well_known::owl_cardinality(),
+                well_known::owl_qualifiedCardinality(),
+            ),
+        ),
+        ClassConstructor::ObjectComplementOf(oco) => anonymous(vec![
+            typ(well_known::owl_Class()),
+            (
+                well_known::owl_complementOf(),
+                nested_class(&oco.cls, blank_nodes, &mut context),
+            ),
+        ]),
+        ClassConstructor::ObjectMaxCardinality(omc) => object_cardinality(
+            omc.value,
+            &omc.object_property_iri,
+            &omc.cls,
+            (
+                well_known::owl_maxCardinality(),
+                well_known::owl_maxQualifiedCardinality(),
+            ),
+            blank_nodes,
+            &mut context,
+        ),
+        ClassConstructor::ObjectMinCardinality(omc) => object_cardinality(
+            omc.value,
+            &omc.object_property_iri,
+            &omc.cls,
+            (
+                well_known::owl_minCardinality(),
+                well_known::owl_minQualifiedCardinality(),
+            ),
+            blank_nodes,
+            &mut context,
+        ),
+        ClassConstructor::ObjectExactCardinality(oec) => object_cardinality(
+            oec.value,
+            &oec.object_property_iri,
+            &oec.cls,
+            (
+                well_known::owl_cardinality(),
+                well_known::owl_qualifiedCardinality(),
+            ),
+            blank_nodes,
+            &mut context,
+        ),
+        ClassConstructor::ObjectSomeValuesFrom(o) => anonymous(vec![
+            typ(well_known::owl_Restriction()),
+            (
+                well_known::owl_onProperty(),
+                property_triples(&o.object_property),
+            ),
+            (
+                well_known::owl_someValuesFrom(),
+                nested_class(&o.cls, blank_nodes, &mut context),
+            ),
+        ]),
+        ClassConstructor::ObjectAllValuesFrom(o) => Node::Anonymous(
+            vec![
+                typ(well_known::owl_Restriction()),
+                (
+                    well_known::owl_onProperty(),
+                    property_triples(&o.object_property),
                 ),
-                Vec::new(),
-            )
-        }
-        ClassConstructor::ObjectMinCardinality(omc) => {
-            let typ = well_known::rdf_type().ttl(imports);
-            let restriction = well_known::owl_Restriction().ttl(imports);
-            let mut on_class = String::new();
-            let owl_cardinality = if let Some(iri) = &omc.class_iri {
-                on_class = format!(
-                    ";\n{indent}{} {}",
-                    well_known::owl_onClass().ttl(imports),
-                    iri.ttl(imports),
-                );
-                well_known::owl_minCardinality().ttl(imports)
-            } else {
-                well_known::owl_minQualifiedCardinality().ttl(imports)
-            };
-            let cardinality = Literal::Number {
-                number: omc.value.into(),
-                type_iri: well_known::xsd_nonNegativeInteger().into(),
-            }
-            .ttl(imports);
-            let on_prop = well_known::owl_onProperty().ttl(imports);
-            let prop = omc.object_property_iri.ttl(imports);
-
+                (
+                    well_known::owl_allValuesFrom(),
+                    nested_class(&o.cls, blank_nodes, &mut context),
+                ),
+            ],
+            Layout::LinesTrailingSpace,
+        ),
+        ClassConstructor::ObjectHasValue(o) => anonymous(vec![
+            typ(well_known::owl_Restriction()),
             (
-                format!(
-                    "[\n{indent}{typ} {restriction} ;\n{indent}{owl_cardinality} {cardinality} ;\n{indent}{on_prop} {prop} {on_class} \n{indent_sub1}]"
+                well_known::owl_onProperty(),
+                property_triples(&o.object_property),
+            ),
+            (well_known::owl_hasValue(), (&o.value_or_iri).into()),
+        ]),
+        ClassConstructor::ObjectHasSelf(o) => anonymous(vec![
+            typ(well_known::owl_Restriction()),
+            (
+                well_known::owl_onProperty(),
+                property_triples(&o.object_property),
+            ),
+            (well_known::owl_hasSelf(), (&Literal::Bool(true)).into()),
+        ]),
+        ClassConstructor::ObjectOneOf(o) => Node::Anonymous(
+            vec![
+                typ(well_known::owl_Class()),
+                (
+                    well_known::owl_oneOf(),
+                    Node::Collection(
+                        o.individuals.iter().map(Node::from).collect(),
+                        Layout::Compact,
+                    ),
                 ),
-                Vec::new(),
-            )
-        }
-        ClassConstructor::ObjectExactCardinality(oec) => {
-            let typ = well_known::rdf_type().ttl(imports);
-            let restriction = well_known::owl_Restriction().ttl(imports);
-            let mut on_class = String::new();
-            let owl_cardinality = if let Some(iri) = &oec.class_iri {
-                on_class = format!(
-                    ";\n{indent}{} {}",
-                    well_known::owl_onClass().ttl(imports),
-                    iri.ttl(imports),
-                );
-                well_known::owl_cardinality().ttl(imports)
-            } else {
-                well_known::owl_qualifiedCardinality().ttl(imports)
-            };
-            let cardinality = Literal::Number {
-                number: oec.value.into(),
-                type_iri: well_known::xsd_nonNegativeInteger().into(),
-            }
-            .ttl(imports);
-            let on_prop = well_known::owl_onProperty().ttl(imports);
-            let prop = oec.object_property_iri.ttl(imports);
+            ],
+            Layout::LinesTrailingSpace,
+        ),
+    };
+    (cls, context)
+}
+
+/// Object cardinality restriction, `predicates` being the (unqualified, qualified) cardinality
+/// IRIs.
+fn object_cardinality(
+    value: u64,
+    object_property_iri: &ObjectPropertyIRI,
+    cls: &Option<Box<ClassConstructor>>,
+    predicates: (IRI, IRI),
+    blank_nodes: &BlankNodes,
+    context: &mut Vec<Triple>,
+) -> Node {
+    let on_class = cls.as_ref().map(|cls| {
+        (
+            well_known::owl_onClass(),
+            nested_class(cls, blank_nodes, context),
+        )
+    });
+    cardinality(value, object_property_iri.into(), on_class, predicates)
+}
+
+/// Data cardinality restriction, `predicates` being the (unqualified, qualified) cardinality IRIs.
+fn data_cardinality(
+    value: u64,
+    data_property_iri: &DataPropertyIRI,
+    datatype_iri: &Option<DatatypeIRI>,
+    predicates: (IRI, IRI),
+) -> Node {
+    let on_data_range = datatype_iri
+        .as_ref()
+        .map(|iri| (well_known::owl_onDataRange(), iri.into()));
+    cardinality(value, data_property_iri.into(), on_data_range, predicates)
+}
 
+fn cardinality(
+    value: u64,
+    property: Node,
+    qualification: Option<(IRI, Node)>,
+    (unqualified, qualified): (IRI, IRI),
+) -> Node {
+    let cardinality = Literal::Number {
+        number: value.into(),
+        type_iri: well_known::xsd_nonNegativeInteger().into(),
+    };
+    let predicate = if qualification.is_some() {
+        qualified
+    } else {
+        unqualified
+    };
+    let mut properties = vec![
+        typ(well_known::owl_Restriction()),
+        (predicate, (&cardinality).into()),
+        (well_known::owl_onProperty(), property),
+    ];
+    properties.extend(qualification);
+    anonymous(properties)
+}
+
+fn data_range(range: &DatatypeDefinitionConstructor) -> Node {
+    match range {
+        DatatypeDefinitionConstructor::DatatypeRestriction(r) => restriction(r),
+        DatatypeDefinitionConstructor::DataComplementOf(d) => anonymous(vec![
+            typ(well_known::rdfs_Datatype()),
             (
-                format!(
-                    "[\n{indent}{typ} {restriction} ;\n{indent}{owl_cardinality} {cardinality} ;\n{indent}{on_prop} {prop} {on_class} \n{indent_sub1}]"
-                ),
-                Vec::new(),
-            )
-        }
-        ClassConstructor::ObjectAllValuesFrom(o) => {
-            let typ = well_known::rdf_type().ttl(imports);
-            let cls = well_known::owl_Class().ttl(imports);
-            let on_prop = well_known::owl_onProperty().ttl(imports);
-            let owl_all_from = well_known::owl_allValuesFrom().ttl(imports);
+                well_known::owl_datatypeComplementOf(),
+                (&d.data_property_iri).into(),
+            ),
+        ]),
+        DatatypeDefinitionConstructor::DataIntersectionOf(d) => anonymous(vec![
+            typ(well_known::rdfs_Datatype()),
             (
-                format!(
-                    "[\n{indent}{typ} {cls} ;\n{indent}{on_prop} {} ;\n{indent}{owl_all_from} {} \n{indent_sub1}]",
-                    property_triples(&o.object_property, imports).0,
-                    o.class_iri.ttl(imports)
-                ),
-                Vec::new(),
-            )
-        }
-        ClassConstructor::ObjectOneOf(o) => {
-            let typ = well_known::rdf_type().ttl(imports);
-            let cls = well_known::owl_Class().ttl(imports);
-            let owl_one_of = well_known::owl_oneOf().ttl(imports);
+                well_known::owl_intersectionOf(),
+                collection(vec![(&d.data_property_iri).into(), data_range(&d.datatype)]),
+            ),
+        ]),
+        DatatypeDefinitionConstructor::DataUnionOf(d) => anonymous(vec![
+            typ(well_known::rdfs_Datatype()),
             (
-                format!(
-                    "[\n{indent}{typ} {cls} ;\n{indent}{owl_one_of} ({}) \n{indent_sub1}]",
-                    o.individuals.iter().fold(String::new(), |acc, x| format!(
-                        "{} {}",
-                        acc,
-                        x.ttl(imports)
-                    ))
-                ),
-                Vec::new(),
-            )
-        }
-        ClassConstructor::ObjectHasValue(_) => todo!(),
-        ClassConstructor::ObjectHasSelf(_) => todo!(),
-    }
-}
-
-fn restriction(
-    restriction: &crate::owl::DatatypeRestriction,
-    imports: &HashMap<String, IRI>,
-    level: usize,
-) -> String {
-    let indent_sub1 = indentation(0.max(level - 1));
-    let indent_add1 = indentation(0.max(level + 1));
-    let indent = indentation(level);
-    let typ = well_known::rdf_type().ttl(imports);
-    let rdfs_datatype = well_known::rdfs_Datatype().ttl(imports);
-    let on_datatype = well_known::owl_onDatatype().ttl(imports);
-    let datatype = restriction.datatype_iri.ttl(imports);
-    let with_restrictions = well_known::owl_withRestrictions().ttl(imports);
-    format!(
-        "[\n{indent}{typ} {rdfs_datatype} ;\n{indent}{on_datatype} {datatype} ;\n{indent}{with_restrictions} ({}\n{indent})\n{indent_sub1}]",
-        restriction.restrictions.iter().fold(String::new(), |acc, x| format!("{} {}", acc, match x {
-            crate::owl::Restriction::Numeric { datatype_iri, value } => {
-                format!("\n{indent_add1}[{} {}]", datatype_iri.ttl(imports), value.ttl(imports))
-            },
-        }))
-    )
+                well_known::owl_unionOf(),
+                collection(vec![(&d.data_property_iri).into(), data_range(&d.datatype)]),
+            ),
+        ]),
+        DatatypeDefinitionConstructor::DataOneOf(d) => anonymous(vec![
+            typ(well_known::rdfs_Datatype()),
+            (
+                well_known::owl_oneOf(),
+                collection(d.literals.iter().map(Node::from).collect()),
+            ),
+        ]),
+    }
+}
+
+fn restriction(restriction: &crate::owl::DatatypeRestriction) -> Node {
+    if restriction.restrictions.is_empty() {
+        return (&restriction.datatype_iri).into();
+    }
+    let facets = restriction
+        .restrictions
+        .iter()
+        .map(|r| Node::Anonymous(vec![(r.facet(), r.value().into())], Layout::Compact))
+        .collect();
+    anonymous(vec![
+        typ(well_known::rdfs_Datatype()),
+        (
+            well_known::owl_onDatatype(),
+            (&restriction.datatype_iri).into(),
+        ),
+        (
+            well_known::owl_withRestrictions(),
+            Node::Collection(facets, Layout::Lines),
+        ),
+    ])
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::owl::well_known;
+    use crate::{api::Ontology, owl::well_known};
 
     use super::ToTtl;
 
@@ -780,4 +1682,51 @@ mod tests {
         onto.imports.insert("xsd".into(), well_known::xsd());
         assert_eq!(onto.ttl(), EXPECTED)
     }
+
+    #[test]
+    fn round_trip() {
+        let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+
+        :A rdf:type owl:Class .
+        :B rdf:type owl:Class .
+        :C rdf:type owl:Class .
+        :hasPart rdf:type owl:ObjectProperty .
+        :age rdf:type owl:DatatypeProperty .
+
+        :C rdfs:subClassOf [
+            rdf:type owl:Restriction ;
+            owl:onProperty :hasPart ;
+            owl:someValuesFrom [
+                rdf:type owl:Class ;
+                owl:unionOf ( :A [
+                    rdf:type owl:Restriction ;
+                    owl:onProperty [ owl:inverseOf :hasPart ] ;
+                    owl:hasSelf true
+                ] )
+            ]
+        ] .
+        _:and rdf:type owl:Class ;
+            owl:intersectionOf ( :A :B ) .
+        _:and rdfs:subClassOf [
+            rdf:type owl:Restriction ;
+            owl:onProperty :age ;
+            owl:hasValue "a \"quoted\" value"
+        ] .
+        :B rdfs:subClassOf :A .
+        "##;
+
+        let onto = Ontology::parse(turtle, Default::default()).unwrap();
+        assert_eq!(onto.axioms().len(), 3);
+        let ttl = onto.ttl();
+        let reparsed = Ontology::parse(&ttl, Default::default()).unwrap();
+        assert_eq!(reparsed.declarations(), onto.declarations());
+        assert_eq!(reparsed.axioms(), onto.axioms());
+    }
 }
@@ -0,0 +1,525 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    api::Ontology,
+    owl::{well_known, BlankNode, ResourceId, IRI},
+    rdf::{Graph, Literal, Term, Triple},
+};
+
+use super::quoted;
+
+/// The order in which the subjects of a section are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubjectOrder {
+    /// The order of the axioms in the ontology.
+    #[default]
+    Mapping,
+    /// Subjects, predicates and objects are sorted, independent of the order of the axioms and
+    /// the identity of blank nodes.
+    Sorted,
+}
+
+/// Options of [`super::ToTtl::ttl_with_options`].
+#[derive(Debug, Clone)]
+pub struct TtlOptions {
+    subject_order: SubjectOrder,
+    group_predicates: bool,
+    group_objects: bool,
+    inline_blank_nodes: bool,
+    collections: bool,
+    indent: usize,
+    section_comments: bool,
+    document_layout: bool,
+}
+
+impl Default for TtlOptions {
+    fn default() -> Self {
+        Self {
+            subject_order: SubjectOrder::Mapping,
+            group_predicates: true,
+            group_objects: true,
+            inline_blank_nodes: true,
+            collections: true,
+            indent: 4,
+            section_comments: true,
+            document_layout: true,
+        }
+    }
+}
+
+impl TtlOptions {
+    pub fn builder() -> TtlOptionsBuilder {
+        TtlOptionsBuilder {
+            ..Default::default()
+        }
+    }
+
+    /// Options for canonical output: ontologies with the same prefixes, declarations and axioms
+    /// are serialized byte-identical, no matter in which order the axioms and prefixes were
+    /// added.
+    pub fn canonical() -> Self {
+        Self::builder()
+            .subject_order(SubjectOrder::Sorted)
+            .document_layout(false)
+            .build()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct TtlOptionsBuilder {
+    options: TtlOptions,
+}
+
+impl TtlOptionsBuilder {
+    pub fn subject_order(mut self, subject_order: SubjectOrder) -> Self {
+        self.options.subject_order = subject_order;
+        self
+    }
+    /// Write the statements about a subject as predicate list (`s p1 o1 ; p2 o2 .`).
+    pub fn group_predicates(mut self, group_predicates: bool) -> Self {
+        self.options.group_predicates = group_predicates;
+        self
+    }
+    /// Write the objects of a predicate as object list (`s p o1 , o2 .`).
+    pub fn group_objects(mut self, group_objects: bool) -> Self {
+        self.options.group_objects = group_objects;
+        self
+    }
+    /// Write blank nodes which are referenced once as `[ p o ]` instead of labelling them.
+    pub fn inline_blank_nodes(mut self, inline_blank_nodes: bool) -> Self {
+        self.options.inline_blank_nodes = inline_blank_nodes;
+        self
+    }
+    /// Write RDF lists as `( a b )` instead of `rdf:first` and `rdf:rest` statements.
+    pub fn collections(mut self, collections: bool) -> Self {
+        self.options.collections = collections;
+        self
+    }
+    /// The number of spaces per nesting level.
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.options.indent = indent;
+        self
+    }
+    /// Introduce the sections of declarations, class assertions, etc. with a comment.
+    pub fn section_comments(mut self, section_comments: bool) -> Self {
+        self.options.section_comments = section_comments;
+        self
+    }
+    /// Keep the layout of the parsed document: its `@base`, the order of its prefixes and the
+    /// comments in front of subjects. Otherwise prefixes are sorted by name.
+    pub fn document_layout(mut self, document_layout: bool) -> Self {
+        self.options.document_layout = document_layout;
+        self
+    }
+    pub fn build(self) -> TtlOptions {
+        self.options
+    }
+}
+
+pub(super) fn write(ontology: &Ontology, options: &TtlOptions) -> String {
+    let (graph, sections) = super::sections(ontology);
+    let mut prefixes = super::prefixes(ontology, |a, b| a.cmp(b));
+    if !options.document_layout {
+        prefixes.sort_by(|a, b| a.0.cmp(b.0));
+    }
+
+    let mut writer = Writer::new(&graph, prefixes, options);
+    let mut out = String::new();
+    if options.document_layout {
+        writer.comments = super::comments(ontology);
+        if let Some(base) = &ontology.base {
+            out.push_str(&format!("@base <{}> .\n", base.as_str()));
+        }
+    }
+    for (prefix, iri) in &writer.prefixes {
+        out.push_str(&format!("@prefix {}: <{}> .\n", prefix, iri.as_str()));
+    }
+
+    for (i, (title, start)) in sections.iter().enumerate() {
+        let end = sections.get(i + 1).map(|s| s.1).unwrap_or(graph.len());
+        let blocks = writer.section(&graph.triples()[*start..end]);
+        if blocks.is_empty() {
+            continue;
+        }
+        out.push('\n');
+        if let (Some(title), true) = (title, options.section_comments) {
+            out.push_str(&format!("#{}\n\n", title));
+        }
+        out.push_str(&blocks.join("\n"));
+    }
+
+    // blank nodes which only reference each other
+    let remaining: Vec<ResourceId> = graph
+        .iter()
+        .map(|t| t.subject.clone())
+        .filter(|s| matches!(s, ResourceId::BlankNode(bn) if !writer.written.contains(bn)))
+        .collect();
+    for subject in remaining {
+        if let ResourceId::BlankNode(bn) = &subject {
+            if !writer.written.contains(bn) {
+                let statements: Vec<&Triple> = graph.with_subject(&subject).collect();
+                out.push('\n');
+                out.push_str(&writer.block(&subject, statements, true));
+            }
+        }
+    }
+    out
+}
+
+struct Writer<'a> {
+    graph: &'a Graph,
+    prefixes: Vec<(&'a String, &'a IRI)>,
+    options: &'a TtlOptions,
+    /// how often a blank node is used as object
+    references: HashMap<BlankNode, usize>,
+    labels: HashMap<BlankNode, String>,
+    written: HashSet<BlankNode>,
+    /// comments which are still to be written in front of their subject
+    comments: HashMap<&'a IRI, Vec<&'a str>>,
+}
+
+impl<'a> Writer<'a> {
+    fn new(
+        graph: &'a Graph,
+        prefixes: Vec<(&'a String, &'a IRI)>,
+        options: &'a TtlOptions,
+    ) -> Self {
+        let mut references: HashMap<BlankNode, usize> = HashMap::new();
+        for triple in graph {
+            if let Term::BlankNode(bn) = &triple.object {
+                *references.entry(bn.clone()).or_default() += 1;
+            }
+        }
+        Self {
+            graph,
+            prefixes,
+            options,
+            references,
+            labels: HashMap::new(),
+            written: HashSet::new(),
+            comments: HashMap::new(),
+        }
+    }
+
+    /// The statement blocks of the subjects of a section.
+    fn section(&mut self, triples: &'a [Triple]) -> Vec<String> {
+        let mut subjects: Vec<&ResourceId> = Vec::new();
+        let mut statements: HashMap<&ResourceId, Vec<&Triple>> = HashMap::new();
+        for triple in triples {
+            let embedded = match &triple.subject {
+                ResourceId::BlankNode(bn) => self.embedded(bn),
+                ResourceId::IRI(_) => false,
+            };
+            if embedded {
+                continue;
+            }
+            let subject_statements = statements.entry(&triple.subject).or_default();
+            if subject_statements.is_empty() {
+                subjects.push(&triple.subject);
+            }
+            subject_statements.push(triple);
+        }
+
+        if self.options.subject_order == SubjectOrder::Sorted {
+            let mut keyed: Vec<((bool, String), &ResourceId)> = subjects
+                .into_iter()
+                .map(|s| {
+                    let key = match s {
+                        ResourceId::IRI(iri) => (false, iri.as_str().to_string()),
+                        ResourceId::BlankNode(_) => (
+                            true,
+                            self.statements_key(&statements[s], &mut HashSet::new()),
+                        ),
+                    };
+                    (key, s)
+                })
+                .collect();
+            keyed.sort_by(|a, b| a.0.cmp(&b.0));
+            subjects = keyed.into_iter().map(|(_, s)| s).collect();
+        }
+
+        let mut blocks = Vec::new();
+        for subject in subjects {
+            let subject_statements = statements.remove(subject).unwrap_or_default();
+            blocks.push(self.block(subject, subject_statements, false));
+        }
+        blocks
+    }
+
+    /// Writes the statements about a subject.
+    fn block(&mut self, subject: &ResourceId, statements: Vec<&Triple>, labelled: bool) -> String {
+        let mut comment = String::new();
+        let subject = match subject {
+            ResourceId::IRI(iri) => {
+                for c in self.comments.remove(iri).unwrap_or_default() {
+                    comment.push_str(&super::comment_lines(c));
+                }
+                self.iri(iri)
+            }
+            ResourceId::BlankNode(bn) => {
+                self.written.insert(bn.clone());
+                // `[]` is a new blank node in every statement
+                let anonymous = !labelled
+                    && !self.references.contains_key(bn)
+                    && (self.options.group_predicates || statements.len() == 1)
+                    && self.graph.with_subject(&bn.clone().into()).count() == statements.len();
+                if anonymous {
+                    "[]".into()
+                } else {
+                    self.label(bn)
+                }
+            }
+        };
+        if self.options.group_predicates {
+            let predicates = self.predicates(statements, 1);
+            format!(
+                "{}{} {} .\n",
+                comment,
+                subject,
+                predicates.join(&format!(" ;\n{}", self.indentation(1)))
+            )
+        } else {
+            let statements: String = self
+                .predicates(statements, 1)
+                .iter()
+                .map(|p| format!("{} {} .\n", subject, p))
+                .collect();
+            comment + &statements
+        }
+    }
+
+    /// The predicates with their objects (`p o1 , o2`), objects are written at the given level.
+    fn predicates(&mut self, statements: Vec<&Triple>, level: usize) -> Vec<String> {
+        let mut predicates: Vec<(&IRI, Vec<&Term>)> = Vec::new();
+        for triple in statements {
+            match predicates.iter_mut().find(|(p, _)| *p == &triple.predicate) {
+                Some((_, objects)) => objects.push(&triple.object),
+                None => predicates.push((&triple.predicate, vec![&triple.object])),
+            }
+        }
+        if self.options.subject_order == SubjectOrder::Sorted {
+            let rdf_type = well_known::rdf_type();
+            predicates.sort_by_key(|(p, _)| (*p != rdf_type.as_iri(), p.as_str()));
+            for (_, objects) in predicates.iter_mut() {
+                let mut keyed: Vec<(String, &Term)> = objects
+                    .iter()
+                    .map(|o| (self.key(o, &mut HashSet::new()), *o))
+                    .collect();
+                keyed.sort_by(|a, b| a.0.cmp(&b.0));
+                *objects = keyed.into_iter().map(|(_, o)| o).collect();
+            }
+        }
+
+        let mut out = Vec::new();
+        for (predicate, objects) in predicates {
+            let predicate = self.predicate(predicate);
+            let objects: Vec<String> = objects.into_iter().map(|o| self.object(o, level)).collect();
+            if self.options.group_objects {
+                out.push(format!("{} {}", predicate, objects.join(" , ")));
+            } else {
+                out.extend(objects.iter().map(|o| format!("{} {}", predicate, o)));
+            }
+        }
+        out
+    }
+
+    fn object(&mut self, object: &Term, level: usize) -> String {
+        match object {
+            Term::IRI(iri) if self.options.collections && *iri == well_known::rdf_nil() => {
+                "()".into()
+            }
+            Term::IRI(iri) => self.iri(iri),
+            Term::Literal(literal) => self.literal(literal),
+            Term::BlankNode(bn) => {
+                if !self.embedded(bn) {
+                    return self.label(bn);
+                }
+                self.written.insert(bn.clone());
+                if let Some(items) = self.list(bn) {
+                    let mut list = Vec::new();
+                    for (cell, item) in items {
+                        self.written.insert(cell);
+                        list.push(self.object(item, level));
+                    }
+                    return format!("( {} )", list.join(" "));
+                }
+                let statements: Vec<&Triple> =
+                    self.graph.with_subject(&bn.clone().into()).collect();
+                if statements.is_empty() {
+                    return "[]".into();
+                }
+                let predicates = self.predicates(statements, level + 1);
+                match predicates.as_slice() {
+                    [predicate] if !predicate.contains('\n') => format!("[ {} ]", predicate),
+                    _ => format!(
+                        "[\n{}{}\n{}]",
+                        self.indentation(level + 1),
+                        predicates.join(&format!(" ;\n{}", self.indentation(level + 1))),
+                        self.indentation(level)
+                    ),
+                }
+            }
+        }
+    }
+
+    fn indentation(&self, level: usize) -> String {
+        " ".repeat(self.options.indent * level)
+    }
+
+    /// Whether the blank node is written as part of the statement referencing it.
+    fn embedded(&self, bn: &BlankNode) -> bool {
+        self.references.get(bn) == Some(&1)
+            && !self.written.contains(bn)
+            && (self.options.inline_blank_nodes || self.list(bn).is_some())
+    }
+
+    /// The cells and items of a list which can be written as collection: every cell is used
+    /// once and consists of an `rdf:first` and an `rdf:rest` only.
+    fn list(&self, bn: &BlankNode) -> Option<Vec<(BlankNode, &'a Term)>> {
+        if !self.options.collections {
+            return None;
+        }
+        let nil: Term = well_known::rdf_nil().into();
+        let mut cells: Vec<(BlankNode, &Term)> = Vec::new();
+        let mut cell: Term = bn.clone().into();
+        while cell != nil {
+            let Term::BlankNode(bn) = cell else {
+                return None;
+            };
+            if self.references.get(&bn) != Some(&1) || cells.iter().any(|(c, _)| *c == bn) {
+                return None;
+            }
+            let statements: Vec<&Triple> = self.graph.with_subject(&bn.clone().into()).collect();
+            let objects = |predicate: IRI| {
+                statements
+                    .iter()
+                    .filter(|t| t.predicate == predicate)
+                    .map(|t| &t.object)
+                    .collect::<Vec<_>>()
+            };
+            let (first, rest) = (
+                objects(well_known::rdf_first()),
+                objects(well_known::rdf_rest()),
+            );
+            match (first.as_slice(), rest.as_slice()) {
+                ([item], [next]) if statements.len() == 2 => {
+                    cells.push((bn, *item));
+                    cell = (*next).clone();
+                }
+                _ => return None,
+            }
+        }
+        Some(cells)
+    }
+
+    /// A sort key of a term which doesn't depend on the labels of blank nodes.
+    fn key(&self, term: &Term, visiting: &mut HashSet<BlankNode>) -> String {
+        match term {
+            Term::IRI(iri) => format!("<{}>", iri.as_str()),
+            Term::Literal(literal) => format!(
+                "{}^^{}@{}",
+                quoted(&literal.lexical_form),
+                literal.datatype.as_ref().map(|d| d.as_str()).unwrap_or(""),
+                literal.lang.as_deref().unwrap_or("")
+            ),
+            Term::BlankNode(bn) if self.embedded(bn) && visiting.insert(bn.clone()) => {
+                let statements: Vec<&Triple> =
+                    self.graph.with_subject(&bn.clone().into()).collect();
+                format!("[{}]", self.statements_key(&statements, visiting))
+            }
+            Term::BlankNode(_) => "_:".into(),
+        }
+    }
+
+    fn statements_key(&self, statements: &[&Triple], visiting: &mut HashSet<BlankNode>) -> String {
+        let mut keys: Vec<String> = statements
+            .iter()
+            .map(|t| {
+                format!(
+                    "<{}> {}",
+                    t.predicate.as_str(),
+                    self.key(&t.object, visiting)
+                )
+            })
+            .collect();
+        keys.sort();
+        keys.join(" ; ")
+    }
+
+    fn label(&mut self, bn: &BlankNode) -> String {
+        let next = self.labels.len();
+        self.labels
+            .entry(bn.clone())
+            .or_insert_with(|| format!("_:b{}", next))
+            .clone()
+    }
+
+    fn predicate(&self, iri: &IRI) -> String {
+        if *iri == *well_known::rdf_type().as_iri() {
+            "a".into()
+        } else {
+            self.iri(iri)
+        }
+    }
+
+    /// Abbreviates an IRI with the longest matching prefix.
+    fn iri(&self, iri: &IRI) -> String {
+        prefixed_iri(iri, self.prefixes.iter().copied())
+    }
+
+    fn literal(&self, literal: &Literal) -> String {
+        let lexical = &literal.lexical_form;
+        match (&literal.lang, &literal.datatype) {
+            (Some(lang), _) => format!("{}@{}", quoted(lexical), lang),
+            (None, None) => quoted(lexical),
+            (None, Some(datatype)) => {
+                let bare = match datatype.as_str() {
+                    well_known::xsd_integer_str => is_integer(lexical),
+                    well_known::xsd_decimal_str => lexical
+                        .split_once('.')
+                        .is_some_and(|(i, f)| (i.is_empty() || is_integer(i)) && is_digits(f)),
+                    well_known::xsd_boolean_str => lexical == "true" || lexical == "false",
+                    _ => false,
+                };
+                if bare {
+                    lexical.clone()
+                } else {
+                    format!("{}^^{}", quoted(lexical), self.iri(datatype))
+                }
+            }
+        }
+    }
+}
+
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_integer(s: &str) -> bool {
+    is_digits(s.strip_prefix(['+', '-']).unwrap_or(s))
+}
+
+/// Writes the IRI as prefixed name with the longest matching prefix, or as `<iri>` if no prefix
+/// leaves a valid local name.
+pub(super) fn prefixed_iri<'p>(
+    iri: &IRI,
+    prefixes: impl IntoIterator<Item = (&'p String, &'p IRI)>,
+) -> String {
+    let iri = iri.as_str();
+    prefixes
+        .into_iter()
+        .map(|(prefix, ns)| (prefix, ns.as_str()))
+        .filter(|(_, ns)| iri.starts_with(ns) && is_local_name(&iri[ns.len()..]))
+        .max_by_key(|(_, ns)| ns.len())
+        .map(|(prefix, ns)| format!("{}:{}", prefix, &iri[ns.len()..]))
+        .unwrap_or_else(|| format!("<{}>", iri))
+}
+
+/// Whether the string can be written as local name of a prefixed name. Less permissive than
+/// the grammar of turtle, names with escapes are written as IRI.
+fn is_local_name(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars().all(|c| c.is_alphanumeric() || "_-.".contains(c))
+        && !s.starts_with(['-', '.'])
+        && !s.ends_with('.')
+}
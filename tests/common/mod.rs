@@ -0,0 +1,47 @@
+//! Fixtures and helpers shared by the tests of the serialization formats.
+#![allow(dead_code)]
+
+use owlish::owl::IRI;
+
+/// An ontology in functional-style syntax which uses prefixes, imports, ontology annotations,
+/// nested class expressions, keys, annotated axioms and anonymous individuals.
+pub const OFN: &str = r#"
+Prefix(:=<http://test#>)
+Prefix(skos:=<http://www.w3.org/2004/02/skos/core#>)
+
+Ontology(<http://test#>
+Import(<http://www.w3.org/2004/02/skos/core>)
+Annotation(rdfs:comment "A test ontology")
+
+# entities
+Declaration(Class(:Person))
+Declaration(Annotation(rdfs:label "Man"@en) Class(:Man))
+Declaration(ObjectProperty(:hasParent))
+Declaration(DataProperty(:age))
+Declaration(NamedIndividual(:Bob))
+
+SubClassOf(Annotation(rdfs:comment "Men are persons") :Man :Person)
+EquivalentClasses(:Parent ObjectSomeValuesFrom(ObjectInverseOf(:hasParent) :Person))
+DisjointClasses(:Man ObjectComplementOf(:Person) ObjectUnionOf(:A :B))
+SubClassOf(:Person DataSomeValuesFrom(:age DatatypeRestriction(xsd:integer xsd:minInclusive "0"^^xsd:integer)))
+SubClassOf(:Person ObjectMaxCardinality(2 :hasParent :Person))
+SubClassOf(:Person DataExactCardinality(1 :age))
+SubObjectPropertyOf(ObjectPropertyChain(:hasParent :hasParent) :hasGrandParent)
+TransitiveObjectProperty(:hasAncestor)
+DataPropertyRange(:age xsd:integer)
+DatatypeDefinition(:adult DataUnionOf(xsd:integer DataOneOf("18"^^xsd:integer "19"^^xsd:integer)))
+HasKey(:Person (:hasParent) (:age))
+
+ClassAssertion(:Man :Bob)
+ObjectPropertyAssertion(Annotation(Annotation(rdfs:comment "nested") rdfs:comment "stated") :hasParent :Bob :Alice)
+DataPropertyAssertion(:age :Bob "42"^^xsd:integer)
+NegativeDataPropertyAssertion(:age :Alice "7"^^xsd:integer)
+AnnotationAssertion(skos:prefLabel :Bob "Bob \"the builder\"")
+AnnotationAssertion(rdfs:comment _:x "anonymous")
+AnnotationAssertion(rdfs:seeAlso _:x :Bob)
+)
+"#;
+
+pub fn iri(iri: &str) -> IRI {
+    IRI::new(iri).unwrap()
+}
@@ -30,16 +30,15 @@ fn data_properties() {
     // println!("{:#?}", o);
     assert_eq!(o.declarations().len(), 2);
     assert_eq!(o.axioms().len(), 2);
-    assert_eq!(
-        o.axioms()[0],
-        Axiom::DataPropertyAssertion(DataPropertyAssertion::new(
+    assert!(o
+        .axioms()
+        .contains(&Axiom::DataPropertyAssertion(DataPropertyAssertion::new(
             IRI::new("http://field33.com/ontologies/test/TestDataProperty")
                 .unwrap()
                 .into(),
             IRI::new("http://field33.com/dataset/test").unwrap().into(),
             "29.25".into(),
             vec![],
-                vec![],
-        ))
-    )
+            vec![],
+        ))))
 }
@@ -0,0 +1,239 @@
+use owlish::{
+    api::Ontology,
+    error::{ErrorKind, Position},
+    parser::{DiagnosticKind, ParserOptions},
+};
+
+const PREFIXES: &str = r##"@prefix : <http://test#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+<http://test#> rdf:type owl:Ontology .
+"##;
+
+fn parse(turtle: &str) -> Result<Ontology, owlish::error::Error> {
+    Ontology::parse(&format!("{}{}", PREFIXES, turtle), Default::default())
+}
+
+fn parse_lenient(turtle: &str) -> (Ontology, Vec<owlish::parser::Diagnostic>) {
+    Ontology::parse_with_diagnostics(
+        &format!("{}{}", PREFIXES, turtle),
+        ParserOptions::builder().lenient(true).build(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn syntax_error_position() {
+    let e = parse(":A rdf:type owl:Class .\n  :B rdf:type ; owl:Class .\n").unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert_eq!(e.position(), Some(Position { line: 8, column: 3 }));
+}
+
+#[test]
+fn invalid_string_escape() {
+    let e = parse(":A rdfs:label \"broken \\q escape\" .\n").unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::InvalidLiteral);
+    assert_eq!(
+        e.position(),
+        Some(Position {
+            line: 7,
+            column: 15
+        })
+    );
+}
+
+#[test]
+fn unknown_prefix() {
+    let e = parse(":A rdf:type owl:Class .\n:A rdf:type unknown:Class .\n").unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert_eq!(e.position(), Some(Position { line: 8, column: 1 }));
+}
+
+#[test]
+fn unsupported_blank_node_property_list_subject() {
+    let e = parse("[ rdf:type owl:Class ] .\n").unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::UnsupportedConstruct);
+    assert_eq!(e.position(), Some(Position { line: 7, column: 1 }));
+}
+
+#[test]
+fn unsupported_collection_subject() {
+    let e = parse(":A rdf:type owl:Class .\n( :A ) rdf:type owl:Class .\n").unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::UnsupportedConstruct);
+    assert_eq!(e.position(), Some(Position { line: 8, column: 1 }));
+}
+
+#[test]
+fn missing_ontology_iri() {
+    let e = Ontology::parse(
+        "@prefix owl: <http://www.w3.org/2002/07/owl#> .\n<http://test#A> a owl:Class .",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::MissingOntologyIri);
+}
+
+#[test]
+fn dangling_list() {
+    let e = parse(
+        r##":A rdf:type owl:Class .
+:B rdf:type owl:Class .
+:C rdf:type owl:Class .
+:A owl:disjointUnionOf _:list .
+"##,
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::DanglingList);
+    assert_eq!(
+        e.position(),
+        Some(Position {
+            line: 10,
+            column: 1
+        })
+    );
+    assert!(e.triple().unwrap().contains("disjointUnionOf"));
+}
+
+#[test]
+fn cyclic_list() {
+    let e = parse(
+        r##":A rdf:type owl:Class .
+:B rdf:type owl:Class .
+:A owl:disjointUnionOf _:list .
+_:list rdf:first :B ; rdf:rest _:list .
+"##,
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::CyclicBlankNode);
+    assert_eq!(e.position(), Some(Position { line: 9, column: 1 }));
+}
+
+#[test]
+fn cyclic_class_expression_list() {
+    let e = parse(
+        r##":A rdf:type owl:Class .
+:B rdf:type owl:Class .
+:B owl:equivalentClass _:x .
+_:x rdf:type owl:Class ; owl:unionOf _:list .
+_:list rdf:first :A ; rdf:rest _:list .
+"##,
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::CyclicBlankNode);
+}
+
+#[test]
+fn type_punning_conflict() {
+    let e = parse(
+        r##":A rdf:type owl:Class .
+:p rdf:type owl:ObjectProperty .
+:p rdf:type owl:DatatypeProperty .
+:A rdfs:subClassOf [ rdf:type owl:Restriction ; owl:onProperty :p ; owl:someValuesFrom :A ] .
+"##,
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::TypePunningConflict);
+    assert_eq!(
+        e.position(),
+        Some(Position {
+            line: 10,
+            column: 1
+        })
+    );
+}
+
+#[test]
+fn malformed_typed_literals_do_not_panic() {
+    let o = parse(
+        r##":p rdf:type owl:DatatypeProperty .
+:i :p "not a number"^^xsd:integer .
+:i rdfs:label "also not a number"^^xsd:integer .
+:i :q "2023"^^xsd:duration .
+"##,
+    )
+    .unwrap();
+    assert_eq!(o.declarations().len(), 1);
+}
+
+#[test]
+fn lenient_skips_dangling_list() {
+    let (o, diagnostics) = parse_lenient(
+        r##":A rdf:type owl:Class .
+:B rdf:type owl:Class .
+:A owl:disjointUnionOf _:list .
+:B rdfs:subClassOf :A .
+"##,
+    );
+    assert_eq!(o.declarations().len(), 2);
+    assert_eq!(o.axioms().len(), 1);
+    let skipped: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.kind == DiagnosticKind::SkippedConstruct)
+        .collect();
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0].position, Some(Position { line: 9, column: 1 }));
+    assert!(skipped[0].message.contains("dangling list"));
+}
+
+#[test]
+fn lenient_reports_unknown_property_kind() {
+    let (_, diagnostics) = parse_lenient(
+        r##":A rdf:type owl:Class .
+:A :unknown :B .
+"##,
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::UnknownPropertyKind);
+    assert_eq!(
+        diagnostics[0].position,
+        Some(Position { line: 8, column: 1 })
+    );
+}
+
+#[test]
+fn unresolved_blank_node() {
+    let (_, diagnostics) = parse_lenient(
+        r##":A rdf:type owl:Class .
+_:x rdf:type owl:Class .
+_:x owl:unionOf :A .
+"##,
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::UnresolvedBlankNode);
+    assert_eq!(
+        diagnostics[0].position,
+        Some(Position { line: 8, column: 1 })
+    );
+}
+
+#[test]
+fn unconsumed_triple() {
+    let (_, diagnostics) = parse_lenient(
+        r##":A rdf:type owl:Class .
+:A owl:versionInfo :B .
+"##,
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::UnconsumedTriple);
+}
+
+#[test]
+fn consumed_constructs_produce_no_diagnostics() {
+    let (o, diagnostics) = Ontology::parse_with_diagnostics(
+        &format!(
+            "{}{}",
+            PREFIXES,
+            r##":A rdf:type owl:Class .
+:p rdf:type owl:ObjectProperty .
+:A rdfs:subClassOf [ rdf:type owl:Restriction ; owl:onProperty :p ; owl:someValuesFrom :A ] .
+:A rdfs:label "A" .
+"##
+        ),
+        Default::default(),
+    )
+    .unwrap();
+    assert_eq!(o.axioms().len(), 2);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+}
@@ -0,0 +1,180 @@
+mod common;
+
+use common::iri;
+use owlish::{
+    api::Ontology,
+    error::{ErrorKind, Position},
+    jsonld::ToJsonLd,
+    owl::{
+        well_known, AnnotationAssertion, Axiom, ClassConstructor, Declaration, Literal,
+        ObjectIntersectionOf, ObjectPropertyConstructor, ObjectSomeValuesFrom, SubClassOf,
+    },
+};
+use serde_json::Value;
+
+const JSONLD: &str = r#"{
+    "@context": {
+        "@vocab": "http://test#",
+        "owl": "http://www.w3.org/2002/07/owl#",
+        "rdfs": "http://www.w3.org/2000/01/rdf-schema#",
+        "xsd": "http://www.w3.org/2001/XMLSchema#",
+        "skos": "http://www.w3.org/2004/02/skos/core#",
+        "subClassOf": { "@id": "rdfs:subClassOf", "@type": "@id" }
+    },
+    "@graph": [
+        {
+            "@id": "http://test#",
+            "@type": "owl:Ontology"
+        },
+        { "@id": "http://test#Person", "@type": "owl:Class", "rdfs:label": { "@value": "Person", "@language": "en" } },
+        { "@id": "http://test#Doctor", "@type": "owl:Class", "subClassOf": "http://test#Person" },
+        { "@id": "http://test#hasChild", "@type": "owl:ObjectProperty" },
+        { "@id": "http://test#age", "@type": "owl:DatatypeProperty" },
+        {
+            "@id": "http://test#Parent",
+            "@type": "owl:Class",
+            "rdfs:subClassOf": {
+                "@type": "owl:Class",
+                "owl:intersectionOf": {
+                    "@list": [
+                        { "@id": "http://test#Person" },
+                        {
+                            "@type": "owl:Restriction",
+                            "owl:onProperty": { "@id": "http://test#hasChild" },
+                            "owl:someValuesFrom": { "@id": "http://test#Doctor" }
+                        }
+                    ]
+                }
+            }
+        },
+        { "@id": "http://test#Bob", "@type": "owl:NamedIndividual", "age": 42 }
+    ]
+}"#;
+
+#[test]
+fn parse_jsonld() {
+    let o = Ontology::parse_jsonld(JSONLD, Default::default()).unwrap();
+    assert_eq!(o.iri(), &iri("http://test#"));
+    assert_eq!(o.declarations().len(), 6);
+    assert!(o.declarations().contains(&Declaration::NamedIndividual {
+        iri: iri("http://test#Bob").into(),
+        annotations: vec![],
+    }));
+
+    assert!(o
+        .axioms()
+        .contains(&Axiom::AnnotationAssertion(AnnotationAssertion::new(
+            well_known::rdfs_label(),
+            iri("http://test#Person"),
+            Literal::LangString {
+                string: "Person".into(),
+                lang: "en".try_into().unwrap(),
+            }
+            .into(),
+            vec![],
+            vec![],
+        ))));
+    assert!(o.axioms().contains(&Axiom::SubClassOf(SubClassOf::new(
+        Box::new(iri("http://test#Doctor").into()),
+        Box::new(iri("http://test#Person").into()),
+        vec![],
+    ))));
+    assert!(o.axioms().contains(&Axiom::SubClassOf(SubClassOf::new(
+        Box::new(iri("http://test#Parent").into()),
+        Box::new(ClassConstructor::ObjectIntersectionOf(
+            ObjectIntersectionOf::new(
+                vec![
+                    iri("http://test#Person").into(),
+                    ObjectSomeValuesFrom::new(
+                        ObjectPropertyConstructor::IRI(iri("http://test#hasChild").into()),
+                        Box::new(iri("http://test#Doctor").into()),
+                        vec![],
+                    )
+                    .into(),
+                ],
+                vec![],
+            )
+        )),
+        vec![],
+    ))));
+    // native numbers are typed literals, `@vocab` applies to the key
+    assert!(o.axioms().iter().any(|a| matches!(
+        a,
+        Axiom::DataPropertyAssertion(dpa) if dpa.value == Literal::Number {
+            number: 42.into(),
+            type_iri: Some(well_known::xsd_integer()),
+        }
+    )));
+}
+
+#[test]
+fn jsonld_context() {
+    let o = Ontology::parse_jsonld(JSONLD, Default::default()).unwrap();
+    let json: Value = serde_json::from_str(&o.jsonld()).unwrap();
+    assert_eq!(
+        json["@context"]["owl"],
+        Value::String(well_known::owl_base_str.into())
+    );
+    let graph = json["@graph"].as_array().unwrap();
+    let parent = graph
+        .iter()
+        .find(|n| n["@id"] == "http://test#Parent")
+        .unwrap();
+    assert_eq!(parent["@type"], "owl:Class");
+    // blank nodes which are used once are embedded, lists are written as `@list`
+    assert_eq!(
+        parent["rdfs:subClassOf"]["owl:intersectionOf"]["@list"][0]["@id"],
+        "http://test#Person"
+    );
+    let bob = graph
+        .iter()
+        .find(|n| n["@id"] == "http://test#Bob")
+        .unwrap();
+    assert_eq!(bob["http://test#age"]["@value"], "42");
+    assert_eq!(bob["http://test#age"]["@type"], "xsd:integer");
+
+    // the prefixes of imports are part of the context
+    let o = Ontology::parse_ofn(
+        r#"
+Prefix(skos:=<http://www.w3.org/2004/02/skos/core#>)
+Ontology(<http://test#>
+Import(<http://www.w3.org/2004/02/skos/core>)
+AnnotationAssertion(skos:prefLabel <http://test#Bob> "Bob")
+)"#,
+        Default::default(),
+    )
+    .unwrap();
+    let json: Value = serde_json::from_str(&o.jsonld()).unwrap();
+    assert_eq!(
+        json["@context"]["skos"],
+        Value::String("http://www.w3.org/2004/02/skos/core".into())
+    );
+}
+
+#[test]
+fn jsonld_errors() {
+    let e = Ontology::parse_jsonld(
+        "{\n  \"@id\": \"http://test#\",\n  \"a\" 1\n}",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert_eq!(e.position(), Some(Position { line: 3, column: 7 }));
+
+    let e = Ontology::parse_jsonld(
+        r#"{ "@context": "https://schema.org/", "@id": "http://test#" }"#,
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::UnsupportedConstruct);
+
+    let e = Ontology::parse_jsonld(
+        r#"{ "@id": "Person", "@type": "http://www.w3.org/2002/07/owl#Class" }"#,
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::InvalidIri);
+
+    let e = Ontology::parse_jsonld("[1, 2]", Default::default()).unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+}
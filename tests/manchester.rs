@@ -0,0 +1,185 @@
+mod common;
+
+use common::iri;
+use owlish::{
+    api::Ontology,
+    error::{ErrorKind, Position},
+    manchester::Manchester,
+    owl::{
+        well_known, ClassConstructor, DataSomeValuesFrom, DatatypeRestriction, Literal,
+        ObjectIntersectionOf, ObjectPropertyConstructor, ObjectSomeValuesFrom, Restriction,
+    },
+};
+
+/// An ontology with labels, to render and parse the class expressions with labels.
+const OFN: &str = r#"
+Prefix(:=<http://test#>)
+Ontology(<http://test#>
+Declaration(Class(:Person))
+Declaration(Class(:Doctor))
+Declaration(Class(:Man))
+Declaration(ObjectProperty(:hasChild))
+Declaration(DataProperty(:age))
+Declaration(NamedIndividual(:Bob))
+AnnotationAssertion(rdfs:label :Doctor "Medical Doctor"@en)
+AnnotationAssertion(rdfs:label :Man "Man")
+
+SubClassOf(:Man :Person)
+SubClassOf(:Man ObjectIntersectionOf(:Person ObjectMaxCardinality(2 :hasChild)))
+EquivalentClasses(:Parent ObjectSomeValuesFrom(:hasChild :Person))
+ClassAssertion(:Man :Bob)
+DataPropertyAssertion(:age :Bob "42"^^xsd:integer)
+)
+"#;
+
+#[test]
+fn render_class() {
+    let o = Ontology::parse_ofn(OFN, Default::default()).unwrap();
+    let m = Manchester::new(&o).use_labels(false);
+    let cls: ClassConstructor = ObjectIntersectionOf::new(
+        vec![
+            iri("http://test#Person").into(),
+            ObjectSomeValuesFrom::new(
+                ObjectPropertyConstructor::IRI(iri("http://test#hasChild").into()),
+                Box::new(iri("http://test#Doctor").into()),
+                vec![],
+            )
+            .into(),
+        ],
+        vec![],
+    )
+    .into();
+    assert_eq!(m.render_class(&cls), "Person and (hasChild some Doctor)");
+
+    // labels are used if available and quoted if necessary
+    let m = Manchester::new(&o);
+    assert_eq!(
+        m.render_class(&cls),
+        "Person and (hasChild some 'Medical Doctor')"
+    );
+
+    let cls: ClassConstructor = DataSomeValuesFrom::new(
+        iri("http://test#age").into(),
+        DatatypeRestriction::new(
+            well_known::xsd_integer(),
+            vec![Restriction::MinInclusive(Literal::Number {
+                number: 18.into(),
+                type_iri: Some(well_known::xsd_integer()),
+            })],
+            vec![],
+        ),
+        vec![],
+    )
+    .into();
+    assert_eq!(m.render_class(&cls), "age some xsd:integer[>= 18]");
+}
+
+#[test]
+fn render_axioms() {
+    let o = Ontology::parse_ofn(OFN, Default::default()).unwrap();
+    let m = Manchester::new(&o);
+    let rendered: Vec<String> = o.axioms().iter().map(|a| m.render_axiom(a)).collect();
+    assert_eq!(
+        rendered,
+        vec![
+            "'Medical Doctor' rdfs:label \"Medical Doctor\"@en",
+            "Man rdfs:label \"Man\"",
+            "Man SubClassOf Person",
+            "Man SubClassOf Person and (hasChild max 2)",
+            "Parent EquivalentTo hasChild some Person",
+            "Bob Type Man",
+            "Bob age 42",
+        ]
+    );
+}
+
+#[test]
+fn parse_class() {
+    let o = Ontology::parse_ofn(OFN, Default::default()).unwrap();
+    let m = Manchester::new(&o);
+    let cls = m
+        .parse_class("Person and (hasChild some 'Medical Doctor')")
+        .unwrap();
+    assert_eq!(
+        cls,
+        ObjectIntersectionOf::new(
+            vec![
+                iri("http://test#Person").into(),
+                ObjectSomeValuesFrom::new(
+                    ObjectPropertyConstructor::IRI(iri("http://test#hasChild").into()),
+                    Box::new(iri("http://test#Doctor").into()),
+                    vec![],
+                )
+                .into(),
+            ],
+            vec![],
+        )
+        .into()
+    );
+
+    // the kind of the property decides about the restriction
+    let cls = m.parse_class("age some xsd:integer[>= 18, < 65]").unwrap();
+    let ClassConstructor::DataSomeValuesFrom(d) = &cls else {
+        panic!("Expected a data restriction, got {:?}", cls);
+    };
+    assert_eq!(d.restriction.restrictions.len(), 2);
+
+    let cls = m.parse_class("age value 42").unwrap();
+    let ClassConstructor::DataHasValue(d) = &cls else {
+        panic!("Expected a data restriction, got {:?}", cls);
+    };
+    assert_eq!(
+        d.value,
+        Literal::Number {
+            number: 42.into(),
+            type_iri: Some(well_known::xsd_integer())
+        }
+    );
+}
+
+#[test]
+fn manchester_round_trip() {
+    let o = Ontology::parse_ofn(OFN, Default::default()).unwrap();
+    for use_labels in [true, false] {
+        let m = Manchester::new(&o).use_labels(use_labels);
+        for s in [
+            "Person and (hasChild some 'Medical Doctor')",
+            "Man or not (hasChild only Person)",
+            "{Bob} and (inverse hasChild value Bob)",
+            "hasChild min 1 (Person and Man) or hasChild exactly 2",
+            "hasChild Self and (age max 1 xsd:integer) and (age value \"x\"@en)",
+            "<http://test#Person> and owl:Thing",
+        ] {
+            let cls = m.parse_class(s).unwrap();
+            let rendered = m.render_class(&cls);
+            assert_eq!(m.parse_class(&rendered).unwrap(), cls, "{}", rendered);
+        }
+    }
+}
+
+#[test]
+fn manchester_errors() {
+    let o = Ontology::parse_ofn(OFN, Default::default()).unwrap();
+    let m = Manchester::new(&o);
+
+    let e = m.parse_class("Person and Unknown").unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::UnknownEntity);
+    assert_eq!(
+        e.position(),
+        Some(Position {
+            line: 1,
+            column: 12
+        })
+    );
+
+    let e = m
+        .parse_class("Person and (hasChild some Doctor")
+        .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+
+    let e = m.parse_class("Person some Doctor").unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::UnknownEntity);
+
+    let e = m.parse_class("age value \"x\"^^xsd:integer").unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::InvalidLiteral);
+}
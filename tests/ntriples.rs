@@ -0,0 +1,168 @@
+use owlish::{
+    api::Ontology,
+    error::{ErrorKind, Position},
+    ntriples::ToNTriples,
+    owl::{Axiom, Literal, LiteralOrIRI},
+};
+
+#[test]
+fn parse_ntriples() {
+    // these test ontologies are written as N-Triples (which is a subset of turtle)
+    for nt in [
+        include_str!("./classes.ttl"),
+        include_str!("./data_properties.ttl"),
+    ] {
+        let o = Ontology::parse_ntriples(nt, Default::default()).unwrap();
+        let turtle = Ontology::parse(nt, Default::default()).unwrap();
+        assert_eq!(o.iri(), turtle.iri());
+        assert_eq!(o.declarations(), turtle.declarations());
+        assert_eq!(o.axioms(), turtle.axioms());
+        assert_eq!(o.unmapped_triples().len(), turtle.unmapped_triples().len());
+    }
+}
+
+#[test]
+fn triple_order() {
+    for turtle in [
+        include_str!("./object_properties.ttl"),
+        r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+        :hasChild rdf:type owl:ObjectProperty .
+        :age rdf:type owl:DatatypeProperty .
+        :confidence rdf:type owl:DatatypeProperty .
+        :Parent rdf:type owl:Class .
+        :Person rdf:type owl:Class .
+
+        :Parent rdfs:subClassOf [
+            rdf:type owl:Restriction ;
+            owl:onProperty :hasChild ;
+            owl:someValuesFrom [ rdf:type owl:Class ; owl:unionOf ( :Parent :Person ) ]
+        ] .
+        [] rdf:type owl:Axiom ;
+            owl:annotatedSource :Parent ;
+            owl:annotatedProperty rdfs:subClassOf ;
+            owl:annotatedTarget [
+                rdf:type owl:Restriction ;
+                owl:onProperty :hasChild ;
+                owl:someValuesFrom [ rdf:type owl:Class ; owl:unionOf ( :Parent :Person ) ]
+            ] ;
+            :confidence 1 .
+        :Bob :age 42 .
+        [] rdf:type owl:Axiom ;
+            owl:annotatedSource :Bob ;
+            owl:annotatedProperty :age ;
+            owl:annotatedTarget 42 ;
+            rdfs:label "age of Bob" .
+        "##,
+    ] {
+        let o = Ontology::parse(turtle, Default::default()).unwrap();
+        let nt = o.ntriples();
+        let reversed: String = nt.lines().rev().map(|l| format!("{}\n", l)).collect();
+        let reparsed = Ontology::parse_ntriples(&reversed, Default::default()).unwrap();
+        assert_eq!(reparsed.axioms().len(), o.axioms().len());
+        for axiom in o.axioms() {
+            match axiom {
+                // anonymous resource ids are relabeled
+                Axiom::AnnotationAssertion(a) if a.subject.is_blank_node() => {}
+                Axiom::DataPropertyAssertion(a) => assert!(reparsed.axioms().iter().any(|r| {
+                    matches!(r, Axiom::DataPropertyAssertion(r)
+                        if r.subject == a.subject && r.value == a.value && r.annotations == a.annotations)
+                })),
+                axiom => assert!(reparsed.axioms().contains(axiom), "{:?}", axiom),
+            }
+        }
+    }
+}
+
+#[test]
+fn ntriples_output() {
+    for turtle in [
+        include_str!("./classes.ttl"),
+        include_str!("./object_properties.ttl"),
+        include_str!("./data_properties.ttl"),
+    ] {
+        let o = Ontology::parse(turtle, Default::default()).unwrap();
+        assert!(o.ntriples().lines().all(|l| l.ends_with(" .")));
+    }
+}
+
+#[test]
+fn nquads() {
+    let o = Ontology::parse(include_str!("./data_properties.ttl"), Default::default()).unwrap();
+    let nq = o.nquads();
+    assert!(nq
+        .lines()
+        .all(|l| l.ends_with(&format!(" <{}> .", o.iri().as_str()))));
+    let reparsed = Ontology::parse_nquads(&nq, Default::default()).unwrap();
+    assert_eq!(reparsed.declarations(), o.declarations());
+    assert_eq!(reparsed.axioms(), o.axioms());
+}
+
+#[test]
+fn ntriples_escapes() {
+    let nt = r#"
+# a comment
+<http://test#> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Ontology> .
+<http://test#A> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Class> .
+<http://test#A> <http://www.w3.org/2000/01/rdf-schema#comment> "line\nbreak \"quoted\" \u00e4" . # trailing
+"#;
+    let o = Ontology::parse_ntriples(nt, Default::default()).unwrap();
+    let Axiom::AnnotationAssertion(a) = &o.axioms()[0] else {
+        panic!("Expected an annotation assertion, got {:?}", o.axioms());
+    };
+    assert_eq!(
+        a.value,
+        LiteralOrIRI::Literal(Literal::String("line\nbreak \"quoted\" ä".into()))
+    );
+    assert!(o
+        .ntriples()
+        .contains(r#""line\nbreak \"quoted\" ä"^^<http://www.w3.org/2001/XMLSchema#string> ."#));
+}
+
+#[test]
+fn ntriples_errors() {
+    let e = Ontology::parse_ntriples(
+        "<http://test#> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Ontology> .\n  <http://test#A> \"a\" <http://test#B> .",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert_eq!(
+        e.position(),
+        Some(Position {
+            line: 2,
+            column: 19
+        })
+    );
+
+    let e = Ontology::parse_ntriples(
+        "<http://test#A> <http://test#p> \"\\q\" .",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert_eq!(
+        e.position(),
+        Some(Position {
+            line: 1,
+            column: 34
+        })
+    );
+
+    let e = Ontology::parse_ntriples("<A> <http://test#p> <http://test#B> .", Default::default())
+        .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::InvalidIri);
+
+    // statements in N-Triples have no graph label
+    let e = Ontology::parse_ntriples(
+        "<http://test#A> <http://test#p> <http://test#B> <http://test#g> .",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+}
@@ -0,0 +1,188 @@
+mod common;
+
+use common::{iri, OFN};
+use owlish::{
+    api::Ontology,
+    error::{ErrorKind, Position},
+    ofn::ToOfn,
+    owl::{
+        well_known, Annotation, AnnotationAssertion, Axiom, ClassConstructor, Declaration,
+        EquivalentClasses, Literal, LiteralOrIRI, ObjectSomeValuesFrom, ResourceId, SubClassOf,
+    },
+    parser::{DiagnosticKind, ParserOptions},
+};
+
+#[test]
+fn parse_ofn() {
+    let o = Ontology::parse_ofn(OFN, Default::default()).unwrap();
+    assert_eq!(o.iri(), &iri("http://test#"));
+    assert_eq!(
+        o.imports().get("skos"),
+        Some(&iri("http://www.w3.org/2004/02/skos/core"))
+    );
+    assert_eq!(o.declarations().len(), 5);
+    assert_eq!(
+        o.declarations()[1],
+        Declaration::Class {
+            iri: iri("http://test#Man").into(),
+            annotations: vec![Annotation::new(
+                well_known::rdfs_label(),
+                Literal::LangString {
+                    string: "Man".into(),
+                    lang: "en".try_into().unwrap(),
+                }
+                .into(),
+                vec![],
+            )],
+        }
+    );
+
+    let axioms = o.axioms();
+    assert_eq!(axioms.len(), 19);
+    // ontology annotations are annotation assertions on the ontology
+    assert_eq!(
+        axioms[0],
+        Axiom::AnnotationAssertion(AnnotationAssertion::new(
+            well_known::rdfs_comment(),
+            iri("http://test#"),
+            LiteralOrIRI::Literal(Literal::String("A test ontology".into())),
+            vec![],
+            vec![],
+        ))
+    );
+    assert_eq!(
+        axioms[1],
+        Axiom::SubClassOf(SubClassOf::new(
+            Box::new(iri("http://test#Man").into()),
+            Box::new(iri("http://test#Person").into()),
+            vec![Annotation::new(
+                well_known::rdfs_comment(),
+                Literal::String("Men are persons".into()).into(),
+                vec![],
+            )],
+        ))
+    );
+    assert_eq!(
+        axioms[2],
+        Axiom::EquivalentClasses(EquivalentClasses::new(
+            iri("http://test#Parent").into(),
+            Box::new(ClassConstructor::ObjectSomeValuesFrom(
+                ObjectSomeValuesFrom::new(
+                    owlish::owl::ObjectInverseOf(iri("http://test#hasParent").into()).into(),
+                    Box::new(iri("http://test#Person").into()),
+                    vec![],
+                )
+            )),
+            vec![],
+        ))
+    );
+    let Axiom::ObjectPropertyAssertion(opa) = &axioms[13] else {
+        panic!(
+            "Expected an object property assertion, got {:?}",
+            axioms[13]
+        );
+    };
+    assert_eq!(opa.annotations[0].annotations.len(), 1);
+    let Axiom::AnnotationAssertion(a) = &axioms[16] else {
+        panic!("Expected an annotation assertion, got {:?}", axioms[16]);
+    };
+    assert_eq!(
+        a.value,
+        LiteralOrIRI::Literal(Literal::String("Bob \"the builder\"".into()))
+    );
+    // anonymous individuals with the same label are the same node
+    let (Axiom::AnnotationAssertion(a1), Axiom::AnnotationAssertion(a2)) =
+        (&axioms[17], &axioms[18])
+    else {
+        panic!("Expected annotation assertions");
+    };
+    assert!(matches!(a1.subject, ResourceId::BlankNode(_)));
+    assert_eq!(a1.subject, a2.subject);
+}
+
+#[test]
+fn ofn_output() {
+    let o = Ontology::parse_ofn(OFN, Default::default()).unwrap();
+    let ofn = o.ofn();
+    assert!(ofn.contains("Prefix(skos:=<http://www.w3.org/2004/02/skos/core>)"));
+    assert!(ofn.contains("Import(<http://www.w3.org/2004/02/skos/core>)"));
+    assert!(ofn.contains(
+        "DataPropertyAssertion(<http://test#age> <http://test#Bob> \"42\"^^xsd:integer)"
+    ));
+    assert!(ofn.contains("\"Bob \\\"the builder\\\"\""));
+}
+
+#[test]
+fn ofn_syntax_error_position() {
+    let e = Ontology::parse_ofn(
+        "Ontology(<http://test#>\n  Declaration(Class(<http://test#A>)\n)",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert_eq!(e.position(), Some(Position { line: 3, column: 2 }));
+}
+
+#[test]
+fn ofn_undeclared_prefix() {
+    let e = Ontology::parse_ofn(
+        "Ontology(<http://test#>\nDeclaration(Class(ex:A)))",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert_eq!(
+        e.position(),
+        Some(Position {
+            line: 2,
+            column: 19
+        })
+    );
+}
+
+#[test]
+fn ofn_invalid_literal() {
+    let e = Ontology::parse_ofn(
+        "Ontology(<http://test#>\nDataPropertyAssertion(<http://test#age> <http://test#Bob> \"x\"^^xsd:integer))", Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::InvalidLiteral);
+    assert_eq!(
+        e.position(),
+        Some(Position {
+            line: 2,
+            column: 59
+        })
+    );
+}
+
+#[test]
+fn ofn_missing_ontology_iri() {
+    let e = Ontology::parse_ofn(
+        "Ontology(Declaration(Class(<http://test#A>)))",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::MissingOntologyIri);
+}
+
+#[test]
+fn ofn_lenient() {
+    let ofn = "Ontology(<http://test#>\n  SubClassOf(<http://test#A>)\n  Declaration(Class(<http://test#B>))\n)";
+    assert!(Ontology::parse_ofn(ofn, Default::default()).is_err());
+
+    // the malformed axiom is skipped and reported
+    let options = ParserOptions::builder().lenient(true).build();
+    let (o, diagnostics) = Ontology::parse_ofn_with_diagnostics(ofn, options).unwrap();
+    assert!(o.axioms().is_empty());
+    assert_eq!(o.declarations().len(), 1);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::SkippedConstruct);
+    assert_eq!(
+        diagnostics[0].position,
+        Some(Position {
+            line: 2,
+            column: 29
+        })
+    );
+}
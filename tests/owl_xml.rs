@@ -0,0 +1,293 @@
+mod common;
+
+use common::iri;
+use owlish::{
+    api::Ontology,
+    error::{ErrorKind, Position},
+    owl::{well_known, Annotation, Axiom, Declaration, Literal},
+    owl_xml::ToOwlXml,
+    parser::{DiagnosticKind, ParserOptions},
+};
+
+const OWL_XML: &str = r##"<?xml version="1.0"?>
+<!DOCTYPE Ontology [
+    <!ENTITY xsd "http://www.w3.org/2001/XMLSchema#" >
+]>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#"
+     xml:base="http://test"
+     ontologyIRI="http://test#">
+    <Prefix name="" IRI="http://test#"/>
+    <Prefix name="skos" IRI="http://www.w3.org/2004/02/skos/core#"/>
+    <Import>http://www.w3.org/2004/02/skos/core</Import>
+    <Annotation>
+        <AnnotationProperty abbreviatedIRI="rdfs:comment"/>
+        <Literal>A test ontology</Literal>
+    </Annotation>
+    <Declaration>
+        <Class IRI="#Person"/>
+    </Declaration>
+    <Declaration>
+        <Annotation>
+            <AnnotationProperty abbreviatedIRI="rdfs:label"/>
+            <Literal xml:lang="en">Man</Literal>
+        </Annotation>
+        <Class abbreviatedIRI=":Man"/>
+    </Declaration>
+    <Declaration>
+        <ObjectProperty IRI="#hasParent"/>
+    </Declaration>
+    <Declaration>
+        <DataProperty IRI="#age"/>
+    </Declaration>
+    <SubClassOf>
+        <Annotation>
+            <AnnotationProperty abbreviatedIRI="rdfs:comment"/>
+            <Literal>Men are persons</Literal>
+        </Annotation>
+        <Class IRI="#Man"/>
+        <Class IRI="#Person"/>
+    </SubClassOf>
+    <EquivalentClasses>
+        <Class IRI="#Parent"/>
+        <ObjectIntersectionOf>
+            <Class IRI="#Person"/>
+            <ObjectSomeValuesFrom>
+                <ObjectInverseOf>
+                    <ObjectProperty IRI="#hasParent"/>
+                </ObjectInverseOf>
+                <Class IRI="#Person"/>
+            </ObjectSomeValuesFrom>
+        </ObjectIntersectionOf>
+    </EquivalentClasses>
+    <SubClassOf>
+        <Class IRI="#Person"/>
+        <ObjectMaxCardinality cardinality="2">
+            <ObjectProperty IRI="#hasParent"/>
+            <Class IRI="#Person"/>
+        </ObjectMaxCardinality>
+    </SubClassOf>
+    <SubClassOf>
+        <Class IRI="#Person"/>
+        <DataSomeValuesFrom>
+            <DataProperty IRI="#age"/>
+            <DatatypeRestriction>
+                <Datatype abbreviatedIRI="xsd:integer"/>
+                <FacetRestriction facet="&xsd;minInclusive">
+                    <Literal datatypeIRI="&xsd;integer">0</Literal>
+                </FacetRestriction>
+            </DatatypeRestriction>
+        </DataSomeValuesFrom>
+    </SubClassOf>
+    <SubObjectPropertyOf>
+        <ObjectPropertyChain>
+            <ObjectProperty IRI="#hasParent"/>
+            <ObjectProperty IRI="#hasParent"/>
+        </ObjectPropertyChain>
+        <ObjectProperty IRI="#hasGrandParent"/>
+    </SubObjectPropertyOf>
+    <DatatypeDefinition>
+        <Datatype IRI="#adult"/>
+        <DataUnionOf>
+            <Datatype abbreviatedIRI="xsd:integer"/>
+            <DataOneOf>
+                <Literal datatypeIRI="&xsd;integer">18</Literal>
+                <Literal datatypeIRI="&xsd;integer">19</Literal>
+            </DataOneOf>
+        </DataUnionOf>
+    </DatatypeDefinition>
+    <HasKey>
+        <Class IRI="#Person"/>
+        <ObjectProperty IRI="#hasParent"/>
+        <DataProperty IRI="#age"/>
+    </HasKey>
+    <ClassAssertion>
+        <Class IRI="#Man"/>
+        <NamedIndividual IRI="#Bob"/>
+    </ClassAssertion>
+    <DataPropertyAssertion>
+        <DataProperty IRI="#age"/>
+        <NamedIndividual IRI="#Bob"/>
+        <Literal datatypeIRI="&xsd;integer">42</Literal>
+    </DataPropertyAssertion>
+    <AnnotationAssertion>
+        <AnnotationProperty abbreviatedIRI="skos:prefLabel"/>
+        <AbbreviatedIRI>:Bob</AbbreviatedIRI>
+        <Literal>Bob &lt;the builder&gt;</Literal>
+    </AnnotationAssertion>
+</Ontology>
+"##;
+
+/// The same ontology in functional-style syntax.
+const OFN: &str = r#"
+Prefix(:=<http://test#>)
+Prefix(skos:=<http://www.w3.org/2004/02/skos/core#>)
+Ontology(<http://test#>
+Import(<http://www.w3.org/2004/02/skos/core>)
+Annotation(rdfs:comment "A test ontology")
+Declaration(Class(:Person))
+Declaration(Annotation(rdfs:label "Man"@en) Class(:Man))
+Declaration(ObjectProperty(:hasParent))
+Declaration(DataProperty(:age))
+SubClassOf(Annotation(rdfs:comment "Men are persons") :Man :Person)
+EquivalentClasses(:Parent ObjectIntersectionOf(:Person ObjectSomeValuesFrom(ObjectInverseOf(:hasParent) :Person)))
+SubClassOf(:Person ObjectMaxCardinality(2 :hasParent :Person))
+SubClassOf(:Person DataSomeValuesFrom(:age DatatypeRestriction(xsd:integer xsd:minInclusive "0"^^xsd:integer)))
+SubObjectPropertyOf(ObjectPropertyChain(:hasParent :hasParent) :hasGrandParent)
+DatatypeDefinition(:adult DataUnionOf(xsd:integer DataOneOf("18"^^xsd:integer "19"^^xsd:integer)))
+HasKey(:Person (:hasParent) (:age))
+ClassAssertion(:Man :Bob)
+DataPropertyAssertion(:age :Bob "42"^^xsd:integer)
+AnnotationAssertion(skos:prefLabel :Bob "Bob <the builder>")
+)
+"#;
+
+#[test]
+fn parse_owl_xml() {
+    let o = Ontology::parse_owl_xml(OWL_XML, Default::default()).unwrap();
+    let ofn = Ontology::parse_ofn(OFN, Default::default()).unwrap();
+    assert_eq!(o.iri(), &iri("http://test#"));
+    assert_eq!(o.imports(), ofn.imports());
+    assert_eq!(
+        o.declarations()[1],
+        Declaration::Class {
+            iri: iri("http://test#Man").into(),
+            annotations: vec![Annotation::new(
+                well_known::rdfs_label(),
+                Literal::LangString {
+                    string: "Man".into(),
+                    lang: "en".try_into().unwrap(),
+                }
+                .into(),
+                vec![],
+            )],
+        }
+    );
+    assert_eq!(o.declarations(), ofn.declarations());
+    assert_eq!(o.axioms(), ofn.axioms());
+}
+
+#[test]
+fn owl_xml_output() {
+    let o = Ontology::parse_owl_xml(OWL_XML, Default::default()).unwrap();
+    let xml = o.owl_xml();
+    assert!(xml.contains(r#"<Prefix name="skos" IRI="http://www.w3.org/2004/02/skos/core"/>"#));
+    assert!(xml.contains("<Import>http://www.w3.org/2004/02/skos/core</Import>"));
+    assert!(xml.contains("<Literal>Bob &lt;the builder&gt;</Literal>"));
+    assert!(xml.contains(r#"<ObjectMaxCardinality cardinality="2">"#));
+}
+
+#[test]
+fn owl_xml_anonymous_individuals() {
+    let o = Ontology::parse_owl_xml(
+        r#"<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://test#">
+    <AnnotationAssertion>
+        <AnnotationProperty abbreviatedIRI="rdfs:comment"/>
+        <AnonymousIndividual nodeID="x"/>
+        <Literal>anonymous</Literal>
+    </AnnotationAssertion>
+    <AnnotationAssertion>
+        <AnnotationProperty abbreviatedIRI="rdfs:seeAlso"/>
+        <AnonymousIndividual nodeID="x"/>
+        <IRI>#Bob</IRI>
+    </AnnotationAssertion>
+</Ontology>"#,
+        Default::default(),
+    )
+    .unwrap();
+    let (Axiom::AnnotationAssertion(a1), Axiom::AnnotationAssertion(a2)) =
+        (&o.axioms()[0], &o.axioms()[1])
+    else {
+        panic!("Expected annotation assertions, got {:?}", o.axioms());
+    };
+    assert_eq!(a1.subject, a2.subject);
+    // relative IRIs are resolved against the ontology IRI
+    assert_eq!(a2.value, iri("http://test#Bob").into());
+
+    let reparsed = Ontology::parse_owl_xml(&o.owl_xml(), Default::default()).unwrap();
+    let (Axiom::AnnotationAssertion(a1), Axiom::AnnotationAssertion(a2)) =
+        (&reparsed.axioms()[0], &reparsed.axioms()[1])
+    else {
+        panic!(
+            "Expected annotation assertions, got {:?}",
+            reparsed.axioms()
+        );
+    };
+    assert_eq!(a1.subject, a2.subject);
+}
+
+#[test]
+fn owl_xml_errors() {
+    // errors of the structure are reported at the element
+    let e = Ontology::parse_owl_xml(
+        "<Ontology xmlns=\"http://www.w3.org/2002/07/owl#\" ontologyIRI=\"http://test#\">\n  <SubClassOf>\n    <Class IRI=\"#A\"/>\n  </SubClassOf>\n</Ontology>",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert_eq!(e.position(), Some(Position { line: 4, column: 3 }));
+
+    let e = Ontology::parse_owl_xml(
+        "<Ontology xmlns=\"http://www.w3.org/2002/07/owl#\" ontologyIRI=\"http://test#\">\n  <SubClassOf>\n    <Clas IRI=\"#A\"/>\n  </SubClassOf>\n</Ontology>",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert_eq!(e.position(), Some(Position { line: 3, column: 5 }));
+
+    let e = Ontology::parse_owl_xml(
+        "<Ontology xmlns=\"http://www.w3.org/2002/07/owl#\" ontologyIRI=\"http://test#\">\n  <Declaration><Class/></Declaration>\n</Ontology>",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert_eq!(
+        e.position(),
+        Some(Position {
+            line: 2,
+            column: 16
+        })
+    );
+
+    let e = Ontology::parse_owl_xml(
+        "<Ontology xmlns=\"http://www.w3.org/2002/07/owl#\"/>",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::MissingOntologyIri);
+
+    let e = Ontology::parse_owl_xml(
+        "<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\"/>",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+
+    let e = Ontology::parse_owl_xml("<Ontology>\n</Ontolog>", Default::default()).unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert_eq!(e.position().map(|p| p.line), Some(2));
+}
+
+#[test]
+fn owl_xml_lenient() {
+    let xml = r##"<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://test#">
+    <SubClassOf>
+        <Class IRI="#A"/>
+    </SubClassOf>
+    <Declaration>
+        <Class IRI="#B"/>
+    </Declaration>
+</Ontology>"##;
+    assert!(Ontology::parse_owl_xml(xml, Default::default()).is_err());
+
+    // the malformed axiom is skipped and reported
+    let options = ParserOptions::builder().lenient(true).build();
+    let (o, diagnostics) = Ontology::parse_owl_xml_with_diagnostics(xml, options).unwrap();
+    assert!(o.axioms().is_empty());
+    assert_eq!(o.declarations().len(), 1);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::SkippedConstruct);
+    assert_eq!(
+        diagnostics[0].position,
+        Some(Position { line: 4, column: 5 })
+    );
+}
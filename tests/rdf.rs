@@ -0,0 +1,267 @@
+mod common;
+
+use common::iri;
+use owlish::{
+    api::Ontology,
+    jsonld::ToJsonLd,
+    ntriples::ToNTriples,
+    owl::{
+        well_known, Axiom, BlankNode, ClassConstructor, ObjectPropertyConstructor,
+        ObjectSomeValuesFrom, ResourceId, SubClassOf, IRI,
+    },
+    rdf::{Graph, Literal, Term, ToTriples, Triple},
+    rdf_xml::ToRdfXml,
+};
+
+fn rdf_type() -> IRI {
+    well_known::rdf_type().as_iri().clone()
+}
+
+#[test]
+fn graph_lookups() {
+    let person: ResourceId = iri("http://test#Person").into();
+    let bob: ResourceId = iri("http://test#Bob").into();
+    let class: Term = well_known::owl_Class().as_iri().clone().into();
+
+    let mut graph = Graph::new();
+    assert!(graph.insert(Triple::new(person.clone(), rdf_type(), class.clone())));
+    assert!(graph.insert(Triple::new(
+        person.clone(),
+        well_known::rdfs_label().as_iri().clone(),
+        Literal::lang_string("Person", "en"),
+    )));
+    assert!(graph.insert(Triple::new(bob.clone(), rdf_type(), person.clone())));
+    // graphs are sets of triples
+    assert!(!graph.insert(Triple::new(person.clone(), rdf_type(), class.clone())));
+    assert_eq!(graph.len(), 3);
+
+    assert_eq!(graph.with_subject(&person).count(), 2);
+    assert_eq!(graph.with_predicate(&rdf_type()).count(), 2);
+    assert_eq!(
+        graph
+            .with_object(&person.clone().into())
+            .map(|t| &t.subject)
+            .collect::<Vec<_>>(),
+        vec![&bob]
+    );
+    assert_eq!(
+        graph.objects(&person, &rdf_type()).collect::<Vec<_>>(),
+        vec![&class]
+    );
+    assert_eq!(
+        graph
+            .matching(None, Some(&rdf_type()), Some(&class))
+            .count(),
+        1
+    );
+    assert_eq!(graph.matching(None, None, None).count(), 3);
+    assert_eq!(
+        graph
+            .matching(Some(&bob), Some(&rdf_type()), Some(&class))
+            .count(),
+        0
+    );
+
+    // the order of the triples doesn't matter for equality
+    let reversed: Graph = graph.clone().into_iter().rev().collect();
+    assert_eq!(reversed, graph);
+    assert_eq!(reversed.triples()[0].subject, bob);
+}
+
+#[test]
+fn ontology_from_graph() {
+    let (person, doctor, has_child) = (
+        iri("http://test#Person"),
+        iri("http://test#Doctor"),
+        iri("http://test#hasChild"),
+    );
+    let restriction = BlankNode::new();
+    let graph: Graph = [
+        Triple::new(iri("http://test#"), rdf_type(), well_known::owl_Ontology()),
+        Triple::new(
+            person.clone(),
+            rdf_type(),
+            well_known::owl_Class().as_iri().clone(),
+        ),
+        Triple::new(
+            person.clone(),
+            well_known::rdfs_subClassOf().as_iri().clone(),
+            restriction.clone(),
+        ),
+        Triple::new(
+            restriction.clone(),
+            rdf_type(),
+            well_known::owl_Restriction().as_iri().clone(),
+        ),
+        Triple::new(
+            restriction.clone(),
+            well_known::owl_onProperty(),
+            has_child.clone(),
+        ),
+        Triple::new(
+            restriction,
+            well_known::owl_someValuesFrom(),
+            doctor.clone(),
+        ),
+        Triple::new(
+            iri("http://test#Bob"),
+            iri("http://test#age"),
+            Literal::typed("42", well_known::xsd_integer().as_iri().clone()),
+        ),
+    ]
+    .into_iter()
+    .collect();
+
+    let o = Ontology::from_graph(&graph, Default::default()).unwrap();
+    assert_eq!(o.iri(), &iri("http://test#"));
+    assert_eq!(o.declarations().len(), 1);
+    assert!(o.axioms().contains(&Axiom::SubClassOf(SubClassOf::new(
+        Box::new(person.into()),
+        Box::new(ClassConstructor::ObjectSomeValuesFrom(
+            ObjectSomeValuesFrom::new(
+                ObjectPropertyConstructor::IRI(has_child.into()),
+                Box::new(doctor.into()),
+                vec![]
+            )
+        )),
+        vec![],
+    ))));
+    // the age is not declared as data property
+    assert_eq!(o.unmapped_triples().len(), 1);
+    assert_eq!(
+        o.unmapped_triples()[0].object,
+        Literal::typed("42", well_known::xsd_integer().as_iri().clone()).into()
+    );
+}
+
+#[test]
+fn graph_round_trip() {
+    for turtle in [
+        include_str!("./classes.ttl"),
+        include_str!("./object_properties.ttl"),
+        include_str!("./data_properties.ttl"),
+    ] {
+        let o = Ontology::parse(turtle, Default::default()).unwrap();
+        let graph = Graph::from(&o);
+        assert!(!graph.is_empty());
+        let reparsed = Ontology::from_graph(&graph, Default::default()).unwrap();
+        assert_eq!(reparsed.iri(), o.iri());
+        assert_eq!(reparsed.declarations(), o.declarations());
+        // the serialization groups the axioms by kind
+        assert_eq!(reparsed.axioms().len(), o.axioms().len());
+        for axiom in o.axioms() {
+            assert!(reparsed.axioms().contains(axiom), "{:?}", axiom);
+        }
+        assert_eq!(
+            reparsed.unmapped_triples().len(),
+            o.unmapped_triples().len()
+        );
+    }
+}
+
+#[test]
+fn reifications_round_trip_in_all_formats() {
+    let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+        :Man rdf:type owl:Class .
+        :Person rdf:type owl:Class .
+        :bla rdf:type owl:AnnotationProperty .
+
+        :Man rdfs:subClassOf :Person .
+        [] rdf:type owl:Axiom ;
+            owl:annotatedSource :Man ;
+            owl:annotatedProperty rdfs:subClassOf ;
+            owl:annotatedTarget :Person ;
+            rdfs:comment "Every man is a person" .
+
+        :Man :bla "test" .
+        [] rdf:type owl:Axiom ;
+            owl:annotatedSource :Man ;
+            owl:annotatedProperty :bla ;
+            owl:annotatedTarget "test" ;
+            rdfs:comment "An annotated annotation" .
+
+        [] rdf:type owl:AllDifferent ;
+            owl:members ( :Bob :Alice ) ;
+            rdfs:label "all different" .
+        "##;
+    let o = Ontology::parse(turtle, Default::default()).unwrap();
+    assert_eq!(o.axioms().len(), 4);
+
+    type Write = fn(&Ontology) -> String;
+    type Parse = fn(&str) -> Ontology;
+    let formats: [(Write, Parse); 4] = [
+        (
+            |o| o.rdf_xml(),
+            |s| Ontology::parse_rdf_xml(s, Default::default()).unwrap(),
+        ),
+        (
+            |o| o.ntriples(),
+            |s| Ontology::parse_ntriples(s, Default::default()).unwrap(),
+        ),
+        (
+            |o| o.nquads(),
+            |s| Ontology::parse_nquads(s, Default::default()).unwrap(),
+        ),
+        (
+            |o| o.jsonld(),
+            |s| Ontology::parse_jsonld(s, Default::default()).unwrap(),
+        ),
+    ];
+    for (write, parse) in formats {
+        let mut current = o.clone();
+        for _ in 0..2 {
+            current = parse(&write(&current));
+            assert_eq!(current.axioms().len(), o.axioms().len());
+        }
+    }
+}
+
+#[test]
+fn ontology_to_triples() {
+    let o = Ontology::parse_ofn(
+        r#"
+Prefix(:=<http://test#>)
+Prefix(rdfs:=<http://www.w3.org/2000/01/rdf-schema#>)
+Ontology(<http://test#>
+Declaration(Class(:A))
+Declaration(Class(:B))
+Declaration(Class(:C))
+SubClassOf(:C ObjectIntersectionOf(:A :B))
+SubClassOf(Annotation(rdfs:comment "B is an A") :B :A)
+)"#,
+        Default::default(),
+    )
+    .unwrap();
+    let graph = o.to_triples();
+    // ontology, declarations, intersection with its collection, reified axiom
+    assert_eq!(graph.len(), 1 + 3 + 7 + 6);
+    // blank nodes are fresh for every mapping
+    assert_eq!(Graph::from(&o).len(), graph.len());
+    assert_eq!(graph.with_predicate(&well_known::rdf_first()).count(), 2);
+
+    let b: Term = iri("http://test#B").into();
+    let annotated_source = well_known::owl_annotatedSource();
+    let reifications: Vec<&Triple> = graph
+        .matching(None, Some(&annotated_source), Some(&b))
+        .collect();
+    assert_eq!(reifications.len(), 1);
+    let comment = well_known::rdfs_comment().as_iri().clone();
+    assert_eq!(
+        graph
+            .objects(&reifications[0].subject, &comment)
+            .collect::<Vec<_>>(),
+        vec![&Literal::string("B is an A").into()]
+    );
+
+    let reparsed = Ontology::from_graph(&graph, Default::default()).unwrap();
+    assert_eq!(reparsed.declarations(), o.declarations());
+    for axiom in o.axioms() {
+        assert!(reparsed.axioms().contains(axiom), "{:?}", axiom);
+    }
+}
@@ -0,0 +1,191 @@
+mod common;
+
+use common::iri;
+use owlish::{
+    api::Ontology,
+    error::{ErrorKind, Position},
+    owl::{
+        well_known, Annotation, AnnotationAssertion, Axiom, ClassConstructor, Declaration, Literal,
+        LiteralOrIRI, ObjectIntersectionOf, ObjectPropertyConstructor, ObjectSomeValuesFrom,
+        SubClassOf,
+    },
+    rdf_xml::ToRdfXml,
+};
+
+const RDF_XML: &str = r##"<?xml version="1.0"?>
+<!DOCTYPE rdf:RDF [
+    <!ENTITY owl "http://www.w3.org/2002/07/owl#" >
+    <!ENTITY xsd "http://www.w3.org/2001/XMLSchema#" >
+]>
+<rdf:RDF xmlns="http://test#"
+     xml:base="http://test"
+     xmlns:owl="http://www.w3.org/2002/07/owl#"
+     xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+     xmlns:rdfs="http://www.w3.org/2000/01/rdf-schema#">
+    <owl:Ontology rdf:about="http://test#"/>
+
+    <owl:Class rdf:about="#Person">
+        <rdfs:label xml:lang="en">Person</rdfs:label>
+    </owl:Class>
+    <owl:Class rdf:about="#Doctor">
+        <rdfs:subClassOf rdf:resource="#Person"/>
+    </owl:Class>
+    <owl:ObjectProperty rdf:about="#hasChild"/>
+    <owl:DatatypeProperty rdf:about="#age"/>
+
+    <rdf:Description rdf:about="#Parent">
+        <rdf:type rdf:resource="&owl;Class"/>
+        <rdfs:subClassOf>
+            <owl:Class>
+                <owl:intersectionOf rdf:parseType="Collection">
+                    <rdf:Description rdf:about="#Person"/>
+                    <owl:Restriction>
+                        <owl:onProperty rdf:resource="#hasChild"/>
+                        <owl:someValuesFrom rdf:resource="#Doctor"/>
+                    </owl:Restriction>
+                </owl:intersectionOf>
+            </owl:Class>
+        </rdfs:subClassOf>
+    </rdf:Description>
+    <owl:Axiom>
+        <owl:annotatedSource rdf:resource="#Doctor"/>
+        <owl:annotatedProperty rdf:resource="http://www.w3.org/2000/01/rdf-schema#subClassOf"/>
+        <owl:annotatedTarget rdf:resource="#Person"/>
+        <rdfs:comment>Doctors are persons</rdfs:comment>
+    </owl:Axiom>
+
+    <owl:NamedIndividual rdf:about="#Bob">
+        <age rdf:datatype="&xsd;integer">42</age>
+    </owl:NamedIndividual>
+</rdf:RDF>
+"##;
+
+#[test]
+fn parse_rdf_xml() {
+    let o = Ontology::parse_rdf_xml(RDF_XML, Default::default()).unwrap();
+    assert_eq!(o.iri(), &iri("http://test#"));
+    assert_eq!(
+        o.declarations(),
+        &vec![
+            Declaration::Class {
+                iri: iri("http://test#Person").into(),
+                annotations: vec![],
+            },
+            Declaration::Class {
+                iri: iri("http://test#Doctor").into(),
+                annotations: vec![],
+            },
+            Declaration::ObjectProperty {
+                iri: iri("http://test#hasChild").into(),
+                annotations: vec![],
+            },
+            Declaration::DataProperty {
+                iri: iri("http://test#age").into(),
+                annotations: vec![],
+            },
+            Declaration::Class {
+                iri: iri("http://test#Parent").into(),
+                annotations: vec![],
+            },
+            Declaration::NamedIndividual {
+                iri: iri("http://test#Bob").into(),
+                annotations: vec![],
+            },
+        ]
+    );
+
+    assert!(o
+        .axioms()
+        .contains(&Axiom::AnnotationAssertion(AnnotationAssertion::new(
+            well_known::rdfs_label(),
+            iri("http://test#Person"),
+            Literal::LangString {
+                string: "Person".into(),
+                lang: "en".try_into().unwrap(),
+            }
+            .into(),
+            vec![],
+            vec![],
+        ))));
+    assert!(o.axioms().contains(&Axiom::SubClassOf(SubClassOf::new(
+        Box::new(iri("http://test#Parent").into()),
+        Box::new(ClassConstructor::ObjectIntersectionOf(
+            ObjectIntersectionOf::new(
+                vec![
+                    iri("http://test#Person").into(),
+                    ObjectSomeValuesFrom::new(
+                        ObjectPropertyConstructor::IRI(iri("http://test#hasChild").into()),
+                        Box::new(iri("http://test#Doctor").into()),
+                        vec![],
+                    )
+                    .into(),
+                ],
+                vec![],
+            )
+        )),
+        vec![],
+    ))));
+    assert!(o.axioms().contains(&Axiom::SubClassOf(SubClassOf::new(
+        Box::new(iri("http://test#Doctor").into()),
+        Box::new(iri("http://test#Person").into()),
+        vec![Annotation::new(
+            well_known::rdfs_comment(),
+            Literal::String("Doctors are persons".into()).into(),
+            vec![],
+        )],
+    ))));
+    assert!(o.axioms().iter().any(|a| matches!(
+        a,
+        Axiom::DataPropertyAssertion(dpa) if dpa.value == Literal::Number {
+            number: 42.into(),
+            type_iri: Some(well_known::xsd_integer()),
+        }
+    )));
+}
+
+#[test]
+fn rdf_xml_literals_are_escaped() {
+    let o = Ontology::parse(
+        r#"
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        <http://test#> a owl:Ontology .
+        <http://test#A> a owl:Class ;
+            rdfs:comment "<b>bold</b> & \"quoted\"" .
+        "#,
+        Default::default(),
+    )
+    .unwrap();
+    let xml = o.rdf_xml();
+    assert!(xml.contains("&lt;b&gt;bold&lt;/b&gt; &amp; \"quoted\""));
+    let reparsed = Ontology::parse_rdf_xml(&xml, Default::default()).unwrap();
+    let Axiom::AnnotationAssertion(a) = &reparsed.axioms()[0] else {
+        panic!(
+            "Expected an annotation assertion, got {:?}",
+            reparsed.axioms()
+        );
+    };
+    assert_eq!(
+        a.value,
+        LiteralOrIRI::Literal(Literal::String("<b>bold</b> & \"quoted\"".into()))
+    );
+}
+
+#[test]
+fn rdf_xml_errors() {
+    let e = Ontology::parse_rdf_xml(
+        "<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">\n  <rdf:Description>\n</rdf:RDF>",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert_eq!(e.position(), Some(Position { line: 3, column: 1 }));
+
+    let e = Ontology::parse_rdf_xml(
+        "<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">\n  <Thing/>\n</rdf:RDF>",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert_eq!(e.position(), Some(Position { line: 2, column: 3 }));
+}
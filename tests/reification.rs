@@ -6,6 +6,7 @@ use owlish::{
         Literal, LiteralOrIRI, ObjectPropertyAssertion, SubClassOf, IRI,
     },
     parser::{ParserOptions, ParserOptionsBuilder},
+    serializer::ToTtl,
 };
 
 #[test]
@@ -37,9 +38,9 @@ fn annotations_on_sub_class_of() {
 
     println!("{:#?}", o);
     assert_eq!(o.declarations().len(), 2);
-    assert_eq!(o.axioms().len(), 2);
+    assert_eq!(o.axioms().len(), 1);
     assert_eq!(
-        o.axioms()[1],
+        o.axioms()[0],
         Axiom::SubClassOf(SubClassOf::new(
             IRI::new("http://test#Man").unwrap().into(),
             IRI::new("http://test#Person").unwrap().into(),
@@ -52,6 +53,45 @@ fn annotations_on_sub_class_of() {
     );
 }
 
+/// The annotations stated on an `owl:Axiom` node belong to the reified axiom. They are not
+/// also annotation assertions about the `owl:Axiom` node itself, which would write the
+/// annotation a second time when the ontology is saved.
+#[test]
+fn annotations_on_sub_class_of_are_not_asserted_on_the_reification() {
+    env_logger::try_init().ok();
+    let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+
+        :Man rdf:type owl:Class .
+        :Person rdf:type owl:Class .
+
+        :Man rdfs:subClassOf        :Person .
+        []   rdf:type               owl:Axiom ;
+             owl:annotatedSource    :Man ;
+             owl:annotatedProperty  rdfs:subClassOf ;
+             owl:annotatedTarget    :Person ;
+             rdfs:comment           "States that every man is a person."^^xsd:string .
+
+        "##;
+
+    let o = Ontology::parse(turtle, Default::default()).unwrap();
+    assert!(!o.axioms().iter().any(|a| matches!(
+        a,
+        Axiom::AnnotationAssertion(aa) if aa.subject.is_blank_node()
+    )));
+
+    let ttl = o.ttl();
+    assert_eq!(ttl.matches("States that every man is a person.").count(), 1);
+    let reparsed = Ontology::parse(&ttl, Default::default()).unwrap();
+    assert_eq!(reparsed.axioms(), o.axioms());
+}
+
 /// Reification test:
 /// What is reified: AnnotationAssertion
 /// Reification subject:
@@ -97,7 +137,10 @@ fn annotations_on_annotations() {
         reified_annotation.subject,
         IRI::new("http://test#Man").unwrap().into()
     );
-    assert_eq!(reified_annotation.iri, IRI::new("http://test#bla").unwrap().into());
+    assert_eq!(
+        reified_annotation.iri,
+        IRI::new("http://test#bla").unwrap().into()
+    );
     assert_eq!(
         reified_annotation.value,
         Literal::String("test".into()).into(),
@@ -245,8 +288,8 @@ fn annotations_on_annotations_unsorted() {
     assert_eq!(o.axioms().len(), 2);
 
     let Axiom::AnnotationAssertion(reified_annotation) = o.axioms()[1].clone() else {
-            panic!("Did not parse as AnnotationAssertion");
-        };
+        panic!("Did not parse as AnnotationAssertion");
+    };
     assert_eq!(
         reified_annotation.subject,
         IRI::new("http://field33.com/dataset/foobar#7025935")
@@ -322,8 +365,8 @@ fn annotations_on_data_property_assertions() {
     assert_eq!(o.axioms().len(), 2);
 
     let Axiom::DataPropertyAssertion(dpa) = o.axioms()[1].clone() else {
-            panic!("Not an OPA")
-        };
+        panic!("Not an OPA")
+    };
     assert_eq!(
         dpa.subject,
         IRI::new("http://field33.com/ontologies/@fld33_domain/dora_metrics/Team2")
@@ -408,8 +451,8 @@ fn annotations_on_data_property_assertions_with_iri_object() {
     assert_eq!(o.axioms().len(), 2);
 
     let Axiom::DataPropertyAssertion(dpa) = o.axioms()[1].clone() else {
-            panic!("Not an OPA")
-        };
+        panic!("Not an OPA")
+    };
     assert_eq!(
         dpa.subject,
         IRI::new("http://field33.com/ontologies/@fld33_domain/dora_metrics/Team2")
@@ -548,8 +591,8 @@ fn annotations_on_object_property_assertions_blank_node() {
     assert_eq!(o.declarations().len(), 3);
     assert_eq!(o.axioms().len(), 3);
     let Axiom::ObjectPropertyAssertion(opa) = o.axioms()[2].clone() else {
-            panic!("Not an OPA")
-        };
+        panic!("Not an OPA")
+    };
     assert_eq!(
         opa.iri,
         IRI::new("http://field33.com/ontologies/@fld33/relations/Has")
@@ -664,3 +707,204 @@ vec![]
             ),
         );
 }
+
+/// Reification test:
+/// What is reified: SubClassOf
+/// Reification subject:
+///   - Subject: IRI
+///   - Object: BlankNode (anonymous class expression, stated again for the reification)
+/// Reification ID: BlankNode
+/// Assertions stated on reification: AnnotationAssertion
+#[test]
+fn annotations_on_sub_class_of_class_expression() {
+    env_logger::try_init().ok();
+    let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+
+        :Parent rdf:type owl:Class .
+        :Person rdf:type owl:Class .
+        :hasChild rdf:type owl:ObjectProperty .
+
+        :Parent rdfs:subClassOf [
+            rdf:type owl:Restriction ;
+            owl:onProperty :hasChild ;
+            owl:someValuesFrom :Person
+        ] .
+        []   rdf:type               owl:Axiom ;
+             owl:annotatedSource    :Parent ;
+             owl:annotatedProperty  rdfs:subClassOf ;
+             owl:annotatedTarget    [
+                rdf:type owl:Restriction ;
+                owl:onProperty :hasChild ;
+                owl:someValuesFrom :Person
+             ] ;
+             rdfs:comment           "Every parent has a child." .
+
+        "##;
+
+    harriet::TurtleDocument::parse_full(turtle).unwrap();
+    let o = Ontology::parse(turtle, Default::default()).unwrap();
+
+    let sub_class_ofs: Vec<&SubClassOf> = o
+        .axioms()
+        .iter()
+        .filter_map(|a| match a {
+            Axiom::SubClassOf(sco) => Some(sco),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(sub_class_ofs.len(), 1);
+    assert_eq!(
+        sub_class_ofs[0].annotations,
+        vec![Annotation::new(
+            well_known::rdfs_comment(),
+            LiteralOrIRI::Literal(Literal::String("Every parent has a child.".into())),
+            vec![]
+        )]
+    );
+}
+
+/// Reification test:
+/// What is reified: DataPropertyAssertion, AnnotationAssertion
+/// Reification subject:
+///   - Subject: IRI
+///   - Object: Literal with datatype or language tag
+/// Reification ID: IRI
+/// Assertions stated on reification: -
+#[test]
+fn reification_of_typed_and_language_tagged_literals() {
+    env_logger::try_init().ok();
+    let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+
+        :John rdf:type owl:NamedIndividual .
+        :hasAge rdf:type owl:DatatypeProperty .
+
+        :John :hasAge "51"^^xsd:integer .
+        :John :hasAge "51" .
+        :age rdf:type               owl:Axiom ;
+             owl:annotatedSource    :John ;
+             owl:annotatedProperty  :hasAge ;
+             owl:annotatedTarget    "51"^^xsd:integer .
+
+        :John rdfs:label "John"@en .
+        :John rdfs:label "John"@de .
+        :label rdf:type             owl:Axiom ;
+             owl:annotatedSource    :John ;
+             owl:annotatedProperty  rdfs:label ;
+             owl:annotatedTarget    "John"@de .
+
+        "##;
+
+    harriet::TurtleDocument::parse_full(turtle).unwrap();
+    let o = Ontology::parse(turtle, Default::default()).unwrap();
+
+    let age = ResourceId::from(IRI::new("http://test#age").unwrap());
+    let data_prop_assertions: Vec<&DataPropertyAssertion> = o
+        .axioms()
+        .iter()
+        .filter_map(|a| match a {
+            Axiom::DataPropertyAssertion(dpa) => Some(dpa),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(data_prop_assertions.len(), 2);
+    for dpa in data_prop_assertions {
+        match &dpa.value {
+            Literal::Number { .. } => assert_eq!(dpa.resource_ids, vec![age.clone()]),
+            _ => assert!(dpa.resource_ids.is_empty()),
+        }
+    }
+
+    let label = ResourceId::from(IRI::new("http://test#label").unwrap());
+    let labels: Vec<&AnnotationAssertion> = o
+        .axioms()
+        .iter()
+        .filter_map(|a| match a {
+            Axiom::AnnotationAssertion(aa) if aa.iri == well_known::rdfs_label() => Some(aa),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(labels.len(), 2);
+    for aa in labels {
+        match &aa.value {
+            LiteralOrIRI::Literal(Literal::LangString { lang, .. }) if lang.to_string() == "de" => {
+                assert_eq!(aa.resource_ids, vec![label.clone()])
+            }
+            _ => assert!(aa.resource_ids.is_empty()),
+        }
+    }
+}
+
+/// Reified axioms are written with their annotations again, so saving and parsing an ontology
+/// must not add copies of those annotations.
+#[test]
+fn reifications_round_trip() {
+    env_logger::try_init().ok();
+    let turtle = r##"
+        @prefix : <http://test#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        <http://test#> rdf:type owl:Ontology .
+
+        :Man rdf:type owl:Class .
+        :Person rdf:type owl:Class .
+        :bla rdf:type owl:AnnotationProperty .
+
+        :Man rdfs:subClassOf        :Person .
+        []   rdf:type               owl:Axiom ;
+             owl:annotatedSource    :Man ;
+             owl:annotatedProperty  rdfs:subClassOf ;
+             owl:annotatedTarget    :Person ;
+             rdfs:comment           "States that every man is a person."^^xsd:string .
+
+        :Man :bla "test" .
+        []   rdf:type               owl:Axiom ;
+             owl:annotatedSource    :Man ;
+             owl:annotatedProperty  :bla ;
+             owl:annotatedTarget    "test" ;
+             rdfs:comment           "An annotated annotation." .
+
+        [] rdf:type owl:AllDifferent ;
+            owl:members ( :Bob :Alice ) ;
+            rdfs:label "all different" .
+
+        "##;
+
+    let o = Ontology::parse(turtle, Default::default()).unwrap();
+    assert_eq!(o.axioms().len(), 4);
+
+    let mut current = o.clone();
+    for _ in 0..2 {
+        let reparsed = Ontology::parse(&current.ttl(), Default::default()).unwrap();
+        assert_eq!(reparsed.axioms().len(), o.axioms().len());
+        for axiom in o.axioms() {
+            match axiom {
+                // anonymous resource ids are relabeled
+                Axiom::AnnotationAssertion(aa) if aa.subject.is_blank_node() => {}
+                Axiom::AnnotationAssertion(aa) => assert!(reparsed
+                    .axioms()
+                    .iter()
+                    .any(|a| matches!(a, Axiom::AnnotationAssertion(r)
+                        if r.iri == aa.iri && r.value == aa.value && r.annotations == aa.annotations))),
+                axiom => assert!(reparsed.axioms().contains(axiom), "{:?}", axiom),
+            }
+        }
+        current = reparsed;
+    }
+}
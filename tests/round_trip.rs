@@ -0,0 +1,117 @@
+//! Round trips of the test ontologies through the serialization formats. The edge cases of a
+//! format are tested with the format.
+mod common;
+
+use owlish::{
+    api::Ontology, error::Error, jsonld::ToJsonLd, ntriples::ToNTriples, ofn::ToOfn, owl::Axiom,
+    owl_xml::ToOwlXml, rdf_xml::ToRdfXml,
+};
+
+/// A serialization format and what a round trip through it keeps.
+struct Format {
+    name: &'static str,
+    write: fn(&Ontology) -> String,
+    read: fn(&str) -> Result<Ontology, Error>,
+    /// The format writes the axioms instead of their mapping to RDF. It keeps the imports and
+    /// all constructs of `common::OFN`, but not the triples which aren't mapped to axioms.
+    structural: bool,
+    /// The labels of blank nodes don't depend on the internal ids of the nodes, so writing the
+    /// reparsed ontology gives the same output.
+    stable: bool,
+}
+
+const FORMATS: &[Format] = &[
+    Format {
+        name: "OFN",
+        write: |o| o.ofn(),
+        read: |s| Ontology::parse_ofn(s, Default::default()),
+        structural: true,
+        stable: false,
+    },
+    Format {
+        name: "RDF/XML",
+        write: |o| o.rdf_xml(),
+        read: |s| Ontology::parse_rdf_xml(s, Default::default()),
+        structural: false,
+        stable: true,
+    },
+    Format {
+        name: "N-Triples",
+        write: |o| o.ntriples(),
+        read: |s| Ontology::parse_ntriples(s, Default::default()),
+        structural: false,
+        stable: true,
+    },
+    Format {
+        name: "OWL/XML",
+        write: |o| o.owl_xml(),
+        read: |s| Ontology::parse_owl_xml(s, Default::default()),
+        structural: true,
+        stable: false,
+    },
+    Format {
+        name: "JSON-LD",
+        write: |o| o.jsonld(),
+        read: |s| Ontology::parse_jsonld(s, Default::default()),
+        structural: false,
+        stable: true,
+    },
+];
+
+const TURTLE: &[(&str, &str)] = &[
+    ("classes.ttl", include_str!("./classes.ttl")),
+    (
+        "object_properties.ttl",
+        include_str!("./object_properties.ttl"),
+    ),
+    ("data_properties.ttl", include_str!("./data_properties.ttl")),
+];
+
+#[test]
+fn round_trip() {
+    for format in FORMATS {
+        if format.structural {
+            let o = Ontology::parse_ofn(common::OFN, Default::default()).unwrap();
+            assert_round_trip(format, "common::OFN", &o);
+        }
+        for (name, turtle) in TURTLE {
+            let o = Ontology::parse(turtle, Default::default()).unwrap();
+            assert_round_trip(format, name, &o);
+        }
+    }
+}
+
+fn assert_round_trip(format: &Format, name: &str, o: &Ontology) {
+    let context = format!("{} round trip of {}", format.name, name);
+    let written = (format.write)(o);
+    let reparsed = (format.read)(&written).unwrap_or_else(|e| panic!("{}: {}", context, e));
+    assert_eq!(reparsed.iri(), o.iri(), "{}", context);
+    assert_eq!(reparsed.declarations(), o.declarations(), "{}", context);
+    // the formats group the axioms differently, which changes their order
+    assert_eq!(reparsed.axioms().len(), o.axioms().len(), "{}", context);
+    for axiom in o.axioms() {
+        // anonymous individuals are relabelled
+        if matches!(axiom, Axiom::AnnotationAssertion(a) if a.subject.is_blank_node()) {
+            continue;
+        }
+        assert!(
+            reparsed.axioms().contains(axiom),
+            "{}: {:?}",
+            context,
+            axiom
+        );
+    }
+    if format.structural {
+        assert_eq!(reparsed.imports(), o.imports(), "{}", context);
+    } else {
+        assert_eq!(
+            reparsed.unmapped_triples().len(),
+            o.unmapped_triples().len(),
+            "{}",
+            context
+        );
+    }
+    if format.stable {
+        assert_eq!((format.write)(&reparsed), written, "{}", context);
+    }
+}
@@ -0,0 +1,521 @@
+use owlish::{
+    api::Ontology,
+    ofn::ToOfn,
+    owl::{well_known, Annotation, Axiom, IRIList, Literal, ResourceId, SameIndividual, IRI},
+    parser::ParserOptions,
+    serializer::{SubjectOrder, ToTtl, TtlOptions},
+};
+
+const OFN: &str = r#"
+Prefix(:=<http://test#>)
+
+Ontology(<http://test#>
+Declaration(Class(:Person))
+Declaration(Class(:Man))
+Declaration(Class(:Woman))
+Declaration(Class(:Parent))
+Declaration(ObjectProperty(:hasChild))
+Declaration(DataProperty(:age))
+Declaration(NamedIndividual(:Bob))
+SubClassOf(Annotation(rdfs:comment "Men are persons") :Man :Person)
+SubClassOf(:Woman :Person)
+EquivalentClasses(:Parent ObjectSomeValuesFrom(:hasChild :Person))
+EquivalentClasses(:Person ObjectUnionOf(:Man :Woman))
+ClassAssertion(:Man :Bob)
+ClassAssertion(:Parent :Bob)
+DataPropertyAssertion(:age :Bob "42"^^xsd:integer)
+)"#;
+
+const SHUFFLED: &str = r#"
+Prefix(:=<http://test#>)
+
+Ontology(<http://test#>
+Declaration(NamedIndividual(:Bob))
+Declaration(DataProperty(:age))
+Declaration(ObjectProperty(:hasChild))
+Declaration(Class(:Parent))
+Declaration(Class(:Woman))
+Declaration(Class(:Man))
+Declaration(Class(:Person))
+DataPropertyAssertion(:age :Bob "42"^^xsd:integer)
+ClassAssertion(:Parent :Bob)
+ClassAssertion(:Man :Bob)
+EquivalentClasses(:Person ObjectUnionOf(:Man :Woman))
+EquivalentClasses(:Parent ObjectSomeValuesFrom(:hasChild :Person))
+SubClassOf(:Woman :Person)
+SubClassOf(Annotation(rdfs:comment "Men are persons") :Man :Person)
+)"#;
+
+/// Parses the functional syntax and registers the prefixes used for the Turtle output, in the
+/// given order.
+fn parse(ofn: &str, prefixes: &[(&str, IRI)]) -> Ontology {
+    let mut onto = Ontology::parse_ofn(ofn, Default::default()).unwrap();
+    for (name, iri) in prefixes {
+        onto.push_import(name, iri.clone());
+    }
+    onto
+}
+
+fn prefixes() -> Vec<(&'static str, IRI)> {
+    vec![
+        ("", IRI::new("http://test#").unwrap()),
+        ("owl", well_known::owl()),
+        ("rdf", well_known::rdf()),
+        ("rdfs", well_known::rdfs()),
+        ("xsd", well_known::xsd()),
+    ]
+}
+
+#[test]
+fn canonical_output_is_deterministic() {
+    let onto = parse(OFN, &prefixes());
+    let shuffled = parse(SHUFFLED, &prefixes().into_iter().rev().collect::<Vec<_>>());
+    let canonical = onto.ttl_with_options(&TtlOptions::canonical());
+    assert_eq!(
+        canonical,
+        shuffled.ttl_with_options(&TtlOptions::canonical())
+    );
+    // blank nodes are fresh for every mapping
+    assert_eq!(canonical, onto.ttl_with_options(&TtlOptions::canonical()));
+
+    // the axioms are still written in mapping order by default
+    assert_ne!(
+        onto.ttl_with_options(&TtlOptions::default()),
+        shuffled.ttl_with_options(&TtlOptions::default())
+    );
+
+    let reparsed = Ontology::parse(&canonical, Default::default()).unwrap();
+    assert_eq!(reparsed.declarations().len(), onto.declarations().len());
+    for declaration in onto.declarations() {
+        assert!(reparsed.declarations().contains(declaration));
+    }
+    for axiom in onto.axioms() {
+        assert!(reparsed.axioms().contains(axiom), "{:?}", axiom);
+    }
+}
+
+#[test]
+fn canonical_output() {
+    let onto = parse(OFN, &prefixes());
+    let ttl = onto.ttl_with_options(&TtlOptions::canonical());
+    assert!(ttl.starts_with(
+        "@prefix : <http://test#> .\n@prefix owl: <http://www.w3.org/2002/07/owl#> .\n"
+    ));
+    assert!(ttl.contains(
+        "\n##### Declarations #####\n\n:Bob a owl:NamedIndividual .\n\n:Man a owl:Class .\n"
+    ));
+    assert!(ttl.contains(
+        ":Person owl:equivalentClass [\n        a owl:Class ;\n        owl:unionOf ( :Man :Woman )\n    ] .\n"
+    ));
+    assert!(ttl.contains(":Bob a :Man , :Parent .\n"));
+    assert!(ttl.contains(":Bob :age 42 .\n"));
+}
+
+#[test]
+fn sections() {
+    let onto = parse(OFN, &prefixes());
+    let ttl = onto.ttl();
+    let sections: Vec<&str> = ttl
+        .lines()
+        .filter(|line| line.starts_with("#####"))
+        .collect();
+    assert_eq!(
+        sections,
+        vec![
+            "##### Declarations #####",
+            "##### Classes #####",
+            "##### ClassAssertions #####",
+            "##### DataPropertyAssertions #####",
+        ]
+    );
+
+    let classes =
+        &ttl[ttl.find("##### Classes").unwrap()..ttl.find("##### ClassAssertions").unwrap()];
+    assert!(classes.contains(":Woman rdfs:subClassOf :Person"));
+    assert!(!classes.contains(":Bob"));
+}
+
+#[test]
+fn ttl_without_options() {
+    // implementations of `ToTtl` outside of owlish only need to provide `ttl`
+    struct Fixed;
+    impl ToTtl for Fixed {
+        fn ttl(&self) -> String {
+            "<http://test#> a <http://www.w3.org/2002/07/owl#Ontology> .\n".into()
+        }
+    }
+    assert_eq!(
+        Fixed.ttl_with_options(&TtlOptions::canonical()),
+        Fixed.ttl()
+    );
+}
+
+#[test]
+fn pretty_printing_options() {
+    let onto = parse(OFN, &prefixes());
+    let canonical = || TtlOptions::builder().subject_order(SubjectOrder::Sorted);
+
+    let ttl = onto.ttl_with_options(&canonical().section_comments(false).build());
+    assert!(!ttl.contains("#####"));
+
+    let ttl = onto.ttl_with_options(&canonical().group_objects(false).build());
+    assert!(ttl.contains(":Bob a :Man ;\n    a :Parent .\n"));
+
+    let ttl = onto.ttl_with_options(&canonical().group_predicates(false).build());
+    assert!(ttl.contains(":Bob a :Man , :Parent .\n"));
+    assert!(ttl.contains("\n_:b0 a owl:Axiom .\n_:b0 rdfs:comment \"Men are persons\" .\n"));
+
+    let ttl = onto.ttl_with_options(&canonical().inline_blank_nodes(false).build());
+    assert!(!ttl.contains(" ["));
+    assert!(ttl.contains(":Person owl:equivalentClass _:b"));
+
+    let ttl = onto.ttl_with_options(&canonical().collections(false).build());
+    assert!(!ttl.contains('('));
+    assert!(ttl.contains("rdf:first :Man"));
+    assert!(ttl.contains("rdf:rest rdf:nil"));
+
+    let ttl = onto.ttl_with_options(&canonical().indent(2).build());
+    assert!(ttl.contains(
+        ":Person owl:equivalentClass [\n    a owl:Class ;\n    owl:unionOf ( :Man :Woman )\n  ] .\n"
+    ));
+
+    for options in [
+        canonical().section_comments(false).build(),
+        canonical()
+            .group_objects(false)
+            .group_predicates(false)
+            .build(),
+        canonical()
+            .inline_blank_nodes(false)
+            .collections(false)
+            .build(),
+        TtlOptions::default(),
+    ] {
+        let reparsed =
+            Ontology::parse(&onto.ttl_with_options(&options), Default::default()).unwrap();
+        for axiom in onto.axioms() {
+            assert!(reparsed.axioms().contains(axiom), "{:?}", axiom);
+        }
+    }
+}
+
+const LAYOUT: &str = r#"@base <http://test/> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+@prefix : <http://test#> .
+@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+
+<http://test#> rdf:type owl:Ontology .
+
+# People
+# and everybody else
+:Person rdf:type owl:Class . # Man is not commented
+
+:Man rdf:type owl:Class ;
+    rdfs:subClassOf :Person .
+"#;
+
+#[test]
+fn document_layout() {
+    let onto = Ontology::parse(LAYOUT, Default::default()).unwrap();
+    assert_eq!(onto.base(), Some(&IRI::new("http://test/").unwrap()));
+    assert_eq!(
+        onto.prefixes()
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["xsd", "owl", "rdfs", "", "rdf"]
+    );
+    // comments are only retained on request
+    assert!(onto.comments().is_empty());
+
+    let header = "@base <http://test/> .\n\
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n\
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .\n\
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .\n\
+        @prefix : <http://test#> .\n\
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .\n";
+    assert!(onto
+        .ttl_with_options(&TtlOptions::default())
+        .starts_with(header));
+    assert!(onto.ttl().starts_with(&header.replace(" .\n", " . \n")));
+
+    let options = ParserOptions::builder().comments(true).build();
+    let onto = Ontology::parse(LAYOUT, options.clone()).unwrap();
+    let comments = vec![(
+        IRI::new("http://test#Person").unwrap(),
+        " People\n and everybody else".to_string(),
+    )];
+    assert_eq!(onto.comments(), &comments);
+    assert!(onto
+        .ttl_with_options(&TtlOptions::default())
+        .contains("\n# People\n# and everybody else\n:Person a owl:Class .\n"));
+
+    let reparsed = Ontology::parse(&onto.ttl(), options).unwrap();
+    assert_eq!(reparsed.base(), onto.base());
+    assert_eq!(reparsed.prefixes(), onto.prefixes());
+    assert_eq!(reparsed.comments(), &comments);
+}
+
+#[test]
+fn canonical_output_ignores_document_layout() {
+    let options = ParserOptions::builder().comments(true).build();
+    let onto = Ontology::parse(LAYOUT, options.clone()).unwrap();
+    let mut lines: Vec<&str> = LAYOUT.lines().collect();
+    lines[1..6].reverse();
+    let reordered = Ontology::parse(&lines.join("\n"), options).unwrap();
+    assert_ne!(reordered.prefixes(), onto.prefixes());
+
+    let canonical = onto.ttl_with_options(&TtlOptions::canonical());
+    assert_eq!(
+        canonical,
+        reordered.ttl_with_options(&TtlOptions::canonical())
+    );
+    assert!(canonical.starts_with(
+        "@prefix : <http://test#> .\n\
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .\n\
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .\n\
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .\n\
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n"
+    ));
+    assert!(!canonical.contains("# People"));
+}
+
+#[test]
+fn individual_equality() {
+    let onto = parse(
+        r#"
+Prefix(:=<http://test#>)
+
+Ontology(<http://test#>
+Declaration(NamedIndividual(:a))
+Declaration(NamedIndividual(:b))
+Declaration(NamedIndividual(:c))
+SameIndividual(Annotation(rdfs:comment "all the same") :a :b :c)
+DifferentIndividuals(:a :b :c)
+)"#,
+        &prefixes(),
+    );
+    let ttl = onto.ttl();
+    assert!(ttl.contains(":a owl:sameAs :b"));
+    assert!(ttl.contains(":b owl:sameAs :c"));
+
+    let reparsed = Ontology::parse(&ttl, Default::default()).unwrap();
+    let comment = Annotation::new(
+        well_known::rdfs_comment(),
+        Literal::String("all the same".into()).into(),
+        vec![],
+    );
+    for (a, b) in [("a", "b"), ("b", "c")] {
+        let same = Axiom::SameIndividual(SameIndividual::new(
+            vec![
+                IRI::new(&format!("http://test#{}", a)).unwrap().into(),
+                IRI::new(&format!("http://test#{}", b)).unwrap().into(),
+            ],
+            vec![comment.clone()],
+        ));
+        assert!(reparsed.axioms().contains(&same), "{:?}", same);
+    }
+    let different = onto
+        .axioms()
+        .iter()
+        .find(|a| matches!(a, Axiom::DifferentIndividuals(_)))
+        .unwrap();
+    assert!(reparsed.axioms().contains(different));
+}
+
+#[test]
+fn negative_property_assertions() {
+    let onto = parse(
+        r#"
+Prefix(:=<http://test#>)
+
+Ontology(<http://test#>
+Declaration(ObjectProperty(:hasChild))
+Declaration(DataProperty(:age))
+Declaration(NamedIndividual(:Bob))
+Declaration(NamedIndividual(:Alice))
+NegativeObjectPropertyAssertion(Annotation(rdfs:comment "not a child") :hasChild :Bob :Alice)
+NegativeDataPropertyAssertion(Annotation(rdfs:comment "not 42") :age :Bob "42"^^xsd:integer)
+)"#,
+        &prefixes(),
+    );
+    for ttl in [onto.ttl(), onto.ttl_with_options(&TtlOptions::canonical())] {
+        assert!(ttl.contains("owl:NegativePropertyAssertion"));
+        let reparsed = Ontology::parse(&ttl, Default::default()).unwrap();
+        assert!(reparsed.unmapped_triples().is_empty());
+        for axiom in onto.axioms() {
+            assert!(reparsed.axioms().contains(axiom), "{:?}", axiom);
+        }
+    }
+}
+
+#[test]
+fn datatype_definitions() {
+    let onto = parse(
+        r#"
+Prefix(:=<http://test#>)
+
+Ontology(<http://test#>
+Declaration(Datatype(:age))
+Declaration(Datatype(:code))
+Declaration(Datatype(:english))
+Declaration(Datatype(:notAnInteger))
+Declaration(Datatype(:number))
+Declaration(Datatype(:positive))
+Declaration(DataProperty(:hasAge))
+Declaration(Class(:Adult))
+DatatypeDefinition(:age DatatypeRestriction(xsd:integer xsd:minInclusive "0"^^xsd:integer xsd:maxExclusive "150"^^xsd:integer xsd:totalDigits "3"^^xsd:integer))
+DatatypeDefinition(Annotation(rdfs:comment "three capitals") :code DatatypeRestriction(xsd:string xsd:pattern "[A-Z]+" xsd:length "3"^^xsd:integer xsd:minLength "3"^^xsd:integer xsd:maxLength "3"^^xsd:integer))
+DatatypeDefinition(:english DatatypeRestriction(rdf:PlainLiteral rdf:langRange "en"))
+DatatypeDefinition(:notAnInteger DataComplementOf(xsd:integer))
+DatatypeDefinition(:number DataUnionOf(xsd:integer xsd:decimal DataOneOf("1.5"^^xsd:decimal)))
+DatatypeDefinition(:positive DataIntersectionOf(xsd:integer DatatypeRestriction(xsd:integer xsd:minExclusive "0"^^xsd:integer xsd:maxInclusive "99"^^xsd:integer xsd:fractionDigits "0"^^xsd:integer)))
+SubClassOf(:Adult DataSomeValuesFrom(:hasAge DatatypeRestriction(xsd:integer xsd:minInclusive "18"^^xsd:integer)))
+)"#,
+        &prefixes(),
+    );
+    assert_eq!(
+        onto.axioms()
+            .iter()
+            .filter(|a| matches!(a, Axiom::DatatypeDefinition(_)))
+            .count(),
+        6
+    );
+    for ttl in [onto.ttl(), onto.ttl_with_options(&TtlOptions::canonical())] {
+        assert!(ttl.contains("xsd:pattern \"[A-Z]+\""));
+        assert!(ttl.contains("rdf:langRange \"en\""));
+        let reparsed = Ontology::parse(&ttl, Default::default()).unwrap();
+        assert!(
+            reparsed.unmapped_triples().is_empty(),
+            "{:?}\n{}",
+            reparsed.unmapped_triples(),
+            ttl
+        );
+        for axiom in onto.axioms() {
+            assert!(reparsed.axioms().contains(axiom), "{:?}", axiom);
+        }
+    }
+}
+
+#[test]
+fn has_key() {
+    let onto = parse(
+        r#"
+Prefix(:=<http://test#>)
+
+Ontology(<http://test#>
+Declaration(Class(:Person))
+Declaration(Class(:Employee))
+Declaration(ObjectProperty(:worksFor))
+Declaration(DataProperty(:hasSSN))
+Declaration(DataProperty(:employeeId))
+HasKey(:Person () (:hasSSN))
+HasKey(Annotation(rdfs:comment "unique per employer") ObjectIntersectionOf(:Person :Employee) (:worksFor) (:employeeId))
+)"#,
+        &prefixes(),
+    );
+    for ttl in [onto.ttl(), onto.ttl_with_options(&TtlOptions::canonical())] {
+        let reparsed = Ontology::parse(&ttl, Default::default()).unwrap();
+        assert!(
+            reparsed.unmapped_triples().is_empty(),
+            "{:?}\n{}",
+            reparsed.unmapped_triples(),
+            ttl
+        );
+        let keys: Vec<_> = reparsed
+            .axioms()
+            .iter()
+            .filter(|a| matches!(a, Axiom::HasKey(_)))
+            .collect();
+        assert_eq!(keys.len(), 2, "{}", ttl);
+        for axiom in onto.axioms() {
+            assert!(reparsed.axioms().contains(axiom), "{:?}\n{}", axiom, ttl);
+        }
+    }
+}
+
+#[test]
+fn anonymous_individuals() {
+    let turtle = r#"
+@prefix : <http://test#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+<http://test#> rdf:type owl:Ontology .
+:Order rdf:type owl:Class .
+:OrderLine rdf:type owl:Class .
+:hasLine rdf:type owl:ObjectProperty .
+:qty rdf:type owl:DatatypeProperty .
+:order rdf:type :Order ;
+    :hasLine [ rdf:type :OrderLine ; :qty 3 ] , _:second .
+_:second rdf:type :OrderLine ;
+    :qty 5 ;
+    owl:differentFrom :order .
+"#;
+    let onto = Ontology::parse(turtle, Default::default()).unwrap();
+    assert!(
+        onto.unmapped_triples().is_empty(),
+        "{:?}",
+        onto.unmapped_triples()
+    );
+    assert_anonymous_lines(&onto);
+
+    for ttl in [onto.ttl(), onto.ttl_with_options(&TtlOptions::canonical())] {
+        let reparsed = Ontology::parse(&ttl, Default::default()).unwrap();
+        assert!(reparsed.unmapped_triples().is_empty(), "{}", ttl);
+        assert_eq!(reparsed.axioms().len(), onto.axioms().len(), "{}", ttl);
+        assert_anonymous_lines(&reparsed);
+    }
+    let reparsed = Ontology::parse_ofn(&onto.ofn(), Default::default()).unwrap();
+    assert_anonymous_lines(&reparsed);
+}
+
+/// Both order lines are anonymous and each is typed and quantified by the same blank node.
+fn assert_anonymous_lines(onto: &Ontology) {
+    let lines: Vec<ResourceId> = onto
+        .axioms()
+        .iter()
+        .filter_map(|a| match a {
+            Axiom::ObjectPropertyAssertion(a) => match &a.object {
+                IRIList::BlankNode(bn) => Some(bn.clone().into()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    assert_eq!(lines.len(), 2, "{:?}", onto.axioms());
+    for line in &lines {
+        assert!(onto.axioms().iter().any(|a| matches!(
+            a,
+            Axiom::ClassAssertion(ca) if &ca.individual == line
+        )));
+        assert!(onto.axioms().iter().any(|a| matches!(
+            a,
+            Axiom::DataPropertyAssertion(dpa) if &dpa.subject == line
+        )));
+    }
+    assert!(onto.axioms().iter().any(|a| matches!(
+        a,
+        Axiom::DifferentIndividuals(di) if lines.contains(&di.individuals[0])
+    )));
+}
+
+#[test]
+fn prefixed_names_round_trip() {
+    for turtle in [
+        include_str!("./apqc.ttl"),
+        include_str!("./object_properties.ttl"),
+    ] {
+        let o = Ontology::parse(turtle, Default::default()).unwrap();
+        let ttl = o.ttl();
+        harriet::TurtleDocument::parse_full(&ttl)
+            .map_err(|e| format!("{}...", &format!("{:?}", e)[..200]))
+            .expect("Could not parse with harriet");
+        let reparsed = Ontology::parse(&ttl, Default::default()).unwrap();
+        assert_eq!(reparsed.declarations().len(), o.declarations().len());
+        assert_eq!(reparsed.axioms().len(), o.axioms().len());
+    }
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::iri;
+use owlish::{
+    api::Ontology,
+    owl::ResourceId,
+    rdf::{Literal, Term, Triple},
+    serializer::ToTtl,
+};
+
+const TURTLE: &str = r##"
+@prefix : <http://test#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+<http://test#> rdf:type owl:Ontology .
+
+:Person rdf:type owl:Class .
+:Man rdf:type owl:Class .
+:Man rdfs:subClassOf :Person .
+:Man rdfs:label "Man" .
+
+:Man skos:broader :Person .
+:Man skos:prefLabel "man"@en .
+:Man :weight "1.50"^^xsd:decimal .
+:Man :related _:x .
+_:x :name "x" .
+"##;
+
+#[test]
+fn unmapped_triples() {
+    let o = Ontology::parse(TURTLE, Default::default()).unwrap();
+    assert_eq!(o.declarations().len(), 2);
+    assert_eq!(o.axioms().len(), 2);
+
+    let unmapped = o.unmapped_triples();
+    assert_eq!(unmapped.len(), 5);
+    assert_eq!(
+        unmapped[0],
+        Triple::new(
+            iri("http://test#Man"),
+            iri("http://www.w3.org/2004/02/skos/core#broader"),
+            iri("http://test#Person"),
+        )
+    );
+    assert_eq!(
+        unmapped[1].object,
+        Term::Literal(Literal::lang_string("man", "en"))
+    );
+    // the lexical form of unmapped literals is preserved
+    assert_eq!(
+        unmapped[2].object,
+        Term::Literal(Literal::typed(
+            "1.50",
+            iri("http://www.w3.org/2001/XMLSchema#decimal")
+        ))
+    );
+    let Term::BlankNode(bn) = &unmapped[3].object else {
+        panic!("Expected a blank node, got {:?}", unmapped[3].object);
+    };
+    assert_eq!(unmapped[4].subject, ResourceId::BlankNode(bn.clone()));
+}
+
+#[test]
+fn unmapped_triples_round_trip() {
+    let o = Ontology::parse(TURTLE, Default::default()).unwrap();
+    let ttl = o.ttl();
+    // the prefixes of the document are used again
+    assert!(ttl.contains("\"1.50\"^^xsd:decimal"));
+
+    let reparsed = Ontology::parse(&ttl, Default::default()).unwrap();
+    assert_eq!(reparsed.declarations(), o.declarations());
+    assert_eq!(reparsed.axioms(), o.axioms());
+    assert_eq!(reparsed.unmapped_triples().len(), 5);
+    for (a, b) in o.unmapped_triples().iter().zip(reparsed.unmapped_triples()) {
+        assert_eq!(a.predicate, b.predicate);
+        if let Term::Literal(_) = a.object {
+            assert_eq!(a.object, b.object);
+        }
+    }
+    // blank nodes keep their identity
+    let Term::BlankNode(bn) = &reparsed.unmapped_triples()[3].object else {
+        panic!("Expected a blank node");
+    };
+    assert_eq!(
+        reparsed.unmapped_triples()[4].subject,
+        ResourceId::BlankNode(bn.clone())
+    );
+}
+
+#[test]
+fn lists_with_literals_are_unmapped() {
+    let turtle = r##"
+@prefix : <http://test#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+
+<http://test#> rdf:type owl:Ontology .
+:hasMember rdf:type owl:ObjectProperty .
+:s :hasMember ( :a :b "lit" ) .
+"##;
+    let o = Ontology::parse(turtle, Default::default()).unwrap();
+    assert!(o.axioms().is_empty(), "{:?}", o.axioms());
+    // the statement and the three cells of the list
+    assert_eq!(o.unmapped_triples().len(), 7);
+    assert!(o
+        .unmapped_triples()
+        .iter()
+        .any(|t| t.object == Term::Literal(Literal::string("lit"))));
+
+    let reparsed = Ontology::parse(&o.ttl(), Default::default()).unwrap();
+    assert!(reparsed.axioms().is_empty());
+    assert_eq!(reparsed.unmapped_triples().len(), 7);
+}